@@ -1,6 +1,9 @@
 pub mod utils;
 
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+/// Maximum number of signers in an admin council.
+pub const MAX_COUNCIL_MEMBERS: usize = 8;
 
 pub trait Ownable {
     fn owner(&self) -> &Pubkey;
@@ -20,8 +23,335 @@ pub trait OwnerAssistant: Ownable {
     fn owner_assistant_mut(&mut self) -> &mut Pubkey;
 }
 
+/// Maximum number of keys that can hold roles at once.
+pub const MAX_ROLE_HOLDERS: usize = 8;
+
+/// Admin role that can be granted to a key. The owner and owner assistant implicitly hold every
+/// role.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum Role {
+    /// Can pause and unpause the program.
+    Pauser,
+
+    /// Can manage router endpoints.
+    EndpointManager,
+
+    /// Can manage fees and fee recipients.
+    FeeManager,
+
+    /// Can propose auction parameters.
+    AuctionParameterProposer,
+}
+
+impl Role {
+    fn mask(&self) -> u8 {
+        match self {
+            Role::Pauser => 1,
+            Role::EndpointManager => 2,
+            Role::FeeManager => 4,
+            Role::AuctionParameterProposer => 8,
+        }
+    }
+}
+
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct RoleHolder {
+    pub authority: Pubkey,
+
+    /// Bitmap of granted roles. A holder without roles is an empty slot.
+    pub roles: u8,
+}
+
+impl RoleHolder {
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles & role.mask() != 0
+    }
+}
+
+/// Keys that were granted roles.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct Roles {
+    pub holders: [RoleHolder; MAX_ROLE_HOLDERS],
+}
+
+impl Roles {
+    pub fn has_role(&self, authority: &Pubkey, role: Role) -> bool {
+        self.holders
+            .iter()
+            .any(|holder| &holder.authority == authority && holder.has_role(role))
+    }
+
+    /// Grant the role to the authority. Returns false if the authority does not hold any roles yet
+    /// and there is no room for another holder.
+    pub fn grant(&mut self, authority: &Pubkey, role: Role) -> bool {
+        let holder = match self
+            .holders
+            .iter()
+            .position(|holder| holder.roles != 0 && &holder.authority == authority)
+            .or_else(|| self.holders.iter().position(|holder| holder.roles == 0))
+        {
+            Some(index) => &mut self.holders[index],
+            None => return false,
+        };
+
+        holder.authority = *authority;
+        holder.roles |= role.mask();
+
+        true
+    }
+
+    /// Revoke the role from the authority. Returns false if the authority does not hold the role.
+    pub fn revoke(&mut self, authority: &Pubkey, role: Role) -> bool {
+        match self
+            .holders
+            .iter_mut()
+            .find(|holder| &holder.authority == authority && holder.has_role(role))
+        {
+            Some(holder) => {
+                holder.roles &= !role.mask();
+                if holder.roles == 0 {
+                    *holder = Default::default();
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// M-of-N council whose members can collectively approve admin actions. A council with a zero
+/// threshold is disabled.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct CouncilInfo {
+    /// Incremented whenever the council is replaced so that approvals collected by a previous
+    /// council cannot be used.
+    pub nonce: u32,
+
+    /// Number of member approvals required to perform an action.
+    pub threshold: u8,
+
+    pub num_members: u8,
+    pub members: [Pubkey; MAX_COUNCIL_MEMBERS],
+}
+
+impl CouncilInfo {
+    pub fn is_enabled(&self) -> bool {
+        self.threshold > 0
+    }
+
+    pub fn members(&self) -> &[Pubkey] {
+        &self.members[..usize::from(self.num_members).min(MAX_COUNCIL_MEMBERS)]
+    }
+
+    pub fn member_index(&self, key: &Pubkey) -> Option<u8> {
+        self.members()
+            .iter()
+            .position(|member| member == key)
+            .and_then(|index| u8::try_from(index).ok())
+    }
+
+    /// Replace the council's members and threshold, which invalidates all outstanding approvals.
+    /// Passing no members and a zero threshold disables the council. Returns false if the members
+    /// or threshold are invalid, in which case the council is left unchanged.
+    pub fn update(&mut self, members: &[Pubkey], threshold: u8) -> bool {
+        let num_members = match u8::try_from(members.len()) {
+            Ok(num_members) if members.len() <= MAX_COUNCIL_MEMBERS => num_members,
+            _ => return false,
+        };
+
+        // The threshold must be reachable, and only a council without members can be disabled.
+        if threshold > num_members || (threshold == 0) != (num_members == 0) {
+            return false;
+        }
+
+        for (i, member) in members.iter().enumerate() {
+            if member == &Pubkey::default() || members[..i].contains(member) {
+                return false;
+            }
+        }
+
+        let mut new_members = [Pubkey::default(); MAX_COUNCIL_MEMBERS];
+        new_members[..members.len()].copy_from_slice(members);
+
+        *self = Self {
+            nonce: self.nonce.wrapping_add(1),
+            threshold,
+            num_members,
+            members: new_members,
+        };
+
+        true
+    }
+
+    /// Whether the approvals were collected by this council and meet its threshold.
+    pub fn is_approved(&self, approvals: &CouncilApprovals) -> bool {
+        self.is_enabled()
+            && approvals.council_nonce == self.nonce
+            && approvals.num_approvals() >= u32::from(self.threshold)
+    }
+}
+
+/// Approvals collected from council members for a single admin action.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct CouncilApprovals {
+    /// Nonce of the council that these approvals were collected by.
+    pub council_nonce: u32,
+
+    /// Bitmap of council members (by index) who have approved the action.
+    pub approved_by: u8,
+}
+
+impl CouncilApprovals {
+    pub fn num_approvals(&self) -> u32 {
+        self.approved_by.count_ones()
+    }
+
+    pub fn has_approved(&self, member_index: u8) -> bool {
+        self.approved_by & member_bit(member_index) != 0
+    }
+
+    pub fn approve(&mut self, member_index: u8) {
+        self.approved_by |= member_bit(member_index);
+    }
+}
+
+fn member_bit(member_index: u8) -> u8 {
+    1u8.checked_shl(member_index.into()).unwrap_or_default()
+}
+
+/// Compute the digest of an admin action that council members approve, which is the keccak256 hash
+/// of the serialized action.
+pub fn council_action_digest<T: AnchorSerialize>(action: &T) -> Result<[u8; 32]> {
+    Ok(keccak::hash(&action.try_to_vec()?).0)
+}
+
+pub trait RoleBased: OwnerAssistant {
+    fn roles(&self) -> &Roles;
+
+    fn roles_mut(&mut self) -> &mut Roles;
+}
+
+pub trait Council {
+    fn council(&self) -> &CouncilInfo;
+
+    fn council_mut(&mut self) -> &mut CouncilInfo;
+}
+
+pub trait CouncilApproval {
+    fn action_digest(&self) -> &[u8; 32];
+
+    fn approvals(&self) -> &CouncilApprovals;
+
+    fn approvals_mut(&mut self) -> &mut CouncilApprovals;
+}
+
 #[cfg(test)]
 mod tests {
-    //    use super::*;
-    // TODO
+    use super::*;
+
+    fn keys(n: u8) -> Vec<Pubkey> {
+        (1..=n).map(|i| Pubkey::new_from_array([i; 32])).collect()
+    }
+
+    #[test]
+    fn grant_and_revoke_roles() {
+        let mut roles = Roles::default();
+        let [pauser, manager] = [keys(2)[0], keys(2)[1]];
+
+        assert!(roles.grant(&pauser, Role::Pauser));
+        assert!(roles.grant(&manager, Role::EndpointManager));
+        assert!(roles.grant(&manager, Role::FeeManager));
+        assert!(roles.has_role(&pauser, Role::Pauser));
+        assert!(!roles.has_role(&pauser, Role::EndpointManager));
+        assert!(roles.has_role(&manager, Role::EndpointManager));
+        assert!(roles.has_role(&manager, Role::FeeManager));
+        assert!(!roles.has_role(&manager, Role::Pauser));
+
+        assert!(!roles.revoke(&pauser, Role::FeeManager));
+        assert!(roles.revoke(&manager, Role::EndpointManager));
+        assert!(!roles.has_role(&manager, Role::EndpointManager));
+        assert!(roles.has_role(&manager, Role::FeeManager));
+
+        // Revoking the last role frees the holder's slot.
+        assert!(roles.revoke(&pauser, Role::Pauser));
+        assert_eq!(roles.holders[0], RoleHolder::default());
+    }
+
+    #[test]
+    fn grant_roles_too_many_holders() {
+        let mut roles = Roles::default();
+        let authorities = keys(9);
+
+        for authority in &authorities[..MAX_ROLE_HOLDERS] {
+            assert!(roles.grant(authority, Role::Pauser));
+        }
+        assert!(!roles.grant(&authorities[MAX_ROLE_HOLDERS], Role::Pauser));
+
+        // An existing holder can still be granted more roles.
+        assert!(roles.grant(&authorities[0], Role::FeeManager));
+    }
+
+    #[test]
+    fn update_council() {
+        let mut council = CouncilInfo::default();
+        assert!(!council.is_enabled());
+
+        let members = keys(3);
+        assert!(council.update(&members, 2));
+        assert!(council.is_enabled());
+        assert_eq!(council.nonce, 1);
+        assert_eq!(council.members(), &members[..]);
+        assert_eq!(council.member_index(&members[2]), Some(2));
+        assert_eq!(council.member_index(&Pubkey::default()), None);
+
+        // Disable.
+        assert!(council.update(&[], 0));
+        assert!(!council.is_enabled());
+        assert!(council.members().is_empty());
+        assert_eq!(council.nonce, 2);
+    }
+
+    #[test]
+    fn update_council_invalid() {
+        let mut council = CouncilInfo::default();
+        let members = keys(3);
+
+        assert!(!council.update(&members, 0));
+        assert!(!council.update(&members, 4));
+        assert!(!council.update(&[], 1));
+        assert!(!council.update(&keys(9), 1));
+        assert!(!council.update(&[members[0], members[0]], 1));
+        assert!(!council.update(&[members[0], Pubkey::default()], 1));
+        assert_eq!(council, CouncilInfo::default());
+    }
+
+    #[test]
+    fn council_approvals() {
+        let mut council = CouncilInfo::default();
+        assert!(council.update(&keys(3), 2));
+
+        let mut approvals = CouncilApprovals {
+            council_nonce: council.nonce,
+            approved_by: 0b001,
+        };
+        assert!(approvals.has_approved(0));
+        assert!(!approvals.has_approved(1));
+        assert!(!council.is_approved(&approvals));
+
+        approvals.approved_by |= 0b100;
+        assert!(council.is_approved(&approvals));
+
+        // Replacing the council invalidates the approvals.
+        assert!(council.update(&keys(3), 2));
+        assert!(!council.is_approved(&approvals));
+    }
 }
@@ -0,0 +1,56 @@
+use crate::admin::{Council, CouncilApproval};
+use anchor_lang::prelude::*;
+
+/// Returns the index of the council member if the signer is one.
+pub fn only_council_member<A>(acct: &Account<A>, member: &Signer, custom_error: Error) -> Result<u8>
+where
+    A: Council + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.council()
+        .member_index(&member.key())
+        .ok_or_else(|| custom_error.with_account_name("member"))
+}
+
+/// Record a council member's approval. Approvals collected by a previous council are discarded.
+pub fn approve<A, B>(acct: &Account<A>, approval: &mut Account<B>, member_index: u8)
+where
+    A: Council + Clone + AccountSerialize + AccountDeserialize,
+    B: CouncilApproval + Clone + AccountSerialize + AccountDeserialize,
+{
+    let council_nonce = acct.council().nonce;
+
+    let approvals = approval.approvals_mut();
+    if approvals.council_nonce != council_nonce {
+        approvals.council_nonce = council_nonce;
+        approvals.approved_by = 0;
+    }
+    approvals.approve(member_index);
+}
+
+pub fn only_council_approved<A, B>(
+    acct: &Account<A>,
+    approval: &Account<B>,
+    action_digest: &[u8; 32],
+    custom_error: Error,
+) -> Result<bool>
+where
+    A: Council + Clone + AccountSerialize + AccountDeserialize,
+    B: CouncilApproval + Clone + AccountSerialize + AccountDeserialize,
+{
+    if approval.action_digest() == action_digest && acct.council().is_approved(approval.approvals())
+    {
+        Ok(true)
+    } else {
+        Err(custom_error)
+    }
+}
+
+/// Returns false if the members or threshold are invalid (see [CouncilInfo::update]).
+///
+/// [CouncilInfo::update]: crate::admin::CouncilInfo::update
+pub fn update_council<A>(acct: &mut Account<A>, members: &[Pubkey], threshold: u8) -> bool
+where
+    A: Council + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.council_mut().update(members, threshold)
+}
@@ -1,7 +1,11 @@
 pub mod assistant;
 
+pub mod council;
+
 pub mod ownable;
 
 pub mod pending_owner;
 
+pub mod role;
+
 pub mod upgrade;
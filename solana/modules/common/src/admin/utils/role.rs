@@ -0,0 +1,39 @@
+use crate::admin::{Role, RoleBased};
+use anchor_lang::prelude::*;
+
+/// The owner and owner assistant implicitly hold every role.
+pub fn only_role<A>(
+    acct: &Account<A>,
+    authority: &Signer,
+    role: Role,
+    custom_error: Error,
+) -> Result<bool>
+where
+    A: RoleBased + Clone + AccountSerialize + AccountDeserialize,
+{
+    let authority = authority.key();
+    if acct.owner() == &authority
+        || acct.owner_assistant() == &authority
+        || acct.roles().has_role(&authority, role)
+    {
+        Ok(true)
+    } else {
+        Err(custom_error)
+    }
+}
+
+/// Returns false if there is no room for another role holder.
+pub fn grant_role<A>(acct: &mut Account<A>, authority: &Pubkey, role: Role) -> bool
+where
+    A: RoleBased + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.roles_mut().grant(authority, role)
+}
+
+/// Returns false if the authority does not hold the role.
+pub fn revoke_role<A>(acct: &mut Account<A>, authority: &Pubkey, role: Role) -> bool
+where
+    A: RoleBased + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.roles_mut().revoke(authority, role)
+}
@@ -2,6 +2,8 @@ pub use wormhole_cctp_solana;
 
 pub mod admin;
 
+pub mod migration;
+
 pub use liquidity_layer_messages as messages;
 pub use messages::wormhole_io;
 
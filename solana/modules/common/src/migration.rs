@@ -0,0 +1,158 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+
+/// Header found at the start of a versioned account's data (right after its discriminator).
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct VersionHeader {
+    pub version: u8,
+}
+
+/// Progress of a batched account migration, which is stored in a program's versioned account
+/// (e.g. its custodian).
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct MigrationProgress {
+    /// Whether a migration has been started since the last upgrade was committed.
+    pub started: bool,
+
+    /// Number of accounts which still need to be migrated.
+    pub num_remaining: u32,
+}
+
+impl MigrationProgress {
+    /// A migration is complete once it has been started and every counted account was migrated.
+    pub fn is_complete(&self) -> bool {
+        self.started && self.num_remaining == 0
+    }
+
+    /// Start a migration of the given number of accounts.
+    pub fn start(&mut self, num_remaining: u32) {
+        self.started = true;
+        self.num_remaining = num_remaining;
+    }
+
+    /// Finish a complete migration once the upgrade requiring it is committed, so the next upgrade
+    /// must start its own migration.
+    pub fn finish(&mut self) {
+        *self = Default::default();
+    }
+
+    /// Record that one of the remaining accounts was migrated.
+    pub fn record_migrated(&mut self) {
+        self.num_remaining = self.num_remaining.saturating_sub(1);
+    }
+}
+
+/// Function upgrading an account's data (excluding its discriminator) from one version to the
+/// next. The upgraded data must start with the new version's header.
+pub type UpgradeFn = fn(&[u8]) -> Result<Vec<u8>>;
+
+/// Account whose data starts with a [VersionHeader] (right after its discriminator). When a program
+/// changes the layout of a versioned account, it bumps the account's version and adds a function
+/// upgrading the account's data from the previous version.
+///
+/// Accounts created before their type was versioned are version 0. Because these accounts do not
+/// have a header, each type must be able to identify its legacy layout (e.g. by its length).
+pub trait Versioned: Discriminator {
+    /// Current version of this account's layout.
+    const VERSION: u8;
+
+    /// Functions upgrading this account, where the function at index N upgrades an account from
+    /// version N to N + 1.
+    const UPGRADES: &'static [UpgradeFn];
+
+    /// Determine whether an account's data (including its discriminator) has the layout used before
+    /// its type was versioned. No later version of this account can be mistaken for this layout.
+    fn is_legacy(key: &Pubkey, data: &[u8]) -> bool;
+}
+
+/// Determine the version of an account's data.
+pub fn account_version<T: Versioned>(key: &Pubkey, data: &[u8]) -> Result<u8> {
+    require!(
+        data.get(..8) == Some(&T::DISCRIMINATOR[..]),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    if T::is_legacy(key, data) {
+        Ok(0)
+    } else {
+        data.get(8)
+            .copied()
+            .ok_or_else(|| error!(ErrorCode::AccountDidNotDeserialize))
+    }
+}
+
+/// Serialize an upgraded account (excluding its discriminator), where the serialized data is padded
+/// with zeros to fill the given space (also excluding the discriminator). Accounts with optional or
+/// variable-length fields need this padding so these fields can be set after the migration.
+pub fn serialize_upgraded(acct: &impl AnchorSerialize, space: usize) -> Result<Vec<u8>> {
+    let mut data = Vec::with_capacity(space);
+    acct.serialize(&mut data)?;
+
+    require_gte!(space, data.len(), ErrorCode::AccountDidNotSerialize);
+    data.resize(space, 0);
+
+    Ok(data)
+}
+
+/// Migrate an account to its current version by chaining its upgrade functions. The account is
+/// reallocated to fit its upgraded data, where the payer covers any additional rent. Returns the
+/// version the account was migrated from (or none if it was already at its current version).
+///
+/// NOTE: The account must be owned by the calling program.
+pub fn migrate_account<'info, T: Versioned>(
+    acc_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<Option<u8>> {
+    let (legacy_version, mut data) = {
+        let data = acc_info.try_borrow_data()?;
+        (
+            account_version::<T>(acc_info.key, &data)?,
+            data[8..].to_vec(),
+        )
+    };
+
+    if legacy_version == T::VERSION {
+        return Ok(None);
+    }
+
+    let mut version = legacy_version;
+    while version < T::VERSION {
+        let upgrade = T::UPGRADES
+            .get(usize::from(version))
+            .ok_or_else(|| error!(ErrorCode::AccountDidNotDeserialize))?;
+        data = upgrade(&data)?;
+        version = version.saturating_add(1);
+
+        require!(
+            data.first() == Some(&version),
+            ErrorCode::AccountDidNotSerialize
+        );
+    }
+
+    // Accounts at a later version than this program knows about cannot be migrated.
+    require_eq!(version, T::VERSION, ErrorCode::AccountDidNotDeserialize);
+
+    let space = data.len().saturating_add(8);
+    let lamports_diff = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(acc_info.lamports());
+    if lamports_diff > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: acc_info.to_account_info(),
+                },
+            ),
+            lamports_diff,
+        )?;
+    }
+
+    acc_info.realloc(space, false)?;
+    acc_info.try_borrow_mut_data()?[8..].copy_from_slice(&data);
+
+    Ok(Some(legacy_version))
+}
@@ -0,0 +1,87 @@
+use anchor_lang::{prelude::*, Discriminator};
+use matching_engine::state::{
+    Auction, AuctionConfig, AuctionHistory, AuctionTranche, Custodian, FastFill, FastFillSequencer,
+    OfferCommitment, PreparedOrderResponse, Proposal, RegisteredSolver, ReservedFastFillSequence,
+    RouteAuctionConfig, RouterEndpoint, SolverVault,
+};
+
+macro_rules! matching_engine_accounts {
+    ($($name:ident),* $(,)?) => {
+        /// Any account owned by the Matching Engine program, decoded using its discriminator.
+        #[derive(Debug, Clone)]
+        pub enum MatchingEngineAccount {
+            $($name(Box<$name>),)*
+        }
+
+        impl MatchingEngineAccount {
+            /// Decode account data (including its discriminator) into the matching account type.
+            /// This method does not check the account's owner (see [Self::try_from_account]).
+            pub fn try_deserialize(data: &[u8]) -> Result<Self> {
+                let discriminator = data
+                    .get(..8)
+                    .ok_or_else(|| error!(ErrorCode::AccountDiscriminatorNotFound))?;
+
+                $(
+                    if discriminator == $name::DISCRIMINATOR {
+                        let mut buf = data;
+                        return $name::try_deserialize(&mut buf)
+                            .map(|account| Self::$name(Box::new(account)));
+                    }
+                )*
+
+                err!(ErrorCode::AccountDiscriminatorMismatch)
+            }
+
+            /// Name of the decoded account type.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$name(_) => stringify!($name),)*
+                }
+            }
+        }
+    };
+}
+
+matching_engine_accounts!(
+    Auction,
+    AuctionConfig,
+    AuctionHistory,
+    AuctionTranche,
+    Custodian,
+    FastFill,
+    FastFillSequencer,
+    OfferCommitment,
+    PreparedOrderResponse,
+    Proposal,
+    RegisteredSolver,
+    ReservedFastFillSequence,
+    RouteAuctionConfig,
+    RouterEndpoint,
+    SolverVault,
+);
+
+impl MatchingEngineAccount {
+    /// Decode the data of an account fetched from the cluster. This method returns an error if the
+    /// account is not owned by the Matching Engine program.
+    pub fn try_from_account(owner: &Pubkey, data: &[u8]) -> Result<Self> {
+        require_keys_eq!(
+            *owner,
+            matching_engine::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+
+        Self::try_deserialize(data)
+    }
+}
+
+/// Decode the data of an account fetched from the cluster as a specific Matching Engine account
+/// type (e.g. [Auction]), checking both its owner and its discriminator.
+pub fn decode_account<T>(owner: &Pubkey, data: &[u8]) -> Result<T>
+where
+    T: AccountDeserialize + Owner,
+{
+    require_keys_eq!(*owner, T::owner(), ErrorCode::AccountOwnedByWrongProgram);
+
+    let mut buf = data;
+    T::try_deserialize(&mut buf)
+}
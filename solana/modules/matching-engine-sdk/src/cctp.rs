@@ -0,0 +1,134 @@
+//! Accounts belonging to Circle's CCTP programs (Token Messenger Minter and Message Transmitter),
+//! which the Matching Engine needs to burn and mint USDC.
+
+use anchor_lang::prelude::Pubkey;
+use common::wormhole_cctp_solana::cctp::{
+    message_transmitter_program, token_messenger_minter_program,
+};
+
+/// Number of nonces tracked by each used nonces account of the Message Transmitter program.
+const MAX_NONCES: u64 = 6400;
+
+/// Message header length of a CCTP message. The token burn message body follows it.
+const CCTP_HEADER_LEN: usize = 116;
+
+/// Information from a CCTP token burn message needed to derive the accounts used to receive it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CctpTokenBurnHeader {
+    pub source_domain: u32,
+    pub nonce: u64,
+    pub burn_token: [u8; 32],
+}
+
+impl CctpTokenBurnHeader {
+    /// Parse the source domain, nonce and burn token address from an encoded CCTP token burn
+    /// message. Returns `None` if the message is too short.
+    pub fn parse(encoded_cctp_message: &[u8]) -> Option<Self> {
+        let source_domain = encoded_cctp_message.get(4..8)?.try_into().ok()?;
+        let nonce = encoded_cctp_message.get(12..20)?.try_into().ok()?;
+
+        // The burn token address follows the token burn message's version (4 bytes).
+        let burn_token_start = CCTP_HEADER_LEN.saturating_add(4);
+        let burn_token = encoded_cctp_message
+            .get(burn_token_start..burn_token_start.saturating_add(32))?
+            .try_into()
+            .ok()?;
+
+        Some(Self {
+            source_domain: u32::from_be_bytes(source_domain),
+            nonce: u64::from_be_bytes(nonce),
+            burn_token,
+        })
+    }
+}
+
+fn find_token_messenger_minter_address(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &token_messenger_minter_program::id()).0
+}
+
+fn find_message_transmitter_address(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &message_transmitter_program::id()).0
+}
+
+/// Seeds: \["token_messenger"\] (Token Messenger Minter program).
+pub fn token_messenger() -> Pubkey {
+    find_token_messenger_minter_address(&[b"token_messenger"])
+}
+
+/// Seeds: \["token_minter"\] (Token Messenger Minter program).
+pub fn token_minter() -> Pubkey {
+    find_token_messenger_minter_address(&[b"token_minter"])
+}
+
+/// Seeds: \["sender_authority"\] (Token Messenger Minter program).
+pub fn token_messenger_minter_sender_authority() -> Pubkey {
+    find_token_messenger_minter_address(&[b"sender_authority"])
+}
+
+/// Seeds: \["__event_authority"\] (Token Messenger Minter program).
+pub fn token_messenger_minter_event_authority() -> Pubkey {
+    find_token_messenger_minter_address(&[crate::pda::EVENT_AUTHORITY_SEED])
+}
+
+/// Seeds: \["custody", mint\] (Token Messenger Minter program).
+pub fn token_messenger_minter_custody_token(mint: &Pubkey) -> Pubkey {
+    find_token_messenger_minter_address(&[b"custody", mint.as_ref()])
+}
+
+/// Seeds: \["local_token", mint\] (Token Messenger Minter program).
+pub fn local_token(mint: &Pubkey) -> Pubkey {
+    find_token_messenger_minter_address(&[b"local_token", mint.as_ref()])
+}
+
+/// Seeds: \["remote_token_messenger", remote_domain.to_string()\] (Token Messenger Minter
+/// program).
+pub fn remote_token_messenger(remote_domain: u32) -> Pubkey {
+    find_token_messenger_minter_address(&[
+        b"remote_token_messenger",
+        remote_domain.to_string().as_bytes(),
+    ])
+}
+
+/// Seeds: \["token_pair", remote_domain.to_string(), remote_token\] (Token Messenger Minter
+/// program).
+pub fn token_pair(remote_domain: u32, remote_token: &[u8; 32]) -> Pubkey {
+    find_token_messenger_minter_address(&[
+        b"token_pair",
+        remote_domain.to_string().as_bytes(),
+        remote_token,
+    ])
+}
+
+/// Seeds: \["message_transmitter"\] (Message Transmitter program).
+pub fn message_transmitter_config() -> Pubkey {
+    find_message_transmitter_address(&[b"message_transmitter"])
+}
+
+/// Authority that the Message Transmitter uses to invoke the Token Messenger Minter. Seeds:
+/// \["message_transmitter_authority", token_messenger_minter_program\] (Message Transmitter
+/// program).
+pub fn message_transmitter_authority() -> Pubkey {
+    find_message_transmitter_address(&[
+        b"message_transmitter_authority",
+        token_messenger_minter_program::id().as_ref(),
+    ])
+}
+
+/// Seeds: \["__event_authority"\] (Message Transmitter program).
+pub fn message_transmitter_event_authority() -> Pubkey {
+    find_message_transmitter_address(&[crate::pda::EVENT_AUTHORITY_SEED])
+}
+
+/// Account tracking whether a nonce from the remote domain has been used. Each of these accounts
+/// covers a range of nonces starting with the first nonce of the range. Seeds: \["used_nonces",
+/// remote_domain.to_string(), first_nonce.to_string()\] (Message Transmitter program).
+pub fn used_nonces(remote_domain: u32, nonce: u64) -> Pubkey {
+    let offset = nonce.saturating_sub(1);
+    let first_nonce = offset.saturating_sub(offset % MAX_NONCES).saturating_add(1);
+
+    find_message_transmitter_address(&[
+        b"used_nonces",
+        remote_domain.to_string().as_bytes(),
+        first_nonce.to_string().as_bytes(),
+    ])
+}
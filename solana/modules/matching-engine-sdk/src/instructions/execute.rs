@@ -0,0 +1,143 @@
+use super::*;
+use matching_engine::state::{Auction, ReservedFastFillSequence};
+
+/// Accounts needed to execute an order once its auction has ended.
+#[derive(Debug, Clone, Copy)]
+pub struct ExecuteOrderAccounts {
+    pub fast_vaa: Pubkey,
+    pub auction: Pubkey,
+    pub auction_config_id: u32,
+    pub best_offer_token: Pubkey,
+    pub executor_token: Pubkey,
+    pub initial_offer_token: Pubkey,
+
+    /// Payer of the initial offer (see [Auction::prepared_by]).
+    pub initial_participant: Pubkey,
+
+    /// Must be provided if the best offer's deposit is locked in a solver vault.
+    pub best_offer_vault: Option<Pubkey>,
+
+    /// Circle-supported mint of the auction (see [Auction::mint]).
+    pub mint: Pubkey,
+}
+
+impl ExecuteOrderAccounts {
+    /// Fill in the execute order accounts using a decoded auction account. Returns `None` if there
+    /// was no auction.
+    pub fn new(fast_vaa: Pubkey, executor_token: Pubkey, auction: &Auction) -> Option<Self> {
+        let info = auction.info.as_ref()?;
+
+        Some(Self {
+            fast_vaa,
+            auction: pda::auction(&auction.vaa_hash),
+            auction_config_id: info.config_id,
+            best_offer_token: info.best_offer_token,
+            executor_token,
+            initial_offer_token: info.initial_offer_token,
+            initial_participant: auction.prepared_by,
+            best_offer_vault: info.best_offer_vault,
+            mint: auction.mint,
+        })
+    }
+
+    fn execute_order(&self) -> accounts::ExecuteOrder {
+        accounts::ExecuteOrder {
+            fast_vaa: accounts::LiquidityLayerVaa { vaa: self.fast_vaa },
+            active_auction: accounts::ActiveAuction {
+                auction: self.auction,
+                custody_token: pda::auction_custody_token(&self.auction),
+                config: pda::auction_config(self.auction_config_id),
+                best_offer_token: self.best_offer_token,
+            },
+            executor_token: self.executor_token,
+            initial_offer_token: self.initial_offer_token,
+            initial_participant: self.initial_participant,
+            best_offer_vault: self.best_offer_vault,
+        }
+    }
+}
+
+/// Execute an order whose target is another CCTP domain.
+///
+/// The `to_router_endpoint_chain` is the order's target chain. If the order must be refunded
+/// because the user amount does not satisfy the minimum amount out, this is the source chain
+/// instead, and `destination_cctp_domain` must be the source chain's CCTP domain.
+pub fn execute_fast_order_cctp(
+    payer: &Pubkey,
+    execute_order: &ExecuteOrderAccounts,
+    to_router_endpoint_chain: u16,
+    destination_cctp_domain: u32,
+) -> Instruction {
+    build_instruction(
+        accounts::ExecuteFastOrderCctp {
+            payer: *payer,
+            core_message: pda::core_message(&execute_order.auction),
+            cctp_message: pda::cctp_message(&execute_order.auction),
+            custodian: checked_custodian(),
+            execute_order: execute_order.execute_order(),
+            to_router_endpoint: accounts::LiveRouterEndpoint {
+                endpoint: pda::router_endpoint(to_router_endpoint_chain),
+            },
+            wormhole: wormhole_publish_message(),
+            cctp: cctp_deposit_for_burn(&execute_order.mint, destination_cctp_domain),
+            system_program: anchor_lang::system_program::ID,
+            token_program: anchor_spl::token::ID,
+            sysvars: required_sysvars(),
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::ExecuteFastOrderCctp {},
+    )
+}
+
+/// Accounts needed to deliver the destination asset requested by an order.
+#[derive(Debug, Clone, Copy)]
+pub struct DestinationAssetAccounts {
+    /// Mint of the destination asset encoded in the fast market order.
+    pub mint: Pubkey,
+
+    /// Token account from which the payer delivers the order's minimum amount out.
+    pub executor_destination_token: Pubkey,
+}
+
+/// Execute an order whose target is Solana. A fast fill sequence must have been reserved for this
+/// order (see [reserve_fast_fill_sequence_active_auction]). If the order requests a destination
+/// asset, the accounts needed to deliver it must be provided.
+pub fn execute_fast_order_local(
+    payer: &Pubkey,
+    execute_order: &ExecuteOrderAccounts,
+    reserved_sequence: &ReservedFastFillSequence,
+    destination_asset: Option<&DestinationAssetAccounts>,
+) -> Instruction {
+    let fast_fill_seeds = &reserved_sequence.fast_fill_seeds;
+    let fast_fill = pda::fast_fill(
+        fast_fill_seeds.source_chain,
+        &fast_fill_seeds.order_sender,
+        fast_fill_seeds.sequence,
+    );
+
+    build_instruction(
+        accounts::ExecuteFastOrderLocal {
+            payer: *payer,
+            custodian: checked_custodian(),
+            execute_order: execute_order.execute_order(),
+            reserved_sequence: pda::reserved_fast_fill_sequence(
+                &reserved_sequence.seeds.fast_vaa_hash,
+            ),
+            reserve_beneficiary: reserved_sequence.beneficiary,
+            fast_fill,
+            local_custody_token: pda::local_custody_token(fast_fill_seeds.source_chain),
+            destination_mint: destination_asset.map(|accounts| accounts.mint),
+            executor_destination_token: destination_asset
+                .map(|accounts| accounts.executor_destination_token),
+            destination_custody_token: destination_asset
+                .map(|_| pda::destination_custody_token(&fast_fill)),
+            system_program: anchor_lang::system_program::ID,
+            token_program: anchor_spl::token::ID,
+            sysvars: required_sysvars(),
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::ExecuteFastOrderLocal {},
+    )
+}
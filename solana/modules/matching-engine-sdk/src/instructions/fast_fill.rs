@@ -0,0 +1,109 @@
+use super::*;
+use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
+use matching_engine::state::FastFill;
+
+fn reserve_fast_fill_sequence(
+    payer: &Pubkey,
+    fast_order: &FastOrder,
+    order_sender: &[u8; 32],
+) -> accounts::ReserveFastFillSequence {
+    accounts::ReserveFastFillSequence {
+        payer: *payer,
+        fast_order_path: fast_order.path(),
+        sequencer: pda::fast_fill_sequencer(fast_order.source_chain, order_sender),
+        reserved: pda::reserved_fast_fill_sequence(&fast_order.vaa_hash),
+        auction: fast_order.auction(),
+        system_program: anchor_lang::system_program::ID,
+    }
+}
+
+/// Reserve a fast fill sequence for an order whose auction has ended, which must happen before
+/// executing the order locally. The `order_sender` is the sender encoded in the fast market order
+/// and the `auction_config_id` is the one found in the auction's info.
+pub fn reserve_fast_fill_sequence_active_auction(
+    payer: &Pubkey,
+    fast_order: &FastOrder,
+    order_sender: &[u8; 32],
+    auction_config_id: u32,
+) -> Instruction {
+    build_instruction(
+        accounts::ReserveFastFillSequenceActiveAuction {
+            reserve_sequence: reserve_fast_fill_sequence(payer, fast_order, order_sender),
+            auction_config: pda::auction_config(auction_config_id),
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::ReserveFastFillSequenceActiveAuction {},
+    )
+}
+
+/// Reserve a fast fill sequence for an order that was never auctioned, which must happen before
+/// settling the order locally. The order response must have been prepared already.
+pub fn reserve_fast_fill_sequence_no_auction(
+    payer: &Pubkey,
+    fast_order: &FastOrder,
+    order_sender: &[u8; 32],
+) -> Instruction {
+    build_instruction(
+        accounts::ReserveFastFillSequenceNoAuction {
+            reserve_sequence: reserve_fast_fill_sequence(payer, fast_order, order_sender),
+            prepared_order_response: pda::prepared_order_response(&fast_order.vaa_hash),
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::ReserveFastFillSequenceNoAuction {},
+    )
+}
+
+/// Complete a fast fill. Only the local Token Router can invoke this instruction, where its
+/// emitter (custodian) signs and its custody token account receives the fill amount.
+pub fn complete_fast_fill(
+    fast_fill: &FastFill,
+    token_router_emitter: &Pubkey,
+    token_router_custody_token: &Pubkey,
+) -> Instruction {
+    let seeds = &fast_fill.seeds;
+    let fast_fill_key = pda::fast_fill(seeds.source_chain, &seeds.order_sender, seeds.sequence);
+
+    // Local custody token accounts hold USDC, so a fast fill of any other mint delivers a
+    // destination asset held in its destination custody token account.
+    let delivers_destination_asset = fast_fill.info.mint != common::USDC_MINT;
+
+    build_instruction(
+        accounts::CompleteFastFill {
+            custodian: checked_custodian(),
+            fast_fill: fast_fill_key,
+            token_router_emitter: *token_router_emitter,
+            token_router_custody_token: *token_router_custody_token,
+            path: accounts::LiveRouterPath {
+                from_endpoint: accounts::LiveRouterEndpoint {
+                    endpoint: pda::router_endpoint(seeds.source_chain),
+                },
+                to_endpoint: accounts::LiveRouterEndpoint {
+                    endpoint: pda::router_endpoint(SOLANA_CHAIN),
+                },
+            },
+            local_custody_token: pda::local_custody_token(seeds.source_chain),
+            destination_custody_token: delivers_destination_asset
+                .then(|| pda::destination_custody_token(&fast_fill_key)),
+            fast_fill_prepared_by: delivers_destination_asset.then_some(fast_fill.info.prepared_by),
+            token_program: anchor_spl::token::ID,
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::CompleteFastFill {},
+    )
+}
+
+/// Close a redeemed fast fill, returning its lamports to whoever created it.
+pub fn close_redeemed_fast_fill(fast_fill: &FastFill) -> Instruction {
+    let seeds = &fast_fill.seeds;
+
+    build_instruction(
+        accounts::CloseRedeemedFastFill {
+            prepared_by: fast_fill.info.prepared_by,
+            fast_fill: pda::fast_fill(seeds.source_chain, &seeds.order_sender, seeds.sequence),
+        },
+        matching_engine::instruction::CloseRedeemedFastFill {},
+    )
+}
@@ -0,0 +1,367 @@
+//! Builders for Matching Engine instructions. Each builder returns an [Instruction] with every
+//! account the program expects, in the order it expects them.
+
+mod execute;
+pub use execute::*;
+
+mod fast_fill;
+pub use fast_fill::*;
+
+mod offer;
+pub use offer::*;
+
+mod settle;
+pub use settle::*;
+
+use crate::{cctp, pda, wormhole};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, sysvar},
+    InstructionData,
+};
+use common::wormhole_cctp_solana::{
+    cctp::{message_transmitter_program, token_messenger_minter_program},
+    wormhole::core_bridge_program,
+};
+use matching_engine::accounts;
+
+/// Fast market order information found in its posted fast VAA, which is needed to derive the
+/// accounts of offer, settlement and fast fill instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FastOrder {
+    /// Posted fast VAA account.
+    pub vaa: Pubkey,
+
+    /// Digest of the fast VAA, which seeds the auction account (among others).
+    pub vaa_hash: [u8; 32],
+
+    /// Emitter chain of the fast VAA.
+    pub source_chain: u16,
+
+    /// Target chain encoded in the fast market order.
+    pub target_chain: u16,
+}
+
+impl FastOrder {
+    pub fn auction(&self) -> Pubkey {
+        pda::auction(&self.vaa_hash)
+    }
+
+    fn path(&self) -> accounts::FastOrderPath {
+        accounts::FastOrderPath {
+            fast_vaa: accounts::LiquidityLayerVaa { vaa: self.vaa },
+            path: accounts::LiveRouterPath {
+                from_endpoint: accounts::LiveRouterEndpoint {
+                    endpoint: pda::router_endpoint(self.source_chain),
+                },
+                to_endpoint: accounts::LiveRouterEndpoint {
+                    endpoint: pda::router_endpoint(self.target_chain),
+                },
+            },
+        }
+    }
+}
+
+fn build_instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: matching_engine::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn checked_custodian() -> accounts::CheckedCustodian {
+    accounts::CheckedCustodian {
+        custodian: pda::custodian(),
+    }
+}
+
+fn supported_cctp_mint(mint: &Pubkey) -> accounts::SupportedCctpMint {
+    accounts::SupportedCctpMint {
+        mint: *mint,
+        cctp_mint: pda::cctp_mint(mint),
+    }
+}
+
+fn required_sysvars() -> accounts::RequiredSysvars {
+    accounts::RequiredSysvars {
+        clock: sysvar::clock::id(),
+        rent: sysvar::rent::id(),
+    }
+}
+
+fn wormhole_publish_message() -> accounts::WormholePublishMessage {
+    accounts::WormholePublishMessage {
+        config: wormhole::core_bridge_config(),
+        emitter_sequence: wormhole::emitter_sequence(&pda::custodian()),
+        fee_collector: wormhole::fee_collector(),
+        core_bridge_program: core_bridge_program::id(),
+    }
+}
+
+fn cctp_deposit_for_burn(
+    mint: &Pubkey,
+    destination_cctp_domain: u32,
+) -> accounts::CctpDepositForBurn {
+    accounts::CctpDepositForBurn {
+        mint: *mint,
+        token_messenger_minter_sender_authority: cctp::token_messenger_minter_sender_authority(),
+        message_transmitter_config: cctp::message_transmitter_config(),
+        token_messenger: cctp::token_messenger(),
+        remote_token_messenger: cctp::remote_token_messenger(destination_cctp_domain),
+        token_minter: cctp::token_minter(),
+        local_token: cctp::local_token(mint),
+        token_messenger_minter_event_authority: cctp::token_messenger_minter_event_authority(),
+        token_messenger_minter_program: token_messenger_minter_program::id(),
+        message_transmitter_program: message_transmitter_program::id(),
+    }
+}
+
+fn cctp_receive_message(
+    mint: &Pubkey,
+    header: &cctp::CctpTokenBurnHeader,
+) -> accounts::CctpReceiveMessage {
+    let cctp::CctpTokenBurnHeader {
+        source_domain,
+        nonce,
+        burn_token,
+    } = header;
+
+    accounts::CctpReceiveMessage {
+        mint_recipient: matching_engine::client_accounts::CctpMintRecipientMut {
+            mint_recipient: pda::cctp_mint_recipient(mint),
+        },
+        message_transmitter_authority: cctp::message_transmitter_authority(),
+        message_transmitter_config: cctp::message_transmitter_config(),
+        used_nonces: cctp::used_nonces(*source_domain, *nonce),
+        message_transmitter_event_authority: cctp::message_transmitter_event_authority(),
+        token_messenger: cctp::token_messenger(),
+        remote_token_messenger: cctp::remote_token_messenger(*source_domain),
+        token_minter: cctp::token_minter(),
+        local_token: cctp::local_token(mint),
+        token_pair: cctp::token_pair(*source_domain, burn_token),
+        token_messenger_minter_custody_token: cctp::token_messenger_minter_custody_token(mint),
+        token_messenger_minter_event_authority: cctp::token_messenger_minter_event_authority(),
+        token_messenger_minter_program: token_messenger_minter_program::id(),
+        message_transmitter_program: message_transmitter_program::id(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::migration::{VersionHeader, Versioned};
+    use matching_engine::state::{
+        FastFill, FastFillInfo, FastFillSeeds, ReservedFastFillSequence,
+        ReservedFastFillSequenceSeeds,
+    };
+
+    const SOURCE_CHAIN: u16 = 2;
+
+    fn execute_order_for_test() -> ExecuteOrderAccounts {
+        ExecuteOrderAccounts {
+            fast_vaa: Pubkey::new_unique(),
+            auction: Pubkey::new_unique(),
+            auction_config_id: 1,
+            best_offer_token: Pubkey::new_unique(),
+            executor_token: Pubkey::new_unique(),
+            initial_offer_token: Pubkey::new_unique(),
+            initial_participant: Pubkey::new_unique(),
+            best_offer_vault: None,
+            mint: common::USDC_MINT,
+        }
+    }
+
+    fn expected_execute_order_metas(execute_order: &ExecuteOrderAccounts) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(execute_order.fast_vaa, false),
+            AccountMeta::new(execute_order.auction, false),
+            AccountMeta::new(pda::auction_custody_token(&execute_order.auction), false),
+            AccountMeta::new_readonly(pda::auction_config(1), false),
+            AccountMeta::new(execute_order.best_offer_token, false),
+            AccountMeta::new(execute_order.executor_token, false),
+            AccountMeta::new(execute_order.initial_offer_token, false),
+            AccountMeta::new(execute_order.initial_participant, false),
+            AccountMeta::new_readonly(matching_engine::ID, false),
+        ]
+    }
+
+    fn expected_event_cpi_metas() -> [AccountMeta; 2] {
+        [
+            AccountMeta::new_readonly(pda::event_authority(), false),
+            AccountMeta::new_readonly(matching_engine::ID, false),
+        ]
+    }
+
+    #[test]
+    fn execute_fast_order_cctp_account_order() {
+        let payer = Pubkey::new_unique();
+        let execute_order = execute_order_for_test();
+        let mint = execute_order.mint;
+
+        let ix = execute_fast_order_cctp(&payer, &execute_order, SOURCE_CHAIN, 0);
+
+        let mut expected = vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(pda::core_message(&execute_order.auction), false),
+            AccountMeta::new(pda::cctp_message(&execute_order.auction), false),
+            AccountMeta::new_readonly(pda::custodian(), false),
+        ];
+        expected.extend(expected_execute_order_metas(&execute_order));
+        expected.extend([
+            AccountMeta::new_readonly(pda::router_endpoint(SOURCE_CHAIN), false),
+            AccountMeta::new(wormhole::core_bridge_config(), false),
+            AccountMeta::new(wormhole::emitter_sequence(&pda::custodian()), false),
+            AccountMeta::new(wormhole::fee_collector(), false),
+            AccountMeta::new_readonly(core_bridge_program::id(), false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(cctp::token_messenger_minter_sender_authority(), false),
+            AccountMeta::new(cctp::message_transmitter_config(), false),
+            AccountMeta::new_readonly(cctp::token_messenger(), false),
+            AccountMeta::new_readonly(cctp::remote_token_messenger(0), false),
+            AccountMeta::new_readonly(cctp::token_minter(), false),
+            AccountMeta::new(cctp::local_token(&mint), false),
+            AccountMeta::new_readonly(cctp::token_messenger_minter_event_authority(), false),
+            AccountMeta::new_readonly(token_messenger_minter_program::id(), false),
+            AccountMeta::new_readonly(message_transmitter_program::id(), false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ]);
+        expected.extend(expected_event_cpi_metas());
+
+        assert_eq!(ix.program_id, matching_engine::ID);
+        assert_eq!(ix.accounts, expected);
+    }
+
+    #[test]
+    fn execute_fast_order_local_account_order() {
+        let payer = Pubkey::new_unique();
+        let execute_order = execute_order_for_test();
+        let order_sender = [3; 32];
+        let reserved_sequence = ReservedFastFillSequence {
+            seeds: ReservedFastFillSequenceSeeds {
+                fast_vaa_hash: [4; 32],
+                bump: 255,
+            },
+            beneficiary: Pubkey::new_unique(),
+            fast_fill_seeds: FastFillSeeds {
+                source_chain: SOURCE_CHAIN,
+                order_sender,
+                sequence: 5,
+                bump: 255,
+            },
+        };
+
+        let ix = execute_fast_order_local(&payer, &execute_order, &reserved_sequence, None);
+
+        let mut expected = vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(pda::custodian(), false),
+        ];
+        expected.extend(expected_execute_order_metas(&execute_order));
+        expected.extend([
+            AccountMeta::new(pda::reserved_fast_fill_sequence(&[4; 32]), false),
+            AccountMeta::new(reserved_sequence.beneficiary, false),
+            AccountMeta::new(pda::fast_fill(SOURCE_CHAIN, &order_sender, 5), false),
+            AccountMeta::new(pda::local_custody_token(SOURCE_CHAIN), false),
+            AccountMeta::new_readonly(matching_engine::ID, false),
+            AccountMeta::new_readonly(matching_engine::ID, false),
+            AccountMeta::new_readonly(matching_engine::ID, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ]);
+        expected.extend(expected_event_cpi_metas());
+
+        assert_eq!(ix.accounts, expected);
+    }
+
+    #[test]
+    fn improve_offer_account_order() {
+        let accounts = ImproveOfferAccounts {
+            auction: Pubkey::new_unique(),
+            auction_config_id: 1,
+            best_offer_token: Pubkey::new_unique(),
+            offer_token: Pubkey::new_unique(),
+            best_offer_vault: Some(Pubkey::new_unique()),
+            offer_vault: Some(Pubkey::new_unique()),
+            offer_vault_owner: Some(Pubkey::new_unique()),
+            registered_solver: None,
+        };
+        let auction = accounts.auction;
+
+        let ix = improve_offer(&accounts, 69);
+
+        let mut expected = vec![
+            AccountMeta::new_readonly(pda::transfer_authority(&auction, 69), false),
+            AccountMeta::new(auction, false),
+            AccountMeta::new(pda::auction_custody_token(&auction), false),
+            AccountMeta::new_readonly(pda::auction_config(1), false),
+            AccountMeta::new(accounts.best_offer_token, false),
+            AccountMeta::new(accounts.offer_token, false),
+            AccountMeta::new(accounts.best_offer_vault.unwrap(), false),
+            AccountMeta::new_readonly(accounts.offer_vault_owner.unwrap(), true),
+            AccountMeta::new(accounts.offer_vault.unwrap(), false),
+            AccountMeta::new_readonly(matching_engine::ID, false),
+            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+        ];
+        expected.extend(expected_event_cpi_metas());
+
+        assert_eq!(ix.accounts, expected);
+    }
+
+    #[test]
+    fn complete_fast_fill_account_order() {
+        let token_router_emitter = Pubkey::new_unique();
+        let token_router_custody_token = Pubkey::new_unique();
+        let order_sender = [3; 32];
+        let fast_fill = FastFill {
+            header: VersionHeader {
+                version: FastFill::VERSION,
+            },
+            seeds: FastFillSeeds {
+                source_chain: SOURCE_CHAIN,
+                order_sender,
+                sequence: 5,
+                bump: 255,
+            },
+            redeemed: false,
+            info: FastFillInfo {
+                prepared_by: Pubkey::new_unique(),
+                amount: 69,
+                redeemer: Pubkey::new_unique(),
+                timestamp: 420,
+                mint: common::USDC_MINT,
+            },
+            redeemer_message: Vec::new(),
+        };
+
+        let ix = complete_fast_fill(
+            &fast_fill,
+            &token_router_emitter,
+            &token_router_custody_token,
+        );
+
+        let mut expected = vec![
+            AccountMeta::new_readonly(pda::custodian(), false),
+            AccountMeta::new(pda::fast_fill(SOURCE_CHAIN, &order_sender, 5), false),
+            AccountMeta::new_readonly(token_router_emitter, true),
+            AccountMeta::new(token_router_custody_token, false),
+            AccountMeta::new_readonly(pda::router_endpoint(SOURCE_CHAIN), false),
+            AccountMeta::new_readonly(
+                pda::router_endpoint(common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN),
+                false,
+            ),
+            AccountMeta::new(pda::local_custody_token(SOURCE_CHAIN), false),
+            AccountMeta::new_readonly(matching_engine::ID, false),
+            AccountMeta::new_readonly(matching_engine::ID, false),
+            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+        ];
+        expected.extend(expected_event_cpi_metas());
+
+        assert_eq!(ix.accounts, expected);
+    }
+}
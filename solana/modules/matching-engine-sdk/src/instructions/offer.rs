@@ -0,0 +1,127 @@
+use super::*;
+use anchor_spl::token::spl_token;
+
+/// Approve the transfer authority of an offer to spend the offer token's funds. This instruction
+/// must precede placing or improving an offer, where the approved amount is the offer's total
+/// deposit (the order's amount in, max fee and security deposit).
+pub fn approve_transfer_authority(
+    offer_token: &Pubkey,
+    owner: &Pubkey,
+    auction: &Pubkey,
+    offer_price: u64,
+    total_deposit: u64,
+) -> Result<Instruction> {
+    spl_token::instruction::approve(
+        &anchor_spl::token::ID,
+        offer_token,
+        &pda::transfer_authority(auction, offer_price),
+        owner,
+        &[],
+        total_deposit,
+    )
+    .map_err(Into::into)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlaceInitialOfferCctpAccounts {
+    pub payer: Pubkey,
+
+    /// Token account whose funds are transferred to the auction's custody token account. If a
+    /// solver vault is provided, this must be the vault's custody token account.
+    pub offer_token: Pubkey,
+
+    /// ID of the auction config used by this order's route (either the route's overriding config,
+    /// the mint's overriding config or the custodian's auction config ID).
+    pub auction_config_id: u32,
+
+    /// Circle-supported mint of the order, which must be USDC or a registered CCTP mint.
+    pub mint: Pubkey,
+
+    pub solver_vault: Option<Pubkey>,
+
+    /// Must be provided if either of the route's router endpoints has a solver allowlist.
+    pub registered_solver: Option<Pubkey>,
+}
+
+pub fn place_initial_offer_cctp(
+    accounts: &PlaceInitialOfferCctpAccounts,
+    fast_order: &FastOrder,
+    offer_price: u64,
+) -> Instruction {
+    let auction = fast_order.auction();
+
+    build_instruction(
+        accounts::PlaceInitialOfferCctp {
+            payer: accounts.payer,
+            transfer_authority: pda::transfer_authority(&auction, offer_price),
+            custodian: checked_custodian(),
+            auction_config: pda::auction_config(accounts.auction_config_id),
+            route_auction_config: pda::route_auction_config(
+                fast_order.source_chain,
+                fast_order.target_chain,
+            ),
+            inbound_rate_limit: pda::inbound_rate_limit(fast_order.source_chain),
+            outbound_rate_limit: pda::outbound_rate_limit(fast_order.target_chain),
+            fast_order_path: fast_order.path(),
+            auction,
+            offer_token: accounts.offer_token,
+            solver_vault: accounts.solver_vault,
+            registered_solver: accounts.registered_solver,
+            auction_custody_token: pda::auction_custody_token(&auction),
+            mint: supported_cctp_mint(&accounts.mint),
+            system_program: anchor_lang::system_program::ID,
+            token_program: anchor_spl::token::ID,
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::PlaceInitialOfferCctp { offer_price },
+    )
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImproveOfferAccounts {
+    pub auction: Pubkey,
+
+    /// ID of the auction config found in the auction's info.
+    pub auction_config_id: u32,
+
+    /// Current best offer token found in the auction's info.
+    pub best_offer_token: Pubkey,
+
+    pub offer_token: Pubkey,
+
+    /// Must be provided if the current best offer's deposit is locked in a solver vault.
+    pub best_offer_vault: Option<Pubkey>,
+
+    /// Solver vault to lock the new offer's deposit in. Its owner must sign the instruction.
+    pub offer_vault: Option<Pubkey>,
+    pub offer_vault_owner: Option<Pubkey>,
+
+    /// Must be provided if the auction only allows registered solvers.
+    pub registered_solver: Option<Pubkey>,
+}
+
+pub fn improve_offer(accounts: &ImproveOfferAccounts, offer_price: u64) -> Instruction {
+    let auction = accounts.auction;
+
+    build_instruction(
+        accounts::ImproveOffer {
+            transfer_authority: pda::transfer_authority(&auction, offer_price),
+            active_auction: accounts::ActiveAuction {
+                auction,
+                custody_token: pda::auction_custody_token(&auction),
+                config: pda::auction_config(accounts.auction_config_id),
+                best_offer_token: accounts.best_offer_token,
+            },
+            offer_token: accounts.offer_token,
+            best_offer_vault: accounts.best_offer_vault,
+            offer_vault_owner: accounts.offer_vault_owner,
+            offer_vault: accounts.offer_vault,
+            registered_solver: accounts.registered_solver,
+            token_program: anchor_spl::token::ID,
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::ImproveOffer { offer_price },
+    )
+}
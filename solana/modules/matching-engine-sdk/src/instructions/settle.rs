@@ -0,0 +1,177 @@
+use super::*;
+use matching_engine::{
+    state::{Auction, AuctionTranche, PreparedOrderResponse, ReservedFastFillSequence},
+    CctpMessageArgs,
+};
+
+/// Prepare the order response using the finalized VAA and the CCTP message and attestation that
+/// minted the order's funds, whose local mint is `mint`. Returns `None` if the encoded CCTP message
+/// is malformed.
+pub fn prepare_order_response_cctp(
+    payer: &Pubkey,
+    fast_order: &FastOrder,
+    finalized_vaa: &Pubkey,
+    base_fee_token: &Pubkey,
+    mint: &Pubkey,
+    args: CctpMessageArgs,
+) -> Option<Instruction> {
+    let header = cctp::CctpTokenBurnHeader::parse(&args.encoded_cctp_message)?;
+
+    let prepared_order_response = pda::prepared_order_response(&fast_order.vaa_hash);
+
+    build_instruction(
+        accounts::PrepareOrderResponseCctp {
+            payer: *payer,
+            custodian: checked_custodian(),
+            fast_order_path: fast_order.path(),
+            finalized_vaa: accounts::LiquidityLayerVaa {
+                vaa: *finalized_vaa,
+            },
+            prepared_order_response,
+            prepared_custody_token: pda::prepared_custody_token(&prepared_order_response),
+            base_fee_token: *base_fee_token,
+            auction: fast_order.auction(),
+            inbound_rate_limit: pda::inbound_rate_limit(fast_order.source_chain),
+            outbound_rate_limit: pda::outbound_rate_limit(fast_order.target_chain),
+            mint: supported_cctp_mint(mint),
+            cctp: cctp_receive_message(mint, &header),
+            token_program: anchor_spl::token::ID,
+            system_program: anchor_lang::system_program::ID,
+        },
+        matching_engine::instruction::PrepareOrderResponseCctp { args },
+    )
+    .into()
+}
+
+/// Settle a completed auction. For split-fill auctions, each executed tranche is settled
+/// separately by providing its auction tranche account. Returns `None` if there was no auction.
+pub fn settle_auction_complete(
+    prepared_order_response: &PreparedOrderResponse,
+    auction: &Auction,
+    auction_tranche: Option<&AuctionTranche>,
+) -> Option<Instruction> {
+    let best_offer_token = match auction_tranche {
+        Some(auction_tranche) => auction_tranche.best_offer_token,
+        None => auction.info.as_ref()?.best_offer_token,
+    };
+
+    let fast_vaa_hash = &prepared_order_response.seeds.fast_vaa_hash;
+    let prepared_order_response_key = pda::prepared_order_response(fast_vaa_hash);
+    let auction_key = pda::auction(fast_vaa_hash);
+
+    build_instruction(
+        accounts::SettleAuctionComplete {
+            beneficiary: prepared_order_response.prepared_by,
+            base_fee_token: prepared_order_response.base_fee_token,
+            best_offer_token,
+            prepared_order_response: prepared_order_response_key,
+            prepared_custody_token: pda::prepared_custody_token(&prepared_order_response_key),
+            auction: auction_key,
+            auction_tranche: auction_tranche
+                .map(|auction_tranche| pda::auction_tranche(&auction_key, auction_tranche.index)),
+            auction_tranche_prepared_by: auction_tranche
+                .map(|auction_tranche| auction_tranche.prepared_by),
+            token_program: anchor_spl::token::ID,
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::SettleAuctionComplete {},
+    )
+    .into()
+}
+
+fn close_prepared_order_response(
+    prepared_order_response: &PreparedOrderResponse,
+) -> accounts::ClosePreparedOrderResponse {
+    let prepared_order_response_key =
+        pda::prepared_order_response(&prepared_order_response.seeds.fast_vaa_hash);
+
+    accounts::ClosePreparedOrderResponse {
+        by: prepared_order_response.prepared_by,
+        order_response: prepared_order_response_key,
+        custody_token: pda::prepared_custody_token(&prepared_order_response_key),
+    }
+}
+
+/// Settle an order that was never auctioned, whose target is another CCTP domain. The
+/// `fee_recipient_token` is the custodian's fee recipient token account (or the registered CCTP
+/// mint's fee recipient token account if the order's mint is not USDC).
+pub fn settle_auction_none_cctp(
+    payer: &Pubkey,
+    prepared_order_response: &PreparedOrderResponse,
+    fee_recipient_token: &Pubkey,
+    destination_cctp_domain: u32,
+) -> Instruction {
+    let auction = pda::auction(&prepared_order_response.seeds.fast_vaa_hash);
+
+    build_instruction(
+        accounts::SettleAuctionNoneCctp {
+            payer: *payer,
+            core_message: pda::core_message(&auction),
+            cctp_message: pda::cctp_message(&auction),
+            custodian: checked_custodian(),
+            fee_recipient_token: *fee_recipient_token,
+            cctp_mint: pda::cctp_mint(&prepared_order_response.mint),
+            prepared: close_prepared_order_response(prepared_order_response),
+            auction,
+            wormhole: wormhole_publish_message(),
+            cctp: cctp_deposit_for_burn(&prepared_order_response.mint, destination_cctp_domain),
+            token_program: anchor_spl::token::ID,
+            system_program: anchor_lang::system_program::ID,
+            sysvars: required_sysvars(),
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::SettleAuctionNoneCctp {},
+    )
+}
+
+/// Settle an order that was never auctioned, whose target is Solana. A fast fill sequence must have
+/// been reserved for this order (see [reserve_fast_fill_sequence_no_auction]).
+pub fn settle_auction_none_local(
+    payer: &Pubkey,
+    prepared_order_response: &PreparedOrderResponse,
+    fee_recipient_token: &Pubkey,
+    reserved_sequence: &ReservedFastFillSequence,
+) -> Instruction {
+    let fast_vaa_hash = &prepared_order_response.seeds.fast_vaa_hash;
+    let fast_fill_seeds = &reserved_sequence.fast_fill_seeds;
+
+    build_instruction(
+        accounts::SettleAuctionNoneLocal {
+            payer: *payer,
+            custodian: checked_custodian(),
+            fee_recipient_token: *fee_recipient_token,
+            cctp_mint: pda::cctp_mint(&prepared_order_response.mint),
+            prepared: close_prepared_order_response(prepared_order_response),
+            auction: pda::auction(fast_vaa_hash),
+            reserved_sequence: pda::reserved_fast_fill_sequence(fast_vaa_hash),
+            fast_fill: pda::fast_fill(
+                fast_fill_seeds.source_chain,
+                &fast_fill_seeds.order_sender,
+                fast_fill_seeds.sequence,
+            ),
+            local_custody_token: pda::local_custody_token(prepared_order_response.source_chain),
+            token_program: anchor_spl::token::ID,
+            system_program: anchor_lang::system_program::ID,
+            sysvars: required_sysvars(),
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::SettleAuctionNoneLocal {},
+    )
+}
+
+/// Close a settled auction once its fast VAA has expired, returning its lamports to whoever
+/// created it.
+pub fn close_auction(auction: &Auction) -> Instruction {
+    build_instruction(
+        accounts::CloseAuction {
+            auction: pda::auction(&auction.vaa_hash),
+            beneficiary: auction.prepared_by,
+            event_authority: pda::event_authority(),
+            program: matching_engine::ID,
+        },
+        matching_engine::instruction::CloseAuction {},
+    )
+}
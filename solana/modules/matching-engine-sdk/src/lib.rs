@@ -0,0 +1,11 @@
+pub mod accounts;
+
+pub mod cctp;
+
+pub mod instructions;
+
+pub mod pda;
+
+pub mod wormhole;
+
+pub use matching_engine::cpi::*;
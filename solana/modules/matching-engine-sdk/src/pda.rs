@@ -0,0 +1,318 @@
+//! Program-derived addresses owned by the Matching Engine program.
+
+use anchor_lang::prelude::Pubkey;
+use common::{CCTP_MESSAGE_SEED_PREFIX, CORE_MESSAGE_SEED_PREFIX, TRANSFER_AUTHORITY_SEED_PREFIX};
+use matching_engine::state::{
+    Auction, AuctionConfig, AuctionHistory, AuctionTranche, CctpMint, CouncilApproval, Custodian,
+    FastFill, FastFillSequencer, OfferCommitment, PreparedOrderResponse, Proposal, RateLimit,
+    RegisteredSolver, ReservedFastFillSequence, RouteAuctionConfig, RouterEndpoint, SolverVault,
+};
+
+/// Seed used by Anchor to derive the authority of self-CPI events (see `#[event_cpi]`).
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+fn find_address(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &matching_engine::ID).0
+}
+
+/// Custodian, which is also the program's Wormhole emitter. Seeds: \["emitter"\].
+pub fn custodian() -> Pubkey {
+    find_address(&[Custodian::SEED_PREFIX])
+}
+
+/// Authority of the Matching Engine's self-CPI events. Seeds: \["__event_authority"\].
+pub fn event_authority() -> Pubkey {
+    find_address(&[EVENT_AUTHORITY_SEED])
+}
+
+/// Seeds: \["auction-config", id\].
+pub fn auction_config(id: u32) -> Pubkey {
+    find_address(&[AuctionConfig::SEED_PREFIX, &id.to_be_bytes()])
+}
+
+/// Seeds: \["route-auction-config", source_chain, target_chain\].
+pub fn route_auction_config(source_chain: u16, target_chain: u16) -> Pubkey {
+    find_address(&[
+        RouteAuctionConfig::SEED_PREFIX,
+        &source_chain.to_be_bytes(),
+        &target_chain.to_be_bytes(),
+    ])
+}
+
+/// Seeds: \["inbound-rate-limit", source_chain\].
+pub fn inbound_rate_limit(source_chain: u16) -> Pubkey {
+    find_address(&[RateLimit::INBOUND_SEED_PREFIX, &source_chain.to_be_bytes()])
+}
+
+/// Seeds: \["outbound-rate-limit", target_chain\].
+pub fn outbound_rate_limit(target_chain: u16) -> Pubkey {
+    find_address(&[RateLimit::OUTBOUND_SEED_PREFIX, &target_chain.to_be_bytes()])
+}
+
+/// Seeds: \["cctp-mint", mint\].
+pub fn cctp_mint(mint: &Pubkey) -> Pubkey {
+    find_address(&[CctpMint::SEED_PREFIX, mint.as_ref()])
+}
+
+/// Seeds: \["endpoint", chain\].
+pub fn router_endpoint(chain: u16) -> Pubkey {
+    find_address(&[RouterEndpoint::SEED_PREFIX, &chain.to_be_bytes()])
+}
+
+/// Seeds: \["proposal", id\].
+pub fn proposal(id: u64) -> Pubkey {
+    find_address(&[Proposal::SEED_PREFIX, &id.to_be_bytes()])
+}
+
+/// Seeds: \["council-approval", action_digest\].
+pub fn council_approval(action_digest: &[u8; 32]) -> Pubkey {
+    find_address(&[CouncilApproval::SEED_PREFIX, action_digest])
+}
+
+/// Seeds: \["auction-history", id\].
+pub fn auction_history(id: u64) -> Pubkey {
+    find_address(&[AuctionHistory::SEED_PREFIX, &id.to_be_bytes()])
+}
+
+/// Seeds: \["auction", fast_vaa_hash\].
+pub fn auction(fast_vaa_hash: &[u8; 32]) -> Pubkey {
+    find_address(&[Auction::SEED_PREFIX, fast_vaa_hash])
+}
+
+/// Seeds: \["auction-custody", auction\].
+pub fn auction_custody_token(auction: &Pubkey) -> Pubkey {
+    find_address(&[
+        matching_engine::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
+        auction.as_ref(),
+    ])
+}
+
+/// Seeds: \["auction-tranche", auction, index\].
+pub fn auction_tranche(auction: &Pubkey, index: u8) -> Pubkey {
+    find_address(&[AuctionTranche::SEED_PREFIX, auction.as_ref(), &[index]])
+}
+
+/// PDA that an auction participant must approve to spend its offer token's funds. Seeds:
+/// \["transfer-authority", auction, offer_price\].
+pub fn transfer_authority(auction: &Pubkey, offer_price: u64) -> Pubkey {
+    find_address(&[
+        TRANSFER_AUTHORITY_SEED_PREFIX,
+        auction.as_ref(),
+        &offer_price.to_be_bytes(),
+    ])
+}
+
+/// Transfer authority for a sealed-bid offer commitment. Seeds: \["transfer-authority", auction,
+/// commitment\].
+pub fn commitment_transfer_authority(auction: &Pubkey, commitment: &[u8; 32]) -> Pubkey {
+    find_address(&[TRANSFER_AUTHORITY_SEED_PREFIX, auction.as_ref(), commitment])
+}
+
+/// Seeds: \["offer-commitment", auction, offer_token\].
+pub fn offer_commitment(auction: &Pubkey, offer_token: &Pubkey) -> Pubkey {
+    find_address(&[
+        OfferCommitment::SEED_PREFIX,
+        auction.as_ref(),
+        offer_token.as_ref(),
+    ])
+}
+
+/// Seeds: \["commitment-custody", offer_commitment\].
+pub fn commitment_custody_token(offer_commitment: &Pubkey) -> Pubkey {
+    find_address(&[
+        matching_engine::COMMITMENT_CUSTODY_TOKEN_SEED_PREFIX,
+        offer_commitment.as_ref(),
+    ])
+}
+
+/// Seeds: \["core-msg", auction\].
+pub fn core_message(auction: &Pubkey) -> Pubkey {
+    find_address(&[CORE_MESSAGE_SEED_PREFIX, auction.as_ref()])
+}
+
+/// Seeds: \["cctp-msg", auction\].
+pub fn cctp_message(auction: &Pubkey) -> Pubkey {
+    find_address(&[CCTP_MESSAGE_SEED_PREFIX, auction.as_ref()])
+}
+
+/// Seeds: \["order-response", fast_vaa_hash\].
+pub fn prepared_order_response(fast_vaa_hash: &[u8; 32]) -> Pubkey {
+    find_address(&[PreparedOrderResponse::SEED_PREFIX, fast_vaa_hash])
+}
+
+/// Seeds: \["prepared-custody", prepared_order_response\].
+pub fn prepared_custody_token(prepared_order_response: &Pubkey) -> Pubkey {
+    find_address(&[
+        matching_engine::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+        prepared_order_response.as_ref(),
+    ])
+}
+
+/// Seeds: \["local-custody", source_chain\].
+pub fn local_custody_token(source_chain: u16) -> Pubkey {
+    find_address(&[
+        matching_engine::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
+        &source_chain.to_be_bytes(),
+    ])
+}
+
+/// Seeds: \["destination-custody", fast_fill\].
+pub fn destination_custody_token(fast_fill: &Pubkey) -> Pubkey {
+    find_address(&[
+        matching_engine::DESTINATION_CUSTODY_TOKEN_SEED_PREFIX,
+        fast_fill.as_ref(),
+    ])
+}
+
+/// Seeds: \["fast-fill", source_chain, order_sender, sequence\].
+pub fn fast_fill(source_chain: u16, order_sender: &[u8; 32], sequence: u64) -> Pubkey {
+    find_address(&[
+        FastFill::SEED_PREFIX,
+        &source_chain.to_be_bytes(),
+        order_sender,
+        &sequence.to_be_bytes(),
+    ])
+}
+
+/// Seeds: \["fast-fill-sequencer", source_chain, sender\].
+pub fn fast_fill_sequencer(source_chain: u16, sender: &[u8; 32]) -> Pubkey {
+    find_address(&[
+        FastFillSequencer::SEED_PREFIX,
+        &source_chain.to_be_bytes(),
+        sender,
+    ])
+}
+
+/// Seeds: \["reserved-fast-fill-sequence", fast_vaa_hash\].
+pub fn reserved_fast_fill_sequence(fast_vaa_hash: &[u8; 32]) -> Pubkey {
+    find_address(&[ReservedFastFillSequence::SEED_PREFIX, fast_vaa_hash])
+}
+
+/// Seeds: \["registered-solver", authority\].
+pub fn registered_solver(authority: &Pubkey) -> Pubkey {
+    find_address(&[RegisteredSolver::SEED_PREFIX, authority.as_ref()])
+}
+
+/// Seeds: \["solver-vault", owner\].
+pub fn solver_vault(owner: &Pubkey) -> Pubkey {
+    find_address(&[SolverVault::SEED_PREFIX, owner.as_ref()])
+}
+
+/// Seeds: \["solver-vault-custody", solver_vault\].
+pub fn solver_vault_custody_token(solver_vault: &Pubkey) -> Pubkey {
+    find_address(&[
+        matching_engine::SOLVER_VAULT_CUSTODY_TOKEN_SEED_PREFIX,
+        solver_vault.as_ref(),
+    ])
+}
+
+/// Token account that receives CCTP mints of the given mint on behalf of the Matching Engine. This
+/// is the custodian's associated token account for the mint. For USDC, this equals
+/// [matching_engine::CCTP_MINT_RECIPIENT].
+pub fn cctp_mint_recipient(mint: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address(&custodian(), mint)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn find_with_literal_seeds(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &matching_engine::ID).0
+    }
+
+    #[test]
+    fn custodian_signer_seeds() {
+        assert_eq!(
+            custodian(),
+            Pubkey::create_program_address(Custodian::SIGNER_SEEDS, &matching_engine::ID).unwrap()
+        );
+    }
+
+    #[test]
+    fn usdc_cctp_mint_recipient() {
+        assert_eq!(
+            cctp_mint_recipient(&common::USDC_MINT),
+            matching_engine::CCTP_MINT_RECIPIENT
+        );
+    }
+
+    #[test]
+    fn documented_seeds() {
+        let fast_vaa_hash = [1; 32];
+        let order_sender = [2; 32];
+        let authority = Pubkey::new_unique();
+
+        assert_eq!(
+            event_authority(),
+            find_with_literal_seeds(&[b"__event_authority"])
+        );
+        assert_eq!(
+            auction_config(3),
+            find_with_literal_seeds(&[b"auction-config", &3u32.to_be_bytes()])
+        );
+        assert_eq!(
+            router_endpoint(2),
+            find_with_literal_seeds(&[b"endpoint", &2u16.to_be_bytes()])
+        );
+
+        let auction_key = auction(&fast_vaa_hash);
+        assert_eq!(
+            auction_key,
+            find_with_literal_seeds(&[b"auction", &fast_vaa_hash])
+        );
+        assert_eq!(
+            auction_custody_token(&auction_key),
+            find_with_literal_seeds(&[b"auction-custody", auction_key.as_ref()])
+        );
+        assert_eq!(
+            transfer_authority(&auction_key, 69),
+            find_with_literal_seeds(&[
+                b"transfer-authority",
+                auction_key.as_ref(),
+                &69u64.to_be_bytes()
+            ])
+        );
+
+        let prepared_order_response_key = prepared_order_response(&fast_vaa_hash);
+        assert_eq!(
+            prepared_order_response_key,
+            find_with_literal_seeds(&[b"order-response", &fast_vaa_hash])
+        );
+        assert_eq!(
+            prepared_custody_token(&prepared_order_response_key),
+            find_with_literal_seeds(&[b"prepared-custody", prepared_order_response_key.as_ref()])
+        );
+
+        let fast_fill_key = fast_fill(2, &order_sender, 4);
+        assert_eq!(
+            fast_fill_key,
+            find_with_literal_seeds(&[
+                b"fast-fill",
+                &2u16.to_be_bytes(),
+                &order_sender,
+                &4u64.to_be_bytes()
+            ])
+        );
+        assert_eq!(
+            destination_custody_token(&fast_fill_key),
+            find_with_literal_seeds(&[b"destination-custody", fast_fill_key.as_ref()])
+        );
+        assert_eq!(
+            fast_fill_sequencer(2, &order_sender),
+            find_with_literal_seeds(&[b"fast-fill-sequencer", &2u16.to_be_bytes(), &order_sender])
+        );
+        assert_eq!(
+            reserved_fast_fill_sequence(&fast_vaa_hash),
+            find_with_literal_seeds(&[b"reserved-fast-fill-sequence", &fast_vaa_hash])
+        );
+        assert_eq!(
+            local_custody_token(2),
+            find_with_literal_seeds(&[b"local-custody", &2u16.to_be_bytes()])
+        );
+        assert_eq!(
+            registered_solver(&authority),
+            find_with_literal_seeds(&[b"registered-solver", authority.as_ref()])
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Accounts belonging to the Wormhole Core Bridge program, which the Matching Engine needs to
+//! publish messages.
+
+use anchor_lang::prelude::Pubkey;
+use common::wormhole_cctp_solana::wormhole::core_bridge_program;
+
+fn find_address(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &core_bridge_program::id()).0
+}
+
+/// Seeds: \["Bridge"\] (Wormhole Core Bridge program).
+pub fn core_bridge_config() -> Pubkey {
+    find_address(&[b"Bridge"])
+}
+
+/// Sequence tracker of the given emitter. Seeds: \["Sequence", emitter\] (Wormhole Core Bridge
+/// program).
+pub fn emitter_sequence(emitter: &Pubkey) -> Pubkey {
+    find_address(&[b"Sequence", emitter.as_ref()])
+}
+
+/// Seeds: \["fee_collector"\] (Wormhole Core Bridge program).
+pub fn fee_collector() -> Pubkey {
+    find_address(&[b"fee_collector"])
+}
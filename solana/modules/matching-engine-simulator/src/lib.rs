@@ -0,0 +1,552 @@
+//! Off-chain simulator of the Matching Engine's auction economics.
+//!
+//! Given auction parameters, a fast market order and a timeline of offers, the simulator replays
+//! the auction and computes how the order's funds flow between the user, the auction participants,
+//! the executor and the fee recipient. Offer validation and payouts are computed with the same
+//! functions the Matching Engine program uses (see [matching_engine::utils::auction]), so the
+//! simulator cannot drift from the program.
+//!
+//! Only English auctions are supported. Sealed-bid and split-fill auctions are rejected.
+
+use std::collections::BTreeMap;
+
+use anchor_lang::{error::Error, prelude::Pubkey};
+use common::messages::FastMarketOrder;
+use matching_engine::{
+    state::{AuctionInfo, AuctionParameters, MessageProtocol},
+    utils::auction::{
+        self, ExecutePayoutAccounts, ExecutePayouts, SettlePayoutAccounts, SettlePayouts,
+    },
+};
+
+/// Reasons a scenario cannot be simulated. Offer errors reference the offer's index in
+/// [Scenario::offers].
+#[derive(Debug)]
+pub enum SimulationError {
+    /// The auction parameters would not be accepted by the Matching Engine.
+    InvalidParameters(Error),
+    SealedBidUnsupported,
+    SplitFillUnsupported,
+    U64Overflow,
+    OfferPriceTooHigh {
+        index: usize,
+    },
+    OfferOutOfOrder {
+        index: usize,
+    },
+    AuctionPeriodExpired {
+        index: usize,
+    },
+    CarpingNotAllowed {
+        index: usize,
+    },
+    AuctionPeriodNotExpired,
+    /// The Matching Engine would revert when executing or settling the order.
+    Program(Error),
+}
+
+impl From<Error> for SimulationError {
+    fn from(err: Error) -> Self {
+        Self::Program(err)
+    }
+}
+
+/// An offer placed on the auction. The first offer in the timeline is the initial offer, which
+/// starts the auction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Offer {
+    pub slot: u64,
+
+    /// Token account the offer's total deposit is taken from.
+    pub offer_token: Pubkey,
+
+    pub offer_price: u64,
+}
+
+/// Execution of the order once its auction has ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Execution {
+    pub slot: u64,
+    pub executor_token: Pubkey,
+}
+
+/// Settlement of the order once its finalized transfer has arrived. The finalized transfer repays
+/// the order's amount in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settlement {
+    /// Base fee encoded in the slow order response.
+    pub base_fee: u64,
+
+    /// Token account of whoever prepared the order response. This account is paid the base fee if
+    /// the order was executed with a penalty.
+    pub base_fee_token: Pubkey,
+
+    /// The custodian's fee recipient token account, which is paid the base fee and the init
+    /// auction fee if the order was never auctioned.
+    pub fee_recipient_token: Pubkey,
+}
+
+/// Everything that happens to a fast market order, from its first offer until its settlement.
+#[derive(Debug, Clone, Copy)]
+pub struct Scenario<'a> {
+    pub params: &'a AuctionParameters,
+    pub order: &'a FastMarketOrder,
+
+    /// Protocol of the order's target router endpoint. Orders executed locally are given an
+    /// additional grace period.
+    pub target_protocol: MessageProtocol,
+
+    /// Offers in the order they are placed. If there are none, the order is settled without an
+    /// auction and the execution is ignored.
+    pub offers: &'a [Offer],
+
+    pub execution: Execution,
+    pub settlement: Settlement,
+
+    /// Token accounts that are closed after the auction has ended (before the order is executed).
+    /// Payouts owed to these accounts are redirected the same way the Matching Engine redirects
+    /// them.
+    pub closed_tokens: &'a [Pubkey],
+}
+
+/// Outcome of an auction that had at least one offer.
+#[derive(Debug, Clone, Copy)]
+pub struct AuctionOutcome {
+    /// Auction info after the last offer, which is the state the order is executed with.
+    pub info: AuctionInfo,
+
+    /// Last slot of the auction, including any extensions.
+    pub end_slot: u64,
+
+    pub execute: ExecutePayouts,
+    pub settle: SettlePayouts,
+}
+
+/// Result of simulating a [Scenario].
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    /// `None` if there were no offers.
+    pub auction: Option<AuctionOutcome>,
+
+    /// Amount delivered to the user. If this amount does not satisfy the order's minimum amount
+    /// out, it is refunded to the order's refund address instead. If the order requests a
+    /// destination asset, this amount is paid to the executor for delivering the order's minimum
+    /// amount out of that asset (or refunded if there was no auction).
+    pub user_amount: u64,
+    pub refunded: bool,
+
+    /// Amount paid to the fee recipient token account when there was no auction.
+    pub fee_recipient_amount: u64,
+
+    /// Net amount each token account received (negative if it paid) over the lifetime of the order.
+    /// Deposits refunded to outbid offers net to zero, so these accounts are omitted.
+    pub net_flows: BTreeMap<Pubkey, i128>,
+}
+
+impl Simulation {
+    /// Net amount the token account received over the lifetime of the order.
+    pub fn net_flow(&self, token: &Pubkey) -> i128 {
+        self.net_flows.get(token).copied().unwrap_or_default()
+    }
+
+    fn add_flow(&mut self, token: Pubkey, amount: i128) {
+        if amount != 0 {
+            let flow = self.net_flows.entry(token).or_default();
+            *flow = flow.saturating_add(amount);
+
+            if *flow == 0 {
+                self.net_flows.remove(&token);
+            }
+        }
+    }
+}
+
+/// Simulate the auction, execution and settlement of a fast market order.
+pub fn simulate(scenario: &Scenario) -> Result<Simulation, SimulationError> {
+    let Scenario {
+        params,
+        order,
+        target_protocol,
+        offers,
+        execution,
+        settlement,
+        closed_tokens,
+    } = *scenario;
+
+    auction::require_valid_parameters(params).map_err(SimulationError::InvalidParameters)?;
+    if params.is_sealed_bid() {
+        return Err(SimulationError::SealedBidUnsupported);
+    }
+    if params.is_split_fill() {
+        return Err(SimulationError::SplitFillUnsupported);
+    }
+
+    let (initial_offer, improvements) = match offers.split_first() {
+        Some(offers) => offers,
+        None => return Ok(simulate_no_auction(order, &settlement)),
+    };
+
+    let info = place_offers(params, order, initial_offer, improvements)?;
+    let end_slot = info.auction_end_slot(params);
+
+    // The order can only be executed once the auction has ended.
+    if execution.slot <= end_slot {
+        return Err(SimulationError::AuctionPeriodNotExpired);
+    }
+
+    let exists = |token: &Pubkey| !closed_tokens.contains(token);
+
+    // The auction custody token account holds the best offer's total deposit. Outbid offers were
+    // refunded their deposits when they were outbid.
+    let execute = auction::compute_execute_payouts(
+        params,
+        &info,
+        ExecutePayoutAccounts {
+            initial_offer_token_exists: exists(&info.initial_offer_token),
+            best_offer_token_exists: exists(&info.best_offer_token),
+            best_offer_is_initial_offer: info.best_offer_token == info.initial_offer_token,
+            best_offer_is_executor: info.best_offer_token == execution.executor_token,
+        },
+        info.total_deposit(),
+        order.init_auction_fee,
+        execution.slot,
+        auction::compute_additional_grace_period(&target_protocol),
+    )?;
+
+    let settle = auction::compute_settle_payouts(
+        order.amount_in,
+        settlement.base_fee,
+        execute.penalty.is_some(),
+        SettlePayoutAccounts {
+            base_fee_token_exists: exists(&settlement.base_fee_token),
+            best_offer_token_exists: exists(&info.best_offer_token),
+            base_fee_is_best_offer: settlement.base_fee_token == info.best_offer_token,
+        },
+    )?;
+
+    let mut simulation = Simulation {
+        auction: None,
+        user_amount: execute.user_amount,
+        refunded: order.destination_asset.is_none() && execute.user_amount < order.min_amount_out,
+        fee_recipient_amount: 0,
+        net_flows: Default::default(),
+    };
+
+    simulation.add_flow(info.best_offer_token, -i128::from(info.total_deposit()));
+    simulation.add_flow(
+        info.initial_offer_token,
+        execute.initial_offer_amount.into(),
+    );
+    simulation.add_flow(info.best_offer_token, execute.best_offer_amount.into());
+    simulation.add_flow(execution.executor_token, execute.executor_amount.into());
+    if let Some(amount) = settle.base_fee_token_amount {
+        simulation.add_flow(settlement.base_fee_token, amount.into());
+    }
+    if let Some(amount) = settle.best_offer_amount {
+        simulation.add_flow(info.best_offer_token, amount.into());
+    }
+
+    simulation.auction = AuctionOutcome {
+        info,
+        end_slot,
+        execute,
+        settle,
+    }
+    .into();
+
+    Ok(simulation)
+}
+
+/// Replay the offers, validating each one the same way the Matching Engine does. Returns the
+/// auction info after the last offer.
+fn place_offers(
+    params: &AuctionParameters,
+    order: &FastMarketOrder,
+    initial_offer: &Offer,
+    improvements: &[Offer],
+) -> Result<AuctionInfo, SimulationError> {
+    if initial_offer.offer_price > order.max_fee {
+        return Err(SimulationError::OfferPriceTooHigh { index: 0 });
+    }
+
+    let security_deposit =
+        auction::compute_security_deposit(params, order.max_fee, order.amount_in);
+    order
+        .amount_in
+        .checked_add(security_deposit)
+        .ok_or_else(|| SimulationError::U64Overflow)?;
+
+    let mut info = AuctionInfo {
+        config_id: Default::default(),
+        custody_token_bump: Default::default(),
+        vaa_sequence: Default::default(),
+        source_chain: Default::default(),
+        best_offer_token: initial_offer.offer_token,
+        best_offer_vault: Default::default(),
+        initial_offer_token: initial_offer.offer_token,
+        solver_allowlist: Default::default(),
+        start_slot: initial_offer.slot,
+        extended_end_slot: Default::default(),
+        amount_in: order.amount_in,
+        security_deposit,
+        offer_price: initial_offer.offer_price,
+        forfeited_user_reward: Default::default(),
+        split_fill: Default::default(),
+        redeemer_message_len: Default::default(),
+        destination_asset_info: Default::default(),
+    };
+
+    let mut last_slot = initial_offer.slot;
+    for (index, offer) in (1..).zip(improvements) {
+        if offer.slot < last_slot {
+            return Err(SimulationError::OfferOutOfOrder { index });
+        }
+        if offer.slot > info.auction_end_slot(params) {
+            return Err(SimulationError::AuctionPeriodExpired { index });
+        }
+        if offer.offer_price >= auction::compute_min_allowed_offer(params, &info) {
+            return Err(SimulationError::CarpingNotAllowed { index });
+        }
+
+        if let Some(extended_end_slot) =
+            auction::compute_extended_end_slot(params, &info, offer.slot)
+        {
+            info.extended_end_slot = extended_end_slot.into();
+        }
+
+        info.best_offer_token = offer.offer_token;
+        info.offer_price = offer.offer_price;
+        last_slot = offer.slot;
+    }
+
+    Ok(info)
+}
+
+/// Without an auction, the fee recipient is paid the base fee and the init auction fee out of the
+/// finalized transfer, and the user receives the rest.
+fn simulate_no_auction(order: &FastMarketOrder, settlement: &Settlement) -> Simulation {
+    let fee = settlement.base_fee.saturating_add(order.init_auction_fee);
+    let user_amount = order.amount_in.saturating_sub(fee);
+
+    let mut simulation = Simulation {
+        auction: None,
+        user_amount,
+        refunded: order.destination_asset.is_some() || user_amount < order.min_amount_out,
+        fee_recipient_amount: fee,
+        net_flows: Default::default(),
+    };
+    simulation.add_flow(settlement.fee_recipient_token, fee.into());
+
+    simulation
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use super::*;
+
+    const AMOUNT_IN: u64 = 10000000;
+    const MAX_FEE: u64 = 200000;
+    const INIT_AUCTION_FEE: u64 = 10000;
+    const BASE_FEE: u64 = 5000;
+
+    #[test]
+    fn no_auction() {
+        let params = params_for_test();
+        let order = order_for_test();
+        let settlement = settlement_for_test();
+
+        let simulation = simulate(&Scenario {
+            offers: &[],
+            ..scenario_for_test(&params, &order, settlement)
+        })
+        .unwrap();
+
+        assert!(simulation.auction.is_none());
+        assert_eq!(
+            simulation.user_amount,
+            AMOUNT_IN - BASE_FEE - INIT_AUCTION_FEE
+        );
+        assert!(!simulation.refunded);
+        assert_eq!(
+            simulation.net_flow(&settlement.fee_recipient_token),
+            i128::from(BASE_FEE + INIT_AUCTION_FEE)
+        );
+    }
+
+    #[test]
+    fn best_offer_executes_in_time() {
+        let params = params_for_test();
+        let order = order_for_test();
+        let settlement = settlement_for_test();
+
+        let initial = Pubkey::new_unique();
+        let best = Pubkey::new_unique();
+        let offers = [
+            Offer {
+                slot: 100,
+                offer_token: initial,
+                offer_price: MAX_FEE,
+            },
+            Offer {
+                slot: 101,
+                offer_token: best,
+                offer_price: 100000,
+            },
+        ];
+
+        let simulation = simulate(&Scenario {
+            offers: &offers,
+            execution: Execution {
+                slot: 103,
+                executor_token: best,
+            },
+            ..scenario_for_test(&params, &order, settlement)
+        })
+        .unwrap();
+
+        let outcome = simulation.auction.unwrap();
+        assert_eq!(outcome.end_slot, 102);
+        assert!(outcome.execute.penalty.is_none());
+
+        assert_eq!(
+            simulation.user_amount,
+            AMOUNT_IN - 100000 - INIT_AUCTION_FEE
+        );
+        assert_eq!(simulation.net_flow(&initial), i128::from(INIT_AUCTION_FEE));
+
+        // The best offer fronts the user amount and is repaid the amount in, keeping its fee.
+        assert_eq!(simulation.net_flow(&best), 100000);
+        assert_eq!(simulation.net_flow(&settlement.base_fee_token), 0);
+    }
+
+    #[test]
+    fn late_execution_pays_executor() {
+        let params = params_for_test();
+        let order = order_for_test();
+        let settlement = settlement_for_test();
+
+        let best = Pubkey::new_unique();
+        let executor = Pubkey::new_unique();
+        let offers = [Offer {
+            slot: 100,
+            offer_token: best,
+            offer_price: MAX_FEE,
+        }];
+
+        let simulation = simulate(&Scenario {
+            offers: &offers,
+            execution: Execution {
+                slot: 100 + u64::from(params.duration + params.grace_period) + 10,
+                executor_token: executor,
+            },
+            ..scenario_for_test(&params, &order, settlement)
+        })
+        .unwrap();
+
+        let outcome = simulation.auction.unwrap();
+        let penalty = outcome.execute.penalty.unwrap();
+        assert_eq!(simulation.net_flow(&executor), i128::from(penalty));
+        assert_eq!(
+            simulation.net_flow(&settlement.base_fee_token),
+            i128::from(BASE_FEE)
+        );
+
+        // Everything the best offer deposited is accounted for.
+        let total_received = simulation.user_amount
+            + outcome.execute.initial_offer_amount
+            + outcome.execute.best_offer_amount
+            + outcome.execute.executor_amount;
+        assert_eq!(total_received, outcome.info.total_deposit());
+    }
+
+    #[test]
+    fn carping_offer_rejected() {
+        let params = params_for_test();
+        let order = order_for_test();
+
+        let offers = [
+            Offer {
+                slot: 100,
+                offer_token: Pubkey::new_unique(),
+                offer_price: MAX_FEE,
+            },
+            Offer {
+                slot: 101,
+                offer_token: Pubkey::new_unique(),
+                offer_price: MAX_FEE - 1,
+            },
+        ];
+
+        let err = simulate(&Scenario {
+            offers: &offers,
+            ..scenario_for_test(&params, &order, settlement_for_test())
+        })
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            SimulationError::CarpingNotAllowed { index: 1 }
+        ));
+    }
+
+    fn scenario_for_test<'a>(
+        params: &'a AuctionParameters,
+        order: &'a FastMarketOrder,
+        settlement: Settlement,
+    ) -> Scenario<'a> {
+        Scenario {
+            params,
+            order,
+            target_protocol: MessageProtocol::Cctp { domain: 0 },
+            offers: &[],
+            execution: Execution {
+                slot: Default::default(),
+                executor_token: Pubkey::new_unique(),
+            },
+            settlement,
+            closed_tokens: &[],
+        }
+    }
+
+    fn settlement_for_test() -> Settlement {
+        Settlement {
+            base_fee: BASE_FEE,
+            base_fee_token: Pubkey::new_unique(),
+            fee_recipient_token: Pubkey::new_unique(),
+        }
+    }
+
+    fn order_for_test() -> FastMarketOrder {
+        FastMarketOrder {
+            amount_in: AMOUNT_IN,
+            min_amount_out: 0,
+            target_chain: Default::default(),
+            redeemer: Default::default(),
+            sender: Default::default(),
+            refund_address: Default::default(),
+            max_fee: MAX_FEE,
+            init_auction_fee: INIT_AUCTION_FEE,
+            deadline: Default::default(),
+            redeemer_message: Default::default(),
+            destination_asset: Default::default(),
+        }
+    }
+
+    fn params_for_test() -> AuctionParameters {
+        AuctionParameters {
+            user_penalty_reward_bps: 250000, // 25%
+            initial_penalty_bps: 100000,     // 10%
+            duration: 2,
+            grace_period: 4,
+            penalty_period: 20,
+            min_offer_delta_bps: 50000,     // 5%
+            security_deposit_base: 1000000, // 1.0 USDC
+            security_deposit_bps: 5000,     // 0.5%
+            reveal_period: Default::default(),
+            extension_window: Default::default(),
+            max_extension: Default::default(),
+            num_tranches: Default::default(),
+        }
+    }
+}
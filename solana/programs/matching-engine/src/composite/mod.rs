@@ -3,15 +3,19 @@ use std::ops::{Deref, DerefMut};
 use crate::{
     error::MatchingEngineError,
     state::{
-        Auction, AuctionStatus, Custodian, FastFillSequencer, MessageProtocol,
-        PreparedOrderResponse, ReservedFastFillSequence, RouterEndpoint,
+        Auction, AuctionStatus, CctpMint, Custodian, EndpointFlow, FastFillSequencer,
+        MessageProtocol, PreparedOrderResponse, Proposal, ReservedFastFillSequence, RouterEndpoint,
+        SolverVault,
     },
     utils::{self, VaaDigest},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::{
-    admin::utils::{assistant::only_authorized, ownable::only_owner},
+    admin::{
+        utils::{assistant::only_authorized, ownable::only_owner, role::only_role},
+        Role,
+    },
     messages::raw::LiquidityLayerMessage,
     wormhole_cctp_solana::{
         cctp::{message_transmitter_program, token_messenger_minter_program},
@@ -34,18 +38,46 @@ impl<'info> Deref for Usdc<'info> {
     }
 }
 
+/// Circle-supported mint, which must either be USDC or a mint registered in a [CctpMint] account.
+#[derive(Accounts)]
+pub struct SupportedCctpMint<'info> {
+    /// CHECK: This address must equal [USDC_MINT](common::USDC_MINT) or be a registered CCTP mint.
+    pub mint: UncheckedAccount<'info>,
+
+    /// CCTP mint registration, which may not exist if the mint is USDC.
+    ///
+    /// CHECK: Seeds must be \["cctp-mint", mint.key()\].
+    #[account(
+        seeds = [
+            CctpMint::SEED_PREFIX,
+            mint.key().as_ref(),
+        ],
+        bump,
+        constraint = CctpMint::require_supported(&cctp_mint, &mint.key())?,
+    )]
+    pub cctp_mint: UncheckedAccount<'info>,
+}
+
+impl<'info> Deref for SupportedCctpMint<'info> {
+    type Target = UncheckedAccount<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.mint
+    }
+}
+
 /// Mint recipient token account, which is encoded as the mint recipient in the CCTP message.
 /// The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message
 /// from its custody account to this account.
 ///
-/// CHECK: Mutable. Seeds must be \["custody"\].
+/// CHECK: Mutable. Must be the custodian's associated token account for its mint.
 ///
 /// NOTE: This account must be encoded as the mint recipient in the CCTP message.
 #[derive(Accounts)]
 pub struct CctpMintRecipientMut<'info> {
     #[account(
         mut,
-        address = crate::CCTP_MINT_RECIPIENT
+        address = CctpMint::mint_recipient_address(&mint_recipient.mint)
     )]
     pub mint_recipient: Box<Account<'info, token::TokenAccount>>,
 }
@@ -174,6 +206,136 @@ pub struct AdminMut<'info> {
     pub custodian: Box<Account<'info, Custodian>>,
 }
 
+/// The owner, owner assistant or a key that was granted the role to pause and unpause the program.
+#[derive(Accounts)]
+pub struct PauserMut<'info> {
+    #[account(
+        constraint = only_role(
+            &custodian,
+            &authority,
+            Role::Pauser,
+            error!(MatchingEngineError::MissingRole)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+}
+
+/// The owner, owner assistant or a key that was granted the role to pause router endpoints.
+#[derive(Accounts)]
+pub struct Pauser<'info> {
+    #[account(
+        constraint = only_role(
+            &custodian,
+            &authority,
+            Role::Pauser,
+            error!(MatchingEngineError::MissingRole)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    pub custodian: CheckedCustodian<'info>,
+}
+
+/// The owner, owner assistant or a key that was granted the role to manage router endpoints.
+#[derive(Accounts)]
+pub struct EndpointManager<'info> {
+    #[account(
+        constraint = only_role(
+            &custodian,
+            &authority,
+            Role::EndpointManager,
+            error!(MatchingEngineError::MissingRole)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    pub custodian: CheckedCustodian<'info>,
+}
+
+/// The owner, owner assistant or a key that was granted the role to manage fees.
+#[derive(Accounts)]
+pub struct FeeManager<'info> {
+    #[account(
+        constraint = only_role(
+            &custodian,
+            &authority,
+            Role::FeeManager,
+            error!(MatchingEngineError::MissingRole)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    pub custodian: CheckedCustodian<'info>,
+}
+
+/// The owner, owner assistant or a key that was granted the role to propose auction parameters.
+#[derive(Accounts)]
+pub struct AuctionParameterProposer<'info> {
+    #[account(
+        constraint = only_role(
+            &custodian,
+            &authority,
+            Role::AuctionParameterProposer,
+            error!(MatchingEngineError::MissingRole)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    pub custodian: CheckedCustodian<'info>,
+}
+
+/// Proposal being enacted by the owner. The instruction enacting the proposal must check that the
+/// proposal's action is the one it performs.
+#[derive(Accounts)]
+pub struct EnactProposal<'info> {
+    pub admin: OwnerOnlyMut<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &proposal.id.to_be_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = {
+            require_keys_eq!(
+                proposal.owner, admin.owner.key()
+            );
+            require!(
+                proposal.slot_enacted_at.is_none(),
+                MatchingEngineError::ProposalAlreadyEnacted
+            );
+
+            require!(
+                Clock::get().unwrap().slot >= proposal.slot_enact_delay,
+                MatchingEngineError::ProposalDelayNotExpired
+            );
+
+            true
+        }
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+impl<'info> EnactProposal<'info> {
+    /// Set the slot enacted at so the proposal cannot be replayed, and uptick the proposal ID so
+    /// that someone can create a new proposal again.
+    pub fn enact(&mut self) {
+        self.proposal.slot_enacted_at = Clock::get().unwrap().slot.into();
+
+        // NOTE: Overflow check is done in propose instructions.
+        let custodian = &mut self.admin.custodian;
+        custodian.next_proposal_id = custodian.next_proposal_id.saturating_add(1);
+    }
+}
+
 #[derive(Accounts)]
 pub struct LocalTokenRouter<'info> {
     /// CHECK: Must be an executable (the Token Router program), whose ID will be used to derive the
@@ -384,10 +546,10 @@ pub struct ExecuteOrder<'info> {
     )]
     pub active_auction: ActiveAuction<'info>,
 
-    /// Must be a token account, whose mint is [common::USDC_MINT].
+    /// Must be a token account, whose mint is the auction's mint.
     #[account(
         mut,
-        token::mint = common::USDC_MINT,
+        token::mint = active_auction.mint,
     )]
     pub executor_token: Box<Account<'info, token::TokenAccount>>,
 
@@ -404,6 +566,16 @@ pub struct ExecuteOrder<'info> {
         address = active_auction.prepared_by,
     )]
     pub initial_participant: UncheckedAccount<'info>,
+
+    /// Solver vault of the best offer. This account must be provided if the best offer's total
+    /// deposit is locked in a solver vault.
+    #[account(
+        mut,
+        constraint = {
+            Some(best_offer_vault.key()) == active_auction.info.as_ref().unwrap().best_offer_vault
+        } @ MatchingEngineError::SolverVaultMismatch,
+    )]
+    pub best_offer_vault: Option<Box<Account<'info, SolverVault>>>,
 }
 
 #[derive(Accounts)]
@@ -578,6 +750,10 @@ pub struct ReserveFastFillSequence<'info> {
                 MatchingEngineError::InvalidTargetRouter
             );
 
+            fast_order_path
+                .from_endpoint
+                .require_unpaused(EndpointFlow::LocalFills)?;
+
             true
         },
     )]
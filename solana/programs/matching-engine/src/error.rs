@@ -2,6 +2,9 @@
 pub enum MatchingEngineError {
     OwnerOnly = 0x2,
     OwnerOrAssistantOnly = 0x4,
+    GuardianOnly = 0x6,
+    CouncilMemberOnly = 0x8,
+    MissingRole = 0xa,
 
     U64Overflow = 0x10,
     U32Overflow = 0x12,
@@ -23,12 +26,14 @@ pub enum MatchingEngineError {
     InvalidTargetRouter = 0x62,
     EndpointDisabled = 0x64,
     InvalidCctpEndpoint = 0x66,
+    EndpointPaused = 0x68,
 
     Paused = 0x80,
 
     AssistantZeroPubkey = 0x100,
     FeeRecipientZeroPubkey = 0x101,
     ImmutableProgram = 0x102,
+    RoleHolderZeroPubkey = 0x103,
     ZeroDuration = 0x104,
     ZeroGracePeriod = 0x106,
     ZeroPenaltyPeriod = 0x107,
@@ -41,19 +46,36 @@ pub enum MatchingEngineError {
     ZeroSecurityDepositBase = 0x10e,
     #[msg("Value exceeds 1000000")]
     SecurityDepositBpsTooLarge = 0x10f,
+    ExtensionWindowTooLarge = 0x110,
+    ZeroRateLimitWindow = 0x111,
+    #[msg("Value exceeds 8")]
+    TooManyTranches = 0x112,
+    SplitFillSealedBid = 0x114,
 
     InvalidNewOwner = 0x202,
     AlreadyOwner = 0x204,
     NoTransferOwnershipRequest = 0x206,
     NotPendingOwner = 0x208,
     InvalidChain = 0x20c,
+    InvalidCouncil = 0x20e,
+    CouncilActionNotApproved = 0x210,
+    TooManyRoleHolders = 0x212,
+    RoleNotGranted = 0x214,
+    MigrationIncomplete = 0x216,
+    AccountNotMigratable = 0x218,
+    MigrationInProgress = 0x21a,
+    MigrationNotStarted = 0x21c,
 
     ChainNotAllowed = 0x240,
     InvalidMintRecipient = 0x242,
+    CctpMintNotSupported = 0x244,
+    CctpMintMismatch = 0x246,
+    InvalidCctpMint = 0x248,
 
     ProposalAlreadyEnacted = 0x300,
     ProposalDelayNotExpired = 0x302,
     InvalidProposal = 0x304,
+    ProposalDelayExpired = 0x306,
 
     AuctionConfigMismatch = 0x340,
 
@@ -83,10 +105,41 @@ pub enum MatchingEngineError {
     AuctionAlreadySettled = 0x43a,
     InvalidBaseFeeToken = 0x43c,
     BaseFeeTokenRequired = 0x43e,
+    RefundRequired = 0x440,
+    InvalidRefundEndpoint = 0x442,
+    InsufficientVaultBalance = 0x444,
+    SolverVaultMismatch = 0x446,
+    SolverVaultRequired = 0x448,
+    NotSealedBidAuction = 0x44a,
+    SealedBidAuction = 0x44c,
+    RevealPeriodNotStarted = 0x44e,
+    RevealPeriodExpired = 0x450,
+    RevealPeriodNotExpired = 0x452,
+    InvalidOfferReveal = 0x454,
+    SolverNotRegistered = 0x456,
+    SolverSuspended = 0x458,
+    NotSplitFillAuction = 0x45a,
+    SplitFillAuction = 0x45c,
+    InvalidTrancheIndex = 0x45e,
+    AuctionTrancheMismatch = 0x460,
+    AuctionTrancheRequired = 0x462,
+    AuctionTranchesNotFilled = 0x464,
+    AuctionTranchesFilled = 0x466,
+    AuctionTrancheNotActive = 0x468,
+    AuctionTranchesNotExecuted = 0x46a,
+    RateLimitExceeded = 0x46c,
+    InvalidDestinationAsset = 0x46e,
+    DestinationAssetMismatch = 0x470,
+    DestinationCustodyTokenRequired = 0x472,
+    LocalFastOrderMismatch = 0x474,
+    SolverVaultMintMismatch = 0x476,
 
     CannotCloseAuctionYet = 0x500,
     AuctionHistoryNotFull = 0x502,
     AuctionHistoryFull = 0x504,
+
+    NoPendingWithdrawal = 0x540,
+    WithdrawalCooldownNotExpired = 0x542,
 }
 
 #[cfg(test)]
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct AuctionTrancheExecuted {
+    pub fast_vaa_hash: [u8; 32],
+    pub tranche_index: u8,
+    pub best_offer_token: Pubkey,
+    pub penalized: bool,
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct AuctionTrancheRefunded {
+    pub fast_vaa_hash: [u8; 32],
+    pub tranche_index: u8,
+
+    /// Token account that received the refunded total deposit.
+    pub refund_token: Pubkey,
+    pub amount: u64,
+
+    /// Whether this was the last placed tranche, which closes the auction.
+    pub auction_closed: bool,
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct AuctionTrancheUpdated {
+    pub config_id: u32,
+    pub fast_vaa_hash: [u8; 32],
+
+    /// This value will only be some when the initial offer on a tranche is placed.
+    pub vaa: Option<Pubkey>,
+
+    pub tranche_index: u8,
+    pub num_tranches: u8,
+
+    pub end_slot: u64,
+    pub best_offer_token: Pubkey,
+    pub token_balance_before: u64,
+    pub amount_in: u64,
+    pub total_deposit: u64,
+    pub max_offer_price_allowed: Option<u64>,
+}
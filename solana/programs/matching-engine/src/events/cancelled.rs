@@ -0,0 +1,7 @@
+use crate::state::ProposalAction;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct Cancelled {
+    pub action: ProposalAction,
+}
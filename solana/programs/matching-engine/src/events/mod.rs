@@ -4,9 +4,21 @@ pub use auction_closed::*;
 mod auction_settled;
 pub use auction_settled::*;
 
+mod auction_tranche_executed;
+pub use auction_tranche_executed::*;
+
+mod auction_tranche_refunded;
+pub use auction_tranche_refunded::*;
+
+mod auction_tranche_updated;
+pub use auction_tranche_updated::*;
+
 mod auction_updated;
 pub use auction_updated::*;
 
+mod cancelled;
+pub use cancelled::*;
+
 mod enacted;
 pub use enacted::*;
 
@@ -19,8 +31,29 @@ pub use fast_fill_sequence_reserved::*;
 mod filled_local_fast_order;
 pub use filled_local_fast_order::*;
 
+mod offer_commitment_forfeited;
+pub use offer_commitment_forfeited::*;
+
+mod offer_committed;
+pub use offer_committed::*;
+
+mod offer_revealed;
+pub use offer_revealed::*;
+
 mod order_executed;
 pub use order_executed::*;
 
+mod order_refunded;
+pub use order_refunded::*;
+
 mod proposed;
 pub use proposed::*;
+
+mod role_granted;
+pub use role_granted::*;
+
+mod role_revoked;
+pub use role_revoked::*;
+
+mod vetoed;
+pub use vetoed::*;
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct OfferCommitmentForfeited {
+    pub fast_vaa_hash: [u8; 32],
+    pub offer_token: Pubkey,
+
+    /// Portion of the security deposit paid to whoever closed the unrevealed commitment.
+    pub penalty: u64,
+
+    /// Portion of the security deposit paid to the user (or the fee recipient if the auction was
+    /// already executed).
+    pub user_reward: u64,
+}
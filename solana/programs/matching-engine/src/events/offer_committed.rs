@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct OfferCommitted {
+    pub fast_vaa_hash: [u8; 32],
+    pub offer_token: Pubkey,
+    pub commitment: [u8; 32],
+
+    /// Last slot when the committed offer can be revealed.
+    pub reveal_end_slot: u64,
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct OfferRevealed {
+    pub fast_vaa_hash: [u8; 32],
+    pub offer_token: Pubkey,
+    pub offer_price: u64,
+
+    /// Whether the revealed offer is now the best offer.
+    pub best_offer: bool,
+}
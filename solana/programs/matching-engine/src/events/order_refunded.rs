@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct OrderRefunded {
+    pub fast_vaa_hash: [u8; 32],
+
+    /// The chain where the order was placed, which is where the funds are refunded.
+    pub source_chain: u16,
+
+    /// Universal address on the source chain receiving the refund.
+    pub refund_address: [u8; 32],
+
+    /// Amount refunded, which fell below the order's minimum amount out.
+    pub amount: u64,
+    pub min_amount_out: u64,
+}
@@ -13,8 +13,15 @@ use processor::*;
 pub mod state;
 
 pub mod utils;
+pub use processor::{CctpMessageArgs, DepositLocalFastOrderArgs, StartMigrationArgs};
 pub use utils::admin::AddCctpRouterEndpointArgs;
 
+/// Client accounts for composites nested within other composites, which are not re-exported by the
+/// Anchor-generated `accounts` module.
+pub mod client_accounts {
+    pub use crate::composite::__client_accounts_cctp_mint_recipient_mut::CctpMintRecipientMut;
+}
+
 use anchor_lang::{prelude::*, solana_program::pubkey};
 
 cfg_if::cfg_if! {
@@ -22,27 +29,33 @@ cfg_if::cfg_if! {
         declare_id!("HtkeCDdYY4i9ncAxXKjYTx8Uu3WM8JbtiLRYjtHwaVXb");
 
         const CUSTODIAN_BUMP: u8 = 254;
-        const CCTP_MINT_RECIPIENT: Pubkey = pubkey!("HUXc7MBf55vWrrkevVbmJN8HAyfFtjLcPLBt9yWngKzm");
+        pub const CCTP_MINT_RECIPIENT: Pubkey = pubkey!("HUXc7MBf55vWrrkevVbmJN8HAyfFtjLcPLBt9yWngKzm");
     } else if #[cfg(feature = "testnet")] {
         declare_id!("mPydpGUWxzERTNpyvTKdvS7v8kvw5sgwfiP8WQFrXVS");
 
         const CUSTODIAN_BUMP: u8 = 254;
-        const CCTP_MINT_RECIPIENT: Pubkey = pubkey!("6yKmqWarCry3c8ntYKzM4WiS2fVypxLbENE2fP8onJje");
+        pub const CCTP_MINT_RECIPIENT: Pubkey = pubkey!("6yKmqWarCry3c8ntYKzM4WiS2fVypxLbENE2fP8onJje");
     } else if #[cfg(feature = "localnet")] {
         declare_id!("MatchingEngine11111111111111111111111111111");
 
         const CUSTODIAN_BUMP: u8 = 254;
-        const CCTP_MINT_RECIPIENT: Pubkey = pubkey!("35iwWKi7ebFyXNaqpswd1g9e9jrjvqWPV39nCQPaBbX1");
+        pub const CCTP_MINT_RECIPIENT: Pubkey = pubkey!("35iwWKi7ebFyXNaqpswd1g9e9jrjvqWPV39nCQPaBbX1");
     }
 }
 
-const AUCTION_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"auction-custody";
-const LOCAL_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"local-custody";
-const PREPARED_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"prepared-custody";
+pub const AUCTION_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"auction-custody";
+pub const LOCAL_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"local-custody";
+pub const PREPARED_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"prepared-custody";
+pub const COMMITMENT_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"commitment-custody";
+pub const SOLVER_VAULT_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"solver-vault-custody";
+pub const DESTINATION_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"destination-custody";
+pub const DEPOSIT_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"deposit-custody";
 
 const FEE_PRECISION_MAX: u32 = 1_000_000;
 const VAA_AUCTION_EXPIRATION_TIME: i64 = 2 * 60 * 60; // 2 hours
 const EXECUTE_FAST_ORDER_LOCAL_ADDITIONAL_GRACE_PERIOD: u64 = 5; // slots
+const SOLVER_VAULT_WITHDRAWAL_COOLDOWN: u64 = 150; // slots
+const MAX_AUCTION_TRANCHES: u8 = 8;
 
 #[program]
 pub mod matching_engine {
@@ -62,7 +75,7 @@ pub mod matching_engine {
     }
 
     /// This instruction is used to pause or unpause further processing of new auctions. Only the
-    /// `owner` or `owner_assistant` can pause the program.
+    /// `owner`, `owner_assistant` or a holder of the `Pauser` role can pause the program.
     ///
     /// # Arguments
     ///
@@ -72,24 +85,37 @@ pub mod matching_engine {
         processor::set_pause(ctx, pause)
     }
 
-    /// This instruction is used to add a new Token Router endpoint from a foreign chain. The
-    /// endpoint must be CCTP compatible. This instruction can only be called by the `owner` or
-    /// `owner_assistant`.
+    /// This instruction is used to propose adding, updating or disabling a router endpoint. A
+    /// proposal cannot be enacted until the router endpoint enact delay has passed. This
+    /// instruction can only be called by the `owner`, `owner_assistant` or a holder of the
+    /// `EndpointManager` role.
     ///
     /// # Arguments
     ///
-    /// * `ctx`  - `AddCctpRouterEndpoint` context.
-    /// * `args` - The `AddCctpRouterEndpointArgs`, see `admin.rs`.
-    pub fn add_cctp_router_endpoint(
-        ctx: Context<AddCctpRouterEndpoint>,
-        args: AddCctpRouterEndpointArgs,
+    /// * `ctx`  - `ProposeRouterEndpoint` context.
+    /// * `args` - `ProposeRouterEndpointArgs`, which specifies how the router endpoint will be
+    ///            changed.
+    pub fn propose_router_endpoint(
+        ctx: Context<ProposeRouterEndpoint>,
+        args: ProposeRouterEndpointArgs,
     ) -> Result<()> {
-        processor::add_cctp_router_endpoint(ctx, args)
+        processor::propose_router_endpoint(ctx, args)
+    }
+
+    /// This instruction is used to enact an existing proposal to add a new Token Router endpoint
+    /// from a foreign chain. The endpoint must be CCTP compatible. It can only be executed after
+    /// the `slot_enact_delay` has passed. This instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `AddCctpRouterEndpoint` context.
+    pub fn add_cctp_router_endpoint(ctx: Context<AddCctpRouterEndpoint>) -> Result<()> {
+        processor::add_cctp_router_endpoint(ctx)
     }
 
-    /// This instruction is used to add a new Local Router endpoint. Local means that the
-    /// Token Router program exists on Solana. This instruction can only be called by the
-    /// `owner` or `owner_assistant`.
+    /// This instruction is used to enact an existing proposal to add a new Local Router endpoint.
+    /// Local means that the Token Router program exists on Solana. It can only be executed after
+    /// the `slot_enact_delay` has passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -98,9 +124,10 @@ pub mod matching_engine {
         processor::add_local_router_endpoint(ctx)
     }
 
-    /// This instruction is used to disable a router endpoint. This instruction does not close the
-    /// account, it only sets the `protocol` to `None` and clears the `address` and
-    /// `mint_recipient`. This instruction can only be called by the `owner`.
+    /// This instruction is used to enact an existing proposal to disable a router endpoint. This
+    /// instruction does not close the account, it only sets the `protocol` to `None` and clears
+    /// the `address` and `mint_recipient`. It can only be executed after the `slot_enact_delay`
+    /// has passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -109,23 +136,20 @@ pub mod matching_engine {
         processor::disable_router_endpoint(ctx)
     }
 
-    /// This instruction is used to update a CCTP router endpoint. It allows the caller to change
-    /// the `address`, `mint_recipient`, and `domain`. This instruction can only be called by the
-    /// `owner`.
+    /// This instruction is used to enact an existing proposal to update a CCTP router endpoint,
+    /// which changes the `address`, `mint_recipient`, and `domain`. It can only be executed after
+    /// the `slot_enact_delay` has passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
-    /// * `ctx`  - `UpdateCctpRouterEndpoint` context.
-    /// * `args` - The `AddCctpRouterEndpointArgs`, see `admin.rs`.
-    pub fn update_cctp_router_endpoint(
-        ctx: Context<UpdateCctpRouterEndpoint>,
-        args: AddCctpRouterEndpointArgs,
-    ) -> Result<()> {
-        processor::update_cctp_router_endpoint(ctx, args)
+    /// * `ctx` - `UpdateCctpRouterEndpoint` context.
+    pub fn update_cctp_router_endpoint(ctx: Context<UpdateCctpRouterEndpoint>) -> Result<()> {
+        processor::update_cctp_router_endpoint(ctx)
     }
 
-    /// This instruction is used to update a Local router endpoint. It allows the caller to change
-    /// the `address` and `mint_recipient`. This instruction can only be called by the `owner`.
+    /// This instruction is used to enact an existing proposal to update a Local router endpoint,
+    /// which changes the `address` and `mint_recipient`. It can only be executed after the
+    /// `slot_enact_delay` has passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -134,6 +158,123 @@ pub mod matching_engine {
         processor::update_local_router_endpoint(ctx)
     }
 
+    /// This instruction is used to enable or disable the solver allowlist for a router endpoint.
+    /// When enabled, only registered solvers that are not suspended may place offers on auctions
+    /// for orders to or from this endpoint. This instruction can only be called by the `owner`,
+    /// `owner_assistant` or a holder of the `EndpointManager` role.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `SetSolverAllowlist` context.
+    /// * `enabled` - Boolean indicating whether the solver allowlist is enforced.
+    pub fn set_solver_allowlist(ctx: Context<SetSolverAllowlist>, enabled: bool) -> Result<()> {
+        processor::set_solver_allowlist(ctx, enabled)
+    }
+
+    /// This instruction is used to pause or unpause a single flow through a router endpoint, which
+    /// halts new auctions, CCTP transfers, local fills or fast fill redemptions for this endpoint
+    /// without pausing the whole program. This instruction can only be called by the `owner`,
+    /// `owner_assistant` or a holder of the `Pauser` role.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `SetEndpointPause` context.
+    /// * `flow`   - Flow through the router endpoint to pause or unpause.
+    /// * `paused` - Boolean indicating whether to pause the flow.
+    pub fn set_endpoint_pause(
+        ctx: Context<SetEndpointPause>,
+        flow: state::EndpointFlow,
+        paused: bool,
+    ) -> Result<()> {
+        processor::set_endpoint_pause(ctx, flow, paused)
+    }
+
+    /// This instruction is used to create or update a rolling-window rate limit on the notional
+    /// amount of fast market orders sent from (inbound) or destined for (outbound) a chain. Orders
+    /// exceeding either limit cannot be auctioned and must take the slow path. Capacity used by an
+    /// order is not given back if the order is later refunded. This instruction can only be called
+    /// by the `owner` or `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `SetRateLimit` context.
+    /// * `args` - `SetRateLimitArgs`, which specifies the direction, chain, limit and window
+    ///            duration.
+    pub fn set_rate_limit(ctx: Context<SetRateLimit>, args: SetRateLimitArgs) -> Result<()> {
+        processor::set_rate_limit(ctx, args)
+    }
+
+    /// This instruction is used to remove a rate limit, after which orders for its chain and
+    /// direction are no longer rate limited. This instruction can only be called by the `owner` or
+    /// `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `RemoveRateLimit` context.
+    pub fn remove_rate_limit(ctx: Context<RemoveRateLimit>) -> Result<()> {
+        processor::remove_rate_limit(ctx)
+    }
+
+    /// This instruction is used to register a Circle-supported mint other than USDC, which allows
+    /// auctions and order responses to use this mint. It also creates the custodian's associated
+    /// token account for this mint, which is the CCTP mint recipient for this mint. This
+    /// instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `AddCctpMint` context.
+    pub fn add_cctp_mint(ctx: Context<AddCctpMint>) -> Result<()> {
+        processor::add_cctp_mint(ctx)
+    }
+
+    /// This instruction is used to enable or disable a registered CCTP mint and to update its fee
+    /// recipient token account and auction config override. This instruction can only be called by
+    /// the `owner` or `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `UpdateCctpMint` context.
+    /// * `enabled` - Whether new auctions and order responses can use this mint.
+    pub fn update_cctp_mint(ctx: Context<UpdateCctpMint>, enabled: bool) -> Result<()> {
+        processor::update_cctp_mint(ctx, enabled)
+    }
+
+    /// This instruction is used to register a solver, which is required to place offers on
+    /// auctions for routes with a solver allowlist. The registration is keyed by the solver's
+    /// authority. This instruction can only be called by the `owner` or `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `AddRegisteredSolver` context.
+    pub fn add_registered_solver(ctx: Context<AddRegisteredSolver>) -> Result<()> {
+        processor::add_registered_solver(ctx)
+    }
+
+    /// This instruction is used to remove a solver's registration, closing its account. This
+    /// instruction can only be called by the `owner` or `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `RemoveRegisteredSolver` context.
+    pub fn remove_registered_solver(ctx: Context<RemoveRegisteredSolver>) -> Result<()> {
+        processor::remove_registered_solver(ctx)
+    }
+
+    /// This instruction is used to suspend or reinstate a registered solver. A suspended solver
+    /// cannot place offers on auctions for routes with a solver allowlist. This instruction can
+    /// only be called by the `owner` or `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `SuspendRegisteredSolver` context.
+    /// * `suspend` - Boolean indicating whether to suspend the solver.
+    pub fn suspend_registered_solver(
+        ctx: Context<SuspendRegisteredSolver>,
+        suspend: bool,
+    ) -> Result<()> {
+        processor::suspend_registered_solver(ctx, suspend)
+    }
+
     /// This instruction sets the `pending_owner` field in the `Custodian` account. This instruction
     /// can only be called by the `owner`. The `pending_owner` address must be valid, meaning it
     /// cannot be the zero address or the current owner.
@@ -173,8 +314,8 @@ pub mod matching_engine {
     }
 
     /// This instruction is used to propose new auction parameters. A proposal cannot be enacted
-    /// until one epoch has passed. This instruction can only be called by the `owner` or
-    /// `owner_assistant`.
+    /// until one epoch has passed. This instruction can only be called by the `owner`,
+    /// `owner_assistant` or a holder of the `AuctionParameterProposer` role.
     ///
     /// # Arguments
     ///
@@ -198,6 +339,53 @@ pub mod matching_engine {
         processor::update_auction_parameters(ctx)
     }
 
+    /// This instruction is used to propose auction parameters for a specific route, which override
+    /// the global auction parameters for fast market orders sent from the `source_chain` to the
+    /// `target_chain`. If no parameters are specified, the proposal removes the route's override.
+    /// A proposal cannot be enacted until one epoch has passed. This instruction can only be
+    /// called by the `owner`, `owner_assistant` or a holder of the `AuctionParameterProposer`
+    /// role.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `ProposeRouteAuctionParameters` context.
+    /// * `args` - `ProposeRouteAuctionParametersArgs`, which specifies the route and its optional
+    ///            new `AuctionParameters`.
+    pub fn propose_route_auction_parameters(
+        ctx: Context<ProposeRouteAuctionParameters>,
+        args: ProposeRouteAuctionParametersArgs,
+    ) -> Result<()> {
+        processor::propose_route_auction_parameters(ctx, args)
+    }
+
+    /// This instruction is used to enact an existing route auction parameters proposal, which
+    /// creates a new auction config and points the route's auction config to it. It can only be
+    /// executed after the `slot_enact_delay` has passed. This instruction can only be called by
+    /// the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `UpdateRouteAuctionParameters` context.
+    pub fn update_route_auction_parameters(
+        ctx: Context<UpdateRouteAuctionParameters>,
+    ) -> Result<()> {
+        processor::update_route_auction_parameters(ctx)
+    }
+
+    /// This instruction is used to enact an existing proposal to remove a route's auction
+    /// parameters override. Auctions on this route will use the global auction config afterwards.
+    /// It can only be executed after the `slot_enact_delay` has passed. This instruction can only
+    /// be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `RemoveRouteAuctionParameters` context.
+    pub fn remove_route_auction_parameters(
+        ctx: Context<RemoveRouteAuctionParameters>,
+    ) -> Result<()> {
+        processor::remove_route_auction_parameters(ctx)
+    }
+
     /// This instruction is used to close an existing proposal by closing the proposal account. This
     /// instruction can only be called by the `owner` or `owner_assistant`.
     ///
@@ -208,8 +396,135 @@ pub mod matching_engine {
         processor::close_proposal(ctx)
     }
 
-    /// This instruction is used to update the `owner_assistant` field in the `Custodian` account.
-    /// This instruction can only be called by the `owner`.
+    /// This instruction is used to cancel an existing proposal that has not been enacted. The
+    /// proposal account is closed and a `Cancelled` event is emitted. This instruction can only be
+    /// called by the `owner` or `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CancelProposal` context.
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        processor::cancel_proposal(ctx)
+    }
+
+    /// This instruction is used to veto an existing proposal before its `slot_enact_delay` has
+    /// passed. The proposal account is closed and a `Vetoed` event is emitted. This instruction can
+    /// only be called by the `guardian`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `VetoProposal` context.
+    pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+        processor::veto_proposal(ctx)
+    }
+
+    /// This instruction is used to propose a new `guardian`, which can veto proposals. If no new
+    /// guardian is provided, the proposal removes the guardian. A proposal cannot be enacted until
+    /// the guardian enact delay has passed. This instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ProposeGuardian` context.
+    pub fn propose_guardian(ctx: Context<ProposeGuardian>) -> Result<()> {
+        processor::propose_guardian(ctx)
+    }
+
+    /// This instruction is used to enact an existing proposal to update the `guardian` field in the
+    /// `Custodian` account. It can only be executed after the `slot_enact_delay` has passed. This
+    /// instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `UpdateGuardian` context.
+    pub fn update_guardian(ctx: Context<UpdateGuardian>) -> Result<()> {
+        processor::update_guardian(ctx)
+    }
+
+    /// This instruction is used to replace the M-of-N council, whose members can collectively
+    /// approve owner-only actions. Providing no members and a zero threshold disables the council.
+    /// Replacing the council invalidates all outstanding approvals. This instruction can only be
+    /// called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`       - `UpdateCouncil` context.
+    /// * `members`   - Council members (up to 8).
+    /// * `threshold` - Number of member approvals required to execute a council action.
+    pub fn update_council(
+        ctx: Context<UpdateCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        processor::update_council(ctx, members, threshold)
+    }
+
+    /// This instruction is used by a council member to approve a council action. The approval
+    /// account for the action is created if it does not exist yet. This instruction can only be
+    /// called by a council member.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`           - `ApproveCouncilAction` context.
+    /// * `action_digest` - Keccak256 hash of the serialized [CouncilAction](state::CouncilAction).
+    pub fn approve_council_action(
+        ctx: Context<ApproveCouncilAction>,
+        action_digest: [u8; 32],
+    ) -> Result<()> {
+        processor::approve_council_action(ctx, action_digest)
+    }
+
+    /// This instruction is used to execute a council action once the council's threshold of
+    /// approvals has been met, in place of the `owner`. The approval account is closed. This
+    /// instruction can only be called by a council member.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `ExecuteCouncilAction` context.
+    /// * `action` - Approved action to execute.
+    pub fn execute_council_action(
+        ctx: Context<ExecuteCouncilAction>,
+        action: state::CouncilAction,
+    ) -> Result<()> {
+        processor::execute_council_action(ctx, action)
+    }
+
+    /// This instruction is used to grant an admin role to the `authority`, which can then perform
+    /// the instructions that the role allows. A `RoleGranted` event is emitted. This instruction can
+    /// only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `GrantRole` context.
+    /// * `role` - Role to grant.
+    pub fn grant_role(ctx: Context<GrantRole>, role: common::admin::Role) -> Result<()> {
+        processor::grant_role(ctx, role)
+    }
+
+    /// This instruction is used to revoke an admin role from the `authority`. A `RoleRevoked` event
+    /// is emitted. This instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `RevokeRole` context.
+    /// * `role` - Role to revoke.
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: common::admin::Role) -> Result<()> {
+        processor::revoke_role(ctx, role)
+    }
+
+    /// This instruction is used to propose a new `owner_assistant`. A proposal cannot be enacted
+    /// until the owner assistant enact delay has passed. This instruction can only be called by
+    /// the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ProposeOwnerAssistant` context.
+    pub fn propose_owner_assistant(ctx: Context<ProposeOwnerAssistant>) -> Result<()> {
+        processor::propose_owner_assistant(ctx)
+    }
+
+    /// This instruction is used to enact an existing proposal to update the `owner_assistant`
+    /// field in the `Custodian` account. It can only be executed after the `slot_enact_delay` has
+    /// passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -218,8 +533,20 @@ pub mod matching_engine {
         processor::update_owner_assistant(ctx)
     }
 
-    /// This instruction is used to update the `fee_recipient` field in the `Custodian` account.
-    /// This instruction can only be called by the `owner` or `owner_assistant`.
+    /// This instruction is used to propose a new fee recipient token account. A proposal cannot be
+    /// enacted until the fee recipient enact delay has passed. This instruction can only be called
+    /// by the `owner`, `owner_assistant` or a holder of the `FeeManager` role.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ProposeFeeRecipient` context.
+    pub fn propose_fee_recipient(ctx: Context<ProposeFeeRecipient>) -> Result<()> {
+        processor::propose_fee_recipient(ctx)
+    }
+
+    /// This instruction is used to enact an existing proposal to update the `fee_recipient` field
+    /// in the `Custodian` account. It can only be executed after the `slot_enact_delay` has
+    /// passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -228,8 +555,37 @@ pub mod matching_engine {
         processor::update_fee_recipient(ctx)
     }
 
-    /// This instruction is used for executing logic during an upgrade. This instruction can only be
-    /// called by the `upgrade_manager_program`.
+    /// This instruction is used to propose new enact delays, which determine how many slots must
+    /// pass before each type of proposal can be enacted. A proposal cannot be enacted until the
+    /// current enact delay for enact delays has passed. This instruction can only be called by the
+    /// `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`          - `ProposeEnactDelays` context.
+    /// * `enact_delays` - The new `EnactDelays`, see `proposal.rs`.
+    pub fn propose_enact_delays(
+        ctx: Context<ProposeEnactDelays>,
+        enact_delays: EnactDelays,
+    ) -> Result<()> {
+        processor::propose_enact_delays(ctx, enact_delays)
+    }
+
+    /// This instruction is used to enact an existing proposal to update the enact delays. It can
+    /// only be executed after the `slot_enact_delay` has passed. This instruction can only be
+    /// called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `UpdateEnactDelays` context.
+    pub fn update_enact_delays(ctx: Context<UpdateEnactDelays>) -> Result<()> {
+        processor::update_enact_delays(ctx)
+    }
+
+    /// This instruction is used for executing logic during an upgrade. It fails until the upgrade's
+    /// migration has been started and every counted account has been migrated, which prevents the
+    /// upgrade from being committed. Every upgrade must start a migration, even if no accounts need
+    /// to be migrated. This instruction can only be called by the `upgrade_manager_program`.
     ///
     /// # Arguments
     ///
@@ -238,9 +594,42 @@ pub mod matching_engine {
         processor::migrate(ctx)
     }
 
+    /// This instruction is used to start an account migration after an upgrade. The custodian is
+    /// migrated first if needed. The number of accounts that must be migrated is the number of
+    /// accounts of every versioned type with a new version, where router endpoints and auction
+    /// configs are counted by the custodian. A migration cannot be started again until the upgrade
+    /// is committed. This instruction can only be called by the `owner`, which is the Upgrade
+    /// Manager's upgrade authority until the upgrade is committed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `StartMigration` context.
+    /// * `args` - `StartMigrationArgs`, which specifies the number of accounts of each type that
+    ///            the custodian does not count.
+    pub fn start_migration(ctx: Context<StartMigration>, args: StartMigrationArgs) -> Result<()> {
+        processor::start_migration(ctx, args)
+    }
+
+    /// This instruction is used to migrate a batch of versioned accounts (passed in as remaining
+    /// accounts) to their current versions. Accounts that are already migrated are skipped, so
+    /// batches can be retried across transactions. Accounts can only be migrated once the migration
+    /// has started. This instruction can be called by anyone.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `MigrateAccounts` context.
+    pub fn migrate_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateAccounts<'info>>,
+    ) -> Result<()> {
+        processor::migrate_accounts(ctx)
+    }
+
     /// This instruction is used to create a new auction given a valid `FastMarketOrder` vaa. This
     /// instruction will record information about the auction and transfer funds from the payer to
-    /// an auction-specific token custody account. This instruction can be called by anyone.
+    /// an auction-specific token custody account. The auction uses the route's auction config if
+    /// one exists for the order's source and target chains. Otherwise the global auction config is
+    /// used. For sealed-bid auctions, the initial offer is public and subsequent offers are
+    /// committed via `commit_offer`. This instruction can be called by anyone.
     /// # Arguments
     ///
     /// * `ctx`         - `PlaceInitialOfferCctp` context.
@@ -256,7 +645,9 @@ pub mod matching_engine {
     /// This instruction is used to improve an existing auction offer. The `offer_price` must be
     /// greater than the current `offer_price` in the auction. This instruction will revert if the
     /// `offer_price` is less than the current `offer_price`. This instruction can be called by
-    /// anyone.
+    /// anyone. If the offer is placed within the auction config's extension window, the auction is
+    /// extended (up to the config's max extension). Offers for sealed-bid auctions must be
+    /// committed with `commit_offer` instead.
     ///
     /// # Arguments
     ///
@@ -267,10 +658,118 @@ pub mod matching_engine {
         processor::improve_offer(ctx, offer_price)
     }
 
+    /// This instruction is used to commit a sealed offer to a sealed-bid auction during the
+    /// auction duration. The commitment is the keccak256 hash of the offer token, the offer price
+    /// (big-endian) and a salt. The committed offer's total deposit is transferred to a custody
+    /// token account owned by the `OfferCommitment` account. This instruction will revert if the
+    /// auction is not a sealed-bid auction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`        - `CommitOffer` context.
+    /// * `commitment` - Hash of the offer token, offer price and salt.
+    pub fn commit_offer(ctx: Context<CommitOffer>, commitment: [u8; 32]) -> Result<()> {
+        processor::commit_offer(ctx, commitment)
+    }
+
+    /// This instruction is used to reveal a committed offer during the reveal period of a
+    /// sealed-bid auction. If the revealed offer price is lower than the best offer, the committed
+    /// deposit becomes the best offer's deposit and the previous best offer's deposit is returned.
+    /// Otherwise the committed deposit is returned to the offer token. This instruction can be
+    /// called by anyone who knows the offer price and salt.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`         - `RevealOffer` context.
+    /// * `offer_price` - The committed offer price.
+    /// * `salt`        - The salt used to compute the commitment.
+    pub fn reveal_offer(ctx: Context<RevealOffer>, offer_price: u64, salt: [u8; 32]) -> Result<()> {
+        processor::reveal_offer(ctx, offer_price, salt)
+    }
+
+    /// This instruction is used to close an offer commitment that was not revealed by the end of a
+    /// sealed-bid auction's reveal period. Part of the commitment's security deposit is forfeited
+    /// and split between the `executor_token` and the user (or the fee recipient if the order was
+    /// already executed). The rest of the deposit is returned to the offer token. This instruction
+    /// can be called by anyone.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CloseUnrevealedOfferCommitment` context.
+    pub fn close_unrevealed_offer_commitment(
+        ctx: Context<CloseUnrevealedOfferCommitment>,
+    ) -> Result<()> {
+        processor::close_unrevealed_offer_commitment(ctx)
+    }
+
+    /// This instruction is used to place the initial offer on a tranche of a split-fill auction.
+    /// The first tranche offer placed starts the auction. Each tranche is a portion of the
+    /// `FastMarketOrder`'s `amount_in`, and its offer price cannot exceed the tranche's portion of
+    /// the order's `max_fee`. The offer authority must approve the tranche's transfer authority to
+    /// transfer the tranche's total deposit to the auction's custody token account.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`           - `PlaceInitialTrancheOfferCctp` context.
+    /// * `tranche_index` - Index of the tranche, which must be less than the auction's number of
+    ///                     tranches.
+    /// * `offer_price`   - Fee offer for this tranche.
+    pub fn place_initial_tranche_offer_cctp(
+        ctx: Context<PlaceInitialTrancheOfferCctp>,
+        tranche_index: u8,
+        offer_price: u64,
+    ) -> Result<()> {
+        processor::place_initial_tranche_offer_cctp(ctx, tranche_index, offer_price)
+    }
+
+    /// This instruction is used to improve an existing offer on a tranche of a split-fill auction.
+    /// The `offer_price` must be less than the tranche's current offer price by at least the
+    /// auction's minimum offer delta. The previous best offer of the tranche is refunded its total
+    /// deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`         - `ImproveTrancheOffer` context.
+    /// * `offer_price` - New fee offer for this tranche.
+    pub fn improve_tranche_offer(
+        ctx: Context<ImproveTrancheOffer>,
+        offer_price: u64,
+    ) -> Result<()> {
+        processor::improve_tranche_offer(ctx, offer_price)
+    }
+
+    /// This instruction is used to execute a tranche of a split-fill auction after the auction
+    /// period has ended and every tranche has an offer. It should be executed before the
+    /// `grace_period` has ended, otherwise the tranche's best offer will incur a penalty. The
+    /// tranche's participants are paid and the amount owed to the user is held in the auction's
+    /// custody token account. Once every tranche is executed, the fast order can be executed with
+    /// `execute_fast_order_cctp` or `execute_fast_order_local`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ExecuteAuctionTranche` context.
+    pub fn execute_auction_tranche(ctx: Context<ExecuteAuctionTranche>) -> Result<()> {
+        processor::execute_auction_tranche(ctx)
+    }
+
+    /// This instruction is used to close a tranche of a split-fill auction that ended before every
+    /// tranche had an offer. The tranche's total deposit is refunded to its best offer token. Once
+    /// every placed tranche is closed, the auction is closed. This instruction can be called by
+    /// anyone.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CloseUnfilledAuctionTranche` context.
+    pub fn close_unfilled_auction_tranche(ctx: Context<CloseUnfilledAuctionTranche>) -> Result<()> {
+        processor::close_unfilled_auction_tranche(ctx)
+    }
+
     /// This instruction is used to execute the fast order after the auction period has ended.
     /// It should be executed before the `grace_period` has ended, otherwise the best offer will
     /// incur a penalty. Once executed, a CCTP transfer will be sent to the recipient encoded in the
-    /// `FastMarketOrder` VAA on the target chain.
+    /// `FastMarketOrder` VAA on the target chain. If the amount after fees is less than the order's
+    /// `min_amount_out`, the funds are instead sent to the order's `refund_address` on the source
+    /// chain.
     ///
     /// # Arguments
     ///
@@ -281,7 +780,15 @@ pub mod matching_engine {
 
     /// This instruction is used to execute the fast order after the auction period has ended.
     /// It should be executed before the `grace_period` has ended, otherwise the best offer will
-    /// incur a penalty. Once executed, a `FastFill` account will be created.
+    /// incur a penalty. Once executed, a `FastFill` account will be created. If the amount after
+    /// fees is less than the order's `min_amount_out`, the fast fill's redeemer is the order's
+    /// `refund_address` instead, which is only allowed if the order originated from Solana. Otherwise
+    /// this instruction will revert because the order must be refunded to the source chain.
+    ///
+    /// If the order requests a destination asset, the payer must deliver the order's
+    /// `min_amount_out` of this asset, which is held in a destination custody token account until
+    /// the fast fill is redeemed. In exchange, the amount owed to the user is paid to the executor
+    /// token account.
     ///
     /// # Arguments
     ///
@@ -293,7 +800,8 @@ pub mod matching_engine {
     /// This instruction is used to complete the fast fill after the `FastFill` account has been
     /// created. The Token Router program on Solana will invoke this instruction to complete the
     /// fast fill, marking it as redeemed. Tokens will be deposited into the local endpoint's
-    /// custody account.
+    /// custody account. If the fast fill delivers a destination asset, these tokens are transferred
+    /// from the fast fill's destination custody token account instead, which is then closed.
     ///
     /// # Arguments
     ///
@@ -318,10 +826,40 @@ pub mod matching_engine {
         processor::prepare_order_response_cctp(ctx, args)
     }
 
+    /// This instruction is used by the local Token Router to deposit the funds for a fast market
+    /// order placed on Solana. The Token Router invokes this instruction right after it publishes
+    /// the `FastMarketOrder` message, whose sequence is used to derive the `local_fast_order`
+    /// account. Only the Token Router's emitter (registered as the local router endpoint) can
+    /// sign for this deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `DepositLocalFastOrder` context.
+    /// * `args` - Deposit args, which has the fast market order's sequence, amount and base fee.
+    pub fn deposit_local_fast_order(
+        ctx: Context<DepositLocalFastOrder>,
+        args: DepositLocalFastOrderArgs,
+    ) -> Result<()> {
+        processor::deposit_local_fast_order(ctx, args)
+    }
+
+    /// This instruction is used to prepare the order response for a `FastMarketOrder` placed by
+    /// the local Token Router. Instead of redeeming a CCTP message, this instruction transfers the
+    /// funds deposited via `deposit_local_fast_order` to the `prepared_custody_token` account and
+    /// creates the `PreparedOrderResponse` account used to settle the auction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `PrepareOrderResponseLocal` context.
+    pub fn prepare_order_response_local(ctx: Context<PrepareOrderResponseLocal>) -> Result<()> {
+        processor::prepare_order_response_local(ctx)
+    }
+
     /// This instruction is used to settle the acution after the `FastMarketOrder` has been
     /// executed, and the `PreparedOrderResponse` has been created. This instruction will settle the
     /// auction by transferring the funds from the `prepared_custody_token` account to the best
-    /// offer account.
+    /// offer account. For split-fill auctions, this instruction is called once per tranche, which
+    /// repays the tranche's best offer its portion of the funds pro rata.
     ///
     /// # Arguments
     ///
@@ -333,7 +871,10 @@ pub mod matching_engine {
     /// This instruction is used to route funds to the `recipient` for a `FastMarketOrder` with
     /// no corresponding auction on Solana. This instruction can be called by anyone, but the sum of
     /// `init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to
-    /// the `fee_recipient`. This instruction generates a `Fill` message.
+    /// the `fee_recipient`. This instruction generates a `Fill` message. If the amount after fees
+    /// is less than the order's `min_amount_out` (or if the order requests a destination asset,
+    /// which requires an auction), the `Fill` is sent to the order's `refund_address` on the source
+    /// chain instead.
     ///
     /// # Arguments
     ///
@@ -345,7 +886,11 @@ pub mod matching_engine {
     /// This instruction is used to settle a `FastMarketOrder` with no corresponding auction. This
     /// instruction can be called by anyone, but the sum of `init_auction_fee` and `base_fee`
     /// associated with relaying a finalized VAA will be paid to the `fee_recipient`. This
-    /// instruction creates a `FastFill` account.
+    /// instruction creates a `FastFill` account. If the amount after fees is less than the order's
+    /// `min_amount_out` (or if the order requests a destination asset, which requires an auction),
+    /// the fast fill's redeemer is the order's `refund_address` instead, which is only allowed if
+    /// the order originated from Solana. Otherwise this instruction will revert because the order
+    /// must be refunded to the source chain.
     ///
     /// # Arguments
     ///
@@ -426,6 +971,47 @@ pub mod matching_engine {
         processor::close_auction(ctx)
     }
 
+    /// This instruction is used to create a `SolverVault` account and its custody token account
+    /// for the `owner`. Collateral deposited into the vault's custody token account can be used to
+    /// place and improve offers without transferring tokens for every improvement. The vault's
+    /// custody token account is used as the offer token for auctions, so any auction proceeds
+    /// (including returned deposits) will be paid into the vault. Because vaults only custody USDC,
+    /// they can only be used to place and improve offers on USDC auctions.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CreateSolverVault` context.
+    pub fn create_solver_vault(ctx: Context<CreateSolverVault>) -> Result<()> {
+        processor::create_solver_vault(ctx)
+    }
+
+    /// This instruction is used by the vault owner to request a withdrawal of collateral that is
+    /// not locked in any active auction. The withdrawal can be executed via
+    /// `withdraw_solver_vault` after the withdrawal cooldown has elapsed. Requesting a new
+    /// withdrawal replaces any existing pending withdrawal.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `RequestSolverVaultWithdrawal` context.
+    /// * `amount` - Amount of collateral to withdraw.
+    pub fn request_solver_vault_withdrawal(
+        ctx: Context<RequestSolverVaultWithdrawal>,
+        amount: u64,
+    ) -> Result<()> {
+        processor::request_solver_vault_withdrawal(ctx, amount)
+    }
+
+    /// This instruction is used by the vault owner to execute a pending withdrawal after the
+    /// withdrawal cooldown has elapsed. This instruction will revert if the requested amount
+    /// exceeds the collateral that is not locked in active auctions.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `WithdrawSolverVault` context.
+    pub fn withdraw_solver_vault(ctx: Context<WithdrawSolverVault>) -> Result<()> {
+        processor::withdraw_solver_vault(ctx)
+    }
+
     // Deprecated instructions. These instructions will revert with `ErrorCode::InstructionMissing`.
 
     /// DEPRECATED. This instruction does not exist anymore.
@@ -0,0 +1,37 @@
+use crate::{composite::*, error::MatchingEngineError, state::Proposal};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelProposal<'info> {
+    admin: Admin<'info>,
+
+    /// CHECK: This account must equal proposal.by pubkey.
+    #[account(
+        mut,
+        address = proposal.by
+    )]
+    proposed_by: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = proposed_by,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &proposal.id.to_be_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = proposal.slot_enacted_at.is_none() @ MatchingEngineError::ProposalAlreadyEnacted
+    )]
+    proposal: Account<'info, Proposal>,
+}
+
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    // Emit event to reflect cancelling the proposal.
+    emit_cpi!(crate::events::Cancelled {
+        action: ctx.accounts.proposal.action
+    });
+
+    // Done.
+    Ok(())
+}
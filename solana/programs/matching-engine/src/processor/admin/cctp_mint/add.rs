@@ -0,0 +1,70 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{AuctionConfig, CctpMint},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token};
+
+#[derive(Accounts)]
+pub struct AddCctpMint<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CctpMint::INIT_SPACE,
+        seeds = [
+            CctpMint::SEED_PREFIX,
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    cctp_mint: Account<'info, CctpMint>,
+
+    /// Circle-supported mint other than USDC, which is always supported.
+    #[account(
+        constraint = {
+            mint.key() != common::USDC_MINT
+        } @ MatchingEngineError::InvalidCctpMint,
+    )]
+    mint: Box<Account<'info, token::Mint>>,
+
+    /// Custodian's associated token account for this mint, which will be encoded as the mint
+    /// recipient in CCTP messages sent to this program.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = admin.custodian,
+    )]
+    mint_recipient: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(token::mint = mint)]
+    fee_recipient_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Auction config used for auctions of this mint without a route override. If not provided,
+    /// the global auction config is used.
+    auction_config: Option<Box<Account<'info, AuctionConfig>>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+    associated_token_program: Program<'info, associated_token::AssociatedToken>,
+}
+
+pub fn add_cctp_mint(ctx: Context<AddCctpMint>) -> Result<()> {
+    ctx.accounts.cctp_mint.set_inner(CctpMint {
+        bump: ctx.bumps.cctp_mint,
+        mint: ctx.accounts.mint.key(),
+        mint_recipient: ctx.accounts.mint_recipient.key(),
+        fee_recipient_token: ctx.accounts.fee_recipient_token.key(),
+        enabled: true,
+        config_id: ctx.accounts.auction_config.as_ref().map(|config| config.id),
+    });
+
+    // Done.
+    Ok(())
+}
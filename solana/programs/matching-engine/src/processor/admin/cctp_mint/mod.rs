@@ -0,0 +1,5 @@
+mod add;
+pub use add::*;
+
+mod update;
+pub use update::*;
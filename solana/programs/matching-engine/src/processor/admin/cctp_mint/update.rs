@@ -0,0 +1,38 @@
+use crate::{
+    composite::*,
+    state::{AuctionConfig, CctpMint},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct UpdateCctpMint<'info> {
+    admin: Admin<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            CctpMint::SEED_PREFIX,
+            cctp_mint.mint.as_ref(),
+        ],
+        bump = cctp_mint.bump,
+    )]
+    cctp_mint: Account<'info, CctpMint>,
+
+    #[account(token::mint = cctp_mint.mint)]
+    fee_recipient_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Auction config used for auctions of this mint without a route override. If not provided,
+    /// the global auction config is used.
+    auction_config: Option<Box<Account<'info, AuctionConfig>>>,
+}
+
+pub fn update_cctp_mint(ctx: Context<UpdateCctpMint>, enabled: bool) -> Result<()> {
+    let cctp_mint = &mut ctx.accounts.cctp_mint;
+    cctp_mint.fee_recipient_token = ctx.accounts.fee_recipient_token.key();
+    cctp_mint.enabled = enabled;
+    cctp_mint.config_id = ctx.accounts.auction_config.as_ref().map(|config| config.id);
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,5 @@
+mod approve;
+pub use approve::*;
+
+mod execute;
+pub use execute::*;
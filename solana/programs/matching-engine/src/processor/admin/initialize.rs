@@ -1,10 +1,14 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{AuctionConfig, Custodian},
+    state::{
+        Auction, AuctionConfig, Custodian, FastFill, MigratedVersions, PreparedOrderResponse,
+        Proposal, RouterEndpoint,
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use common::migration::{VersionHeader, Versioned};
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
 
 // Because this is used as the args for initialize, we'll make it public here.
@@ -145,6 +149,9 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
     crate::utils::auction::require_valid_parameters(&auction_params)?;
 
     ctx.accounts.custodian.set_inner(Custodian {
+        header: VersionHeader {
+            version: Custodian::VERSION,
+        },
         owner,
         pending_owner: None,
         paused: false,
@@ -152,10 +159,29 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
         owner_assistant: ctx.accounts.owner_assistant.key(),
         fee_recipient_token: ctx.accounts.fee_recipient_token.key(),
         auction_config_id,
+        latest_auction_config_id: auction_config_id,
         next_proposal_id: Default::default(),
+        enact_delays: Default::default(),
+        guardian: None,
+        council: Default::default(),
+        roles: Default::default(),
+        migration: Default::default(),
+        // There are no accounts to migrate to their current versions.
+        migrated_versions: MigratedVersions {
+            router_endpoint: RouterEndpoint::VERSION,
+            auction_config: AuctionConfig::VERSION,
+            auction: Auction::VERSION,
+            prepared_order_response: PreparedOrderResponse::VERSION,
+            proposal: Proposal::VERSION,
+            fast_fill: FastFill::VERSION,
+        },
+        num_router_endpoints: 0,
     });
 
     ctx.accounts.auction_config.set_inner(AuctionConfig {
+        header: VersionHeader {
+            version: AuctionConfig::VERSION,
+        },
         id: auction_config_id,
         parameters: auction_params,
     });
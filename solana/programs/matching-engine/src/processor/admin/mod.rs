@@ -1,6 +1,15 @@
+mod cancel_proposal;
+pub use cancel_proposal::*;
+
+mod cctp_mint;
+pub use cctp_mint::*;
+
 mod close_proposal;
 pub use close_proposal::*;
 
+mod council;
+pub use council::*;
+
 mod initialize;
 pub use initialize::*;
 
@@ -16,8 +25,20 @@ pub use ownership_transfer_request::*;
 mod propose;
 pub use propose::*;
 
+mod rate_limit;
+pub use rate_limit::*;
+
+mod role;
+pub use role::*;
+
 mod router_endpoint;
 pub use router_endpoint::*;
 
+mod solver_registry;
+pub use solver_registry::*;
+
 mod update;
 pub use update::*;
+
+mod veto_proposal;
+pub use veto_proposal::*;
@@ -11,7 +11,7 @@ pub struct ProposeAuctionParameters<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
-    admin: Admin<'info>,
+    admin: AuctionParameterProposer<'info>,
 
     #[account(
         init,
@@ -40,7 +40,7 @@ pub fn propose_auction_parameters(
         .accounts
         .admin
         .custodian
-        .auction_config_id
+        .latest_auction_config_id
         .checked_add(1)
         .ok_or_else(|| MatchingEngineError::U32Overflow)?;
     let action = ProposalAction::UpdateAuctionParameters { id, parameters };
@@ -49,7 +49,7 @@ pub fn propose_auction_parameters(
         super::Propose {
             custodian: &ctx.accounts.admin.custodian,
             proposal: &mut ctx.accounts.proposal,
-            by: &ctx.accounts.admin.owner_or_assistant,
+            by: &ctx.accounts.admin.authority,
             epoch_schedule: &ctx.accounts.epoch_schedule,
         },
         action,
@@ -0,0 +1,57 @@
+use crate::{
+    composite::*,
+    state::{Proposal, ProposalAction},
+};
+use anchor_lang::prelude::*;
+
+// Because this is used as the args for propose_enact_delays, we'll make it public here.
+pub use crate::state::EnactDelays;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeEnactDelays<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &admin.custodian.next_proposal_id.to_be_bytes()
+        ],
+        bump,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    system_program: Program<'info, System>,
+
+    epoch_schedule: Sysvar<'info, EpochSchedule>,
+}
+
+pub fn propose_enact_delays(
+    ctx: Context<ProposeEnactDelays>,
+    enact_delays: EnactDelays,
+) -> Result<()> {
+    let action = ProposalAction::UpdateEnactDelays { enact_delays };
+
+    super::propose(
+        super::Propose {
+            custodian: &ctx.accounts.admin.custodian,
+            proposal: &mut ctx.accounts.proposal,
+            by: &ctx.accounts.admin.owner,
+            epoch_schedule: &ctx.accounts.epoch_schedule,
+        },
+        action,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Proposal, ProposalAction},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeFeeRecipient<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: FeeManager<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &admin.custodian.next_proposal_id.to_be_bytes()
+        ],
+        bump,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    #[account(
+        associated_token::mint = common::USDC_MINT,
+        associated_token::authority = new_fee_recipient,
+    )]
+    new_fee_recipient_token: Account<'info, token::TokenAccount>,
+
+    /// New Fee Recipient.
+    ///
+    /// CHECK: Must not be zero pubkey.
+    #[account(
+        constraint = {
+            new_fee_recipient.key() != Pubkey::default()
+        } @ MatchingEngineError::FeeRecipientZeroPubkey,
+    )]
+    new_fee_recipient: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+
+    epoch_schedule: Sysvar<'info, EpochSchedule>,
+}
+
+pub fn propose_fee_recipient(ctx: Context<ProposeFeeRecipient>) -> Result<()> {
+    let action = ProposalAction::UpdateFeeRecipient {
+        fee_recipient_token: ctx.accounts.new_fee_recipient_token.key(),
+    };
+
+    super::propose(
+        super::Propose {
+            custodian: &ctx.accounts.admin.custodian,
+            proposal: &mut ctx.accounts.proposal,
+            by: &ctx.accounts.admin.authority,
+            epoch_schedule: &ctx.accounts.epoch_schedule,
+        },
+        action,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
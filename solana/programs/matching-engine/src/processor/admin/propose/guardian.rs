@@ -0,0 +1,62 @@
+use crate::{
+    composite::*,
+    state::{Proposal, ProposalAction},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeGuardian<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &admin.custodian.next_proposal_id.to_be_bytes()
+        ],
+        bump,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    /// New guardian. If not provided, the proposal removes the guardian.
+    ///
+    /// CHECK: This account can be any pubkey.
+    new_guardian: Option<UncheckedAccount<'info>>,
+
+    system_program: Program<'info, System>,
+
+    epoch_schedule: Sysvar<'info, EpochSchedule>,
+}
+
+pub fn propose_guardian(ctx: Context<ProposeGuardian>) -> Result<()> {
+    let action = ProposalAction::UpdateGuardian {
+        guardian: ctx
+            .accounts
+            .new_guardian
+            .as_ref()
+            .map(|guardian| guardian.key()),
+    };
+
+    super::propose(
+        super::Propose {
+            custodian: &ctx.accounts.admin.custodian,
+            proposal: &mut ctx.accounts.proposal,
+            by: &ctx.accounts.admin.owner,
+            epoch_schedule: &ctx.accounts.epoch_schedule,
+        },
+        action,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
@@ -1,12 +1,31 @@
 mod auction_parameters;
 pub use auction_parameters::*;
 
+mod enact_delays;
+pub use enact_delays::*;
+
+mod fee_recipient;
+pub use fee_recipient::*;
+
+mod guardian;
+pub use guardian::*;
+
+mod owner_assistant;
+pub use owner_assistant::*;
+
+mod route_auction_parameters;
+pub use route_auction_parameters::*;
+
+mod router_endpoint;
+pub use router_endpoint::*;
+
 use crate::{
     composite::*,
     error::MatchingEngineError,
     state::{Proposal, ProposalAction},
 };
 use anchor_lang::prelude::*;
+use common::migration::{VersionHeader, Versioned};
 
 struct Propose<'ctx, 'info> {
     custodian: &'ctx CheckedCustodian<'info>,
@@ -36,25 +55,32 @@ fn propose(accounts: Propose, action: ProposalAction, proposal_bump_seed: u8) ->
         if #[cfg(feature = "integration-test")] {
             let _ = epoch_schedule;
             // Arbitrary set for fast testing.
-            let slot_enact_delay = slot_proposed_at
-                .checked_add(8)
-                .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+            let default_enact_delay = 8;
         } else if #[cfg(feature = "testnet")] {
             let _ = epoch_schedule;
             // Arbitrary set to roughly 10 seconds (10 seconds / 0.4 seconds per slot) for
             // faster testing.
-            let slot_enact_delay = slot_proposed_at
-                .checked_add(25)
-                .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+            let default_enact_delay = 25;
         } else {
-            let slot_enact_delay = slot_proposed_at
-                .checked_add(epoch_schedule.slots_per_epoch)
-                .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+            let default_enact_delay = epoch_schedule.slots_per_epoch;
         }
     }
 
+    // Each type of action has its own enact delay. If it has not been configured, the default
+    // delay is used.
+    let enact_delay = match custodian.enact_delays.get(&action) {
+        0 => default_enact_delay,
+        enact_delay => enact_delay,
+    };
+    let slot_enact_delay = slot_proposed_at
+        .checked_add(enact_delay)
+        .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+
     // Create the proposal.
     proposal.set_inner(Proposal {
+        header: VersionHeader {
+            version: Proposal::VERSION,
+        },
         id: custodian.next_proposal_id,
         bump: proposal_bump_seed,
         action,
@@ -0,0 +1,64 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Proposal, ProposalAction},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeOwnerAssistant<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &admin.custodian.next_proposal_id.to_be_bytes()
+        ],
+        bump,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    /// New Assistant.
+    ///
+    /// CHECK: Must not be zero pubkey.
+    #[account(
+        constraint = {
+            new_owner_assistant.key() != Pubkey::default()
+        } @ MatchingEngineError::AssistantZeroPubkey,
+    )]
+    new_owner_assistant: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+
+    epoch_schedule: Sysvar<'info, EpochSchedule>,
+}
+
+pub fn propose_owner_assistant(ctx: Context<ProposeOwnerAssistant>) -> Result<()> {
+    let action = ProposalAction::UpdateOwnerAssistant {
+        owner_assistant: ctx.accounts.new_owner_assistant.key(),
+    };
+
+    super::propose(
+        super::Propose {
+            custodian: &ctx.accounts.admin.custodian,
+            proposal: &mut ctx.accounts.proposal,
+            by: &ctx.accounts.admin.owner,
+            epoch_schedule: &ctx.accounts.epoch_schedule,
+        },
+        action,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
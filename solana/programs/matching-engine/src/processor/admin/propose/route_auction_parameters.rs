@@ -0,0 +1,103 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{AuctionParameters, Proposal, ProposalAction},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeRouteAuctionParameters<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: AuctionParameterProposer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &admin.custodian.next_proposal_id.to_be_bytes()
+        ],
+        bump,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    system_program: Program<'info, System>,
+
+    epoch_schedule: Sysvar<'info, EpochSchedule>,
+}
+
+/// Arguments for [propose_route_auction_parameters].
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposeRouteAuctionParametersArgs {
+    /// Chain where fast market orders are created.
+    pub source_chain: u16,
+
+    /// Chain where fast market orders are destined.
+    pub target_chain: u16,
+
+    /// New auction parameters for this route. If `None`, the route's override will be removed so
+    /// auctions on this route use the global auction config.
+    pub parameters: Option<AuctionParameters>,
+}
+
+pub fn propose_route_auction_parameters(
+    ctx: Context<ProposeRouteAuctionParameters>,
+    args: ProposeRouteAuctionParametersArgs,
+) -> Result<()> {
+    let ProposeRouteAuctionParametersArgs {
+        source_chain,
+        target_chain,
+        parameters,
+    } = args;
+
+    require!(
+        source_chain != 0 && target_chain != 0,
+        MatchingEngineError::InvalidChain
+    );
+
+    let action = match parameters {
+        Some(parameters) => {
+            crate::utils::auction::require_valid_parameters(&parameters)?;
+
+            let id = ctx
+                .accounts
+                .admin
+                .custodian
+                .latest_auction_config_id
+                .checked_add(1)
+                .ok_or_else(|| MatchingEngineError::U32Overflow)?;
+
+            ProposalAction::UpdateRouteAuctionParameters {
+                source_chain,
+                target_chain,
+                id,
+                parameters,
+            }
+        }
+        None => ProposalAction::RemoveRouteAuctionParameters {
+            source_chain,
+            target_chain,
+        },
+    };
+
+    super::propose(
+        super::Propose {
+            custodian: &ctx.accounts.admin.custodian,
+            proposal: &mut ctx.accounts.proposal,
+            by: &ctx.accounts.admin.authority,
+            epoch_schedule: &ctx.accounts.epoch_schedule,
+        },
+        action,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Proposal, ProposalAction},
+    utils::{self, admin::AddCctpRouterEndpointArgs},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeRouterEndpoint<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: EndpointManager<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &admin.custodian.next_proposal_id.to_be_bytes()
+        ],
+        bump,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    system_program: Program<'info, System>,
+
+    epoch_schedule: Sysvar<'info, EpochSchedule>,
+}
+
+/// Arguments for [propose_router_endpoint], which specify how a router endpoint will be changed.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ProposeRouterEndpointArgs {
+    /// Add a new Token Router endpoint from a foreign chain, which must be CCTP compatible.
+    AddCctp(AddCctpRouterEndpointArgs),
+
+    /// Change an existing CCTP router endpoint's `address`, `mint_recipient` and `domain`.
+    UpdateCctp(AddCctpRouterEndpointArgs),
+
+    /// Add the Local Router endpoint, which is the Token Router program on Solana.
+    AddLocal { token_router_program: Pubkey },
+
+    /// Point the Local Router endpoint to another Token Router program.
+    UpdateLocal { token_router_program: Pubkey },
+
+    /// Disable an existing router endpoint.
+    Disable { chain: u16 },
+}
+
+pub fn propose_router_endpoint(
+    ctx: Context<ProposeRouterEndpoint>,
+    args: ProposeRouterEndpointArgs,
+) -> Result<()> {
+    let action = match args {
+        ProposeRouterEndpointArgs::AddCctp(args) => {
+            utils::admin::require_valid_cctp_router_endpoint_args(&args)?;
+            ProposalAction::AddCctpRouterEndpoint { args }
+        }
+        ProposeRouterEndpointArgs::UpdateCctp(args) => {
+            utils::admin::require_valid_cctp_router_endpoint_args(&args)?;
+            ProposalAction::UpdateCctpRouterEndpoint { args }
+        }
+        // NOTE: The Token Router program is validated when the proposal is enacted.
+        ProposeRouterEndpointArgs::AddLocal {
+            token_router_program,
+        } => {
+            require_keys_neq!(
+                token_router_program,
+                Pubkey::default(),
+                MatchingEngineError::InvalidEndpoint
+            );
+            ProposalAction::AddLocalRouterEndpoint {
+                token_router_program,
+            }
+        }
+        ProposeRouterEndpointArgs::UpdateLocal {
+            token_router_program,
+        } => {
+            require_keys_neq!(
+                token_router_program,
+                Pubkey::default(),
+                MatchingEngineError::InvalidEndpoint
+            );
+            ProposalAction::UpdateLocalRouterEndpoint {
+                token_router_program,
+            }
+        }
+        ProposeRouterEndpointArgs::Disable { chain } => {
+            require!(chain != 0, MatchingEngineError::InvalidChain);
+            ProposalAction::DisableRouterEndpoint { chain }
+        }
+    };
+
+    super::propose(
+        super::Propose {
+            custodian: &ctx.accounts.admin.custodian,
+            proposal: &mut ctx.accounts.proposal,
+            by: &ctx.accounts.admin.authority,
+            epoch_schedule: &ctx.accounts.epoch_schedule,
+        },
+        action,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
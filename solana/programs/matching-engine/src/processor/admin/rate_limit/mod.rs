@@ -0,0 +1,5 @@
+mod remove;
+pub use remove::*;
+
+mod set;
+pub use set::*;
@@ -0,0 +1,27 @@
+use crate::{composite::*, state::RateLimit};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveRateLimit<'info> {
+    /// This account will receive the lamports from closing the rate limit.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: Admin<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            rate_limit.direction.seed_prefix(),
+            &rate_limit.chain.to_be_bytes(),
+        ],
+        bump = rate_limit.bump,
+    )]
+    rate_limit: Account<'info, RateLimit>,
+}
+
+pub fn remove_rate_limit(_ctx: Context<RemoveRateLimit>) -> Result<()> {
+    // Done.
+    Ok(())
+}
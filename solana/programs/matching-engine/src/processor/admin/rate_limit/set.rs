@@ -0,0 +1,77 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{RateLimit, RateLimitDirection},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SetRateLimitArgs {
+    pub direction: RateLimitDirection,
+    pub chain: u16,
+
+    /// Maximum notional amount that can flow within any window.
+    pub limit: u64,
+
+    /// Duration of the rolling window in seconds. Cannot be zero.
+    pub window_duration: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetRateLimitArgs)]
+pub struct SetRateLimit<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: Admin<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RateLimit::INIT_SPACE,
+        seeds = [
+            args.direction.seed_prefix(),
+            &args.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    rate_limit: Account<'info, RateLimit>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn set_rate_limit(ctx: Context<SetRateLimit>, args: SetRateLimitArgs) -> Result<()> {
+    let SetRateLimitArgs {
+        direction,
+        chain,
+        limit,
+        window_duration,
+    } = args;
+
+    require!(
+        window_duration != 0,
+        MatchingEngineError::ZeroRateLimitWindow
+    );
+
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+    let rate_limit = &mut ctx.accounts.rate_limit;
+
+    // A new rate limit starts with its full capacity. Otherwise usage within the current window
+    // still counts against the new limit.
+    if rate_limit.window_duration == 0 {
+        rate_limit.set_inner(RateLimit {
+            bump: ctx.bumps.rate_limit,
+            direction,
+            chain,
+            limit,
+            window_duration,
+            capacity_at_last_tx: limit,
+            last_tx_timestamp: timestamp,
+        });
+    } else {
+        rate_limit.set_limit(limit, window_duration, timestamp)?;
+    }
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::{composite::*, error::MatchingEngineError};
+use anchor_lang::prelude::*;
+use common::admin::{utils::role, Role};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct GrantRole<'info> {
+    admin: OwnerOnlyMut<'info>,
+
+    /// Key being granted the role.
+    ///
+    /// CHECK: Must not be zero pubkey.
+    #[account(
+        constraint = {
+            authority.key() != Pubkey::default()
+        } @ MatchingEngineError::RoleHolderZeroPubkey,
+    )]
+    authority: UncheckedAccount<'info>,
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+
+    require!(
+        role::grant_role(&mut ctx.accounts.admin.custodian, &authority, role),
+        MatchingEngineError::TooManyRoleHolders
+    );
+
+    // Emit event reflecting the granted role.
+    emit_cpi!(crate::events::RoleGranted { authority, role });
+
+    // Done.
+    Ok(())
+}
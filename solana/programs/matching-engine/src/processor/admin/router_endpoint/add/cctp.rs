@@ -1,7 +1,8 @@
 use crate::{
     composite::*,
-    state::RouterEndpoint,
-    utils::{self, admin::AddCctpRouterEndpointArgs},
+    error::MatchingEngineError,
+    state::{ProposalAction, RouterEndpoint},
+    utils,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -10,12 +11,12 @@ use common::wormhole_cctp_solana::cctp::token_messenger_minter_program::{
 };
 
 #[derive(Accounts)]
-#[instruction(args: AddCctpRouterEndpointArgs)]
+#[event_cpi]
 pub struct AddCctpRouterEndpoint<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
-    admin: Admin<'info>,
+    enact: EnactProposal<'info>,
 
     #[account(
         init,
@@ -23,7 +24,7 @@ pub struct AddCctpRouterEndpoint<'info> {
         space = 8 + RouterEndpoint::INIT_SPACE,
         seeds = [
             RouterEndpoint::SEED_PREFIX,
-            &args.chain.to_be_bytes()
+            &enact.proposal.action.router_endpoint_chain().unwrap_or_default().to_be_bytes()
         ],
         bump,
     )]
@@ -36,7 +37,7 @@ pub struct AddCctpRouterEndpoint<'info> {
         token::authority = router_endpoint,
         seeds = [
             crate::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
-            &args.chain.to_be_bytes(),
+            &enact.proposal.action.router_endpoint_chain().unwrap_or_default().to_be_bytes(),
         ],
         bump,
     )]
@@ -49,7 +50,7 @@ pub struct AddCctpRouterEndpoint<'info> {
     #[account(
         seeds = [
             RemoteTokenMessenger::SEED_PREFIX,
-            args.cctp_domain.to_string().as_ref()
+            enact.proposal.action.cctp_domain().unwrap_or_default().to_string().as_ref()
         ],
         bump,
         seeds::program = token_messenger_minter_program::id(),
@@ -60,13 +61,29 @@ pub struct AddCctpRouterEndpoint<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn add_cctp_router_endpoint(
-    ctx: Context<AddCctpRouterEndpoint>,
-    args: AddCctpRouterEndpointArgs,
-) -> Result<()> {
-    utils::admin::handle_add_cctp_router_endpoint(
-        &mut ctx.accounts.router_endpoint,
-        args,
-        ctx.bumps.router_endpoint.into(),
-    )
+pub fn add_cctp_router_endpoint(ctx: Context<AddCctpRouterEndpoint>) -> Result<()> {
+    let action = ctx.accounts.enact.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::AddCctpRouterEndpoint { args } => {
+            utils::admin::handle_add_cctp_router_endpoint(
+                &mut ctx.accounts.router_endpoint,
+                args,
+                ctx.bumps.router_endpoint.into(),
+            )?;
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    // Count the new endpoint so it is migrated after upgrades that change its layout.
+    let custodian = &mut ctx.accounts.enact.admin.custodian;
+    custodian.num_router_endpoints = custodian.num_router_endpoints.saturating_add(1);
+
+    ctx.accounts.enact.enact();
+
+    // Done.
+    Ok(())
 }
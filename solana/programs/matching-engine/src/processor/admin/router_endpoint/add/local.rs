@@ -1,13 +1,19 @@
-use crate::{composite::*, state::RouterEndpoint, utils};
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{ProposalAction, RouterEndpoint},
+    utils,
+};
 use anchor_lang::prelude::*;
 use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct AddLocalRouterEndpoint<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
-    admin: Admin<'info>,
+    enact: EnactProposal<'info>,
 
     #[account(
         init,
@@ -27,11 +33,38 @@ pub struct AddLocalRouterEndpoint<'info> {
 }
 
 pub fn add_local_router_endpoint(ctx: Context<AddLocalRouterEndpoint>) -> Result<()> {
-    utils::admin::handle_add_local_router_endpoint(
-        &mut ctx.accounts.router_endpoint,
-        &ctx.accounts.local.token_router_program,
-        &ctx.accounts.local.token_router_emitter,
-        &ctx.accounts.local.token_router_mint_recipient,
-        ctx.bumps.router_endpoint.into(),
-    )
+    let action = ctx.accounts.enact.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::AddLocalRouterEndpoint {
+            token_router_program,
+        } => {
+            require_keys_eq!(
+                ctx.accounts.local.token_router_program.key(),
+                token_router_program,
+                MatchingEngineError::InvalidProposal
+            );
+
+            utils::admin::handle_add_local_router_endpoint(
+                &mut ctx.accounts.router_endpoint,
+                &ctx.accounts.local.token_router_program,
+                &ctx.accounts.local.token_router_emitter,
+                &ctx.accounts.local.token_router_mint_recipient,
+                ctx.bumps.router_endpoint.into(),
+            )?;
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    // Count the new endpoint so it is migrated after upgrades that change its layout.
+    let custodian = &mut ctx.accounts.enact.admin.custodian;
+    custodian.num_router_endpoints = custodian.num_router_endpoints.saturating_add(1);
+
+    ctx.accounts.enact.enact();
+
+    // Done.
+    Ok(())
 }
@@ -1,18 +1,41 @@
-use crate::{composite::*, state::MessageProtocol};
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{MessageProtocol, ProposalAction},
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct DisableRouterEndpoint<'info> {
-    admin: OwnerOnly<'info>,
+    enact: EnactProposal<'info>,
 
     router_endpoint: ExistingMutRouterEndpoint<'info>,
 }
 
 pub fn disable_router_endpoint(ctx: Context<DisableRouterEndpoint>) -> Result<()> {
-    let endpoint = &mut ctx.accounts.router_endpoint.info;
-    endpoint.protocol = MessageProtocol::None;
-    endpoint.address = Default::default();
-    endpoint.mint_recipient = Default::default();
+    let action = ctx.accounts.enact.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::DisableRouterEndpoint { chain } => {
+            require_eq!(
+                ctx.accounts.router_endpoint.chain,
+                chain,
+                MatchingEngineError::InvalidProposal
+            );
+
+            let endpoint = &mut ctx.accounts.router_endpoint.info;
+            endpoint.protocol = MessageProtocol::None;
+            endpoint.address = Default::default();
+            endpoint.mint_recipient = Default::default();
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    ctx.accounts.enact.enact();
 
     // Done.
     Ok(())
@@ -4,5 +4,11 @@ pub use add::*;
 mod disable;
 pub use disable::*;
 
+mod pause;
+pub use pause::*;
+
+mod solver_allowlist;
+pub use solver_allowlist::*;
+
 mod update;
 pub use update::*;
@@ -0,0 +1,22 @@
+use crate::{composite::*, state::EndpointFlow};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetEndpointPause<'info> {
+    admin: Pauser<'info>,
+
+    router_endpoint: ExistingMutRouterEndpoint<'info>,
+}
+
+pub fn set_endpoint_pause(
+    ctx: Context<SetEndpointPause>,
+    flow: EndpointFlow,
+    paused: bool,
+) -> Result<()> {
+    let pause_flag = ctx.accounts.router_endpoint.pause_flags.get_mut(flow);
+    pause_flag.paused = paused;
+    pause_flag.set_by = ctx.accounts.admin.authority.key();
+
+    // Done.
+    Ok(())
+}
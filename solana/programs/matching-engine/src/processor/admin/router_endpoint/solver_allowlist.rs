@@ -0,0 +1,16 @@
+use crate::composite::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetSolverAllowlist<'info> {
+    admin: EndpointManager<'info>,
+
+    router_endpoint: ExistingMutRouterEndpoint<'info>,
+}
+
+pub fn set_solver_allowlist(ctx: Context<SetSolverAllowlist>, enabled: bool) -> Result<()> {
+    ctx.accounts.router_endpoint.solver_allowlist = enabled;
+
+    // Done.
+    Ok(())
+}
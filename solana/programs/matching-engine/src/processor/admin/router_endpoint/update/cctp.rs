@@ -1,23 +1,19 @@
-use crate::{
-    composite::*,
-    utils::{self, admin::AddCctpRouterEndpointArgs},
-};
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction, utils};
 use anchor_lang::prelude::*;
 use common::wormhole_cctp_solana::cctp::token_messenger_minter_program::{
     self, RemoteTokenMessenger,
 };
 
 #[derive(Accounts)]
-#[instruction(args: AddCctpRouterEndpointArgs)]
+#[event_cpi]
 pub struct UpdateCctpRouterEndpoint<'info> {
-    admin: OwnerOnly<'info>,
+    enact: EnactProposal<'info>,
 
     #[account(
         constraint = {
-            require_eq!(
-                args.chain,
-                router_endpoint.chain,
-                crate::error::MatchingEngineError::InvalidEndpoint,
+            require!(
+                enact.proposal.action.router_endpoint_chain() == Some(router_endpoint.chain),
+                MatchingEngineError::InvalidProposal,
             );
 
             true
@@ -30,7 +26,7 @@ pub struct UpdateCctpRouterEndpoint<'info> {
     #[account(
         seeds = [
             RemoteTokenMessenger::SEED_PREFIX,
-            args.cctp_domain.to_string().as_ref()
+            enact.proposal.action.cctp_domain().unwrap_or_default().to_string().as_ref()
         ],
         bump,
         seeds::program = token_messenger_minter_program::id(),
@@ -38,9 +34,25 @@ pub struct UpdateCctpRouterEndpoint<'info> {
     remote_token_messenger: Account<'info, RemoteTokenMessenger>,
 }
 
-pub fn update_cctp_router_endpoint(
-    ctx: Context<UpdateCctpRouterEndpoint>,
-    args: AddCctpRouterEndpointArgs,
-) -> Result<()> {
-    utils::admin::handle_add_cctp_router_endpoint(&mut ctx.accounts.router_endpoint, args, None)
+pub fn update_cctp_router_endpoint(ctx: Context<UpdateCctpRouterEndpoint>) -> Result<()> {
+    let action = ctx.accounts.enact.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::UpdateCctpRouterEndpoint { args } => {
+            utils::admin::handle_add_cctp_router_endpoint(
+                &mut ctx.accounts.router_endpoint,
+                args,
+                None,
+            )?;
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    ctx.accounts.enact.enact();
+
+    // Done.
+    Ok(())
 }
@@ -1,10 +1,11 @@
-use crate::{composite::*, error::MatchingEngineError, utils};
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction, utils};
 use anchor_lang::prelude::*;
 use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct UpdateLocalRouterEndpoint<'info> {
-    admin: OwnerOnly<'info>,
+    enact: EnactProposal<'info>,
 
     #[account(
         constraint = {
@@ -22,11 +23,34 @@ pub struct UpdateLocalRouterEndpoint<'info> {
 }
 
 pub fn update_local_router_endpoint(ctx: Context<UpdateLocalRouterEndpoint>) -> Result<()> {
-    utils::admin::handle_add_local_router_endpoint(
-        &mut ctx.accounts.router_endpoint,
-        &ctx.accounts.local.token_router_program,
-        &ctx.accounts.local.token_router_emitter,
-        &ctx.accounts.local.token_router_mint_recipient,
-        None,
-    )
+    let action = ctx.accounts.enact.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::UpdateLocalRouterEndpoint {
+            token_router_program,
+        } => {
+            require_keys_eq!(
+                ctx.accounts.local.token_router_program.key(),
+                token_router_program,
+                MatchingEngineError::InvalidProposal
+            );
+
+            utils::admin::handle_add_local_router_endpoint(
+                &mut ctx.accounts.router_endpoint,
+                &ctx.accounts.local.token_router_program,
+                &ctx.accounts.local.token_router_emitter,
+                &ctx.accounts.local.token_router_mint_recipient,
+                None,
+            )?;
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    ctx.accounts.enact.enact();
+
+    // Done.
+    Ok(())
 }
@@ -0,0 +1,39 @@
+use crate::{composite::*, state::RegisteredSolver};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddRegisteredSolver<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: Admin<'info>,
+
+    /// CHECK: Solver authority, which is the owner of the solver's offer token accounts (or its
+    /// solver vault).
+    solver_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RegisteredSolver::INIT_SPACE,
+        seeds = [
+            RegisteredSolver::SEED_PREFIX,
+            solver_authority.key().as_ref(),
+        ],
+        bump,
+    )]
+    registered_solver: Account<'info, RegisteredSolver>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_registered_solver(ctx: Context<AddRegisteredSolver>) -> Result<()> {
+    ctx.accounts.registered_solver.set_inner(RegisteredSolver {
+        bump: ctx.bumps.registered_solver,
+        authority: ctx.accounts.solver_authority.key(),
+        suspended: false,
+    });
+
+    // Done.
+    Ok(())
+}
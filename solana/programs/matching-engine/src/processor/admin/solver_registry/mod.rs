@@ -0,0 +1,8 @@
+mod add;
+pub use add::*;
+
+mod remove;
+pub use remove::*;
+
+mod suspend;
+pub use suspend::*;
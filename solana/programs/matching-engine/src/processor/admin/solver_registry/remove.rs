@@ -0,0 +1,26 @@
+use crate::{composite::*, state::RegisteredSolver};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveRegisteredSolver<'info> {
+    admin: Admin<'info>,
+
+    /// CHECK: This account receives the registered solver account's lamports.
+    #[account(mut)]
+    beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [
+            RegisteredSolver::SEED_PREFIX,
+            registered_solver.authority.as_ref(),
+        ],
+        bump = registered_solver.bump,
+    )]
+    registered_solver: Account<'info, RegisteredSolver>,
+}
+
+pub fn remove_registered_solver(_ctx: Context<RemoveRegisteredSolver>) -> Result<()> {
+    Ok(())
+}
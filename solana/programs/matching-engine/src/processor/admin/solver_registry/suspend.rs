@@ -0,0 +1,27 @@
+use crate::{composite::*, state::RegisteredSolver};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SuspendRegisteredSolver<'info> {
+    admin: Admin<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            RegisteredSolver::SEED_PREFIX,
+            registered_solver.authority.as_ref(),
+        ],
+        bump = registered_solver.bump,
+    )]
+    registered_solver: Account<'info, RegisteredSolver>,
+}
+
+pub fn suspend_registered_solver(
+    ctx: Context<SuspendRegisteredSolver>,
+    suspend: bool,
+) -> Result<()> {
+    ctx.accounts.registered_solver.suspended = suspend;
+
+    // Done.
+    Ok(())
+}
@@ -4,6 +4,7 @@ use crate::{
     state::{AuctionConfig, AuctionParameters, Proposal, ProposalAction},
 };
 use anchor_lang::prelude::*;
+use common::migration::{VersionHeader, Versioned};
 
 #[derive(Accounts)]
 #[event_cpi]
@@ -39,7 +40,7 @@ pub struct UpdateAuctionParameters<'info> {
                     require_eq!(
                         *id,
                         // NOTE: This value is checked in `propose_auction_parameters`.
-                        admin.custodian.auction_config_id.saturating_add(1),
+                        admin.custodian.latest_auction_config_id.saturating_add(1),
                         MatchingEngineError::AuctionConfigMismatch
                     );
                 },
@@ -58,7 +59,7 @@ pub struct UpdateAuctionParameters<'info> {
         seeds = [
             AuctionConfig::SEED_PREFIX,
             // NOTE: This value is checked in `propose_auction_parameters`.
-            admin.custodian.auction_config_id.saturating_add(1).to_be_bytes().as_ref()
+            admin.custodian.latest_auction_config_id.saturating_add(1).to_be_bytes().as_ref()
         ],
         bump,
     )]
@@ -86,12 +87,17 @@ fn handle_update_auction_parameters(
     id: u32,
     parameters: AuctionParameters,
 ) -> Result<()> {
-    ctx.accounts
-        .auction_config
-        .set_inner(AuctionConfig { id, parameters });
-
-    // Update the auction config ID.
+    ctx.accounts.auction_config.set_inner(AuctionConfig {
+        header: VersionHeader {
+            version: AuctionConfig::VERSION,
+        },
+        id,
+        parameters,
+    });
+
+    // Update the auction config IDs.
     ctx.accounts.admin.custodian.auction_config_id = id;
+    ctx.accounts.admin.custodian.latest_auction_config_id = id;
 
     // Set the slot enacted at so it cannot be replayed.
     ctx.accounts.proposal.slot_enacted_at = Clock::get().unwrap().slot.into();
@@ -0,0 +1,22 @@
+use crate::{composite::*, error::MatchingEngineError};
+use anchor_lang::prelude::*;
+use common::admin::utils::council;
+
+#[derive(Accounts)]
+pub struct UpdateCouncil<'info> {
+    admin: OwnerOnlyMut<'info>,
+}
+
+pub fn update_council(
+    ctx: Context<UpdateCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        council::update_council(&mut ctx.accounts.admin.custodian, &members, threshold),
+        MatchingEngineError::InvalidCouncil
+    );
+
+    // Done.
+    Ok(())
+}
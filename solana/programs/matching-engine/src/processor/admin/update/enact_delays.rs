@@ -0,0 +1,27 @@
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpdateEnactDelays<'info> {
+    enact: EnactProposal<'info>,
+}
+
+pub fn update_enact_delays(ctx: Context<UpdateEnactDelays>) -> Result<()> {
+    let action = ctx.accounts.enact.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::UpdateEnactDelays { enact_delays } => {
+            ctx.accounts.enact.admin.custodian.enact_delays = enact_delays;
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    ctx.accounts.enact.enact();
+
+    // Done.
+    Ok(())
+}
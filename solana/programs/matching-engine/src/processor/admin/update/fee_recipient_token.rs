@@ -1,10 +1,11 @@
-use crate::{composite::*, error::MatchingEngineError};
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct UpdateFeeRecipient<'info> {
-    admin: AdminMut<'info>,
+    enact: EnactProposal<'info>,
 
     #[account(
         associated_token::mint = common::USDC_MINT,
@@ -24,8 +25,29 @@ pub struct UpdateFeeRecipient<'info> {
 }
 
 pub fn update_fee_recipient(ctx: Context<UpdateFeeRecipient>) -> Result<()> {
-    // Update the fee_recipient key.
-    ctx.accounts.admin.custodian.fee_recipient_token = ctx.accounts.new_fee_recipient_token.key();
+    let action = ctx.accounts.enact.proposal.action;
 
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::UpdateFeeRecipient {
+            fee_recipient_token,
+        } => {
+            require_keys_eq!(
+                ctx.accounts.new_fee_recipient_token.key(),
+                fee_recipient_token,
+                MatchingEngineError::InvalidProposal
+            );
+
+            // Update the fee_recipient key.
+            ctx.accounts.enact.admin.custodian.fee_recipient_token = fee_recipient_token;
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    ctx.accounts.enact.enact();
+
+    // Done.
     Ok(())
 }
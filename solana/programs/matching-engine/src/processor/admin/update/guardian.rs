@@ -0,0 +1,27 @@
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpdateGuardian<'info> {
+    enact: EnactProposal<'info>,
+}
+
+pub fn update_guardian(ctx: Context<UpdateGuardian>) -> Result<()> {
+    let action = ctx.accounts.enact.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::UpdateGuardian { guardian } => {
+            ctx.accounts.enact.admin.custodian.guardian = guardian;
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    ctx.accounts.enact.enact();
+
+    // Done.
+    Ok(())
+}
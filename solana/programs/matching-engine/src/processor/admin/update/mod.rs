@@ -1,8 +1,23 @@
 mod auction_parameters;
 pub use auction_parameters::*;
 
+mod council;
+pub use council::*;
+
+mod enact_delays;
+pub use enact_delays::*;
+
 mod fee_recipient_token;
 pub use fee_recipient_token::*;
 
+mod guardian;
+pub use guardian::*;
+
 mod owner_assistant;
 pub use owner_assistant::*;
+
+mod remove_route_auction_parameters;
+pub use remove_route_auction_parameters::*;
+
+mod route_auction_parameters;
+pub use route_auction_parameters::*;
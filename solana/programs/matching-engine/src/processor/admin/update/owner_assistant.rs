@@ -1,10 +1,11 @@
-use crate::{composite::*, error::MatchingEngineError};
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
 use anchor_lang::prelude::*;
 use common::admin::utils::assistant;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct UpdateOwnerAssistant<'info> {
-    admin: OwnerOnlyMut<'info>,
+    enact: EnactProposal<'info>,
 
     /// New Assistant.
     ///
@@ -18,10 +19,28 @@ pub struct UpdateOwnerAssistant<'info> {
 }
 
 pub fn update_owner_assistant(ctx: Context<UpdateOwnerAssistant>) -> Result<()> {
-    assistant::transfer_owner_assistant(
-        &mut ctx.accounts.admin.custodian,
-        &ctx.accounts.new_owner_assistant,
-    );
+    let action = ctx.accounts.enact.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::UpdateOwnerAssistant { owner_assistant } => {
+            require_keys_eq!(
+                ctx.accounts.new_owner_assistant.key(),
+                owner_assistant,
+                MatchingEngineError::InvalidProposal
+            );
+
+            assistant::transfer_owner_assistant(
+                &mut ctx.accounts.enact.admin.custodian,
+                &ctx.accounts.new_owner_assistant,
+            );
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    ctx.accounts.enact.enact();
 
     // Done.
     Ok(())
@@ -0,0 +1,92 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Proposal, ProposalAction, RouteAuctionConfig},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RemoveRouteAuctionParameters<'info> {
+    /// This account will receive the lamports from closing the route auction config.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnlyMut<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &proposal.id.to_be_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = {
+            require_keys_eq!(
+                proposal.owner, admin.owner.key()
+            );
+            require!(
+                proposal.slot_enacted_at.is_none(),
+                MatchingEngineError::ProposalAlreadyEnacted
+            );
+
+            require!(
+                Clock::get().unwrap().slot >= proposal.slot_enact_delay,
+                MatchingEngineError::ProposalDelayNotExpired
+            );
+
+            match &proposal.action {
+                ProposalAction::RemoveRouteAuctionParameters { .. } => (),
+                _ => return err!(ErrorCode::InstructionMissing),
+            };
+
+            true
+        }
+    )]
+    proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            RouteAuctionConfig::SEED_PREFIX,
+            &route_auction_config.source_chain.to_be_bytes(),
+            &route_auction_config.target_chain.to_be_bytes(),
+        ],
+        bump = route_auction_config.bump,
+        constraint = {
+            require!(
+                proposal.action.route()
+                    == Some((route_auction_config.source_chain, route_auction_config.target_chain)),
+                MatchingEngineError::InvalidProposal
+            );
+
+            true
+        }
+    )]
+    route_auction_config: Account<'info, RouteAuctionConfig>,
+}
+
+pub fn remove_route_auction_parameters(ctx: Context<RemoveRouteAuctionParameters>) -> Result<()> {
+    let action = ctx.accounts.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    // Set the slot enacted at so it cannot be replayed.
+    ctx.accounts.proposal.slot_enacted_at = Clock::get().unwrap().slot.into();
+
+    // Uptick the proposal ID so that someone can create a new proposal again.
+    //
+    // NOTE: Overflow check is done in propose instructions.
+    let next_proposal_id = ctx
+        .accounts
+        .admin
+        .custodian
+        .next_proposal_id
+        .saturating_add(1);
+    ctx.accounts.admin.custodian.next_proposal_id = next_proposal_id;
+
+    // Done.
+    Ok(())
+}
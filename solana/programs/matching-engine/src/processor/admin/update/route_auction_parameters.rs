@@ -0,0 +1,137 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{AuctionConfig, Proposal, ProposalAction, RouteAuctionConfig},
+};
+use anchor_lang::prelude::*;
+use common::migration::{VersionHeader, Versioned};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpdateRouteAuctionParameters<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnlyMut<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &proposal.id.to_be_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = {
+            require_keys_eq!(
+                proposal.owner, admin.owner.key()
+            );
+            require!(
+                proposal.slot_enacted_at.is_none(),
+                MatchingEngineError::ProposalAlreadyEnacted
+            );
+
+            require!(
+                Clock::get().unwrap().slot >= proposal.slot_enact_delay,
+                MatchingEngineError::ProposalDelayNotExpired
+            );
+
+            match &proposal.action {
+                ProposalAction::UpdateRouteAuctionParameters { id, .. } => {
+                    require_eq!(
+                        *id,
+                        // NOTE: This value is checked in `propose_route_auction_parameters`.
+                        admin.custodian.latest_auction_config_id.saturating_add(1),
+                        MatchingEngineError::AuctionConfigMismatch
+                    );
+                },
+                _ => return err!(ErrorCode::InstructionMissing),
+            };
+
+            true
+        }
+    )]
+    proposal: Account<'info, Proposal>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RouteAuctionConfig::INIT_SPACE,
+        seeds = [
+            RouteAuctionConfig::SEED_PREFIX,
+            &proposal.action.route().unwrap_or_default().0.to_be_bytes(),
+            &proposal.action.route().unwrap_or_default().1.to_be_bytes(),
+        ],
+        bump,
+    )]
+    route_auction_config: Account<'info, RouteAuctionConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AuctionConfig::INIT_SPACE,
+        seeds = [
+            AuctionConfig::SEED_PREFIX,
+            // NOTE: This value is checked in `propose_route_auction_parameters`.
+            admin.custodian.latest_auction_config_id.saturating_add(1).to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    auction_config: Account<'info, AuctionConfig>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn update_route_auction_parameters(ctx: Context<UpdateRouteAuctionParameters>) -> Result<()> {
+    let action = ctx.accounts.proposal.action;
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::UpdateRouteAuctionParameters {
+            source_chain,
+            target_chain,
+            id,
+            parameters,
+        } => {
+            ctx.accounts.auction_config.set_inner(AuctionConfig {
+                header: VersionHeader {
+                    version: AuctionConfig::VERSION,
+                },
+                id,
+                parameters,
+            });
+
+            ctx.accounts
+                .route_auction_config
+                .set_inner(RouteAuctionConfig {
+                    bump: ctx.bumps.route_auction_config,
+                    source_chain,
+                    target_chain,
+                    config_id: id,
+                });
+
+            // Only the latest auction config ID is updated. The global auction config ID remains
+            // the same.
+            ctx.accounts.admin.custodian.latest_auction_config_id = id;
+        }
+        _ => return err!(MatchingEngineError::InvalidProposal),
+    }
+
+    // Set the slot enacted at so it cannot be replayed.
+    ctx.accounts.proposal.slot_enacted_at = Clock::get().unwrap().slot.into();
+
+    // Uptick the proposal ID so that someone can create a new proposal again.
+    //
+    // NOTE: Overflow check is done in propose instructions.
+    let next_proposal_id = ctx
+        .accounts
+        .admin
+        .custodian
+        .next_proposal_id
+        .saturating_add(1);
+    ctx.accounts.admin.custodian.next_proposal_id = next_proposal_id;
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::{composite::*, error::MatchingEngineError, state::Proposal};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct VetoProposal<'info> {
+    #[account(
+        constraint = {
+            custodian.guardian == Some(guardian.key())
+        } @ MatchingEngineError::GuardianOnly,
+    )]
+    guardian: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    /// CHECK: This account must equal proposal.by pubkey.
+    #[account(
+        mut,
+        address = proposal.by
+    )]
+    proposed_by: UncheckedAccount<'info>,
+
+    /// The guardian can only veto a proposal while its enact delay has not passed.
+    #[account(
+        mut,
+        close = proposed_by,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &proposal.id.to_be_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = {
+            require!(
+                proposal.slot_enacted_at.is_none(),
+                MatchingEngineError::ProposalAlreadyEnacted
+            );
+
+            require!(
+                Clock::get().unwrap().slot < proposal.slot_enact_delay,
+                MatchingEngineError::ProposalDelayExpired
+            );
+
+            true
+        }
+    )]
+    proposal: Account<'info, Proposal>,
+}
+
+pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+    // Emit event to reflect vetoing the proposal.
+    emit_cpi!(crate::events::Vetoed {
+        action: ctx.accounts.proposal.action
+    });
+
+    // Done.
+    Ok(())
+}
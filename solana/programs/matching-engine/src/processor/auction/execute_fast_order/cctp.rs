@@ -1,7 +1,8 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Custodian, MessageProtocol},
+    state::{Custodian, EndpointFlow},
+    utils,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -40,16 +41,11 @@ pub struct ExecuteFastOrderCctp<'info> {
 
     execute_order: ExecuteOrder<'info>,
 
+    /// Router endpoint of the order's target chain. If the order must be refunded because the user
+    /// amount does not satisfy the minimum amount out, this must be the source chain's endpoint.
+    /// This endpoint is validated after the order is executed.
     #[account(
-        constraint = {
-            require_eq!(
-                to_router_endpoint.protocol,
-                execute_order.active_auction.target_protocol,
-                MatchingEngineError::InvalidEndpoint
-            );
-
-            true
-        }
+        constraint = to_router_endpoint.require_unpaused(EndpointFlow::OutboundCctp)?,
     )]
     to_router_endpoint: LiveRouterEndpoint<'info>,
 
@@ -64,16 +60,6 @@ pub struct ExecuteFastOrderCctp<'info> {
 }
 
 pub fn execute_fast_order_cctp(ctx: Context<ExecuteFastOrderCctp>) -> Result<()> {
-    match ctx.accounts.to_router_endpoint.protocol {
-        MessageProtocol::Cctp { domain } => handle_execute_fast_order_cctp(ctx, domain),
-        _ => err!(MatchingEngineError::InvalidCctpEndpoint),
-    }
-}
-
-pub fn handle_execute_fast_order_cctp(
-    ctx: Context<ExecuteFastOrderCctp>,
-    destination_cctp_domain: u32,
-) -> Result<()> {
     let custodian = &ctx.accounts.custodian;
     let token_program = &ctx.accounts.token_program;
 
@@ -81,6 +67,7 @@ pub fn handle_execute_fast_order_cctp(
         user_amount: amount,
         fill,
         order_executed_event,
+        order_refunded_event,
     } = super::handle_execute_fast_order(
         &mut ctx.accounts.execute_order,
         &ctx.accounts.custodian,
@@ -88,6 +75,28 @@ pub fn handle_execute_fast_order_cctp(
     )?;
 
     let active_auction = &ctx.accounts.execute_order.active_auction;
+
+    // Refunds are sent back to the source chain, whose endpoint must be a CCTP endpoint. Otherwise
+    // the funds go to the target chain.
+    let to_router_endpoint = &ctx.accounts.to_router_endpoint;
+    match &order_refunded_event {
+        Some(order_refunded_event) => require_eq!(
+            to_router_endpoint.chain,
+            order_refunded_event.source_chain,
+            MatchingEngineError::InvalidRefundEndpoint
+        ),
+        None => require_eq!(
+            to_router_endpoint.protocol,
+            active_auction.target_protocol,
+            MatchingEngineError::InvalidEndpoint
+        ),
+    }
+
+    let destination_cctp_domain = utils::auction::compute_fill_cctp_domain(
+        &to_router_endpoint.protocol,
+        order_refunded_event.is_some(),
+    )?;
+
     let auction_custody_token = &active_auction.custody_token;
     let payer = &ctx.accounts.payer;
     let system_program = &ctx.accounts.system_program;
@@ -188,6 +197,10 @@ pub fn handle_execute_fast_order_cctp(
     // being penalized so they can collect the base fee at settlement.
     emit_cpi!(order_executed_event);
 
+    if let Some(order_refunded_event) = order_refunded_event {
+        emit_cpi!(order_refunded_event);
+    }
+
     // Finally close the account since it is no longer needed.
     token::close_account(CpiContext::new_with_signer(
         token_program.to_account_info(),
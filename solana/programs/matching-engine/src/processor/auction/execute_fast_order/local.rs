@@ -1,7 +1,8 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Custodian, FastFill, ReservedFastFillSequence},
+    state::{AuctionDestinationAssetInfo, Custodian, FastFill, ReservedFastFillSequence},
+    utils,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -82,9 +83,53 @@ pub struct ExecuteFastOrderLocal<'info> {
             &execute_order.fast_vaa.load_unchecked().emitter_chain().to_be_bytes(),
         ],
         bump,
+        constraint = {
+            require_keys_eq!(
+                local_custody_token.mint,
+                execute_order.active_auction.mint,
+                MatchingEngineError::CctpMintMismatch
+            );
+
+            true
+        }
     )]
     local_custody_token: Box<Account<'info, token::TokenAccount>>,
 
+    /// Mint of the destination asset requested by the fast market order. This account must be
+    /// provided if the order requests a destination asset.
+    #[account(
+        constraint = {
+            let vaa = execute_order.fast_vaa.load_unchecked();
+            let order = LiquidityLayerMessage::try_from(vaa.payload())
+                .unwrap()
+                .to_fast_market_order_unchecked();
+
+            order.destination_asset() == Some(destination_mint.key().to_bytes())
+        } @ MatchingEngineError::DestinationAssetMismatch,
+    )]
+    destination_mint: Option<Box<Account<'info, token::Mint>>>,
+
+    /// Token account from which the payer delivers the destination asset, so the payer must be
+    /// its owner (or delegate). This account must be provided if the order requests a destination
+    /// asset.
+    #[account(mut)]
+    executor_destination_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    /// Custody token account holding the delivered destination asset until the fast fill is
+    /// redeemed. This account must be provided if the order requests a destination asset.
+    #[account(
+        init,
+        payer = payer,
+        token::mint = destination_mint,
+        token::authority = custodian,
+        seeds = [
+            crate::DESTINATION_CUSTODY_TOKEN_SEED_PREFIX,
+            fast_fill.key().as_ref(),
+        ],
+        bump,
+    )]
+    destination_custody_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
     system_program: Program<'info, System>,
     token_program: Program<'info, token::Token>,
 
@@ -99,6 +144,7 @@ pub fn execute_fast_order_local(ctx: Context<ExecuteFastOrderLocal>) -> Result<(
         user_amount: amount,
         fill,
         order_executed_event,
+        order_refunded_event,
     } = super::handle_execute_fast_order(
         &mut ctx.accounts.execute_order,
         &ctx.accounts.custodian,
@@ -109,12 +155,108 @@ pub fn execute_fast_order_local(ctx: Context<ExecuteFastOrderLocal>) -> Result<(
     // being penalized so they can collect the base fee at settlement.
     emit_cpi!(order_executed_event);
 
+    // If the order is refunded, the fast fill's redeemer is the order's refund address, which can
+    // only be redeemed locally if the order originated from Solana. Otherwise this order must be
+    // executed using the execute fast order CCTP instruction to refund it to the source chain.
+    if let Some(order_refunded_event) = order_refunded_event {
+        utils::auction::require_local_fill(order_refunded_event.source_chain, true)?;
+
+        emit_cpi!(order_refunded_event);
+    }
+
+    let auction_custody_token = &ctx.accounts.execute_order.active_auction.custody_token;
+
+    let vaa = ctx.accounts.execute_order.fast_vaa.load_unchecked();
+    let order = LiquidityLayerMessage::try_from(vaa.payload())
+        .unwrap()
+        .to_fast_market_order_unchecked();
+
+    let (fill_amount, fill_mint) = match order.destination_asset() {
+        // The payer delivers the order's minimum amount out of the destination asset in exchange
+        // for the user amount.
+        Some(destination_asset) => {
+            let (executor_destination_token, destination_custody_token) = match (
+                &ctx.accounts.destination_mint,
+                &ctx.accounts.executor_destination_token,
+                &ctx.accounts.destination_custody_token,
+            ) {
+                (Some(_), Some(executor_destination_token), Some(destination_custody_token)) => {
+                    (executor_destination_token, destination_custody_token)
+                }
+                _ => return err!(MatchingEngineError::DestinationCustodyTokenRequired),
+            };
+
+            let amount_out = order.min_amount_out();
+
+            token::transfer(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: executor_destination_token.to_account_info(),
+                        to: destination_custody_token.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                amount_out,
+            )?;
+
+            // Pay the executor token account the user amount for the delivered asset.
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: auction_custody_token.to_account_info(),
+                        to: ctx.accounts.execute_order.executor_token.to_account_info(),
+                        authority: custodian.to_account_info(),
+                    },
+                    &[Custodian::SIGNER_SEEDS],
+                ),
+                amount,
+            )?;
+
+            (amount_out, Pubkey::from(destination_asset))
+        }
+        // Transfer funds to the local custody account.
+        None => {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: auction_custody_token.to_account_info(),
+                        to: ctx.accounts.local_custody_token.to_account_info(),
+                        authority: custodian.to_account_info(),
+                    },
+                    &[Custodian::SIGNER_SEEDS],
+                ),
+                amount,
+            )?;
+
+            (amount, ctx.accounts.local_custody_token.mint)
+        }
+    };
+
+    if let Some(custody_token_bump) = ctx.bumps.destination_custody_token {
+        let info = ctx
+            .accounts
+            .execute_order
+            .active_auction
+            .info
+            .as_mut()
+            .unwrap();
+        info.destination_asset_info = AuctionDestinationAssetInfo {
+            custody_token_bump,
+            amount_out: fill_amount,
+        }
+        .into();
+    }
+
     let fast_fill = FastFill::new(
         fill,
         ctx.accounts.reserved_sequence.fast_fill_seeds.sequence,
         ctx.bumps.fast_fill,
         ctx.accounts.payer.key(),
-        amount,
+        fill_amount,
+        fill_mint,
     );
 
     // Emit the fast fill.
@@ -127,20 +269,6 @@ pub fn execute_fast_order_local(ctx: Context<ExecuteFastOrderLocal>) -> Result<(
 
     let auction_custody_token = &ctx.accounts.execute_order.active_auction.custody_token;
 
-    // Transfer funds to the local custody account.
-    token::transfer(
-        CpiContext::new_with_signer(
-            token_program.to_account_info(),
-            token::Transfer {
-                from: auction_custody_token.to_account_info(),
-                to: ctx.accounts.local_custody_token.to_account_info(),
-                authority: custodian.to_account_info(),
-            },
-            &[Custodian::SIGNER_SEEDS],
-        ),
-        amount,
-    )?;
-
     // Close the custody token account since it is no longer needed.
     token::close_account(CpiContext::new_with_signer(
         token_program.to_account_info(),
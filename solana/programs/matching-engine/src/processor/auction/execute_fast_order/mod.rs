@@ -7,14 +7,17 @@ pub use local::*;
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    events::OrderExecuted,
-    state::{Auction, AuctionStatus, MessageProtocol},
-    utils::{self, auction::DepositPenalty},
+    events::{OrderExecuted, OrderRefunded},
+    state::{Auction, AuctionStatus, SolverVault},
+    utils::{
+        self,
+        auction::{ExecutePayoutAccounts, ExecutePayouts},
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::messages::{
-    raw::{LiquidityLayerMessage, MessageToVec},
+    raw::{FastMarketOrder, LiquidityLayerMessage, MessageToVec},
     Fill,
 };
 
@@ -22,6 +25,11 @@ struct PreparedOrderExecution {
     pub user_amount: u64,
     pub fill: Fill,
     pub order_executed_event: OrderExecuted,
+
+    /// This event will be some if the user amount does not satisfy the order's minimum amount out.
+    /// In this case, the fill's redeemer is the order's refund address and the funds must be sent
+    /// back to the source chain.
+    pub order_refunded_event: Option<OrderRefunded>,
 }
 
 fn handle_execute_fast_order<'info>(
@@ -29,6 +37,47 @@ fn handle_execute_fast_order<'info>(
     custodian: &CheckedCustodian<'info>,
     token_program: &Program<'info, token::Token>,
 ) -> Result<PreparedOrderExecution> {
+    // If the best offer's deposit is locked in a solver vault, transfer it to the auction's custody
+    // token account so the order can be executed as if the deposit were transferred when the offer
+    // was placed.
+    {
+        let auction_info = execute_order.active_auction.info.as_ref().unwrap();
+        let total_deposit = auction_info.total_deposit();
+
+        match (
+            auction_info.best_offer_vault,
+            execute_order.best_offer_vault.as_mut(),
+        ) {
+            (Some(_), Some(best_offer_vault)) => {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        token::Transfer {
+                            from: execute_order
+                                .active_auction
+                                .best_offer_token
+                                .to_account_info(),
+                            to: execute_order.active_auction.custody_token.to_account_info(),
+                            authority: best_offer_vault.to_account_info(),
+                        },
+                        &[&[
+                            SolverVault::SEED_PREFIX,
+                            best_offer_vault.owner.as_ref(),
+                            &[best_offer_vault.bump],
+                        ]],
+                    ),
+                    total_deposit,
+                )?;
+
+                best_offer_vault.unlock(total_deposit);
+
+                execute_order.active_auction.custody_token.reload()?;
+            }
+            (None, None) => (),
+            _ => return err!(MatchingEngineError::SolverVaultRequired),
+        }
+    }
+
     let auction = &mut execute_order.active_auction.auction;
     let fast_vaa = &execute_order.fast_vaa;
     let custody_token = &execute_order.active_auction.custody_token;
@@ -46,151 +95,117 @@ fn handle_execute_fast_order<'info>(
         let auction_info = auction.info.as_ref().unwrap();
         let current_slot = Clock::get().unwrap().slot;
 
-        // We extend the grace period for locally executed orders. Reserving a sequence number for
-        // the fast fill will most likely require an additional transaction, so this buffer allows
-        // the best offer participant to perform his duty without the risk of getting slashed by
-        // another executor.
-        let additional_grace_period = match auction.target_protocol {
-            MessageProtocol::Local { .. } => {
-                crate::EXECUTE_FAST_ORDER_LOCAL_ADDITIONAL_GRACE_PERIOD.into()
-            }
-            _ => None,
-        };
-
-        let DepositPenalty {
-            penalty,
-            user_reward,
-        } = utils::auction::compute_deposit_penalty(
-            config,
-            auction_info,
-            current_slot,
-            additional_grace_period,
-        );
-
-        let init_auction_fee = order.init_auction_fee();
-
-        let user_amount = auction_info
-            .amount_in
-            .saturating_sub(auction_info.offer_price)
-            .saturating_sub(init_auction_fee)
-            .saturating_add(user_reward);
-
-        // Keep track of the remaining amount in the custody token account. Whatever remains will go
-        // to the executor.
-        let mut remaining_custodied_amount = custody_token.amount.saturating_sub(user_amount);
-
-        // Offer price + security deposit was checked in placing the initial offer.
-        let mut deposit_and_fee = auction_info
-            .offer_price
-            .saturating_add(auction_info.security_deposit)
-            .saturating_sub(user_reward);
-
         let auction_signer_seeds = &[
             Auction::SEED_PREFIX,
             auction.vaa_hash.as_ref(),
             &[auction.bump],
         ];
 
-        let penalized = penalty > 0;
-
-        if penalized && best_offer_token.key() != executor_token.key() {
-            deposit_and_fee = deposit_and_fee.saturating_sub(penalty);
-        }
+        let (user_amount, execute_penalty) = match auction_info.split_fill {
+            // Every tranche of a split-fill auction has already been executed, which paid each
+            // tranche's participants. Only the amount owed to the user remains in the custody token
+            // account.
+            Some(split_fill) => {
+                require!(
+                    split_fill.all_tranches_executed(),
+                    MatchingEngineError::AuctionTranchesNotExecuted
+                );
 
-        // If the initial offer token account doesn't exist anymore, we have nowhere to send the
-        // init auction fee. The executor will get these funds instead.
-        //
-        // We check that this is a legitimate token account.
-        if utils::checked_deserialize_token_account(initial_offer_token, &common::USDC_MINT)
-            .is_some()
-        {
-            if best_offer_token.key() != initial_offer_token.key() {
-                // Pay the auction initiator their fee.
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        token_program.to_account_info(),
-                        token::Transfer {
-                            from: custody_token.to_account_info(),
-                            to: initial_offer_token.to_account_info(),
-                            authority: auction.to_account_info(),
-                        },
-                        &[auction_signer_seeds],
-                    ),
-                    init_auction_fee,
-                )?;
-
-                // Because the initial offer token was paid this fee, we account for it here.
-                remaining_custodied_amount =
-                    remaining_custodied_amount.saturating_sub(init_auction_fee);
-            } else {
-                // Add it to the reimbursement.
-                deposit_and_fee = deposit_and_fee
-                    .checked_add(init_auction_fee)
-                    .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+                (
+                    split_fill
+                        .user_amount
+                        .saturating_add(auction_info.forfeited_user_reward),
+                    split_fill.execute_penalty,
+                )
             }
-        }
-
-        // Return the security deposit and the fee to the highest bidder.
-        //
-        if best_offer_token.key() == executor_token.key() {
-            // If the best offer token is equal to the executor token, just send whatever remains in
-            // the custody token account.
-            //
-            // NOTE: This will revert if the best offer token does not exist. But this will present
-            // an opportunity for another executor to execute this order and take what the best
-            // offer token would have received.
-            token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    token::Transfer {
-                        from: custody_token.to_account_info(),
-                        to: best_offer_token.to_account_info(),
-                        authority: auction.to_account_info(),
+            None => {
+                let ExecutePayouts {
+                    user_amount,
+                    initial_offer_amount,
+                    best_offer_amount,
+                    executor_amount,
+                    penalty,
+                } = utils::auction::compute_execute_payouts(
+                    config,
+                    auction_info,
+                    ExecutePayoutAccounts {
+                        // We check that this is a legitimate token account.
+                        initial_offer_token_exists: utils::checked_deserialize_token_account(
+                            initial_offer_token,
+                            &auction.mint,
+                        )
+                        .is_some(),
+                        best_offer_token_exists: utils::checked_deserialize_token_account(
+                            best_offer_token,
+                            &auction.mint,
+                        )
+                        .is_some(),
+                        best_offer_is_initial_offer: best_offer_token.key()
+                            == initial_offer_token.key(),
+                        best_offer_is_executor: best_offer_token.key() == executor_token.key(),
                     },
-                    &[auction_signer_seeds],
-                ),
-                remaining_custodied_amount,
-            )?;
-        } else {
-            // Otherwise, send the deposit and fee to the best offer token. If the best offer token
-            // doesn't exist at this point (which would be unusual), we will reserve these funds
-            // for the executor token.
-            if utils::checked_deserialize_token_account(best_offer_token, &common::USDC_MINT)
-                .is_some()
-            {
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        token_program.to_account_info(),
-                        token::Transfer {
-                            from: custody_token.to_account_info(),
-                            to: best_offer_token.to_account_info(),
-                            authority: auction.to_account_info(),
-                        },
-                        &[auction_signer_seeds],
-                    ),
-                    deposit_and_fee,
+                    custody_token.amount,
+                    order.init_auction_fee(),
+                    current_slot,
+                    utils::auction::compute_additional_grace_period(&auction.target_protocol),
                 )?;
 
-                remaining_custodied_amount =
-                    remaining_custodied_amount.saturating_sub(deposit_and_fee);
-            }
+                // Pay the auction initiator their fee.
+                if initial_offer_amount > 0 {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            token_program.to_account_info(),
+                            token::Transfer {
+                                from: custody_token.to_account_info(),
+                                to: initial_offer_token.to_account_info(),
+                                authority: auction.to_account_info(),
+                            },
+                            &[auction_signer_seeds],
+                        ),
+                        initial_offer_amount,
+                    )?;
+                }
 
-            // And pay the executor whatever remains in the auction custody token account.
-            if remaining_custodied_amount > 0 {
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        token_program.to_account_info(),
-                        token::Transfer {
-                            from: custody_token.to_account_info(),
-                            to: executor_token.to_account_info(),
-                            authority: auction.to_account_info(),
-                        },
-                        &[auction_signer_seeds],
-                    ),
-                    remaining_custodied_amount,
-                )?;
+                // Return the security deposit and the fee to the highest bidder.
+                //
+                // NOTE: If the best offer token is equal to the executor token, this will revert if
+                // the best offer token does not exist. But this will present an opportunity for
+                // another executor to execute this order and take what the best offer token would
+                // have received.
+                if best_offer_amount > 0 || best_offer_token.key() == executor_token.key() {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            token_program.to_account_info(),
+                            token::Transfer {
+                                from: custody_token.to_account_info(),
+                                to: best_offer_token.to_account_info(),
+                                authority: auction.to_account_info(),
+                            },
+                            &[auction_signer_seeds],
+                        ),
+                        best_offer_amount,
+                    )?;
+                }
+
+                // And pay the executor whatever remains in the auction custody token account.
+                if executor_amount > 0 {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            token_program.to_account_info(),
+                            token::Transfer {
+                                from: custody_token.to_account_info(),
+                                to: executor_token.to_account_info(),
+                                authority: auction.to_account_info(),
+                            },
+                            &[auction_signer_seeds],
+                        ),
+                        executor_amount,
+                    )?;
+                }
+
+                (user_amount, penalty)
             }
-        }
+        };
 
         // Set the authority of the custody token account to the custodian. He will take over from
         // here.
@@ -207,11 +222,13 @@ fn handle_execute_fast_order<'info>(
             custodian.key().into(),
         )?;
 
+        let penalized = execute_penalty.is_some();
+
         (
             user_amount,
             AuctionStatus::Completed {
                 slot: current_slot,
-                execute_penalty: if penalized { penalty.into() } else { None },
+                execute_penalty,
             },
             OrderExecuted {
                 fast_vaa_hash: auction.vaa_hash,
@@ -226,17 +243,140 @@ fn handle_execute_fast_order<'info>(
     // Set the auction status to completed.
     auction.status = new_status;
 
+    let (fill, order_refunded_event) =
+        prepare_order_fill(&order, vaa.emitter_chain(), auction.vaa_hash, user_amount)?;
+
     Ok(PreparedOrderExecution {
         user_amount,
-        fill: Fill {
-            source_chain: vaa.emitter_chain(),
-            order_sender: order.sender(),
-            redeemer: order.redeemer(),
-            redeemer_message: order
-                .message_to_vec()
-                .try_into()
-                .map_err(|_| MatchingEngineError::RedeemerMessageTooLarge)?,
-        },
+        fill,
         order_executed_event,
+        order_refunded_event,
     })
 }
+
+/// If the user amount does not satisfy the minimum amount out, the funds are refunded to the refund
+/// address on the source chain instead of being delivered to the redeemer. Orders that request a
+/// destination asset denominate their minimum amount out in that asset, which the executor delivers
+/// instead (see [execute_fast_order_local]).
+fn prepare_order_fill(
+    order: &FastMarketOrder<'_>,
+    source_chain: u16,
+    fast_vaa_hash: [u8; 32],
+    user_amount: u64,
+) -> Result<(Fill, Option<OrderRefunded>)> {
+    let min_amount_out = order.min_amount_out();
+    if order.destination_asset().is_none() && user_amount < min_amount_out {
+        Ok((
+            Fill {
+                source_chain,
+                order_sender: order.sender(),
+                redeemer: order.refund_address(),
+                redeemer_message: Vec::new()
+                    .try_into()
+                    .map_err(|_| MatchingEngineError::RedeemerMessageTooLarge)?,
+            },
+            OrderRefunded {
+                fast_vaa_hash,
+                source_chain,
+                refund_address: order.refund_address(),
+                amount: user_amount,
+                min_amount_out,
+            }
+            .into(),
+        ))
+    } else {
+        Ok((
+            Fill {
+                source_chain,
+                order_sender: order.sender(),
+                redeemer: order.redeemer(),
+                redeemer_message: order
+                    .message_to_vec()
+                    .try_into()
+                    .map_err(|_| MatchingEngineError::RedeemerMessageTooLarge)?,
+            },
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::wormhole_io::TypePrefixedPayload;
+
+    const SOURCE_CHAIN: u16 = 2;
+    const FAST_VAA_HASH: [u8; 32] = [1; 32];
+    const MIN_AMOUNT_OUT: u64 = 1_000_000;
+
+    fn encoded_order(destination_asset: Option<[u8; 32]>) -> Vec<u8> {
+        common::messages::FastMarketOrder {
+            amount_in: 2_000_000,
+            min_amount_out: MIN_AMOUNT_OUT,
+            target_chain: 1,
+            redeemer: [2; 32],
+            sender: [3; 32],
+            refund_address: [4; 32],
+            max_fee: 100_000,
+            init_auction_fee: 10_000,
+            deadline: 0,
+            redeemer_message: b"All your base are belong to us."
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            destination_asset,
+        }
+        .to_vec()
+    }
+
+    fn prepare_fill(encoded: &[u8], user_amount: u64) -> (Fill, Option<OrderRefunded>) {
+        let order = LiquidityLayerMessage::parse(encoded)
+            .unwrap()
+            .to_fast_market_order_unchecked();
+        prepare_order_fill(&order, SOURCE_CHAIN, FAST_VAA_HASH, user_amount).unwrap()
+    }
+
+    #[test]
+    fn fill_redeemer_when_min_amount_out_met() {
+        let (fill, order_refunded_event) = prepare_fill(&encoded_order(None), MIN_AMOUNT_OUT);
+
+        assert!(order_refunded_event.is_none());
+        assert_eq!(fill.source_chain, SOURCE_CHAIN);
+        assert_eq!(fill.order_sender, [3; 32]);
+        assert_eq!(fill.redeemer, [2; 32]);
+        assert_eq!(
+            fill.redeemer_message.to_vec(),
+            b"All your base are belong to us.".to_vec()
+        );
+    }
+
+    #[test]
+    fn fill_refund_address_when_min_amount_out_not_met() {
+        let user_amount = MIN_AMOUNT_OUT.saturating_sub(1);
+        let (fill, order_refunded_event) = prepare_fill(&encoded_order(None), user_amount);
+
+        // The fill is delivered to the refund address on the source chain without the redeemer
+        // message.
+        assert_eq!(fill.source_chain, SOURCE_CHAIN);
+        assert_eq!(fill.order_sender, [3; 32]);
+        assert_eq!(fill.redeemer, [4; 32]);
+        assert!(fill.redeemer_message.is_empty());
+
+        let order_refunded_event = order_refunded_event.unwrap();
+        assert_eq!(order_refunded_event.fast_vaa_hash, FAST_VAA_HASH);
+        assert_eq!(order_refunded_event.source_chain, SOURCE_CHAIN);
+        assert_eq!(order_refunded_event.refund_address, [4; 32]);
+        assert_eq!(order_refunded_event.amount, user_amount);
+        assert_eq!(order_refunded_event.min_amount_out, MIN_AMOUNT_OUT);
+    }
+
+    #[test]
+    fn fill_redeemer_when_destination_asset_requested() {
+        // The minimum amount out is denominated in the destination asset, so it is not compared
+        // against the user amount.
+        let (fill, order_refunded_event) = prepare_fill(&encoded_order(Some([5; 32])), 0);
+
+        assert!(order_refunded_event.is_none());
+        assert_eq!(fill.redeemer, [2; 32]);
+    }
+}
@@ -12,3 +12,6 @@ pub use prepare_order_response::*;
 
 mod settle;
 pub use settle::*;
+
+mod split_fill;
+pub use split_fill::*;
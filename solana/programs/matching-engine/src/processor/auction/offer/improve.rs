@@ -1,4 +1,9 @@
-use crate::{composite::*, error::MatchingEngineError, state::Auction, utils};
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Auction, RegisteredSolver, SolverVault},
+    utils,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::TRANSFER_AUTHORITY_SEED_PREFIX;
@@ -25,6 +30,18 @@ pub struct ImproveOffer<'info> {
             // This is safe because we know that this is an active auction.
             let info = active_auction.info.as_ref().unwrap();
 
+            // Offers for sealed-bid auctions must be committed and revealed instead.
+            require!(
+                !active_auction.config.is_sealed_bid(),
+                MatchingEngineError::SealedBidAuction
+            );
+
+            // Offers for split-fill auctions must be placed on tranches instead.
+            require!(
+                info.split_fill.is_none(),
+                MatchingEngineError::SplitFillAuction
+            );
+
             require!(
                 info.within_auction_duration(&active_auction.config),
                 MatchingEngineError::AuctionPeriodExpired
@@ -42,19 +59,100 @@ pub struct ImproveOffer<'info> {
     active_auction: ActiveAuction<'info>,
 
     #[account(
+        mut,
+        token::mint = active_auction.mint,
         constraint = {
-            offer_token.key() != active_auction.custody_token.key()
-        } @ MatchingEngineError::InvalidOfferToken,
+            require_keys_neq!(
+                offer_token.key(),
+                active_auction.custody_token.key(),
+                MatchingEngineError::InvalidOfferToken
+            );
+
+            // Solver vaults only custody USDC, so they cannot be used to improve offers on
+            // auctions for other mints.
+            require!(
+                offer_vault.is_none() || active_auction.mint == common::USDC_MINT,
+                MatchingEngineError::SolverVaultMintMismatch
+            );
+
+            true
+        }
     )]
     offer_token: Account<'info, token::TokenAccount>,
 
+    /// Solver vault of the current best offer. This account must be provided if the best offer's
+    /// total deposit is locked in a solver vault.
+    #[account(
+        mut,
+        constraint = {
+            Some(best_offer_vault.key()) == active_auction.info.as_ref().unwrap().best_offer_vault
+        } @ MatchingEngineError::SolverVaultMismatch,
+    )]
+    best_offer_vault: Option<Box<Account<'info, SolverVault>>>,
+
+    /// Owner of the offer vault. This signer must be provided if the offer vault is provided.
+    offer_vault_owner: Option<Signer<'info>>,
+
+    /// If provided, the offer's total deposit is locked in this solver vault instead of being
+    /// transferred to the auction's custody token account. The offer token must be this vault's
+    /// custody token account.
+    #[account(
+        mut,
+        constraint = {
+            require!(
+                offer_vault_owner.as_ref().map(|owner| owner.key()) == Some(offer_vault.owner),
+                MatchingEngineError::SolverVaultMismatch
+            );
+            require_keys_eq!(
+                offer_vault.custody_token,
+                offer_token.key(),
+                MatchingEngineError::SolverVaultMismatch
+            );
+
+            true
+        }
+    )]
+    offer_vault: Option<Box<Account<'info, SolverVault>>>,
+
+    /// Registration of the solver placing this offer. This account must be provided if the auction
+    /// only allows registered solvers.
+    #[account(
+        seeds = [
+            RegisteredSolver::SEED_PREFIX,
+            registered_solver.authority.as_ref(),
+        ],
+        bump = registered_solver.bump,
+    )]
+    registered_solver: Option<Box<Account<'info, RegisteredSolver>>>,
+
     token_program: Program<'info, token::Token>,
 }
 
 pub fn improve_offer(ctx: Context<ImproveOffer>, offer_price: u64) -> Result<()> {
     let offer_token = &ctx.accounts.offer_token;
 
-    {
+    // This is safe because we know that this is an active auction.
+    let info = *ctx.accounts.active_auction.info.as_ref().unwrap();
+    require!(
+        info.best_offer_vault.is_some() == ctx.accounts.best_offer_vault.is_some(),
+        MatchingEngineError::SolverVaultRequired
+    );
+
+    // Only registered solvers can place offers on routes with a solver allowlist.
+    if info.solver_allowlist {
+        let solver_authority = match &ctx.accounts.offer_vault {
+            Some(offer_vault) => offer_vault.owner,
+            None => offer_token.owner,
+        };
+        utils::require_registered_solver(
+            ctx.accounts.registered_solver.as_deref(),
+            &solver_authority,
+        )?;
+    }
+
+    // Transfer funds from the `offer_token` token account to the `best_offer_token` token account,
+    // but only if the pubkeys are different.
+    if offer_token.key() != ctx.accounts.active_auction.best_offer_token.key() {
         let ActiveAuction {
             auction,
             custody_token,
@@ -64,44 +162,45 @@ pub fn improve_offer(ctx: Context<ImproveOffer>, offer_price: u64) -> Result<()>
 
         let token_program = &ctx.accounts.token_program;
 
-        // Transfer funds from the `offer_token` token account to the `best_offer_token` token account,
-        // but only if the pubkeys are different.
-        if offer_token.key() != best_offer_token.key() {
-            // These operations will seem silly, but we do this as a safety measure to ensure that
-            // nothing terrible happened with the auction's custody account.
-            let total_deposit = ctx
-                .accounts
-                .active_auction
-                .info
-                .as_ref()
-                .unwrap()
-                .total_deposit();
-
-            // If the best offer token happens to be closed, we will just keep the funds in the
-            // auction custody account. The executor token account will collect these funds when the
-            // order is executed.
-            if utils::checked_deserialize_token_account(best_offer_token, &common::USDC_MINT)
-                .is_some()
-            {
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        token_program.to_account_info(),
-                        anchor_spl::token::Transfer {
-                            from: custody_token.to_account_info(),
-                            to: best_offer_token.to_account_info(),
-                            authority: auction.to_account_info(),
-                        },
-                        &[&[
-                            Auction::SEED_PREFIX,
-                            auction.vaa_hash.as_ref(),
-                            &[auction.bump],
-                        ]],
-                    ),
-                    total_deposit,
-                )?;
+        // These operations will seem silly, but we do this as a safety measure to ensure that
+        // nothing terrible happened with the auction's custody account.
+        let total_deposit = info.total_deposit();
+
+        match ctx.accounts.best_offer_vault.as_mut() {
+            // If the best offer's deposit is locked in a solver vault, we only need to unlock it.
+            Some(best_offer_vault) => best_offer_vault.unlock(total_deposit),
+            None => {
+                // If the best offer token happens to be closed, we will just keep the funds in the
+                // auction custody account. The executor token account will collect these funds
+                // when the order is executed.
+                if utils::checked_deserialize_token_account(best_offer_token, &auction.mint)
+                    .is_some()
+                {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            token_program.to_account_info(),
+                            anchor_spl::token::Transfer {
+                                from: custody_token.to_account_info(),
+                                to: best_offer_token.to_account_info(),
+                                authority: auction.to_account_info(),
+                            },
+                            &[&[
+                                Auction::SEED_PREFIX,
+                                auction.vaa_hash.as_ref(),
+                                &[auction.bump],
+                            ]],
+                        ),
+                        total_deposit,
+                    )?;
+                }
             }
+        }
 
-            token::transfer(
+        match ctx.accounts.offer_vault.as_mut() {
+            // Lock the total deposit in the offer's solver vault. These funds will be transferred
+            // to the auction's custody token account when the order is executed.
+            Some(offer_vault) => offer_vault.lock(total_deposit, offer_token.amount)?,
+            None => token::transfer(
                 CpiContext::new_with_signer(
                     token_program.to_account_info(),
                     anchor_spl::token::Transfer {
@@ -117,13 +216,33 @@ pub fn improve_offer(ctx: Context<ImproveOffer>, offer_price: u64) -> Result<()>
                     ]],
                 ),
                 total_deposit,
-            )?;
+            )?,
         }
+
+        let offer_vault = ctx.accounts.offer_vault.as_ref().map(|vault| vault.key());
+        ctx.accounts
+            .active_auction
+            .info
+            .as_mut()
+            .unwrap()
+            .best_offer_vault = offer_vault;
     }
 
     // Update info before we emit event.
     {
-        let info = ctx.accounts.active_auction.info.as_mut().unwrap();
+        let ActiveAuction {
+            auction, config, ..
+        } = &mut ctx.accounts.active_auction;
+        let info = auction.info.as_mut().unwrap();
+
+        // An improvement near the end of the auction extends the auction so other participants
+        // have a chance to respond.
+        if let Some(extended_end_slot) =
+            utils::auction::compute_extended_end_slot(config, info, Clock::get().unwrap().slot)
+        {
+            info.extended_end_slot = extended_end_slot.into();
+        }
+
         info.best_offer_token = offer_token.key();
         info.offer_price = offer_price;
     }
@@ -3,3 +3,6 @@ pub use improve::*;
 
 mod place_initial;
 pub use place_initial::*;
+
+mod sealed_bid;
+pub use sealed_bid::*;
@@ -1,12 +1,19 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Auction, AuctionConfig, AuctionInfo, AuctionStatus, MessageProtocol},
+    state::{
+        Auction, AuctionConfig, AuctionInfo, AuctionStatus, CctpMint, EndpointFlow,
+        MessageProtocol, RateLimit, RegisteredSolver, RouteAuctionConfig, SolverVault,
+    },
     utils,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use common::{messages::raw::LiquidityLayerMessage, TRANSFER_AUTHORITY_SEED_PREFIX};
+use common::{
+    messages::raw::LiquidityLayerMessage,
+    migration::{VersionHeader, Versioned},
+    TRANSFER_AUTHORITY_SEED_PREFIX,
+};
 
 #[derive(Accounts)]
 #[instruction(offer_price: u64)]
@@ -32,19 +39,73 @@ pub struct PlaceInitialOfferCctp<'info> {
     #[account(constraint = !custodian.paused @ MatchingEngineError::Paused)]
     custodian: CheckedCustodian<'info>,
 
+    /// If the route has an auction config override, this account must be the overriding config.
+    /// Otherwise if the mint has an auction config override, this account must be the mint's
+    /// config. Otherwise it must be the global auction config.
     #[account(
         constraint = {
             require_eq!(
                 auction_config.id,
-                custodian.auction_config_id,
+                RouteAuctionConfig::config_id_or(
+                    &route_auction_config,
+                    CctpMint::config_id_or(&mint.cctp_mint, custodian.auction_config_id)?,
+                )?,
                 MatchingEngineError::AuctionConfigMismatch,
             );
 
+            // Offers for split-fill auctions must be placed on tranches instead.
+            require!(
+                !auction_config.is_split_fill(),
+                MatchingEngineError::SplitFillAuction
+            );
+
             true
         }
     )]
     auction_config: Account<'info, AuctionConfig>,
 
+    /// Route auction config for this fast market order's source and target chains. This account
+    /// may not exist, in which case the global auction config is used.
+    ///
+    /// CHECK: Seeds must be \["route-auction-config", source_chain, target_chain\].
+    #[account(
+        seeds = [
+            RouteAuctionConfig::SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+            &fast_order_path.to_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    route_auction_config: UncheckedAccount<'info>,
+
+    /// Rate limit for fast market orders sent from the source chain. This account may not exist,
+    /// in which case orders from this chain are not rate limited.
+    ///
+    /// CHECK: Seeds must be \["inbound-rate-limit", source_chain\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::INBOUND_SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    inbound_rate_limit: UncheckedAccount<'info>,
+
+    /// Rate limit for fast market orders destined for the target chain. This account may not
+    /// exist, in which case orders to this chain are not rate limited.
+    ///
+    /// CHECK: Seeds must be \["outbound-rate-limit", target_chain\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::OUTBOUND_SEED_PREFIX,
+            &fast_order_path.to_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    outbound_rate_limit: UncheckedAccount<'info>,
+
     #[account(
         constraint = {
             match fast_order_path.to_endpoint.protocol {
@@ -69,11 +130,21 @@ pub struct PlaceInitialOfferCctp<'info> {
                 MatchingEngineError::FastMarketOrderExpired,
             );
 
+            utils::require_valid_destination_asset(
+                order,
+                &fast_order_path.to_endpoint.protocol,
+                &mint.key(),
+            )?;
+
             require!(
                 offer_price <= order.max_fee(),
                 MatchingEngineError::OfferPriceTooHigh
             );
 
+            fast_order_path
+                .from_endpoint
+                .require_unpaused(EndpointFlow::InboundAuctions)?;
+
             true
         }
     )]
@@ -93,12 +164,59 @@ pub struct PlaceInitialOfferCctp<'info> {
     )]
     auction: Box<Account<'info, Auction>>,
 
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = {
+            // Solver vaults only custody USDC, so they cannot be used to place offers on auctions
+            // for other mints.
+            require!(
+                solver_vault.is_none() || mint.key() == common::USDC_MINT,
+                MatchingEngineError::SolverVaultMintMismatch
+            );
+
+            true
+        }
+    )]
     offer_token: Box<Account<'info, token::TokenAccount>>,
 
+    /// If provided, the offer's total deposit is locked in this solver vault instead of being
+    /// transferred to the auction's custody token account. The offer token must be this vault's
+    /// custody token account and the payer must be the vault's owner.
+    #[account(
+        mut,
+        constraint = {
+            require_keys_eq!(
+                solver_vault.owner,
+                payer.key(),
+                MatchingEngineError::SolverVaultMismatch
+            );
+            require_keys_eq!(
+                solver_vault.custody_token,
+                offer_token.key(),
+                MatchingEngineError::SolverVaultMismatch
+            );
+
+            true
+        }
+    )]
+    solver_vault: Option<Box<Account<'info, SolverVault>>>,
+
+    /// Registration of the solver placing this offer. This account must be provided if either of
+    /// the route's router endpoints has a solver allowlist.
+    #[account(
+        seeds = [
+            RegisteredSolver::SEED_PREFIX,
+            registered_solver.authority.as_ref(),
+        ],
+        bump = registered_solver.bump,
+    )]
+    registered_solver: Option<Box<Account<'info, RegisteredSolver>>>,
+
     #[account(
         init,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = auction,
         seeds = [
             crate::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
@@ -108,7 +226,7 @@ pub struct PlaceInitialOfferCctp<'info> {
     )]
     auction_custody_token: Box<Account<'info, token::TokenAccount>>,
 
-    usdc: Usdc<'info>,
+    mint: SupportedCctpMint<'info>,
 
     system_program: Program<'info, System>,
     token_program: Program<'info, token::Token>,
@@ -129,18 +247,40 @@ pub fn place_initial_offer_cctp(
 
     // Saturating to u64::MAX is safe here. If the amount really ends up being this large, the
     // checked addition below will catch it.
-    let security_deposit =
-        order
-            .max_fee()
-            .saturating_add(utils::auction::compute_notional_security_deposit(
-                &ctx.accounts.auction_config,
-                amount_in,
-            ));
+    let security_deposit = utils::auction::compute_security_deposit(
+        &ctx.accounts.auction_config,
+        order.max_fee(),
+        amount_in,
+    );
+    let total_deposit = amount_in
+        .checked_add(security_deposit)
+        .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+
+    // Orders that exceed either rate limit cannot be auctioned and must take the slow path.
+    RateLimit::consume_if_exists(&ctx.accounts.inbound_rate_limit, amount_in)?;
+    RateLimit::consume_if_exists(&ctx.accounts.outbound_rate_limit, amount_in)?;
+
+    // Only registered solvers can place offers on routes with a solver allowlist.
+    let solver_allowlist = ctx.accounts.fast_order_path.from_endpoint.solver_allowlist
+        || ctx.accounts.fast_order_path.to_endpoint.solver_allowlist;
+    if solver_allowlist {
+        let solver_authority = match &ctx.accounts.solver_vault {
+            Some(solver_vault) => solver_vault.owner,
+            None => ctx.accounts.offer_token.owner,
+        };
+        utils::require_registered_solver(
+            ctx.accounts.registered_solver.as_deref(),
+            &solver_authority,
+        )?;
+    }
 
     // Set up the Auction account for this auction.
     let config = &ctx.accounts.auction_config;
     let initial_offer_token = ctx.accounts.offer_token.key();
     ctx.accounts.auction.set_inner(Auction {
+        header: VersionHeader {
+            version: Auction::VERSION,
+        },
         bump: ctx.bumps.auction,
         vaa_hash: fast_vaa.digest().0,
         vaa_timestamp: fast_vaa.timestamp(),
@@ -153,15 +293,21 @@ pub fn place_initial_offer_cctp(
             vaa_sequence: fast_vaa.sequence(),
             source_chain: fast_vaa.emitter_chain(),
             best_offer_token: initial_offer_token,
+            best_offer_vault: ctx.accounts.solver_vault.as_ref().map(|vault| vault.key()),
             initial_offer_token,
+            solver_allowlist,
             start_slot: Clock::get().unwrap().slot,
+            extended_end_slot: Default::default(),
             amount_in,
             security_deposit,
             offer_price,
+            forfeited_user_reward: Default::default(),
+            split_fill: Default::default(),
             redeemer_message_len: order.redeemer_message_len(),
             destination_asset_info: Default::default(),
         }
         .into(),
+        mint: ctx.accounts.mint.key(),
     });
 
     let info = ctx.accounts.auction.info.as_ref().unwrap();
@@ -183,25 +329,28 @@ pub fn place_initial_offer_cctp(
             .checked_sub(1),
     }));
 
-    // Finally transfer tokens from the offer authority's token account to the
-    // auction's custody account.
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Transfer {
-                from: ctx.accounts.offer_token.to_account_info(),
-                to: ctx.accounts.auction_custody_token.to_account_info(),
-                authority: ctx.accounts.transfer_authority.to_account_info(),
-            },
-            &[&[
-                TRANSFER_AUTHORITY_SEED_PREFIX,
-                ctx.accounts.auction.key().as_ref(),
-                &offer_price.to_be_bytes(),
-                &[ctx.bumps.transfer_authority],
-            ]],
+    match ctx.accounts.solver_vault.as_mut() {
+        // Lock the total deposit in the solver vault. These funds will be transferred to the
+        // auction's custody token account when the order is executed.
+        Some(solver_vault) => solver_vault.lock(total_deposit, ctx.accounts.offer_token.amount),
+        // Finally transfer tokens from the offer authority's token account to the
+        // auction's custody account.
+        None => token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.offer_token.to_account_info(),
+                    to: ctx.accounts.auction_custody_token.to_account_info(),
+                    authority: ctx.accounts.transfer_authority.to_account_info(),
+                },
+                &[&[
+                    TRANSFER_AUTHORITY_SEED_PREFIX,
+                    ctx.accounts.auction.key().as_ref(),
+                    &offer_price.to_be_bytes(),
+                    &[ctx.bumps.transfer_authority],
+                ]],
+            ),
+            total_deposit,
         ),
-        amount_in
-            .checked_add(security_deposit)
-            .ok_or_else(|| MatchingEngineError::U64Overflow)?,
-    )
+    }
 }
@@ -0,0 +1,243 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Auction, AuctionConfig, AuctionStatus, CctpMint, OfferCommitment},
+    utils::{self, auction::DepositPenalty},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CloseUnrevealedOfferCommitment<'info> {
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        mut,
+        address = offer_commitment.auction,
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        constraint = {
+            require_eq!(
+                auction.info.as_ref().unwrap().config_id,
+                config.id,
+                MatchingEngineError::AuctionConfigMismatch
+            );
+
+            let info = auction.info.as_ref().unwrap();
+            require!(
+                Clock::get().unwrap().slot > info.auction_end_slot(&config),
+                MatchingEngineError::RevealPeriodNotExpired
+            );
+
+            true
+        },
+    )]
+    config: Box<Account<'info, AuctionConfig>>,
+
+    /// Auction custody token account, which receives the user reward if the auction has not been
+    /// executed yet.
+    ///
+    /// CHECK: Mutable. Seeds must be \["auction-custody", auction.key()\].
+    #[account(
+        mut,
+        seeds = [
+            crate::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump = auction.info.as_ref().unwrap().custody_token_bump,
+    )]
+    auction_custody_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        seeds = [
+            OfferCommitment::SEED_PREFIX,
+            auction.key().as_ref(),
+            offer_commitment.offer_token.as_ref(),
+        ],
+        bump = offer_commitment.bump,
+    )]
+    offer_commitment: Box<Account<'info, OfferCommitment>>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::COMMITMENT_CUSTODY_TOKEN_SEED_PREFIX,
+            offer_commitment.key().as_ref(),
+        ],
+        bump = offer_commitment.custody_token_bump,
+    )]
+    commitment_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Mutable. Must equal the offer commitment's `offer_token`.
+    #[account(
+        mut,
+        address = offer_commitment.offer_token,
+    )]
+    offer_token: UncheckedAccount<'info>,
+
+    /// Token account receiving the penalty for closing this unrevealed commitment.
+    #[account(
+        mut,
+        token::mint = auction.mint,
+    )]
+    executor_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Receives the user reward if the auction has already been executed.
+    #[account(
+        mut,
+        address = CctpMint::fee_recipient_token_or(&cctp_mint, custodian.fee_recipient_token)?,
+    )]
+    fee_recipient_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CCTP mint registration for the auction's mint, which may not exist if the mint is USDC.
+    ///
+    /// CHECK: Seeds must be \["cctp-mint", mint\].
+    #[account(
+        seeds = [
+            CctpMint::SEED_PREFIX,
+            auction.mint.as_ref(),
+        ],
+        bump,
+    )]
+    cctp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: This account must equal the offer commitment's `prepared_by`.
+    #[account(
+        mut,
+        address = offer_commitment.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn close_unrevealed_offer_commitment(
+    ctx: Context<CloseUnrevealedOfferCommitment>,
+) -> Result<()> {
+    let DepositPenalty {
+        penalty,
+        user_reward,
+    } = utils::auction::compute_unrevealed_commitment_penalty(
+        &ctx.accounts.config,
+        ctx.accounts.auction.info.as_ref().unwrap(),
+    );
+
+    let commitment_custody_token = &ctx.accounts.commitment_custody_token;
+    let executor_token = &ctx.accounts.executor_token;
+    let offer_token = &ctx.accounts.offer_token;
+    let token_program = &ctx.accounts.token_program;
+
+    let offer_commitment = &ctx.accounts.offer_commitment;
+    let commitment_signer_seeds = &[
+        OfferCommitment::SEED_PREFIX,
+        offer_commitment.auction.as_ref(),
+        offer_commitment.offer_token.as_ref(),
+        &[offer_commitment.bump],
+    ];
+
+    // Keep track of the remaining amount in the custody token account. Whatever remains will go
+    // back to the offer token.
+    let mut remaining_custodied_amount = commitment_custody_token.amount;
+
+    if penalty > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: commitment_custody_token.to_account_info(),
+                    to: executor_token.to_account_info(),
+                    authority: offer_commitment.to_account_info(),
+                },
+                &[commitment_signer_seeds],
+            ),
+            penalty,
+        )?;
+
+        remaining_custodied_amount = remaining_custodied_amount.saturating_sub(penalty);
+    }
+
+    if user_reward > 0 {
+        let auction = &mut ctx.accounts.auction;
+
+        // If the auction is still active, the user reward will be paid to the user when the order
+        // is executed. Otherwise it is too late to pay the user, so the fee recipient collects it.
+        let user_reward_token = if matches!(auction.status, AuctionStatus::Active) {
+            let info = auction.info.as_mut().unwrap();
+            info.forfeited_user_reward = info
+                .forfeited_user_reward
+                .checked_add(user_reward)
+                .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+
+            ctx.accounts.auction_custody_token.to_account_info()
+        } else {
+            ctx.accounts.fee_recipient_token.to_account_info()
+        };
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: commitment_custody_token.to_account_info(),
+                    to: user_reward_token,
+                    authority: offer_commitment.to_account_info(),
+                },
+                &[commitment_signer_seeds],
+            ),
+            user_reward,
+        )?;
+
+        remaining_custodied_amount = remaining_custodied_amount.saturating_sub(user_reward);
+    }
+
+    // Return the rest of the deposit to the offer token. If the offer token doesn't exist anymore,
+    // the executor token will collect these funds instead.
+    if remaining_custodied_amount > 0 {
+        let to =
+            if utils::checked_deserialize_token_account(offer_token, &ctx.accounts.auction.mint)
+                .is_some()
+            {
+                offer_token.to_account_info()
+            } else {
+                executor_token.to_account_info()
+            };
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: commitment_custody_token.to_account_info(),
+                    to,
+                    authority: offer_commitment.to_account_info(),
+                },
+                &[commitment_signer_seeds],
+            ),
+            remaining_custodied_amount,
+        )?;
+    }
+
+    // Finally close the commitment's custody token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: commitment_custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: offer_commitment.to_account_info(),
+        },
+        &[commitment_signer_seeds],
+    ))?;
+
+    emit_cpi!(crate::events::OfferCommitmentForfeited {
+        fast_vaa_hash: ctx.accounts.auction.vaa_hash,
+        offer_token: offer_token.key(),
+        penalty,
+        user_reward,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{OfferCommitment, RegisteredSolver},
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::TRANSFER_AUTHORITY_SEED_PREFIX;
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+#[event_cpi]
+pub struct CommitOffer<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// The auction participant needs to set approval to this PDA.
+    ///
+    /// CHECK: Seeds must be \["transfer-authority", auction.key(), commitment\].
+    #[account(
+        seeds = [
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            active_auction.key().as_ref(),
+            &commitment,
+        ],
+        bump
+    )]
+    transfer_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = {
+            require!(
+                active_auction.config.is_sealed_bid(),
+                MatchingEngineError::NotSealedBidAuction
+            );
+
+            // This is safe because we know that this is an active auction.
+            let info = active_auction.info.as_ref().unwrap();
+
+            require!(
+                info.within_offer_period(&active_auction.config),
+                MatchingEngineError::AuctionPeriodExpired
+            );
+
+            true
+        }
+    )]
+    active_auction: ActiveAuction<'info>,
+
+    #[account(
+        mut,
+        constraint = {
+            offer_token.key() != active_auction.custody_token.key()
+        } @ MatchingEngineError::InvalidOfferToken,
+    )]
+    offer_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Registration of the solver committing this offer. This account must be provided if the
+    /// auction only allows registered solvers.
+    #[account(
+        seeds = [
+            RegisteredSolver::SEED_PREFIX,
+            registered_solver.authority.as_ref(),
+        ],
+        bump = registered_solver.bump,
+    )]
+    registered_solver: Option<Box<Account<'info, RegisteredSolver>>>,
+
+    /// Only one offer can be committed per offer token for each auction.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OfferCommitment::INIT_SPACE,
+        seeds = [
+            OfferCommitment::SEED_PREFIX,
+            active_auction.key().as_ref(),
+            offer_token.key().as_ref(),
+        ],
+        bump,
+    )]
+    offer_commitment: Box<Account<'info, OfferCommitment>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = offer_commitment,
+        seeds = [
+            crate::COMMITMENT_CUSTODY_TOKEN_SEED_PREFIX,
+            offer_commitment.key().as_ref(),
+        ],
+        bump,
+    )]
+    commitment_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: This address must equal the auction's mint.
+    #[account(address = active_auction.mint)]
+    mint: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn commit_offer(ctx: Context<CommitOffer>, commitment: [u8; 32]) -> Result<()> {
+    let auction = &ctx.accounts.active_auction;
+    let info = auction.info.as_ref().unwrap();
+    let offer_token = &ctx.accounts.offer_token;
+
+    // Only registered solvers can commit offers on routes with a solver allowlist.
+    if info.solver_allowlist {
+        utils::require_registered_solver(
+            ctx.accounts.registered_solver.as_deref(),
+            &offer_token.owner,
+        )?;
+    }
+
+    ctx.accounts.offer_commitment.set_inner(OfferCommitment {
+        bump: ctx.bumps.offer_commitment,
+        custody_token_bump: ctx.bumps.commitment_custody_token,
+        auction: auction.key(),
+        offer_token: offer_token.key(),
+        prepared_by: ctx.accounts.payer.key(),
+        commitment,
+    });
+
+    emit_cpi!(crate::events::OfferCommitted {
+        fast_vaa_hash: auction.vaa_hash,
+        offer_token: offer_token.key(),
+        commitment,
+        reveal_end_slot: info.auction_end_slot(&auction.config),
+    });
+
+    // Because the offer price is not known until it is revealed, every committed offer must
+    // deposit the same amount as the best offer.
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: offer_token.to_account_info(),
+                to: ctx.accounts.commitment_custody_token.to_account_info(),
+                authority: ctx.accounts.transfer_authority.to_account_info(),
+            },
+            &[&[
+                TRANSFER_AUTHORITY_SEED_PREFIX,
+                auction.key().as_ref(),
+                &commitment,
+                &[ctx.bumps.transfer_authority],
+            ]],
+        ),
+        info.total_deposit(),
+    )
+}
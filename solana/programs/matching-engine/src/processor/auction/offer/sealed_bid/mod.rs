@@ -0,0 +1,8 @@
+mod close_unrevealed;
+pub use close_unrevealed::*;
+
+mod commit;
+pub use commit::*;
+
+mod reveal;
+pub use reveal::*;
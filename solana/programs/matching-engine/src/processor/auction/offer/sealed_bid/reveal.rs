@@ -0,0 +1,241 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Auction, OfferCommitment, SolverVault},
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RevealOffer<'info> {
+    #[account(
+        constraint = {
+            require!(
+                active_auction.config.is_sealed_bid(),
+                MatchingEngineError::NotSealedBidAuction
+            );
+
+            // This is safe because we know that this is an active auction.
+            let info = active_auction.info.as_ref().unwrap();
+
+            require!(
+                !info.within_offer_period(&active_auction.config),
+                MatchingEngineError::RevealPeriodNotStarted
+            );
+            require!(
+                info.within_auction_duration(&active_auction.config),
+                MatchingEngineError::RevealPeriodExpired
+            );
+
+            true
+        }
+    )]
+    active_auction: ActiveAuction<'info>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        seeds = [
+            OfferCommitment::SEED_PREFIX,
+            active_auction.key().as_ref(),
+            offer_commitment.offer_token.as_ref(),
+        ],
+        bump = offer_commitment.bump,
+    )]
+    offer_commitment: Box<Account<'info, OfferCommitment>>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::COMMITMENT_CUSTODY_TOKEN_SEED_PREFIX,
+            offer_commitment.key().as_ref(),
+        ],
+        bump = offer_commitment.custody_token_bump,
+    )]
+    commitment_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// If the revealed offer is not the best offer, its deposit is returned to this token account.
+    #[account(
+        mut,
+        address = offer_commitment.offer_token,
+    )]
+    offer_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Solver vault of the current best offer. This account must be provided if the best offer's
+    /// total deposit is locked in a solver vault.
+    #[account(
+        mut,
+        constraint = {
+            Some(best_offer_vault.key()) == active_auction.info.as_ref().unwrap().best_offer_vault
+        } @ MatchingEngineError::SolverVaultMismatch,
+    )]
+    best_offer_vault: Option<Box<Account<'info, SolverVault>>>,
+
+    /// CHECK: This account must equal the offer commitment's `prepared_by`.
+    #[account(
+        mut,
+        address = offer_commitment.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn reveal_offer(ctx: Context<RevealOffer>, offer_price: u64, salt: [u8; 32]) -> Result<()> {
+    require!(
+        ctx.accounts.offer_commitment.matches(offer_price, &salt),
+        MatchingEngineError::InvalidOfferReveal
+    );
+
+    // This is safe because we know that this is an active auction.
+    let info = *ctx.accounts.active_auction.info.as_ref().unwrap();
+    require!(
+        info.best_offer_vault.is_some() == ctx.accounts.best_offer_vault.is_some(),
+        MatchingEngineError::SolverVaultRequired
+    );
+
+    let offer_token = &ctx.accounts.offer_token;
+    let commitment_custody_token = &ctx.accounts.commitment_custody_token;
+    let token_program = &ctx.accounts.token_program;
+
+    let offer_commitment = &ctx.accounts.offer_commitment;
+    let commitment_signer_seeds = &[
+        OfferCommitment::SEED_PREFIX,
+        offer_commitment.auction.as_ref(),
+        offer_commitment.offer_token.as_ref(),
+        &[offer_commitment.bump],
+    ];
+
+    let total_deposit = info.total_deposit();
+
+    // The lowest revealed offer wins. If there is a tie, the offer revealed first wins.
+    let best_offer = offer_price < info.offer_price;
+
+    if best_offer {
+        let ActiveAuction {
+            auction,
+            custody_token,
+            best_offer_token,
+            ..
+        } = &ctx.accounts.active_auction;
+
+        match ctx.accounts.best_offer_vault.as_mut() {
+            // If the best offer's deposit is locked in a solver vault, we only need to unlock it.
+            Some(best_offer_vault) => best_offer_vault.unlock(total_deposit),
+            None => {
+                // If the best offer token happens to be closed, we will just keep the funds in the
+                // auction custody account. The executor token account will collect these funds
+                // when the order is executed.
+                if utils::checked_deserialize_token_account(best_offer_token, &auction.mint)
+                    .is_some()
+                {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            token_program.to_account_info(),
+                            token::Transfer {
+                                from: custody_token.to_account_info(),
+                                to: best_offer_token.to_account_info(),
+                                authority: auction.to_account_info(),
+                            },
+                            &[&[
+                                Auction::SEED_PREFIX,
+                                auction.vaa_hash.as_ref(),
+                                &[auction.bump],
+                            ]],
+                        ),
+                        total_deposit,
+                    )?;
+                }
+            }
+        }
+
+        // Move the committed deposit to the auction's custody token account.
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: commitment_custody_token.to_account_info(),
+                    to: custody_token.to_account_info(),
+                    authority: offer_commitment.to_account_info(),
+                },
+                &[commitment_signer_seeds],
+            ),
+            total_deposit,
+        )?;
+    }
+
+    // Return whatever remains in the commitment's custody token account to the offer token.
+    let remaining = if best_offer {
+        commitment_custody_token
+            .amount
+            .saturating_sub(total_deposit)
+    } else {
+        commitment_custody_token.amount
+    };
+    if remaining > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: commitment_custody_token.to_account_info(),
+                    to: offer_token.to_account_info(),
+                    authority: offer_commitment.to_account_info(),
+                },
+                &[commitment_signer_seeds],
+            ),
+            remaining,
+        )?;
+    }
+
+    // Finally close the commitment's custody token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: commitment_custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: offer_commitment.to_account_info(),
+        },
+        &[commitment_signer_seeds],
+    ))?;
+
+    let auction = &mut ctx.accounts.active_auction;
+
+    emit_cpi!(crate::events::OfferRevealed {
+        fast_vaa_hash: auction.vaa_hash,
+        offer_token: offer_token.key(),
+        offer_price,
+        best_offer,
+    });
+
+    if best_offer {
+        // Update info before we emit event.
+        let info = auction.info.as_mut().unwrap();
+        info.best_offer_token = offer_token.key();
+        info.best_offer_vault = None;
+        info.offer_price = offer_price;
+
+        let config = &auction.config;
+        let info = auction.info.as_ref().unwrap();
+
+        // Emit event for auction participants to listen to.
+        emit_cpi!(crate::utils::log_emit(crate::events::AuctionUpdated {
+            config_id: info.config_id,
+            fast_vaa_hash: auction.vaa_hash,
+            vaa: Default::default(),
+            source_chain: info.source_chain,
+            target_protocol: auction.target_protocol,
+            redeemer_message_len: info.redeemer_message_len,
+            end_slot: info.auction_end_slot(config),
+            best_offer_token: offer_token.key(),
+            token_balance_before: offer_token.amount,
+            amount_in: info.amount_in,
+            total_deposit: info.total_deposit(),
+            max_offer_price_allowed: offer_price.checked_sub(1),
+        }));
+    }
+
+    // Done.
+    Ok(())
+}
@@ -2,13 +2,15 @@ use crate::{
     composite::*,
     error::MatchingEngineError,
     state::{
-        Custodian, PreparedOrderResponse, PreparedOrderResponseInfo, PreparedOrderResponseSeeds,
+        Auction, Custodian, PreparedOrderResponse, PreparedOrderResponseInfo,
+        PreparedOrderResponseSeeds, RateLimit,
     },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::{
     messages::raw::{LiquidityLayerDepositMessage, LiquidityLayerMessage, MessageToVec},
+    migration::{VersionHeader, Versioned},
     wormhole_cctp_solana::{self, cctp::message_transmitter_program},
 };
 
@@ -87,7 +89,7 @@ pub struct PrepareOrderResponseCctp<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = prepared_order_response,
         seeds = [
             crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
@@ -102,7 +104,7 @@ pub struct PrepareOrderResponseCctp<'info> {
     /// non-existent auctions and the best offer token account will be used for orders executed on
     /// time.
     #[account(
-        token::mint = usdc,
+        token::mint = mint,
         constraint = {
             require!(
                 base_fee_token.key() != prepared_custody_token.key(),
@@ -114,8 +116,55 @@ pub struct PrepareOrderResponseCctp<'info> {
     )]
     base_fee_token: Box<Account<'info, token::TokenAccount>>,
 
-    usdc: Usdc<'info>,
+    /// Auction for the fast market order. If this account does not exist, the order was never
+    /// auctioned and its amount counts against the rate limits when the order response is prepared.
+    ///
+    /// CHECK: Seeds must be \["auction", fast_vaa_hash\].
+    #[account(
+        seeds = [
+            Auction::SEED_PREFIX,
+            fast_order_path.fast_vaa.load_unchecked().digest().as_ref(),
+        ],
+        bump,
+    )]
+    auction: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["inbound-rate-limit", source_chain\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::INBOUND_SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    inbound_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["outbound-rate-limit", target_chain\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::OUTBOUND_SEED_PREFIX,
+            &fast_order_path.to_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    outbound_rate_limit: UncheckedAccount<'info>,
+
+    mint: SupportedCctpMint<'info>,
+
+    /// The mint recipient's mint must be the finalized deposit's mint.
+    #[account(
+        constraint = {
+            require_keys_eq!(
+                cctp.mint_recipient.mint,
+                mint.key(),
+                MatchingEngineError::CctpMintMismatch
+            );
 
+            true
+        }
+    )]
     cctp: CctpReceiveMessage<'info>,
 
     token_program: Program<'info, token::Token>,
@@ -219,6 +268,13 @@ fn handle_prepare_order_response_cctp(
 
     let amount_in = order.amount_in();
 
+    // Auctioned orders were accounted for when their auctions started. If a slow order exceeds
+    // either rate limit, its order response cannot be prepared until enough capacity replenishes.
+    if ctx.accounts.auction.owner != &crate::id() {
+        RateLimit::consume_if_exists(&ctx.accounts.inbound_rate_limit, amount_in)?;
+        RateLimit::consume_if_exists(&ctx.accounts.outbound_rate_limit, amount_in)?;
+    }
+
     // Write to the prepared slow order account, which will be closed by one of the following
     // instructions:
     // * settle_auction_active_cctp
@@ -227,6 +283,9 @@ fn handle_prepare_order_response_cctp(
     ctx.accounts
         .prepared_order_response
         .set_inner(PreparedOrderResponse {
+            header: VersionHeader {
+                version: PreparedOrderResponse::VERSION,
+            },
             seeds: PreparedOrderResponseSeeds {
                 fast_vaa_hash: fast_vaa.digest().0,
                 bump: ctx.bumps.prepared_order_response,
@@ -241,7 +300,12 @@ fn handle_prepare_order_response_cctp(
                 sender: order.sender(),
                 redeemer: order.redeemer(),
                 init_auction_fee: order.init_auction_fee(),
+                min_amount_out: order.min_amount_out(),
+                refund_address: order.refund_address(),
+                mint: ctx.accounts.mint.key(),
+                destination_asset: order.destination_asset(),
             },
+            from_endpoint: ctx.accounts.fast_order_path.from_endpoint.info,
             to_endpoint: ctx.accounts.fast_order_path.to_endpoint.info,
             redeemer_message: order.message_to_vec(),
         });
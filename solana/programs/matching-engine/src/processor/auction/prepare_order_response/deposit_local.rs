@@ -0,0 +1,118 @@
+use crate::{composite::*, error::MatchingEngineError, state::LocalFastOrder};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
+
+#[derive(Accounts)]
+#[instruction(args: DepositLocalFastOrderArgs)]
+pub struct DepositLocalFastOrder<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    /// The local Token Router's emitter (a.k.a. its custodian), which must sign for the deposit.
+    #[account(
+        constraint = {
+            require_eq!(
+                local_endpoint.chain,
+                SOLANA_CHAIN,
+                MatchingEngineError::InvalidSourceRouter
+            );
+            require!(
+                token_router_emitter.key().to_bytes() == local_endpoint.address,
+                MatchingEngineError::InvalidSourceRouter
+            );
+
+            true
+        }
+    )]
+    token_router_emitter: Signer<'info>,
+
+    local_endpoint: LiveRouterEndpoint<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LocalFastOrder::INIT_SPACE,
+        seeds = [
+            LocalFastOrder::SEED_PREFIX,
+            &args.fast_vaa_sequence.to_be_bytes(),
+        ],
+        bump,
+    )]
+    local_fast_order: Box<Account<'info, LocalFastOrder>>,
+
+    /// Token account owned by the local Token Router's emitter, from which the amount in is
+    /// deposited.
+    #[account(
+        mut,
+        token::mint = usdc,
+        token::authority = token_router_emitter,
+    )]
+    source_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = usdc,
+        token::authority = local_fast_order,
+        seeds = [
+            crate::DEPOSIT_CUSTODY_TOKEN_SEED_PREFIX,
+            local_fast_order.key().as_ref(),
+        ],
+        bump,
+    )]
+    custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    usdc: Usdc<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// Arguments for [deposit_local_fast_order].
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct DepositLocalFastOrderArgs {
+    /// Wormhole sequence of the fast market order message published by the local Token Router.
+    pub fast_vaa_sequence: u64,
+
+    /// Amount to deposit, which must equal the fast market order's amount in.
+    pub amount_in: u64,
+
+    /// Fee paid to whomever prepares the order response.
+    pub base_fee: u64,
+}
+
+pub fn deposit_local_fast_order(
+    ctx: Context<DepositLocalFastOrder>,
+    args: DepositLocalFastOrderArgs,
+) -> Result<()> {
+    let DepositLocalFastOrderArgs {
+        fast_vaa_sequence,
+        amount_in,
+        base_fee,
+    } = args;
+
+    ctx.accounts.local_fast_order.set_inner(LocalFastOrder {
+        bump: ctx.bumps.local_fast_order,
+        custody_token_bump: ctx.bumps.custody_token,
+        prepared_by: ctx.accounts.payer.key(),
+        fast_vaa_sequence,
+        amount_in,
+        base_fee,
+    });
+
+    // The Token Router's emitter signed for this instruction, so it can authorize the transfer.
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.source_token.to_account_info(),
+                to: ctx.accounts.custody_token.to_account_info(),
+                authority: ctx.accounts.token_router_emitter.to_account_info(),
+            },
+        ),
+        amount_in,
+    )
+}
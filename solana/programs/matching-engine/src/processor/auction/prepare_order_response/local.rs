@@ -0,0 +1,260 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{
+        Auction, LocalFastOrder, MessageProtocol, PreparedOrderResponse, PreparedOrderResponseInfo,
+        PreparedOrderResponseSeeds, RateLimit,
+    },
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{
+    messages::raw::{LiquidityLayerMessage, MessageToVec},
+    migration::{VersionHeader, Versioned},
+};
+
+#[derive(Accounts)]
+pub struct PrepareOrderResponseLocal<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    /// The fast market order must have been published by the local Token Router.
+    #[account(
+        constraint = {
+            match fast_order_path.from_endpoint.protocol {
+                MessageProtocol::Local { .. } => true,
+                _ => return err!(MatchingEngineError::InvalidSourceRouter),
+            }
+        }
+    )]
+    fast_order_path: FastOrderPath<'info>,
+
+    /// Deposit made by the local Token Router when it published the fast market order. This
+    /// account (along with its custody token account) is closed by this instruction.
+    #[account(
+        mut,
+        close = local_fast_order_prepared_by,
+        seeds = [
+            LocalFastOrder::SEED_PREFIX,
+            &fast_order_path.fast_vaa.load_unchecked().sequence().to_be_bytes(),
+        ],
+        bump = local_fast_order.bump,
+        constraint = {
+            let fast_vaa = fast_order_path.fast_vaa.load_unchecked();
+            let order = LiquidityLayerMessage::try_from(fast_vaa.payload())
+                .unwrap()
+                .to_fast_market_order_unchecked();
+
+            require_eq!(
+                local_fast_order.amount_in,
+                order.amount_in(),
+                MatchingEngineError::LocalFastOrderMismatch
+            );
+
+            true
+        }
+    )]
+    local_fast_order: Box<Account<'info, LocalFastOrder>>,
+
+    /// CHECK: Mutable. This account must equal the local fast order's `prepared_by` pubkey.
+    #[account(
+        mut,
+        address = local_fast_order.prepared_by,
+    )]
+    local_fast_order_prepared_by: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::DEPOSIT_CUSTODY_TOKEN_SEED_PREFIX,
+            local_fast_order.key().as_ref(),
+        ],
+        bump = local_fast_order.custody_token_bump,
+    )]
+    local_fast_order_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PreparedOrderResponse::compute_size({
+            let fast_vaa = fast_order_path.fast_vaa.load_unchecked();
+            let message = LiquidityLayerMessage::try_from(fast_vaa.payload())
+                .unwrap();
+            let order = message
+                .fast_market_order()
+                .ok_or_else(|| MatchingEngineError::InvalidPayloadId)?;
+
+            order.redeemer_message_len().into()
+        }),
+        seeds = [
+            PreparedOrderResponse::SEED_PREFIX,
+            fast_order_path.fast_vaa.load_unchecked().digest().as_ref()
+        ],
+        bump,
+    )]
+    prepared_order_response: Box<Account<'info, PreparedOrderResponse>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = usdc,
+        token::authority = prepared_order_response,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order_response.key().as_ref(),
+        ],
+        bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// This token account will be the one that collects the base fee only if an auction's order
+    /// was executed late. Otherwise, the protocol's fee recipient token account will be used for
+    /// non-existent auctions and the best offer token account will be used for orders executed on
+    /// time.
+    #[account(
+        token::mint = usdc,
+        constraint = {
+            require!(
+                base_fee_token.key() != prepared_custody_token.key(),
+                MatchingEngineError::InvalidBaseFeeToken
+            );
+
+            true
+        }
+    )]
+    base_fee_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Auction for the fast market order. If this account does not exist, the order was never
+    /// auctioned and its amount counts against the rate limits when the order response is prepared.
+    ///
+    /// CHECK: Seeds must be \["auction", fast_vaa_hash\].
+    #[account(
+        seeds = [
+            Auction::SEED_PREFIX,
+            fast_order_path.fast_vaa.load_unchecked().digest().as_ref(),
+        ],
+        bump,
+    )]
+    auction: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["inbound-rate-limit", source_chain\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::INBOUND_SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    inbound_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["outbound-rate-limit", target_chain\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::OUTBOUND_SEED_PREFIX,
+            &fast_order_path.to_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    outbound_rate_limit: UncheckedAccount<'info>,
+
+    usdc: Usdc<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// Unlike [prepare_order_response_cctp](super::prepare_order_response_cctp), this instruction is
+/// not idempotent because the local fast order deposit is closed once the order response is
+/// prepared.
+pub fn prepare_order_response_local(ctx: Context<PrepareOrderResponseLocal>) -> Result<()> {
+    let fast_vaa = ctx.accounts.fast_order_path.fast_vaa.load_unchecked();
+    let order = LiquidityLayerMessage::try_from(fast_vaa.payload())
+        .unwrap()
+        .to_fast_market_order_unchecked();
+
+    let local_fast_order = &ctx.accounts.local_fast_order;
+    let amount_in = local_fast_order.amount_in;
+
+    // Auctioned orders were accounted for when their auctions started. If a slow order exceeds
+    // either rate limit, its order response cannot be prepared until enough capacity replenishes.
+    if ctx.accounts.auction.owner != &crate::id() {
+        RateLimit::consume_if_exists(&ctx.accounts.inbound_rate_limit, amount_in)?;
+        RateLimit::consume_if_exists(&ctx.accounts.outbound_rate_limit, amount_in)?;
+    }
+
+    // Write to the prepared slow order account, which will be closed by one of the following
+    // instructions:
+    // * settle_auction_active_cctp
+    // * settle_auction_complete
+    // * settle_auction_none
+    ctx.accounts
+        .prepared_order_response
+        .set_inner(PreparedOrderResponse {
+            header: VersionHeader {
+                version: PreparedOrderResponse::VERSION,
+            },
+            seeds: PreparedOrderResponseSeeds {
+                fast_vaa_hash: fast_vaa.digest().0,
+                bump: ctx.bumps.prepared_order_response,
+            },
+            info: PreparedOrderResponseInfo {
+                prepared_by: ctx.accounts.payer.key(),
+                base_fee_token: ctx.accounts.base_fee_token.key(),
+                source_chain: fast_vaa.emitter_chain(),
+                base_fee: local_fast_order.base_fee,
+                fast_vaa_timestamp: fast_vaa.timestamp(),
+                amount_in,
+                sender: order.sender(),
+                redeemer: order.redeemer(),
+                init_auction_fee: order.init_auction_fee(),
+                min_amount_out: order.min_amount_out(),
+                refund_address: order.refund_address(),
+                mint: ctx.accounts.usdc.key(),
+                destination_asset: order.destination_asset(),
+            },
+            from_endpoint: ctx.accounts.fast_order_path.from_endpoint.info,
+            to_endpoint: ctx.accounts.fast_order_path.to_endpoint.info,
+            redeemer_message: order.message_to_vec(),
+        });
+
+    let local_fast_order_seeds = &[
+        LocalFastOrder::SEED_PREFIX,
+        &local_fast_order.fast_vaa_sequence.to_be_bytes(),
+        &[local_fast_order.bump],
+    ];
+
+    // Transfer the deposit to prepared custody token.
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx
+                    .accounts
+                    .local_fast_order_custody_token
+                    .to_account_info(),
+                to: ctx.accounts.prepared_custody_token.to_account_info(),
+                authority: local_fast_order.to_account_info(),
+            },
+            &[local_fast_order_seeds],
+        ),
+        amount_in,
+    )?;
+
+    // Finally close the deposit's custody token account.
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx
+                .accounts
+                .local_fast_order_custody_token
+                .to_account_info(),
+            destination: ctx.accounts.local_fast_order_prepared_by.to_account_info(),
+            authority: local_fast_order.to_account_info(),
+        },
+        &[local_fast_order_seeds],
+    ))
+}
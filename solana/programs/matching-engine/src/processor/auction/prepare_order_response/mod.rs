@@ -1,6 +1,12 @@
 mod cctp;
 pub use cctp::*;
 
+mod deposit_local;
+pub use deposit_local::*;
+
+mod local;
+pub use local::*;
+
 use anchor_lang::prelude::*;
 
 fn prepare_order_response_noop() -> Result<()> {
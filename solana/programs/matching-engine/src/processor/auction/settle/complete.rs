@@ -1,8 +1,11 @@
 use crate::{
     error::MatchingEngineError,
     events::SettledTokenAccountInfo,
-    state::{Auction, AuctionStatus, PreparedOrderResponse},
-    utils,
+    state::{Auction, AuctionStatus, AuctionTranche, AuctionTrancheStatus, PreparedOrderResponse},
+    utils::{
+        self,
+        auction::{SettlePayoutAccounts, SettlePayouts},
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, TokenAccount};
@@ -33,16 +36,25 @@ pub struct SettleAuctionComplete<'info> {
     /// to any account he chooses (this one).
     ///
     /// CHECK: This token account may exist. If it doesn't and there is a penalty, we will send all
-    /// of the tokens to the base fee token account.
+    /// of the tokens to the base fee token account. For split-fill auctions, this account must be
+    /// the auction tranche's best offer token.
     #[account(
         mut,
-        address = auction.info.as_ref().unwrap().best_offer_token,
+        constraint = {
+            let expected = match &auction_tranche {
+                Some(auction_tranche) => auction_tranche.best_offer_token,
+                None => auction.info.as_ref().unwrap().best_offer_token,
+            };
+
+            best_offer_token.key() == expected
+        } @ MatchingEngineError::BestOfferTokenMismatch,
     )]
     best_offer_token: UncheckedAccount<'info>,
 
+    /// This account is closed once every tranche of the auction has been settled (which is
+    /// immediately for auctions that are not split-fill auctions).
     #[account(
         mut,
-        close = beneficiary,
         seeds = [
             PreparedOrderResponse::SEED_PREFIX,
             prepared_order_response.seeds.fast_vaa_hash.as_ref()
@@ -62,6 +74,8 @@ pub struct SettleAuctionComplete<'info> {
     )]
     prepared_custody_token: Box<Account<'info, TokenAccount>>,
 
+    /// The auction's mint must be the finalized deposit's mint, which is the mint the best offer
+    /// is repaid in.
     #[account(
         mut,
         seeds = [
@@ -69,9 +83,42 @@ pub struct SettleAuctionComplete<'info> {
             prepared_order_response.seeds.fast_vaa_hash.as_ref(),
         ],
         bump = auction.bump,
+        constraint = {
+            require_keys_eq!(
+                auction.mint,
+                prepared_order_response.mint,
+                MatchingEngineError::CctpMintMismatch
+            );
+
+            true
+        }
     )]
     auction: Box<Account<'info, Auction>>,
 
+    /// Executed tranche of a split-fill auction, which is repaid its portion of the finalized
+    /// deposit. This account must be provided if the auction is a split-fill auction.
+    #[account(
+        mut,
+        seeds = [
+            AuctionTranche::SEED_PREFIX,
+            auction.key().as_ref(),
+            &[auction_tranche.index],
+        ],
+        bump = auction_tranche.bump,
+    )]
+    auction_tranche: Option<Box<Account<'info, AuctionTranche>>>,
+
+    /// CHECK: This account must equal the auction tranche's `prepared_by`, who will receive the
+    /// rent for the auction tranche.
+    #[account(
+        mut,
+        constraint = {
+            Some(auction_tranche_prepared_by.key())
+                == auction_tranche.as_ref().map(|auction_tranche| auction_tranche.prepared_by)
+        } @ MatchingEngineError::PreparedByMismatch,
+    )]
+    auction_tranche_prepared_by: Option<UncheckedAccount<'info>>,
+
     token_program: Program<'info, token::Token>,
 }
 
@@ -89,14 +136,61 @@ fn handle_settle_auction_complete(
     ctx: Context<SettleAuctionComplete>,
     execute_penalty: Option<u64>,
 ) -> Result<()> {
-    let prepared_order_response = &ctx.accounts.prepared_order_response;
-    let base_fee = prepared_order_response.base_fee;
+    let base_fee = ctx.accounts.prepared_order_response.base_fee;
+    let custodied_amount = ctx.accounts.prepared_custody_token.amount;
+
+    // For split-fill auctions, only the tranche's portion of the repayment and base fee is settled.
+    // Each tranche is repaid pro rata from what remains of the repayment, so the last tranche to
+    // be settled collects whatever remains in the prepared custody token account.
+    let info = ctx.accounts.auction.info.as_mut().unwrap();
+    let amount_in = info.amount_in;
+    let (repayment, base_fee_amount, penalty, all_settled) = match info.split_fill.as_mut() {
+        Some(split_fill) => {
+            let auction_tranche = ctx
+                .accounts
+                .auction_tranche
+                .as_ref()
+                .ok_or_else(|| MatchingEngineError::AuctionTrancheRequired)?;
+            require!(
+                ctx.accounts.auction_tranche_prepared_by.is_some(),
+                MatchingEngineError::PreparedByMismatch
+            );
+
+            let tranche_penalty = match auction_tranche.status {
+                AuctionTrancheStatus::Executed { execute_penalty } => execute_penalty,
+                AuctionTrancheStatus::Active => {
+                    return err!(MatchingEngineError::AuctionNotCompleted)
+                }
+            };
+
+            let repayment = utils::auction::compute_pro_rata(
+                custodied_amount,
+                auction_tranche.amount_in,
+                amount_in.saturating_sub(split_fill.amount_in_settled),
+            );
+            split_fill.amount_in_settled = split_fill
+                .amount_in_settled
+                .saturating_add(auction_tranche.amount_in);
 
-    ctx.accounts.auction.status = AuctionStatus::Settled {
-        fee: base_fee,
-        total_penalty: execute_penalty.map(|v| v.saturating_add(base_fee)),
+            (
+                repayment,
+                utils::auction::compute_pro_rata(base_fee, auction_tranche.amount_in, amount_in),
+                tranche_penalty,
+                split_fill.amount_in_settled >= amount_in,
+            )
+        }
+        None => (custodied_amount, base_fee, execute_penalty, true),
     };
 
+    if all_settled {
+        ctx.accounts.auction.status = AuctionStatus::Settled {
+            fee: base_fee,
+            total_penalty: execute_penalty.map(|v| v.saturating_add(base_fee)),
+        };
+    }
+
+    let prepared_order_response = &ctx.accounts.prepared_order_response;
+
     let prepared_order_response_signer_seeds = &[
         PreparedOrderResponse::SEED_PREFIX,
         prepared_order_response.seeds.fast_vaa_hash.as_ref(),
@@ -109,88 +203,46 @@ fn handle_settle_auction_complete(
     let token_program = &ctx.accounts.token_program;
     let prepared_custody_token = &ctx.accounts.prepared_custody_token;
 
-    let repayment = ctx.accounts.prepared_custody_token.amount;
-
     struct TokenAccountResult {
         balance_before: u64,
         amount: u64,
     }
 
-    let (base_fee_result, best_offer_result) = match execute_penalty {
-        // When there is no penalty, we will give everything to the best offer token account.
-        None => {
-            // If the token account happens to not exist anymore, we will revert.
-            let best_offer_token_data =
-                utils::checked_deserialize_token_account(best_offer_token, &common::USDC_MINT)
-                    .ok_or_else(|| MatchingEngineError::BestOfferTokenRequired)?;
+    let base_fee_token_data =
+        utils::checked_deserialize_token_account(base_fee_token, &prepared_order_response.mint);
+    let best_offer_token_data =
+        utils::checked_deserialize_token_account(best_offer_token, &prepared_order_response.mint);
 
-            (
-                None, // base_fee_result
-                TokenAccountResult {
-                    balance_before: best_offer_token_data.amount,
-                    amount: repayment,
-                }
-                .into(),
-            )
-        }
-        // Otherwise, determine how the repayment should be divvied up.
-        _ => {
-            match (
-                utils::checked_deserialize_token_account(base_fee_token, &common::USDC_MINT),
-                utils::checked_deserialize_token_account(best_offer_token, &common::USDC_MINT),
-            ) {
-                (Some(base_fee_token_data), Some(best_offer_token_data)) => {
-                    if base_fee_token.key() == best_offer_token.key() {
-                        (
-                            None, // base_fee_result
-                            TokenAccountResult {
-                                balance_before: best_offer_token_data.amount,
-                                amount: repayment,
-                            }
-                            .into(),
-                        )
-                    } else {
-                        (
-                            TokenAccountResult {
-                                balance_before: base_fee_token_data.amount,
-                                amount: base_fee,
-                            }
-                            .into(),
-                            TokenAccountResult {
-                                balance_before: best_offer_token_data.amount,
-                                amount: repayment.saturating_sub(base_fee),
-                            }
-                            .into(),
-                        )
-                    }
-                }
-                // If the best offer token account does not exist, we will give everything to the
-                // base fee token account.
-                (Some(base_fee_token_data), None) => (
-                    TokenAccountResult {
-                        balance_before: base_fee_token_data.amount,
-                        amount: repayment,
-                    }
-                    .into(),
-                    None, // best_offer_result
-                ),
-                // If the base fee token account does not exist, we will give everything to the best
-                // offer token account.
-                (None, Some(best_offer_data)) => {
-                    (
-                        None, // base_fee_result
-                        TokenAccountResult {
-                            balance_before: best_offer_data.amount,
-                            amount: repayment,
-                        }
-                        .into(),
-                    )
-                }
-                // Otherwise revert.
-                _ => return err!(MatchingEngineError::BestOfferTokenRequired),
-            }
-        }
-    };
+    // Determine how the repayment should be divvied up. When there is no penalty, we will give
+    // everything to the best offer token account.
+    let SettlePayouts {
+        base_fee_token_amount,
+        best_offer_amount,
+    } = utils::auction::compute_settle_payouts(
+        repayment,
+        base_fee_amount,
+        penalty.is_some(),
+        SettlePayoutAccounts {
+            base_fee_token_exists: base_fee_token_data.is_some(),
+            best_offer_token_exists: best_offer_token_data.is_some(),
+            base_fee_is_best_offer: base_fee_token.key() == best_offer_token.key(),
+        },
+    )?;
+
+    let base_fee_result =
+        base_fee_token_amount
+            .zip(base_fee_token_data)
+            .map(|(amount, token_data)| TokenAccountResult {
+                balance_before: token_data.amount,
+                amount,
+            });
+    let best_offer_result =
+        best_offer_amount
+            .zip(best_offer_token_data)
+            .map(|(amount, token_data)| TokenAccountResult {
+                balance_before: token_data.amount,
+                amount,
+            });
 
     // Transfer base fee token his bounty if there are any.
     let settled_base_fee_result = match base_fee_result {
@@ -255,14 +307,30 @@ fn handle_settle_auction_complete(
         with_execute: Default::default(),
     });
 
-    // Finally close the prepared custody token account.
-    token::close_account(CpiContext::new_with_signer(
-        token_program.to_account_info(),
-        token::CloseAccount {
-            account: prepared_custody_token.to_account_info(),
-            destination: beneficiary.to_account_info(),
-            authority: prepared_order_response.to_account_info(),
-        },
-        &[prepared_order_response_signer_seeds],
-    ))
+    // Close the settled auction tranche.
+    if let (Some(auction_tranche), Some(auction_tranche_prepared_by)) = (
+        &ctx.accounts.auction_tranche,
+        &ctx.accounts.auction_tranche_prepared_by,
+    ) {
+        auction_tranche.close(auction_tranche_prepared_by.to_account_info())?;
+    }
+
+    // Finally close the prepared custody token account and the prepared order response once the
+    // whole repayment has been settled.
+    if all_settled {
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::CloseAccount {
+                account: prepared_custody_token.to_account_info(),
+                destination: beneficiary.to_account_info(),
+                authority: prepared_order_response.to_account_info(),
+            },
+            &[prepared_order_response_signer_seeds],
+        ))?;
+
+        prepared_order_response.close(beneficiary.to_account_info())?;
+    }
+
+    // Done.
+    Ok(())
 }
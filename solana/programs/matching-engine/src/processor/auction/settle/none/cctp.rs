@@ -1,7 +1,7 @@
 use crate::{
     composite::*,
-    error::MatchingEngineError,
-    state::{Auction, Custodian, EndpointInfo, MessageProtocol},
+    state::{Auction, CctpMint, Custodian, EndpointInfo},
+    utils,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -45,10 +45,22 @@ pub struct SettleAuctionNoneCctp<'info> {
     /// CHECK: This token account must already exist.
     #[account(
         mut,
-        address = custodian.fee_recipient_token,
+        address = CctpMint::fee_recipient_token_or(&cctp_mint, custodian.fee_recipient_token)?,
     )]
     fee_recipient_token: Account<'info, token::TokenAccount>,
 
+    /// CCTP mint registration for the order's mint, which may not exist if the mint is USDC.
+    ///
+    /// CHECK: Seeds must be \["cctp-mint", mint\].
+    #[account(
+        seeds = [
+            CctpMint::SEED_PREFIX,
+            prepared.order_response.mint.as_ref(),
+        ],
+        bump,
+    )]
+    cctp_mint: UncheckedAccount<'info>,
+
     prepared: ClosePreparedOrderResponse<'info>,
 
     /// There should be no account data here because an auction was never created.
@@ -75,16 +87,6 @@ pub struct SettleAuctionNoneCctp<'info> {
 }
 
 pub fn settle_auction_none_cctp(ctx: Context<SettleAuctionNoneCctp>) -> Result<()> {
-    match ctx.accounts.prepared.order_response.to_endpoint.protocol {
-        MessageProtocol::Cctp { domain } => handle_settle_auction_none_cctp(ctx, domain),
-        _ => err!(MatchingEngineError::InvalidCctpEndpoint),
-    }
-}
-
-fn handle_settle_auction_none_cctp(
-    ctx: Context<SettleAuctionNoneCctp>,
-    destination_cctp_domain: u32,
-) -> Result<()> {
     let auction = &mut ctx.accounts.auction;
 
     // First set data in the auction account.
@@ -103,7 +105,9 @@ fn handle_settle_auction_none_cctp(
     let super::SettledNone {
         user_amount: amount,
         fill,
+        to_endpoint,
         auction_settled_event,
+        order_refunded_event,
     } = super::settle_none_and_prepare_fill(super::SettleNoneAndPrepareFill {
         prepared_order_response: &mut ctx.accounts.prepared.order_response,
         prepared_custody_token,
@@ -113,12 +117,17 @@ fn handle_settle_auction_none_cctp(
         token_program,
     })?;
 
+    // The destination endpoint is the target endpoint or, if the order is refunded, the source
+    // endpoint. Either way, it must be a CCTP endpoint.
     let EndpointInfo {
         chain: _,
         address: destination_caller,
         mint_recipient,
-        protocol: _,
-    } = ctx.accounts.prepared.order_response.to_endpoint;
+        protocol,
+    } = to_endpoint;
+
+    let destination_cctp_domain =
+        utils::auction::compute_fill_cctp_domain(&protocol, order_refunded_event.is_some())?;
 
     let auction = &ctx.accounts.auction;
     let payer = &ctx.accounts.payer;
@@ -214,6 +223,10 @@ fn handle_settle_auction_none_cctp(
     // Emit an event indicating that the auction has been settled.
     emit_cpi!(auction_settled_event);
 
+    if let Some(order_refunded_event) = order_refunded_event {
+        emit_cpi!(order_refunded_event);
+    }
+
     // Finally close the account since it is no longer needed.
     token::close_account(CpiContext::new_with_signer(
         token_program.to_account_info(),
@@ -1,7 +1,8 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Auction, AuctionStatus, Custodian, FastFill, ReservedFastFillSequence},
+    state::{Auction, AuctionStatus, CctpMint, Custodian, FastFill, ReservedFastFillSequence},
+    utils,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -22,10 +23,22 @@ pub struct SettleAuctionNoneLocal<'info> {
     /// CHECK: This token account must already exist.
     #[account(
         mut,
-        address = custodian.fee_recipient_token,
+        address = CctpMint::fee_recipient_token_or(&cctp_mint, custodian.fee_recipient_token)?,
     )]
     fee_recipient_token: Account<'info, token::TokenAccount>,
 
+    /// CCTP mint registration for the order's mint, which may not exist if the mint is USDC.
+    ///
+    /// CHECK: Seeds must be \["cctp-mint", mint\].
+    #[account(
+        seeds = [
+            CctpMint::SEED_PREFIX,
+            prepared.order_response.mint.as_ref(),
+        ],
+        bump,
+    )]
+    cctp_mint: UncheckedAccount<'info>,
+
     #[account(
         constraint = {
             require_keys_eq!(
@@ -108,6 +121,15 @@ pub struct SettleAuctionNoneLocal<'info> {
             &prepared.order_response.source_chain.to_be_bytes(),
         ],
         bump,
+        constraint = {
+            require_keys_eq!(
+                local_custody_token.mint,
+                prepared.order_response.mint,
+                MatchingEngineError::CctpMintMismatch
+            );
+
+            true
+        }
     )]
     local_custody_token: Box<Account<'info, token::TokenAccount>>,
 
@@ -126,7 +148,9 @@ pub fn settle_auction_none_local(ctx: Context<SettleAuctionNoneLocal>) -> Result
     let super::SettledNone {
         user_amount: amount,
         fill,
-        auction_settled_event,
+        to_endpoint: _,
+        mut auction_settled_event,
+        order_refunded_event,
     } = super::settle_none_and_prepare_fill(super::SettleNoneAndPrepareFill {
         prepared_order_response: &mut ctx.accounts.prepared.order_response,
         prepared_custody_token,
@@ -136,6 +160,23 @@ pub fn settle_auction_none_local(ctx: Context<SettleAuctionNoneLocal>) -> Result
         token_program,
     })?;
 
+    // If the order is refunded, the fast fill's redeemer is the order's refund address, which can
+    // only be redeemed locally if the order originated from Solana. Otherwise this order must be
+    // settled using the settle auction none CCTP instruction to refund it to the source chain.
+    if let Some(order_refunded_event) = order_refunded_event {
+        utils::auction::require_local_fill(order_refunded_event.source_chain, true)?;
+
+        auction_settled_event.with_execute = ctx
+            .accounts
+            .prepared
+            .order_response
+            .to_endpoint
+            .protocol
+            .into();
+
+        emit_cpi!(order_refunded_event);
+    }
+
     // Emit an event indicating that the auction has been settled.
     emit_cpi!(auction_settled_event);
 
@@ -145,6 +186,7 @@ pub fn settle_auction_none_local(ctx: Context<SettleAuctionNoneLocal>) -> Result
         ctx.bumps.fast_fill,
         ctx.accounts.payer.key(),
         amount,
+        ctx.accounts.local_custody_token.mint,
     );
 
     // Emit the fast fill.
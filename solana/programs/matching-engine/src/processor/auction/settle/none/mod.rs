@@ -6,8 +6,9 @@ pub use local::*;
 
 use crate::{
     composite::*,
-    events::AuctionSettled,
-    state::{Auction, AuctionStatus, PreparedOrderResponse},
+    error::MatchingEngineError,
+    events::{AuctionSettled, OrderRefunded},
+    state::{Auction, AuctionStatus, EndpointInfo, PreparedOrderResponse},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -25,7 +26,14 @@ struct SettleNoneAndPrepareFill<'ctx, 'info> {
 struct SettledNone {
     user_amount: u64,
     fill: Fill,
+
+    /// Endpoint where the fill is sent. This is the source endpoint if the order is refunded.
+    to_endpoint: EndpointInfo,
+
     auction_settled_event: AuctionSettled,
+
+    /// This event will be some if the user amount does not satisfy the order's minimum amount out.
+    order_refunded_event: Option<OrderRefunded>,
 }
 
 fn settle_none_and_prepare_fill(accounts: SettleNoneAndPrepareFill<'_, '_>) -> Result<SettledNone> {
@@ -83,6 +91,10 @@ fn settle_none_and_prepare_fill(accounts: SettleNoneAndPrepareFill<'_, '_>) -> R
         total_penalty: None,
     };
 
+    let user_amount = prepared_custody_token.amount.saturating_sub(fee);
+    let (fill, to_endpoint, order_refunded_event) =
+        prepare_settled_fill(prepared_order_response, user_amount)?;
+
     let auction_settled_event = AuctionSettled {
         fast_vaa_hash: auction.vaa_hash,
         best_offer_token: Default::default(),
@@ -91,22 +103,171 @@ fn settle_none_and_prepare_fill(accounts: SettleNoneAndPrepareFill<'_, '_>) -> R
             balance_after: fee_recipient_token.amount.saturating_add(fee),
         }
         .into(),
-        with_execute: auction.target_protocol.into(),
+        with_execute: to_endpoint.protocol.into(),
     };
 
-    // TryInto is safe to unwrap here because the redeemer message had to have been able to fit in
-    // the prepared order response account (so it would not have exceed u32::MAX).
-    let redeemer_message = std::mem::take(&mut prepared_order_response.redeemer_message)
-        .try_into()
-        .unwrap();
     Ok(SettledNone {
-        user_amount: prepared_custody_token.amount.saturating_sub(fee),
-        fill: Fill {
-            source_chain: prepared_order_response.source_chain,
-            order_sender: prepared_order_response.sender,
-            redeemer: prepared_order_response.redeemer,
-            redeemer_message,
-        },
+        user_amount,
+        fill,
+        to_endpoint,
         auction_settled_event,
+        order_refunded_event,
     })
 }
+
+/// If the user amount does not satisfy the minimum amount out, the funds are refunded to the refund
+/// address on the source chain instead of being delivered to the redeemer. Orders that request a
+/// destination asset are always refunded because there is no executor to deliver it.
+fn prepare_settled_fill(
+    prepared_order_response: &mut PreparedOrderResponse,
+    user_amount: u64,
+) -> Result<(Fill, EndpointInfo, Option<OrderRefunded>)> {
+    let min_amount_out = prepared_order_response.min_amount_out;
+    if prepared_order_response.destination_asset.is_some() || user_amount < min_amount_out {
+        Ok((
+            Fill {
+                source_chain: prepared_order_response.source_chain,
+                order_sender: prepared_order_response.sender,
+                redeemer: prepared_order_response.refund_address,
+                redeemer_message: Vec::new()
+                    .try_into()
+                    .map_err(|_| MatchingEngineError::RedeemerMessageTooLarge)?,
+            },
+            prepared_order_response.from_endpoint,
+            OrderRefunded {
+                fast_vaa_hash: prepared_order_response.seeds.fast_vaa_hash,
+                source_chain: prepared_order_response.source_chain,
+                refund_address: prepared_order_response.refund_address,
+                amount: user_amount,
+                min_amount_out,
+            }
+            .into(),
+        ))
+    } else {
+        // TryInto is safe to unwrap here because the redeemer message had to have been able to fit
+        // in the prepared order response account (so it would not have exceed u32::MAX).
+        let redeemer_message = std::mem::take(&mut prepared_order_response.redeemer_message)
+            .try_into()
+            .unwrap();
+
+        Ok((
+            Fill {
+                source_chain: prepared_order_response.source_chain,
+                order_sender: prepared_order_response.sender,
+                redeemer: prepared_order_response.redeemer,
+                redeemer_message,
+            },
+            prepared_order_response.to_endpoint,
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state::{MessageProtocol, PreparedOrderResponseInfo, PreparedOrderResponseSeeds};
+    use common::migration::{VersionHeader, Versioned};
+
+    const MIN_AMOUNT_OUT: u64 = 1_000_000;
+
+    fn prepared_order_response_for_test(
+        destination_asset: Option<[u8; 32]>,
+    ) -> PreparedOrderResponse {
+        PreparedOrderResponse {
+            header: VersionHeader {
+                version: PreparedOrderResponse::VERSION,
+            },
+            seeds: PreparedOrderResponseSeeds {
+                fast_vaa_hash: [1; 32],
+                bump: 255,
+            },
+            info: PreparedOrderResponseInfo {
+                prepared_by: Pubkey::new_unique(),
+                base_fee_token: Pubkey::new_unique(),
+                fast_vaa_timestamp: 0,
+                source_chain: 2,
+                base_fee: 10_000,
+                init_auction_fee: 10_000,
+                sender: [3; 32],
+                redeemer: [4; 32],
+                amount_in: 2_000_000,
+                min_amount_out: MIN_AMOUNT_OUT,
+                refund_address: [5; 32],
+                mint: common::USDC_MINT,
+                destination_asset,
+            },
+            from_endpoint: EndpointInfo {
+                chain: 2,
+                address: [6; 32],
+                mint_recipient: [7; 32],
+                protocol: MessageProtocol::Cctp { domain: 0 },
+            },
+            to_endpoint: EndpointInfo {
+                chain: 1,
+                address: [8; 32],
+                mint_recipient: [9; 32],
+                protocol: MessageProtocol::Local {
+                    program_id: Pubkey::new_unique(),
+                },
+            },
+            redeemer_message: b"All your base are belong to us.".to_vec(),
+        }
+    }
+
+    #[test]
+    fn fill_redeemer_when_min_amount_out_met() {
+        let mut prepared_order_response = prepared_order_response_for_test(None);
+
+        let (fill, to_endpoint, order_refunded_event) =
+            prepare_settled_fill(&mut prepared_order_response, MIN_AMOUNT_OUT).unwrap();
+
+        assert!(order_refunded_event.is_none());
+        assert_eq!(to_endpoint.chain, 1);
+        assert_eq!(fill.source_chain, 2);
+        assert_eq!(fill.order_sender, [3; 32]);
+        assert_eq!(fill.redeemer, [4; 32]);
+        assert_eq!(
+            fill.redeemer_message.to_vec(),
+            b"All your base are belong to us.".to_vec()
+        );
+    }
+
+    #[test]
+    fn fill_refund_address_when_min_amount_out_not_met() {
+        let mut prepared_order_response = prepared_order_response_for_test(None);
+
+        let user_amount = MIN_AMOUNT_OUT.saturating_sub(1);
+        let (fill, to_endpoint, order_refunded_event) =
+            prepare_settled_fill(&mut prepared_order_response, user_amount).unwrap();
+
+        // The fill is sent to the refund address via the source endpoint without the redeemer
+        // message.
+        assert_eq!(to_endpoint.chain, 2);
+        assert_eq!(to_endpoint.protocol, MessageProtocol::Cctp { domain: 0 });
+        assert_eq!(fill.source_chain, 2);
+        assert_eq!(fill.order_sender, [3; 32]);
+        assert_eq!(fill.redeemer, [5; 32]);
+        assert!(fill.redeemer_message.is_empty());
+
+        let order_refunded_event = order_refunded_event.unwrap();
+        assert_eq!(order_refunded_event.fast_vaa_hash, [1; 32]);
+        assert_eq!(order_refunded_event.source_chain, 2);
+        assert_eq!(order_refunded_event.refund_address, [5; 32]);
+        assert_eq!(order_refunded_event.amount, user_amount);
+        assert_eq!(order_refunded_event.min_amount_out, MIN_AMOUNT_OUT);
+    }
+
+    #[test]
+    fn fill_refund_address_when_destination_asset_requested() {
+        // Without an auction, there is no executor to deliver the destination asset.
+        let mut prepared_order_response = prepared_order_response_for_test(Some([10; 32]));
+
+        let (fill, to_endpoint, order_refunded_event) =
+            prepare_settled_fill(&mut prepared_order_response, MIN_AMOUNT_OUT).unwrap();
+
+        assert!(order_refunded_event.is_some());
+        assert_eq!(to_endpoint.chain, 2);
+        assert_eq!(fill.redeemer, [5; 32]);
+    }
+}
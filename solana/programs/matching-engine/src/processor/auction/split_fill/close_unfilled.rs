@@ -0,0 +1,196 @@
+use crate::{
+    error::MatchingEngineError,
+    state::{Auction, AuctionConfig, AuctionStatus, AuctionTranche},
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CloseUnfilledAuctionTranche<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Auction::SEED_PREFIX,
+            auction.vaa_hash.as_ref(),
+        ],
+        bump = auction.bump,
+        constraint = matches!(auction.status, AuctionStatus::Active) @ MatchingEngineError::AuctionNotActive,
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        constraint = {
+            // This is safe because we know that this is an active auction.
+            let info = auction.info.as_ref().unwrap();
+
+            require_eq!(
+                info.config_id,
+                config.id,
+                MatchingEngineError::AuctionConfigMismatch
+            );
+            require!(
+                !info.within_auction_duration(&config),
+                MatchingEngineError::AuctionPeriodNotExpired
+            );
+
+            // Tranches can only be closed if at least one tranche never received an offer.
+            let split_fill = info
+                .split_fill
+                .as_ref()
+                .ok_or_else(|| MatchingEngineError::NotSplitFillAuction)?;
+            require!(
+                !split_fill.all_tranches_placed(),
+                MatchingEngineError::AuctionTranchesFilled
+            );
+
+            true
+        },
+    )]
+    config: Box<Account<'info, AuctionConfig>>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        seeds = [
+            AuctionTranche::SEED_PREFIX,
+            auction.key().as_ref(),
+            &[auction_tranche.index],
+        ],
+        bump = auction_tranche.bump,
+    )]
+    auction_tranche: Box<Account<'info, AuctionTranche>>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump = auction.info.as_ref().unwrap().custody_token_bump,
+    )]
+    custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Mutable. Must have the same key in auction tranche data.
+    #[account(
+        mut,
+        address = auction_tranche.best_offer_token,
+    )]
+    best_offer_token: UncheckedAccount<'info>,
+
+    /// Token account receiving the tranche's total deposit if the best offer token does not exist
+    /// anymore.
+    #[account(
+        mut,
+        token::mint = auction.mint,
+    )]
+    executor_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: This account must equal the auction tranche's `prepared_by`.
+    #[account(
+        mut,
+        address = auction_tranche.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    /// CHECK: This account must equal the auction's `prepared_by`. If this is the last placed
+    /// tranche, this account receives the rent for the auction and its custody token account.
+    #[account(
+        mut,
+        address = auction.prepared_by,
+    )]
+    auction_prepared_by: UncheckedAccount<'info>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+/// If a split-fill auction ends before every tranche has an offer, the fast order cannot be
+/// executed. Each placed tranche is closed and its total deposit is refunded without a penalty.
+/// Once the last placed tranche is closed, the auction is closed so the order can be auctioned
+/// again or settled without an auction.
+pub fn close_unfilled_auction_tranche(ctx: Context<CloseUnfilledAuctionTranche>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let custody_token = &ctx.accounts.custody_token;
+    let best_offer_token = &ctx.accounts.best_offer_token;
+    let token_program = &ctx.accounts.token_program;
+
+    let auction_signer_seeds = &[
+        Auction::SEED_PREFIX,
+        auction.vaa_hash.as_ref(),
+        &[auction.bump],
+    ];
+
+    // Refund the tranche's total deposit to the best offer token. If the best offer token doesn't
+    // exist anymore, the executor token will collect these funds instead.
+    let refund_token =
+        if utils::checked_deserialize_token_account(best_offer_token, &auction.mint).is_some() {
+            best_offer_token.to_account_info()
+        } else {
+            ctx.accounts.executor_token.to_account_info()
+        };
+    let amount = ctx.accounts.auction_tranche.total_deposit();
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: custody_token.to_account_info(),
+                to: refund_token.to_account_info(),
+                authority: auction.to_account_info(),
+            },
+            &[auction_signer_seeds],
+        ),
+        amount,
+    )?;
+
+    // This is safe because we know that this is an active split-fill auction.
+    let tranches_placed = {
+        let split_fill = ctx
+            .accounts
+            .auction
+            .info
+            .as_mut()
+            .unwrap()
+            .split_fill
+            .as_mut()
+            .unwrap();
+        split_fill.tranches_placed = split_fill.tranches_placed.saturating_sub(1);
+        split_fill.tranches_placed
+    };
+    let auction_closed = tranches_placed == 0;
+
+    emit_cpi!(crate::events::AuctionTrancheRefunded {
+        fast_vaa_hash: ctx.accounts.auction.vaa_hash,
+        tranche_index: ctx.accounts.auction_tranche.index,
+        refund_token: refund_token.key(),
+        amount,
+        auction_closed,
+    });
+
+    // If this was the last placed tranche, close the auction custody token account and the auction.
+    if auction_closed {
+        let auction = &ctx.accounts.auction;
+
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.custody_token.to_account_info(),
+                destination: ctx.accounts.auction_prepared_by.to_account_info(),
+                authority: auction.to_account_info(),
+            },
+            &[&[
+                Auction::SEED_PREFIX,
+                auction.vaa_hash.as_ref(),
+                &[auction.bump],
+            ]],
+        ))?;
+
+        ctx.accounts
+            .auction
+            .close(ctx.accounts.auction_prepared_by.to_account_info())?;
+    }
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,241 @@
+use crate::{
+    error::MatchingEngineError,
+    state::{Auction, AuctionConfig, AuctionStatus, AuctionTranche, AuctionTrancheStatus},
+    utils::{
+        self,
+        auction::{ExecutePayoutAccounts, ExecutePayouts},
+    },
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExecuteAuctionTranche<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Auction::SEED_PREFIX,
+            auction.vaa_hash.as_ref(),
+        ],
+        bump = auction.bump,
+        constraint = matches!(auction.status, AuctionStatus::Active) @ MatchingEngineError::AuctionNotActive,
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        constraint = {
+            // This is safe because we know that this is an active auction.
+            let info = auction.info.as_ref().unwrap();
+
+            require_eq!(
+                info.config_id,
+                config.id,
+                MatchingEngineError::AuctionConfigMismatch
+            );
+            require!(
+                !info.within_auction_duration(&config),
+                MatchingEngineError::AuctionPeriodNotExpired
+            );
+
+            // A tranche can only be executed if every tranche has an offer.
+            let split_fill = info
+                .split_fill
+                .as_ref()
+                .ok_or_else(|| MatchingEngineError::NotSplitFillAuction)?;
+            require!(
+                split_fill.all_tranches_placed(),
+                MatchingEngineError::AuctionTranchesNotFilled
+            );
+
+            true
+        },
+    )]
+    config: Box<Account<'info, AuctionConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            AuctionTranche::SEED_PREFIX,
+            auction.key().as_ref(),
+            &[auction_tranche.index],
+        ],
+        bump = auction_tranche.bump,
+        constraint = {
+            auction_tranche.status == AuctionTrancheStatus::Active
+        } @ MatchingEngineError::AuctionTrancheNotActive,
+    )]
+    auction_tranche: Box<Account<'info, AuctionTranche>>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump = auction.info.as_ref().unwrap().custody_token_bump,
+    )]
+    custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Mutable. Must have the same key in auction tranche data.
+    #[account(
+        mut,
+        address = auction_tranche.best_offer_token,
+    )]
+    best_offer_token: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Must have the same key in auction tranche data.
+    #[account(
+        mut,
+        address = auction_tranche.initial_offer_token,
+    )]
+    initial_offer_token: UncheckedAccount<'info>,
+
+    /// Must be a token account, whose mint is the auction's mint.
+    #[account(
+        mut,
+        token::mint = auction.mint,
+    )]
+    executor_token: Box<Account<'info, token::TokenAccount>>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+/// Executing a tranche pays its best offer (and its initial offer) from the tranche's total
+/// deposit. What remains of the tranche's amount in is held in the auction's custody token account
+/// until every tranche is executed, when the fast order can be executed.
+pub fn execute_auction_tranche(ctx: Context<ExecuteAuctionTranche>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let auction_tranche = &ctx.accounts.auction_tranche;
+    let custody_token = &ctx.accounts.custody_token;
+    let best_offer_token = &ctx.accounts.best_offer_token;
+    let initial_offer_token = &ctx.accounts.initial_offer_token;
+    let executor_token = &ctx.accounts.executor_token;
+    let token_program = &ctx.accounts.token_program;
+
+    // This is safe because we know that this is an active auction.
+    let tranche_info = auction_tranche.tranche_info(auction.info.as_ref().unwrap());
+
+    // The tranche's total deposit is paid out as if it were the custody token account of an auction
+    // for this tranche alone. Whatever remains is owed to the user.
+    let ExecutePayouts {
+        user_amount,
+        initial_offer_amount,
+        best_offer_amount,
+        executor_amount,
+        penalty: execute_penalty,
+    } = utils::auction::compute_execute_payouts(
+        &ctx.accounts.config,
+        &tranche_info,
+        ExecutePayoutAccounts {
+            initial_offer_token_exists: utils::checked_deserialize_token_account(
+                initial_offer_token,
+                &auction.mint,
+            )
+            .is_some(),
+            best_offer_token_exists: utils::checked_deserialize_token_account(
+                best_offer_token,
+                &auction.mint,
+            )
+            .is_some(),
+            best_offer_is_initial_offer: best_offer_token.key() == initial_offer_token.key(),
+            best_offer_is_executor: best_offer_token.key() == executor_token.key(),
+        },
+        auction_tranche.total_deposit(),
+        auction_tranche.init_auction_fee,
+        Clock::get().unwrap().slot,
+        utils::auction::compute_additional_grace_period(&auction.target_protocol),
+    )?;
+
+    let auction_signer_seeds = &[
+        Auction::SEED_PREFIX,
+        auction.vaa_hash.as_ref(),
+        &[auction.bump],
+    ];
+
+    // Pay the tranche initiator their fee.
+    if initial_offer_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: custody_token.to_account_info(),
+                    to: initial_offer_token.to_account_info(),
+                    authority: auction.to_account_info(),
+                },
+                &[auction_signer_seeds],
+            ),
+            initial_offer_amount,
+        )?;
+    }
+
+    // Return the security deposit and the fee to the best offer of this tranche.
+    if best_offer_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: custody_token.to_account_info(),
+                    to: best_offer_token.to_account_info(),
+                    authority: auction.to_account_info(),
+                },
+                &[auction_signer_seeds],
+            ),
+            best_offer_amount,
+        )?;
+    }
+
+    // And pay the executor whatever remains of this tranche's deposit.
+    if executor_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: custody_token.to_account_info(),
+                    to: executor_token.to_account_info(),
+                    authority: auction.to_account_info(),
+                },
+                &[auction_signer_seeds],
+            ),
+            executor_amount,
+        )?;
+    }
+
+    let penalized = execute_penalty.is_some();
+
+    emit_cpi!(crate::events::AuctionTrancheExecuted {
+        fast_vaa_hash: auction.vaa_hash,
+        tranche_index: auction_tranche.index,
+        best_offer_token: best_offer_token.key(),
+        penalized,
+    });
+
+    ctx.accounts.auction_tranche.status = AuctionTrancheStatus::Executed { execute_penalty };
+
+    // Keep track of what is owed to the user. The fast order is executed with this amount once
+    // every tranche has been executed.
+    let split_fill = ctx
+        .accounts
+        .auction
+        .info
+        .as_mut()
+        .unwrap()
+        .split_fill
+        .as_mut()
+        .unwrap();
+    split_fill.tranches_executed = split_fill.tranches_executed.saturating_add(1);
+    split_fill.user_amount = split_fill
+        .user_amount
+        .checked_add(user_amount)
+        .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+    if let Some(penalty) = execute_penalty {
+        split_fill.execute_penalty = split_fill
+            .execute_penalty
+            .unwrap_or_default()
+            .saturating_add(penalty)
+            .into();
+    }
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use crate::{
+    error::MatchingEngineError,
+    state::{Auction, AuctionConfig, AuctionStatus, AuctionTranche, RegisteredSolver},
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::TRANSFER_AUTHORITY_SEED_PREFIX;
+
+#[derive(Accounts)]
+#[instruction(offer_price: u64)]
+#[event_cpi]
+pub struct ImproveTrancheOffer<'info> {
+    /// The auction participant needs to set approval to this PDA.
+    ///
+    /// CHECK: Seeds must be \["transfer-authority", auction_tranche.key(),
+    /// offer_price.to_be_bytes()\].
+    #[account(
+        seeds = [
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            auction_tranche.key().as_ref(),
+            &offer_price.to_be_bytes()
+        ],
+        bump
+    )]
+    transfer_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            Auction::SEED_PREFIX,
+            auction.vaa_hash.as_ref(),
+        ],
+        bump = auction.bump,
+        constraint = matches!(auction.status, AuctionStatus::Active) @ MatchingEngineError::AuctionNotActive,
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        constraint = {
+            // This is safe because we know that this is an active auction.
+            let info = auction.info.as_ref().unwrap();
+
+            require_eq!(
+                info.config_id,
+                config.id,
+                MatchingEngineError::AuctionConfigMismatch
+            );
+            require!(
+                info.within_auction_duration(&config),
+                MatchingEngineError::AuctionPeriodExpired
+            );
+
+            require!(
+                offer_price
+                    < utils::auction::compute_min_allowed_offer(
+                        &config,
+                        &auction_tranche.tranche_info(info),
+                    ),
+                MatchingEngineError::CarpingNotAllowed
+            );
+
+            true
+        },
+    )]
+    config: Box<Account<'info, AuctionConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            AuctionTranche::SEED_PREFIX,
+            auction.key().as_ref(),
+            &[auction_tranche.index],
+        ],
+        bump = auction_tranche.bump,
+    )]
+    auction_tranche: Box<Account<'info, AuctionTranche>>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump = auction.info.as_ref().unwrap().custody_token_bump,
+    )]
+    custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Mutable. Must have the same key in auction tranche data.
+    #[account(
+        mut,
+        address = auction_tranche.best_offer_token,
+    )]
+    best_offer_token: UncheckedAccount<'info>,
+
+    #[account(
+        token::mint = auction.mint,
+        constraint = {
+            offer_token.key() != custody_token.key()
+        } @ MatchingEngineError::InvalidOfferToken,
+    )]
+    offer_token: Account<'info, token::TokenAccount>,
+
+    /// Registration of the solver placing this offer. This account must be provided if the auction
+    /// only allows registered solvers.
+    #[account(
+        seeds = [
+            RegisteredSolver::SEED_PREFIX,
+            registered_solver.authority.as_ref(),
+        ],
+        bump = registered_solver.bump,
+    )]
+    registered_solver: Option<Box<Account<'info, RegisteredSolver>>>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn improve_tranche_offer(ctx: Context<ImproveTrancheOffer>, offer_price: u64) -> Result<()> {
+    let offer_token = &ctx.accounts.offer_token;
+
+    // This is safe because we know that this is an active auction.
+    let info = ctx.accounts.auction.info.as_ref().unwrap();
+
+    // Only registered solvers can place offers on routes with a solver allowlist.
+    if info.solver_allowlist {
+        utils::require_registered_solver(
+            ctx.accounts.registered_solver.as_deref(),
+            &offer_token.owner,
+        )?;
+    }
+
+    // Transfer funds from the `offer_token` token account to the `best_offer_token` token account,
+    // but only if the pubkeys are different.
+    if offer_token.key() != ctx.accounts.best_offer_token.key() {
+        let auction = &ctx.accounts.auction;
+        let custody_token = &ctx.accounts.custody_token;
+        let best_offer_token = &ctx.accounts.best_offer_token;
+        let token_program = &ctx.accounts.token_program;
+
+        let total_deposit = ctx.accounts.auction_tranche.total_deposit();
+
+        // If the best offer token happens to be closed, we will just keep the funds in the auction
+        // custody account. The executor token account will collect these funds when the tranche is
+        // executed.
+        if utils::checked_deserialize_token_account(best_offer_token, &auction.mint).is_some() {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    anchor_spl::token::Transfer {
+                        from: custody_token.to_account_info(),
+                        to: best_offer_token.to_account_info(),
+                        authority: auction.to_account_info(),
+                    },
+                    &[&[
+                        Auction::SEED_PREFIX,
+                        auction.vaa_hash.as_ref(),
+                        &[auction.bump],
+                    ]],
+                ),
+                total_deposit,
+            )?;
+        }
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: offer_token.to_account_info(),
+                    to: custody_token.to_account_info(),
+                    authority: ctx.accounts.transfer_authority.to_account_info(),
+                },
+                &[&[
+                    TRANSFER_AUTHORITY_SEED_PREFIX,
+                    ctx.accounts.auction_tranche.key().as_ref(),
+                    &offer_price.to_be_bytes(),
+                    &[ctx.bumps.transfer_authority],
+                ]],
+            ),
+            total_deposit,
+        )?;
+    }
+
+    // Update tranche before we emit event.
+    let auction_tranche = &mut ctx.accounts.auction_tranche;
+    auction_tranche.best_offer_token = offer_token.key();
+    auction_tranche.offer_price = offer_price;
+
+    // Emit the auction tranche updated event.
+    {
+        let auction = &ctx.accounts.auction;
+        let config = &ctx.accounts.config;
+        let info = auction.info.as_ref().unwrap();
+        let auction_tranche = &ctx.accounts.auction_tranche;
+
+        // Emit event for auction participants to listen to.
+        emit_cpi!(crate::utils::log_emit(
+            crate::events::AuctionTrancheUpdated {
+                config_id: info.config_id,
+                fast_vaa_hash: auction.vaa_hash,
+                vaa: Default::default(),
+                tranche_index: auction_tranche.index,
+                num_tranches: config.num_tranches,
+                end_slot: info.auction_end_slot(config),
+                best_offer_token: offer_token.key(),
+                token_balance_before: offer_token.amount,
+                amount_in: auction_tranche.amount_in,
+                total_deposit: auction_tranche.total_deposit(),
+                max_offer_price_allowed: utils::auction::compute_min_allowed_offer(
+                    config,
+                    &auction_tranche.tranche_info(info),
+                )
+                .checked_sub(1),
+            }
+        ));
+    }
+
+    // Done.
+    Ok(())
+}
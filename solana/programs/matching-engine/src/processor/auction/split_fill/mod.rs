@@ -0,0 +1,11 @@
+mod close_unfilled;
+pub use close_unfilled::*;
+
+mod execute;
+pub use execute::*;
+
+mod improve;
+pub use improve::*;
+
+mod place_initial;
+pub use place_initial::*;
@@ -0,0 +1,404 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{
+        Auction, AuctionConfig, AuctionInfo, AuctionSplitFillInfo, AuctionStatus, AuctionTranche,
+        AuctionTrancheStatus, CctpMint, EndpointFlow, MessageProtocol, RateLimit, RegisteredSolver,
+        RouteAuctionConfig,
+    },
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{
+    messages::raw::LiquidityLayerMessage,
+    migration::{VersionHeader, Versioned},
+    TRANSFER_AUTHORITY_SEED_PREFIX,
+};
+
+#[derive(Accounts)]
+#[instruction(tranche_index: u8, offer_price: u64)]
+#[event_cpi]
+pub struct PlaceInitialTrancheOfferCctp<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// The auction participant needs to set approval to this PDA.
+    ///
+    /// CHECK: Seeds must be \["transfer-authority", auction_tranche.key(),
+    /// offer_price.to_be_bytes()\].
+    #[account(
+        seeds = [
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            auction_tranche.key().as_ref(),
+            &offer_price.to_be_bytes()
+        ],
+        bump
+    )]
+    transfer_authority: UncheckedAccount<'info>,
+
+    /// NOTE: This account is only used to pause inbound auctions.
+    #[account(constraint = !custodian.paused @ MatchingEngineError::Paused)]
+    custodian: CheckedCustodian<'info>,
+
+    /// If the auction has not started yet, this account must be the route's auction config (see
+    /// [PlaceInitialOfferCctp](crate::processor::PlaceInitialOfferCctp)). Otherwise it must be the
+    /// auction's config.
+    auction_config: Account<'info, AuctionConfig>,
+
+    /// Route auction config for this fast market order's source and target chains. This account
+    /// may not exist, in which case the global auction config is used.
+    ///
+    /// CHECK: Seeds must be \["route-auction-config", source_chain, target_chain\].
+    #[account(
+        seeds = [
+            RouteAuctionConfig::SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+            &fast_order_path.to_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    route_auction_config: UncheckedAccount<'info>,
+
+    /// Rate limit for fast market orders sent from the source chain. This account may not exist,
+    /// in which case orders from this chain are not rate limited.
+    ///
+    /// CHECK: Seeds must be \["inbound-rate-limit", source_chain\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::INBOUND_SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    inbound_rate_limit: UncheckedAccount<'info>,
+
+    /// Rate limit for fast market orders destined for the target chain. This account may not
+    /// exist, in which case orders to this chain are not rate limited.
+    ///
+    /// CHECK: Seeds must be \["outbound-rate-limit", target_chain\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::OUTBOUND_SEED_PREFIX,
+            &fast_order_path.to_endpoint.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    outbound_rate_limit: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = {
+            match fast_order_path.to_endpoint.protocol {
+                MessageProtocol::Cctp { .. } | MessageProtocol::Local { .. } => (),
+                _ => return err!(MatchingEngineError::InvalidEndpoint),
+            }
+
+            let fast_vaa = fast_order_path.fast_vaa.load_unchecked();
+            let message = LiquidityLayerMessage::try_from(fast_vaa.payload()).unwrap();
+            let order = message
+                .fast_market_order()
+                .ok_or_else(|| MatchingEngineError::InvalidPayloadId)?;
+
+            let curr_time = Clock::get().unwrap().unix_timestamp;
+
+            // Check to see if the deadline has expired.
+            let deadline = order.deadline();
+            let expiration =
+                i64::from(fast_vaa.timestamp()).saturating_add(crate::VAA_AUCTION_EXPIRATION_TIME);
+            require!(
+                (deadline == 0 || curr_time < deadline.into()) && curr_time < expiration,
+                MatchingEngineError::FastMarketOrderExpired,
+            );
+
+            utils::require_valid_destination_asset(
+                order,
+                &fast_order_path.to_endpoint.protocol,
+                &mint.key(),
+            )?;
+
+            fast_order_path
+                .from_endpoint
+                .require_unpaused(EndpointFlow::InboundAuctions)?;
+
+            true
+        }
+    )]
+    fast_order_path: FastOrderPath<'info>,
+
+    /// The auction is created when the initial offer is placed on any of its tranches. Because this
+    /// account may already exist, the instruction handler must verify that an existing auction is
+    /// an active split-fill auction before an offer is placed on another tranche.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = [
+            Auction::SEED_PREFIX,
+            fast_order_path.fast_vaa.load_unchecked().digest().as_ref(),
+        ],
+        bump
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    /// This account should only be created once, and should never be changed to
+    /// init_if_needed. Otherwise someone can game an existing tranche.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AuctionTranche::INIT_SPACE,
+        seeds = [
+            AuctionTranche::SEED_PREFIX,
+            auction.key().as_ref(),
+            &[tranche_index],
+        ],
+        bump
+    )]
+    auction_tranche: Box<Account<'info, AuctionTranche>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = {
+            offer_token.key() != auction_custody_token.key()
+        } @ MatchingEngineError::InvalidOfferToken,
+    )]
+    offer_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Registration of the solver placing this offer. This account must be provided if either of
+    /// the route's router endpoints has a solver allowlist.
+    #[account(
+        seeds = [
+            RegisteredSolver::SEED_PREFIX,
+            registered_solver.authority.as_ref(),
+        ],
+        bump = registered_solver.bump,
+    )]
+    registered_solver: Option<Box<Account<'info, RegisteredSolver>>>,
+
+    /// Every tranche's total deposit is held in the auction's custody token account.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = mint,
+        token::authority = auction,
+        seeds = [
+            crate::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    auction_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    mint: SupportedCctpMint<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn place_initial_tranche_offer_cctp(
+    ctx: Context<PlaceInitialTrancheOfferCctp>,
+    tranche_index: u8,
+    offer_price: u64,
+) -> Result<()> {
+    // Create zero copy reference to `FastMarketOrder` payload.
+    let fast_vaa = ctx.accounts.fast_order_path.fast_vaa.load_unchecked();
+    let order = LiquidityLayerMessage::try_from(fast_vaa.payload())
+        .unwrap()
+        .to_fast_market_order_unchecked();
+
+    let config = &ctx.accounts.auction_config;
+    require!(
+        config.is_split_fill(),
+        MatchingEngineError::NotSplitFillAuction
+    );
+
+    let auction = &mut ctx.accounts.auction;
+    let offer_token = &ctx.accounts.offer_token;
+
+    match auction.status {
+        // Set up the Auction account for this auction. The initial offer token of the first tranche
+        // placed is used as the auction's initial and best offer tokens. These are only used to
+        // execute the fast order once every tranche has been executed.
+        AuctionStatus::NotStarted => {
+            require_eq!(
+                config.id,
+                RouteAuctionConfig::config_id_or(
+                    &ctx.accounts.route_auction_config,
+                    CctpMint::config_id_or(
+                        &ctx.accounts.mint.cctp_mint,
+                        ctx.accounts.custodian.auction_config_id
+                    )?,
+                )?,
+                MatchingEngineError::AuctionConfigMismatch,
+            );
+
+            // Orders that exceed either rate limit cannot be auctioned and must take the slow
+            // path.
+            RateLimit::consume_if_exists(&ctx.accounts.inbound_rate_limit, order.amount_in())?;
+            RateLimit::consume_if_exists(&ctx.accounts.outbound_rate_limit, order.amount_in())?;
+
+            auction.set_inner(Auction {
+                header: VersionHeader {
+                    version: Auction::VERSION,
+                },
+                bump: ctx.bumps.auction,
+                vaa_hash: fast_vaa.digest().0,
+                vaa_timestamp: fast_vaa.timestamp(),
+                target_protocol: ctx.accounts.fast_order_path.to_endpoint.protocol,
+                status: AuctionStatus::Active,
+                prepared_by: ctx.accounts.payer.key(),
+                info: AuctionInfo {
+                    config_id: config.id,
+                    custody_token_bump: ctx.bumps.auction_custody_token,
+                    vaa_sequence: fast_vaa.sequence(),
+                    source_chain: fast_vaa.emitter_chain(),
+                    best_offer_token: offer_token.key(),
+                    best_offer_vault: Default::default(),
+                    initial_offer_token: offer_token.key(),
+                    solver_allowlist: ctx.accounts.fast_order_path.from_endpoint.solver_allowlist
+                        || ctx.accounts.fast_order_path.to_endpoint.solver_allowlist,
+                    start_slot: Clock::get().unwrap().slot,
+                    extended_end_slot: Default::default(),
+                    amount_in: order.amount_in(),
+                    security_deposit: Default::default(),
+                    offer_price: Default::default(),
+                    forfeited_user_reward: Default::default(),
+                    split_fill: AuctionSplitFillInfo {
+                        num_tranches: config.num_tranches,
+                        tranches_placed: Default::default(),
+                        tranches_executed: Default::default(),
+                        user_amount: Default::default(),
+                        execute_penalty: Default::default(),
+                        amount_in_settled: Default::default(),
+                    }
+                    .into(),
+                    redeemer_message_len: order.redeemer_message_len(),
+                    destination_asset_info: Default::default(),
+                }
+                .into(),
+                mint: ctx.accounts.mint.key(),
+            });
+        }
+        // Otherwise this auction must be an active split-fill auction.
+        AuctionStatus::Active => {
+            let info = auction
+                .info
+                .as_ref()
+                .ok_or_else(|| MatchingEngineError::NoAuction)?;
+            require_eq!(
+                info.config_id,
+                config.id,
+                MatchingEngineError::AuctionConfigMismatch
+            );
+            require!(
+                info.split_fill.is_some(),
+                MatchingEngineError::NotSplitFillAuction
+            );
+            require!(
+                info.within_auction_duration(config),
+                MatchingEngineError::AuctionPeriodExpired
+            );
+        }
+        _ => return err!(MatchingEngineError::AuctionNotActive),
+    }
+
+    // This is safe because we know that this is an active split-fill auction.
+    let auction_key = auction.key();
+    let info = auction.info.as_mut().unwrap();
+    let split_fill = info.split_fill.as_mut().unwrap();
+    require!(
+        tranche_index < split_fill.num_tranches,
+        MatchingEngineError::InvalidTrancheIndex
+    );
+    split_fill.tranches_placed = split_fill.tranches_placed.saturating_add(1);
+    let num_tranches = split_fill.num_tranches;
+
+    // Only registered solvers can place offers on routes with a solver allowlist.
+    if info.solver_allowlist {
+        utils::require_registered_solver(
+            ctx.accounts.registered_solver.as_deref(),
+            &offer_token.owner,
+        )?;
+    }
+
+    // Each tranche's fees and security deposit are its portion of the order's.
+    let amount_in =
+        utils::auction::compute_tranche_amount_in(info.amount_in, num_tranches, tranche_index);
+    let max_fee = utils::auction::compute_pro_rata(order.max_fee(), amount_in, info.amount_in);
+    require!(
+        offer_price <= max_fee,
+        MatchingEngineError::OfferPriceTooHigh
+    );
+
+    // Saturating to u64::MAX is safe here. If the amount really ends up being this large, the
+    // checked addition below will catch it.
+    let security_deposit = utils::auction::compute_security_deposit(config, max_fee, amount_in);
+    let total_deposit = amount_in
+        .checked_add(security_deposit)
+        .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+
+    ctx.accounts.auction_tranche.set_inner(AuctionTranche {
+        bump: ctx.bumps.auction_tranche,
+        auction: auction_key,
+        index: tranche_index,
+        status: AuctionTrancheStatus::Active,
+        prepared_by: ctx.accounts.payer.key(),
+        initial_offer_token: offer_token.key(),
+        best_offer_token: offer_token.key(),
+        amount_in,
+        security_deposit,
+        offer_price,
+        init_auction_fee: utils::auction::compute_pro_rata(
+            order.init_auction_fee(),
+            amount_in,
+            info.amount_in,
+        ),
+    });
+
+    let auction = &ctx.accounts.auction;
+    let info = auction.info.as_ref().unwrap();
+    let tranche_info = ctx.accounts.auction_tranche.tranche_info(info);
+
+    // Emit event for auction participants to listen to.
+    emit_cpi!(crate::utils::log_emit(
+        crate::events::AuctionTrancheUpdated {
+            config_id: info.config_id,
+            fast_vaa_hash: auction.vaa_hash,
+            vaa: ctx.accounts.fast_order_path.fast_vaa.key().into(),
+            tranche_index,
+            num_tranches,
+            end_slot: info.auction_end_slot(config),
+            best_offer_token: offer_token.key(),
+            token_balance_before: offer_token.amount,
+            amount_in,
+            total_deposit,
+            max_offer_price_allowed: utils::auction::compute_min_allowed_offer(
+                config,
+                &tranche_info
+            )
+            .checked_sub(1),
+        }
+    ));
+
+    // Finally transfer tokens from the offer authority's token account to the auction's custody
+    // account.
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: offer_token.to_account_info(),
+                to: ctx.accounts.auction_custody_token.to_account_info(),
+                authority: ctx.accounts.transfer_authority.to_account_info(),
+            },
+            &[&[
+                TRANSFER_AUTHORITY_SEED_PREFIX,
+                ctx.accounts.auction_tranche.key().as_ref(),
+                &offer_price.to_be_bytes(),
+                &[ctx.bumps.transfer_authority],
+            ]],
+        ),
+        total_deposit,
+    )
+}
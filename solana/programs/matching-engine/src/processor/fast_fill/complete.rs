@@ -1,7 +1,7 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{FastFill, RouterEndpoint},
+    state::{Custodian, EndpointFlow, FastFill, RouterEndpoint},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -11,7 +11,7 @@ use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
 #[derive(Accounts)]
 #[event_cpi]
 pub struct CompleteFastFill<'info> {
-    /// Custodian, which may be used in the future.
+    /// Custodian, which is the authority of destination custody token accounts.
     custodian: CheckedCustodian<'info>,
 
     /// Fast fill account.
@@ -41,7 +41,7 @@ pub struct CompleteFastFill<'info> {
 
     #[account(
         mut,
-        token::mint = local_custody_token.mint,
+        token::mint = fast_fill.info.mint,
     )]
     token_router_custody_token: Box<Account<'info, token::TokenAccount>>,
 
@@ -59,6 +59,8 @@ pub struct CompleteFastFill<'info> {
                 MatchingEngineError::InvalidTargetRouter
             );
 
+            path.from_endpoint.require_unpaused(EndpointFlow::FastFillRedemption)?;
+
             true
         }
     )]
@@ -74,6 +76,29 @@ pub struct CompleteFastFill<'info> {
     )]
     local_custody_token: Box<Account<'info, token::TokenAccount>>,
 
+    /// Custody token account holding the destination asset delivered for this fast fill. This
+    /// account must be provided if the fast fill's mint is not the local custody token's mint.
+    #[account(
+        mut,
+        seeds = [
+            crate::DESTINATION_CUSTODY_TOKEN_SEED_PREFIX,
+            fast_fill.key().as_ref(),
+        ],
+        bump,
+    )]
+    destination_custody_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    /// Whoever created the fast fill, who also paid for the destination custody token account.
+    /// This account receives the lamports from closing the destination custody token account, so
+    /// it must be provided with it.
+    ///
+    /// CHECK: Mutable. This address must equal the fast fill's `prepared_by`.
+    #[account(
+        mut,
+        address = fast_fill.info.prepared_by,
+    )]
+    fast_fill_prepared_by: Option<UncheckedAccount<'info>>,
+
     token_program: Program<'info, token::Token>,
 }
 
@@ -87,21 +112,65 @@ pub fn complete_fast_fill(ctx: Context<CompleteFastFill>) -> Result<()> {
         fast_fill: ctx.accounts.fast_fill.seeds,
     });
 
-    // Finally transfer to local token router's token account.
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.local_custody_token.to_account_info(),
-                to: ctx.accounts.token_router_custody_token.to_account_info(),
-                authority: ctx.accounts.path.from_endpoint.to_account_info(),
-            },
-            &[&[
-                RouterEndpoint::SEED_PREFIX,
-                &ctx.accounts.path.from_endpoint.chain.to_be_bytes(),
-                &[ctx.accounts.path.from_endpoint.bump],
-            ]],
-        ),
-        ctx.accounts.fast_fill.info.amount,
-    )
+    let token_program = &ctx.accounts.token_program;
+    let amount = ctx.accounts.fast_fill.info.amount;
+
+    match (
+        &ctx.accounts.destination_custody_token,
+        &ctx.accounts.fast_fill_prepared_by,
+    ) {
+        (Some(destination_custody_token), Some(fast_fill_prepared_by)) => {
+            // Transfer the destination asset to local token router's token account.
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: destination_custody_token.to_account_info(),
+                        to: ctx.accounts.token_router_custody_token.to_account_info(),
+                        authority: ctx.accounts.custodian.to_account_info(),
+                    },
+                    &[Custodian::SIGNER_SEEDS],
+                ),
+                amount,
+            )?;
+
+            // Finally close the destination custody token account since it is no longer needed.
+            token::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::CloseAccount {
+                    account: destination_custody_token.to_account_info(),
+                    destination: fast_fill_prepared_by.to_account_info(),
+                    authority: ctx.accounts.custodian.to_account_info(),
+                },
+                &[Custodian::SIGNER_SEEDS],
+            ))
+        }
+        _ => {
+            // Without a destination custody token account, the fast fill must be redeemed with
+            // the local custody token's mint.
+            require_keys_eq!(
+                ctx.accounts.fast_fill.info.mint,
+                ctx.accounts.local_custody_token.mint,
+                MatchingEngineError::DestinationCustodyTokenRequired
+            );
+
+            // Finally transfer to local token router's token account.
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.local_custody_token.to_account_info(),
+                        to: ctx.accounts.token_router_custody_token.to_account_info(),
+                        authority: ctx.accounts.path.from_endpoint.to_account_info(),
+                    },
+                    &[&[
+                        RouterEndpoint::SEED_PREFIX,
+                        &ctx.accounts.path.from_endpoint.chain.to_be_bytes(),
+                        &[ctx.accounts.path.from_endpoint.bump],
+                    ]],
+                ),
+                amount,
+            )
+        }
+    }
 }
@@ -6,3 +6,6 @@ pub use auction::*;
 
 mod fast_fill;
 pub use fast_fill::*;
+
+mod solver_vault;
+pub use solver_vault::*;
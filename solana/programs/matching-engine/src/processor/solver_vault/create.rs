@@ -0,0 +1,55 @@
+use crate::{composite::*, state::SolverVault};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct CreateSolverVault<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SolverVault::INIT_SPACE,
+        seeds = [
+            SolverVault::SEED_PREFIX,
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    solver_vault: Account<'info, SolverVault>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = usdc,
+        token::authority = solver_vault,
+        seeds = [
+            crate::SOLVER_VAULT_CUSTODY_TOKEN_SEED_PREFIX,
+            solver_vault.key().as_ref(),
+        ],
+        bump,
+    )]
+    custody_token: Account<'info, token::TokenAccount>,
+
+    usdc: Usdc<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn create_solver_vault(ctx: Context<CreateSolverVault>) -> Result<()> {
+    ctx.accounts.solver_vault.set_inner(SolverVault {
+        bump: ctx.bumps.solver_vault,
+        owner: ctx.accounts.owner.key(),
+        custody_token: ctx.accounts.custody_token.key(),
+        custody_token_bump: ctx.bumps.custody_token,
+        locked: Default::default(),
+        pending_withdrawal: Default::default(),
+    });
+
+    // Done.
+    Ok(())
+}
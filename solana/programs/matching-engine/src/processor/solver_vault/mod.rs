@@ -0,0 +1,8 @@
+mod create;
+pub use create::*;
+
+mod request_withdrawal;
+pub use request_withdrawal::*;
+
+mod withdraw;
+pub use withdraw::*;
@@ -0,0 +1,52 @@
+use crate::{
+    error::MatchingEngineError,
+    state::{PendingWithdrawal, SolverVault},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct RequestSolverVaultWithdrawal<'info> {
+    owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SolverVault::SEED_PREFIX,
+            owner.key().as_ref(),
+        ],
+        bump = solver_vault.bump,
+    )]
+    solver_vault: Account<'info, SolverVault>,
+
+    #[account(address = solver_vault.custody_token)]
+    custody_token: Account<'info, token::TokenAccount>,
+}
+
+pub fn request_solver_vault_withdrawal(
+    ctx: Context<RequestSolverVaultWithdrawal>,
+    amount: u64,
+) -> Result<()> {
+    let solver_vault = &mut ctx.accounts.solver_vault;
+
+    require!(
+        amount > 0 && amount <= solver_vault.available(ctx.accounts.custody_token.amount),
+        MatchingEngineError::InsufficientVaultBalance
+    );
+
+    let slot_available = Clock::get()
+        .unwrap()
+        .slot
+        .checked_add(crate::SOLVER_VAULT_WITHDRAWAL_COOLDOWN)
+        .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+
+    // NOTE: Any existing pending withdrawal is replaced, which restarts the cooldown.
+    solver_vault.pending_withdrawal = PendingWithdrawal {
+        amount,
+        slot_available,
+    }
+    .into();
+
+    // Done.
+    Ok(())
+}
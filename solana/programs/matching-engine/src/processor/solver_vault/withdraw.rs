@@ -0,0 +1,77 @@
+use crate::{
+    error::MatchingEngineError,
+    state::{PendingWithdrawal, SolverVault},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct WithdrawSolverVault<'info> {
+    owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SolverVault::SEED_PREFIX,
+            owner.key().as_ref(),
+        ],
+        bump = solver_vault.bump,
+    )]
+    solver_vault: Account<'info, SolverVault>,
+
+    #[account(
+        mut,
+        address = solver_vault.custody_token,
+    )]
+    custody_token: Account<'info, token::TokenAccount>,
+
+    /// Token account receiving the withdrawn collateral.
+    #[account(
+        mut,
+        token::mint = common::USDC_MINT,
+    )]
+    dst_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn withdraw_solver_vault(ctx: Context<WithdrawSolverVault>) -> Result<()> {
+    let solver_vault = &mut ctx.accounts.solver_vault;
+
+    let PendingWithdrawal {
+        amount,
+        slot_available,
+    } = solver_vault
+        .pending_withdrawal
+        .take()
+        .ok_or_else(|| MatchingEngineError::NoPendingWithdrawal)?;
+
+    require!(
+        Clock::get().unwrap().slot >= slot_available,
+        MatchingEngineError::WithdrawalCooldownNotExpired
+    );
+
+    // Collateral may have been locked in auctions since the withdrawal was requested.
+    require!(
+        amount <= solver_vault.available(ctx.accounts.custody_token.amount),
+        MatchingEngineError::InsufficientVaultBalance
+    );
+
+    let owner = ctx.accounts.owner.key();
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.custody_token.to_account_info(),
+                to: ctx.accounts.dst_token.to_account_info(),
+                authority: solver_vault.to_account_info(),
+            },
+            &[&[
+                SolverVault::SEED_PREFIX,
+                owner.as_ref(),
+                &[solver_vault.bump],
+            ]],
+        ),
+        amount,
+    )
+}
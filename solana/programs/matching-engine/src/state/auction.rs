@@ -1,5 +1,6 @@
 use crate::state::AuctionParameters;
 use anchor_lang::prelude::*;
+use common::migration::{self, UpgradeFn, VersionHeader, Versioned};
 
 use super::MessageProtocol;
 
@@ -44,10 +45,49 @@ impl std::fmt::Display for AuctionStatus {
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
 pub struct AuctionDestinationAssetInfo {
+    /// Bump seed for the custody token account holding the destination asset delivered by the
+    /// executor, whose seeds are \["destination-custody", fast_fill.key()\].
     pub custody_token_bump: u8,
+
+    /// Amount of the destination asset delivered, which is the order's minimum amount out.
     pub amount_out: u64,
 }
 
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct AuctionSplitFillInfo {
+    /// Number of tranches the fast order is split into.
+    pub num_tranches: u8,
+
+    /// Number of tranches with an offer. Each placed tranche has an
+    /// [AuctionTranche](super::AuctionTranche) account.
+    pub tranches_placed: u8,
+
+    /// Number of tranches that have been executed.
+    pub tranches_executed: u8,
+
+    /// Amount owed to the user, which accumulates as each tranche is executed.
+    pub user_amount: u64,
+
+    /// Sum of penalties incurred by late tranche executions. This value is none if no tranche was
+    /// penalized.
+    pub execute_penalty: Option<u64>,
+
+    /// Sum of the amounts in of tranches that have been settled.
+    pub amount_in_settled: u64,
+}
+
+impl AuctionSplitFillInfo {
+    /// Whether every tranche has an offer.
+    pub fn all_tranches_placed(&self) -> bool {
+        self.tranches_placed >= self.num_tranches
+    }
+
+    /// Whether every tranche has been executed.
+    pub fn all_tranches_executed(&self) -> bool {
+        self.tranches_executed >= self.num_tranches
+    }
+}
+
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
 pub struct AuctionInfo {
     pub config_id: u32,
@@ -84,23 +124,50 @@ pub struct AuctionInfo {
     /// Length of the redeemer message, which may impact the expense to execute the auction.
     pub redeemer_message_len: u16,
 
-    /// If the destination asset is not equal to the asset used for auctions, this will be some
-    /// value specifying its custody token bump and amount out.
-    ///
-    /// NOTE: Because this is an option, the `AuctionDestinationAssetInfo` having some definition while this
-    /// field is None will not impact future serialization because the option's serialized value is
-    /// zero. Only when there will be other assets will this struct's members have to be carefully
-    /// considered.
+    /// If the order requested a destination asset, this will be some value specifying its custody
+    /// token bump and amount out once the order is executed locally.
     pub destination_asset_info: Option<AuctionDestinationAssetInfo>,
+
+    /// If some, the best offer's total deposit is locked in this solver vault instead of being
+    /// held in the auction's custody token account. The best offer token is this vault's custody
+    /// token account.
+    pub best_offer_vault: Option<Pubkey>,
+
+    /// User rewards collected from security deposits forfeited by unrevealed commitments in a
+    /// sealed-bid auction. This amount is held in the auction's custody token account and is paid
+    /// to the user when the order is executed.
+    pub forfeited_user_reward: u64,
+
+    /// If some, an improved offer placed near the end of the auction extended the auction to end at
+    /// this slot (see [AuctionParameters::extension_window]).
+    pub extended_end_slot: Option<u64>,
+
+    /// If true, only registered solvers may place offers on this auction. This is determined by
+    /// the route's router endpoints when the auction starts.
+    pub solver_allowlist: bool,
+
+    /// If some, this auction is a split-fill auction. Offers are placed on tranches of the order
+    /// instead of the order itself, so the best offer token, security deposit and offer price of
+    /// this auction are unused (see [AuctionTranche](super::AuctionTranche)).
+    pub split_fill: Option<AuctionSplitFillInfo>,
 }
 
 impl AuctionInfo {
-    /// Compute start slot + duration.
+    /// Compute start slot + duration, or the extended end slot if the auction was extended. For
+    /// sealed-bid auctions, this is the last slot when offers can be committed.
+    pub fn offer_end_slot(&self, params: &AuctionParameters) -> u64 {
+        self.extended_end_slot
+            .unwrap_or_else(|| self.start_slot.saturating_add(params.duration.into()))
+    }
+
+    /// Compute offer end slot + reveal period. The reveal period is zero for auctions that
+    /// are not sealed-bid auctions.
     pub fn auction_end_slot(&self, params: &AuctionParameters) -> u64 {
-        self.start_slot.saturating_add(params.duration.into())
+        self.offer_end_slot(params)
+            .saturating_add(params.reveal_period.into())
     }
 
-    /// Compute start slot + duration + grace period.
+    /// Compute auction end slot + grace period.
     pub fn grace_period_end_slot(
         &self,
         params: &AuctionParameters,
@@ -111,7 +178,7 @@ impl AuctionInfo {
             .saturating_add(additional_grace_period.unwrap_or_default())
     }
 
-    /// Compute start slot + duration + grace period + penalty slots.
+    /// Compute auction end slot + grace period + penalty slots.
     pub fn penalty_period_end_slot(
         &self,
         params: &AuctionParameters,
@@ -130,11 +197,18 @@ impl AuctionInfo {
     pub fn within_auction_duration(&self, params: &AuctionParameters) -> bool {
         Clock::get().unwrap().slot <= self.auction_end_slot(params)
     }
+
+    /// Determine whether offers can still be placed or committed (using [Clock]).
+    pub fn within_offer_period(&self, params: &AuctionParameters) -> bool {
+        Clock::get().unwrap().slot <= self.offer_end_slot(params)
+    }
 }
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Auction {
+    pub header: VersionHeader,
+
     pub bump: u8,
 
     /// VAA hash of the auction.
@@ -154,9 +228,256 @@ pub struct Auction {
 
     /// Optional auction info. This field will be `None`` if there is no auction.
     pub info: Option<AuctionInfo>,
+
+    /// Circle-supported mint of the assets held in custody for this auction.
+    pub mint: Pubkey,
 }
 
 impl Auction {
     pub const SEED_PREFIX: &'static [u8] = b"auction";
     pub const INIT_SPACE_NO_AUCTION: usize = Self::INIT_SPACE - AuctionInfo::INIT_SPACE;
+
+    /// Length of an auction's data (including its discriminator) before it was versioned.
+    const LEGACY_LEN: usize = 252;
+
+    /// Length of an auction's data (including its discriminator) without auction info before it
+    /// was versioned.
+    const LEGACY_LEN_NO_AUCTION: usize = 129;
+}
+
+impl Versioned for Auction {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    fn is_legacy(_key: &Pubkey, data: &[u8]) -> bool {
+        data.len() == Self::LEGACY_LEN || data.len() == Self::LEGACY_LEN_NO_AUCTION
+    }
+}
+
+/// Auction info layout before the auction was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+struct LegacyAuctionInfo {
+    config_id: u32,
+    custody_token_bump: u8,
+    vaa_sequence: u64,
+    source_chain: u16,
+    best_offer_token: Pubkey,
+    initial_offer_token: Pubkey,
+    start_slot: u64,
+    amount_in: u64,
+    security_deposit: u64,
+    offer_price: u64,
+    redeemer_message_len: u16,
+    destination_asset_info: Option<AuctionDestinationAssetInfo>,
+}
+
+/// Auction layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+struct LegacyAuction {
+    bump: u8,
+    vaa_hash: [u8; 32],
+    vaa_timestamp: u32,
+    target_protocol: MessageProtocol,
+    status: AuctionStatus,
+    prepared_by: Pubkey,
+    info: Option<LegacyAuctionInfo>,
+}
+
+/// Version 1 adds the version header, the auction's mint (which was always USDC) and the auction
+/// info needed for solver vaults, sealed-bid, extended and split-fill auctions.
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyAuction {
+        bump,
+        vaa_hash,
+        vaa_timestamp,
+        target_protocol,
+        status,
+        prepared_by,
+        info,
+    } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    let space = if info.is_some() {
+        Auction::INIT_SPACE
+    } else {
+        Auction::INIT_SPACE_NO_AUCTION
+    };
+
+    let info = info.map(|info| {
+        let LegacyAuctionInfo {
+            config_id,
+            custody_token_bump,
+            vaa_sequence,
+            source_chain,
+            best_offer_token,
+            initial_offer_token,
+            start_slot,
+            amount_in,
+            security_deposit,
+            offer_price,
+            redeemer_message_len,
+            destination_asset_info,
+        } = info;
+
+        AuctionInfo {
+            config_id,
+            custody_token_bump,
+            vaa_sequence,
+            source_chain,
+            best_offer_token,
+            best_offer_vault: None,
+            initial_offer_token,
+            solver_allowlist: false,
+            start_slot,
+            extended_end_slot: None,
+            amount_in,
+            security_deposit,
+            offer_price,
+            forfeited_user_reward: 0,
+            split_fill: None,
+            redeemer_message_len,
+            destination_asset_info,
+        }
+    });
+
+    migration::serialize_upgraded(
+        &Auction {
+            header: VersionHeader { version: 1 },
+            bump,
+            vaa_hash,
+            vaa_timestamp,
+            target_protocol,
+            status,
+            prepared_by,
+            info,
+            mint: common::USDC_MINT,
+        },
+        space,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn legacy_auction(info: Option<LegacyAuctionInfo>) -> LegacyAuction {
+        LegacyAuction {
+            bump: 255,
+            vaa_hash: [1; 32],
+            vaa_timestamp: 69,
+            target_protocol: MessageProtocol::Cctp { domain: 1 },
+            status: AuctionStatus::Completed {
+                slot: 420,
+                execute_penalty: Some(1),
+            },
+            prepared_by: Pubkey::new_unique(),
+            info,
+        }
+    }
+
+    fn upgrade(legacy: &LegacyAuction) -> Auction {
+        let mut legacy_data = Auction::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+
+        // Legacy auctions were allocated with enough space for their largest optional fields, or
+        // without the auction info if there was no auction.
+        let legacy_space = if legacy.info.is_some() {
+            LegacyAuction::INIT_SPACE
+        } else {
+            LegacyAuction::INIT_SPACE.saturating_sub(LegacyAuctionInfo::INIT_SPACE)
+        };
+        legacy_data.resize(legacy_space.saturating_add(8), 0);
+
+        let key = Pubkey::new_unique();
+        assert_eq!(
+            migration::account_version::<Auction>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        let mut upgraded_data = Auction::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgrade_v0(&legacy_data[8..]).unwrap());
+        assert!(!Auction::is_legacy(&key, &upgraded_data));
+        assert_eq!(
+            migration::account_version::<Auction>(&key, &upgraded_data).unwrap(),
+            Auction::VERSION
+        );
+
+        let auction = Auction::try_deserialize(&mut &upgraded_data[..]).unwrap();
+        assert_eq!(auction.bump, legacy.bump);
+        assert_eq!(auction.vaa_hash, legacy.vaa_hash);
+        assert_eq!(auction.vaa_timestamp, legacy.vaa_timestamp);
+        assert_eq!(auction.target_protocol, legacy.target_protocol);
+        assert_eq!(auction.status, legacy.status);
+        assert_eq!(auction.prepared_by, legacy.prepared_by);
+        assert_eq!(auction.mint, common::USDC_MINT);
+
+        auction
+    }
+
+    #[test]
+    fn legacy_len() {
+        assert_eq!(
+            LegacyAuction::INIT_SPACE.saturating_add(8),
+            Auction::LEGACY_LEN
+        );
+        assert_eq!(
+            LegacyAuction::INIT_SPACE
+                .saturating_sub(LegacyAuctionInfo::INIT_SPACE)
+                .saturating_add(8),
+            Auction::LEGACY_LEN_NO_AUCTION
+        );
+    }
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let legacy = legacy_auction(Some(LegacyAuctionInfo {
+            config_id: 1,
+            custody_token_bump: 254,
+            vaa_sequence: 2,
+            source_chain: 3,
+            best_offer_token: Pubkey::new_unique(),
+            initial_offer_token: Pubkey::new_unique(),
+            start_slot: 4,
+            amount_in: 5,
+            security_deposit: 6,
+            offer_price: 7,
+            redeemer_message_len: 8,
+            destination_asset_info: Some(AuctionDestinationAssetInfo {
+                custody_token_bump: 253,
+                amount_out: 9,
+            }),
+        }));
+
+        let auction = upgrade(&legacy);
+
+        let legacy_info = legacy.info.as_ref().unwrap();
+        let info = auction.info.unwrap();
+        assert_eq!(info.config_id, legacy_info.config_id);
+        assert_eq!(info.custody_token_bump, legacy_info.custody_token_bump);
+        assert_eq!(info.vaa_sequence, legacy_info.vaa_sequence);
+        assert_eq!(info.source_chain, legacy_info.source_chain);
+        assert_eq!(info.best_offer_token, legacy_info.best_offer_token);
+        assert_eq!(info.best_offer_vault, None);
+        assert_eq!(info.initial_offer_token, legacy_info.initial_offer_token);
+        assert!(!info.solver_allowlist);
+        assert_eq!(info.start_slot, legacy_info.start_slot);
+        assert_eq!(info.extended_end_slot, None);
+        assert_eq!(info.amount_in, legacy_info.amount_in);
+        assert_eq!(info.security_deposit, legacy_info.security_deposit);
+        assert_eq!(info.offer_price, legacy_info.offer_price);
+        assert_eq!(info.forfeited_user_reward, 0);
+        assert!(info.split_fill.is_none());
+        assert_eq!(info.redeemer_message_len, legacy_info.redeemer_message_len);
+
+        let destination_asset_info = info.destination_asset_info.unwrap();
+        assert_eq!(destination_asset_info.custody_token_bump, 253);
+        assert_eq!(destination_asset_info.amount_out, 9);
+    }
+
+    #[test]
+    fn upgrade_from_legacy_no_auction() {
+        let auction = upgrade(&legacy_auction(None));
+        assert!(auction.info.is_none());
+    }
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use common::migration::{self, UpgradeFn, VersionHeader, Versioned};
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
 pub struct AuctionParameters {
@@ -30,11 +31,41 @@ pub struct AuctionParameters {
 
     /// Additional security deposit based on the notional of the order amount.
     pub security_deposit_bps: u32,
+
+    /// If nonzero, auctions are sealed-bid auctions. Offers are committed during the auction
+    /// duration and revealed during this many slots after the auction duration. The reveal period
+    /// delays when the auction can be executed (and when the grace period begins).
+    pub reveal_period: u16,
+
+    /// An improved offer placed within this many slots of the end of the auction extends the
+    /// auction so that there are this many slots left to respond. Zero disables extensions.
+    pub extension_window: u16,
+
+    /// The maximum number of slots an auction can be extended beyond its duration.
+    pub max_extension: u16,
+
+    /// If greater than one, auctions are split-fill auctions. The fast order is split into this
+    /// many tranches, where each tranche has its own best offer, deposit and execution.
+    pub num_tranches: u8,
+}
+
+impl AuctionParameters {
+    /// Whether auctions using these parameters are sealed-bid (commit/reveal) auctions.
+    pub fn is_sealed_bid(&self) -> bool {
+        self.reveal_period > 0
+    }
+
+    /// Whether auctions using these parameters are split-fill auctions.
+    pub fn is_split_fill(&self) -> bool {
+        self.num_tranches > 1
+    }
 }
 
 #[account]
 #[derive(Debug, InitSpace, Copy)]
 pub struct AuctionConfig {
+    pub header: VersionHeader,
+
     /// Monotonically increasing identifier for auction configs.
     pub id: u32,
     /// Auction parameters, which are validated by [crate::utils::auction::require_valid_parameters].
@@ -43,6 +74,9 @@ pub struct AuctionConfig {
 
 impl AuctionConfig {
     pub const SEED_PREFIX: &'static [u8] = b"auction-config";
+
+    /// Length of an auction config's data (including its discriminator) before it was versioned.
+    const LEGACY_LEN: usize = 42;
 }
 
 impl std::ops::Deref for AuctionConfig {
@@ -52,3 +86,143 @@ impl std::ops::Deref for AuctionConfig {
         &self.parameters
     }
 }
+
+impl Versioned for AuctionConfig {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    fn is_legacy(_key: &Pubkey, data: &[u8]) -> bool {
+        data.len() == Self::LEGACY_LEN
+    }
+}
+
+/// Auction parameters before the parameters for sealed-bid, extended and split-fill auctions were
+/// added, which are found in legacy auction configs and proposals.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub(crate) struct LegacyAuctionParameters {
+    pub user_penalty_reward_bps: u32,
+    pub initial_penalty_bps: u32,
+    pub duration: u16,
+    pub grace_period: u16,
+    pub penalty_period: u16,
+    pub min_offer_delta_bps: u32,
+    pub security_deposit_base: u64,
+    pub security_deposit_bps: u32,
+}
+
+impl From<LegacyAuctionParameters> for AuctionParameters {
+    /// Sealed-bid, extended and split-fill auctions are disabled for legacy parameters.
+    fn from(legacy: LegacyAuctionParameters) -> Self {
+        let LegacyAuctionParameters {
+            user_penalty_reward_bps,
+            initial_penalty_bps,
+            duration,
+            grace_period,
+            penalty_period,
+            min_offer_delta_bps,
+            security_deposit_base,
+            security_deposit_bps,
+        } = legacy;
+
+        Self {
+            user_penalty_reward_bps,
+            initial_penalty_bps,
+            duration,
+            grace_period,
+            penalty_period,
+            min_offer_delta_bps,
+            security_deposit_base,
+            security_deposit_bps,
+            reveal_period: 0,
+            extension_window: 0,
+            max_extension: 0,
+            num_tranches: 0,
+        }
+    }
+}
+
+/// Auction config layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyAuctionConfig {
+    id: u32,
+    parameters: LegacyAuctionParameters,
+}
+
+/// Version 1 adds the version header and the parameters for sealed-bid, extended and split-fill
+/// auctions, which are disabled for existing auction configs.
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyAuctionConfig { id, parameters } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    migration::serialize_upgraded(
+        &AuctionConfig {
+            header: VersionHeader { version: 1 },
+            id,
+            parameters: parameters.into(),
+        },
+        AuctionConfig::INIT_SPACE,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let legacy = LegacyAuctionConfig {
+            id: 1,
+            parameters: LegacyAuctionParameters {
+                user_penalty_reward_bps: 250_000,
+                initial_penalty_bps: 250_000,
+                duration: 2,
+                grace_period: 5,
+                penalty_period: 10,
+                min_offer_delta_bps: 20_000,
+                security_deposit_base: 4_200_000,
+                security_deposit_bps: 5_000,
+            },
+        };
+
+        let mut legacy_data = AuctionConfig::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+        assert_eq!(legacy_data.len(), AuctionConfig::LEGACY_LEN);
+
+        let key = Pubkey::new_unique();
+        assert_eq!(
+            migration::account_version::<AuctionConfig>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        let mut upgraded_data = AuctionConfig::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgrade_v0(&legacy_data[8..]).unwrap());
+        assert_eq!(upgraded_data.len(), 8 + AuctionConfig::INIT_SPACE);
+        assert_eq!(
+            migration::account_version::<AuctionConfig>(&key, &upgraded_data).unwrap(),
+            AuctionConfig::VERSION
+        );
+
+        let config = AuctionConfig::try_deserialize(&mut &upgraded_data[..]).unwrap();
+        assert_eq!(config.id, legacy.id);
+        assert_eq!(
+            config.parameters,
+            AuctionParameters {
+                user_penalty_reward_bps: legacy.parameters.user_penalty_reward_bps,
+                initial_penalty_bps: legacy.parameters.initial_penalty_bps,
+                duration: legacy.parameters.duration,
+                grace_period: legacy.parameters.grace_period,
+                penalty_period: legacy.parameters.penalty_period,
+                min_offer_delta_bps: legacy.parameters.min_offer_delta_bps,
+                security_deposit_base: legacy.parameters.security_deposit_base,
+                security_deposit_bps: legacy.parameters.security_deposit_bps,
+                reveal_period: 0,
+                extension_window: 0,
+                max_extension: 0,
+                num_tranches: 0,
+            }
+        );
+        assert!(!config.is_sealed_bid());
+        assert!(!config.is_split_fill());
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use super::AuctionInfo;
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum AuctionTrancheStatus {
+    Active,
+    Executed { execute_penalty: Option<u64> },
+}
+
+/// Tranche of a split-fill auction's fast order. Each tranche has its own best offer, whose total
+/// deposit is held in the auction's custody token account.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct AuctionTranche {
+    pub bump: u8,
+
+    /// Split-fill auction this tranche belongs to.
+    pub auction: Pubkey,
+
+    /// Index of this tranche, which is less than the auction's number of tranches.
+    pub index: u8,
+
+    pub status: AuctionTrancheStatus,
+
+    /// The payer when placing the initial offer on this tranche, who will receive the rent for
+    /// this account.
+    pub prepared_by: Pubkey,
+
+    /// The initial bidder of this tranche.
+    pub initial_offer_token: Pubkey,
+
+    /// The best bidder of this tranche.
+    pub best_offer_token: Pubkey,
+
+    /// This tranche's portion of the auction's amount in.
+    pub amount_in: u64,
+
+    /// The additional deposit made by the best bidder of this tranche.
+    pub security_deposit: u64,
+
+    /// The offer price of this tranche.
+    pub offer_price: u64,
+
+    /// This tranche's portion of the fast market order's init auction fee.
+    pub init_auction_fee: u64,
+}
+
+impl AuctionTranche {
+    pub const SEED_PREFIX: &'static [u8] = b"auction-tranche";
+
+    /// Compute amount in + security deposit.
+    pub fn total_deposit(&self) -> u64 {
+        self.amount_in.saturating_add(self.security_deposit)
+    }
+
+    /// Auction info with this tranche's offer in place of the auction's offer. This is used to
+    /// apply the auction's offer rules and penalties to this tranche.
+    pub fn tranche_info(&self, info: &AuctionInfo) -> AuctionInfo {
+        AuctionInfo {
+            best_offer_token: self.best_offer_token,
+            best_offer_vault: None,
+            initial_offer_token: self.initial_offer_token,
+            amount_in: self.amount_in,
+            security_deposit: self.security_deposit,
+            offer_price: self.offer_price,
+            ..*info
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{error::MatchingEngineError, state::Custodian};
+use anchor_lang::prelude::*;
+
+/// Registered CCTP asset other than USDC (e.g. EURC). USDC is always supported and does not need
+/// to be registered.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CctpMint {
+    pub bump: u8,
+
+    /// Circle-supported mint.
+    pub mint: Pubkey,
+
+    /// Custodian's associated token account for this mint, which must be encoded as the mint
+    /// recipient in CCTP messages sent to this program.
+    pub mint_recipient: Pubkey,
+
+    /// Token account for this mint that collects protocol fees, which replaces the custodian's
+    /// fee recipient token account for orders of this mint.
+    pub fee_recipient_token: Pubkey,
+
+    /// Whether new auctions and order responses can use this mint.
+    pub enabled: bool,
+
+    /// Auction config ID used for auctions of this mint without a route override. If this value is
+    /// `None`, the global auction config is used.
+    pub config_id: Option<u32>,
+}
+
+impl CctpMint {
+    pub const SEED_PREFIX: &'static [u8] = b"cctp-mint";
+
+    /// Custodian's associated token account for the given mint, which is the CCTP mint recipient
+    /// for this mint.
+    pub fn mint_recipient_address(mint: &Pubkey) -> Pubkey {
+        if mint == &common::USDC_MINT {
+            crate::CCTP_MINT_RECIPIENT
+        } else {
+            let custodian =
+                Pubkey::create_program_address(Custodian::SIGNER_SEEDS, &crate::id()).unwrap();
+            anchor_spl::associated_token::get_associated_token_address(&custodian, mint)
+        }
+    }
+
+    /// Require that the mint is either USDC or a registered and enabled CCTP mint.
+    ///
+    /// NOTE: The account passed into this method must be the CCTP mint PDA for the mint.
+    pub fn require_supported(acc_info: &AccountInfo, mint: &Pubkey) -> Result<bool> {
+        if mint != &common::USDC_MINT {
+            require!(
+                acc_info.owner == &crate::id(),
+                MatchingEngineError::CctpMintNotSupported
+            );
+
+            let data = acc_info.try_borrow_data()?;
+            let cctp_mint = Self::try_deserialize(&mut &data[..])?;
+            require!(cctp_mint.enabled, MatchingEngineError::CctpMintNotSupported);
+        }
+
+        Ok(true)
+    }
+
+    /// Determine the auction config ID for an auction given an account which may be an existing
+    /// CCTP mint. If this account does not exist or has no auction config override, the global
+    /// auction config ID is returned.
+    ///
+    /// NOTE: The account passed into this method must be the CCTP mint PDA for the auction's mint.
+    pub fn config_id_or(acc_info: &AccountInfo, global_config_id: u32) -> Result<u32> {
+        if acc_info.owner != &crate::id() {
+            Ok(global_config_id)
+        } else {
+            let data = acc_info.try_borrow_data()?;
+            let cctp_mint = Self::try_deserialize(&mut &data[..])?;
+            Ok(cctp_mint.config_id.unwrap_or_else(|| global_config_id))
+        }
+    }
+
+    /// Determine the fee recipient token account for an order given an account which may be an
+    /// existing CCTP mint. If this account does not exist, the custodian's fee recipient token
+    /// account (for USDC) is returned.
+    ///
+    /// NOTE: The account passed into this method must be the CCTP mint PDA for the order's mint.
+    pub fn fee_recipient_token_or(
+        acc_info: &AccountInfo,
+        custodian_fee_recipient_token: Pubkey,
+    ) -> Result<Pubkey> {
+        if acc_info.owner != &crate::id() {
+            Ok(custodian_fee_recipient_token)
+        } else {
+            let data = acc_info.try_borrow_data()?;
+            let cctp_mint = Self::try_deserialize(&mut &data[..])?;
+            Ok(cctp_mint.fee_recipient_token)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mint_recipient_address() {
+        assert_eq!(
+            CctpMint::mint_recipient_address(&common::USDC_MINT),
+            crate::CCTP_MINT_RECIPIENT,
+            "usdc mint recipient mismatch"
+        );
+
+        let mint = Pubkey::new_unique();
+        let custodian =
+            Pubkey::create_program_address(Custodian::SIGNER_SEEDS, &crate::id()).unwrap();
+        assert_eq!(
+            CctpMint::mint_recipient_address(&mint),
+            anchor_spl::associated_token::get_associated_token_address(&custodian, &mint),
+            "custody ata mismatch"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use common::admin::{self, CouncilApprovals};
+
+/// Owner-only action that the council can perform once enough of its members have approved it.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CouncilAction {
+    SetPause { paused: bool },
+    SubmitOwnershipTransferRequest { new_owner: Pubkey },
+    UpdateCouncil { members: Vec<Pubkey>, threshold: u8 },
+}
+
+impl CouncilAction {
+    /// Digest that council members approve (see [admin::council_action_digest]).
+    pub fn digest(&self) -> Result<[u8; 32]> {
+        admin::council_action_digest(self)
+    }
+}
+
+/// Approvals collected from council members for a council action. This account is closed when the
+/// action is executed.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CouncilApproval {
+    pub bump: u8,
+
+    /// Digest of the approved [CouncilAction].
+    pub action_digest: [u8; 32],
+
+    pub approvals: CouncilApprovals,
+}
+
+impl CouncilApproval {
+    pub const SEED_PREFIX: &'static [u8] = b"council-approval";
+}
+
+impl admin::CouncilApproval for CouncilApproval {
+    fn action_digest(&self) -> &[u8; 32] {
+        &self.action_digest
+    }
+
+    fn approvals(&self) -> &CouncilApprovals {
+        &self.approvals
+    }
+
+    fn approvals_mut(&mut self) -> &mut CouncilApprovals {
+        &mut self.approvals
+    }
+}
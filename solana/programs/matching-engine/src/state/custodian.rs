@@ -1,9 +1,29 @@
+use crate::state::EnactDelays;
 use anchor_lang::prelude::*;
-use common::admin;
+use common::{
+    admin,
+    migration::{self, MigrationProgress, UpgradeFn, VersionHeader, Versioned},
+};
+
+/// Versions of the versioned account types (other than the custodian) that accounts are migrated to,
+/// which are recorded when a migration starts.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct MigratedVersions {
+    pub router_endpoint: u8,
+    pub auction_config: u8,
+    pub auction: u8,
+    pub prepared_order_response: u8,
+    pub proposal: u8,
+    pub fast_fill: u8,
+}
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Custodian {
+    pub header: VersionHeader,
+
     /// Program's owner.
     pub owner: Pubkey,
     pub pending_owner: Option<Pubkey>,
@@ -18,9 +38,38 @@ pub struct Custodian {
     // Recipient of `SlowOrderResponse` relay fees.
     pub fee_recipient_token: Pubkey,
 
+    /// Auction config ID used for auctions without a route override.
     pub auction_config_id: u32,
 
     pub next_proposal_id: u64,
+
+    /// Latest auction config ID, which may belong to a route override. New auction configs are
+    /// assigned the next ID.
+    pub latest_auction_config_id: u32,
+
+    /// Number of slots that must pass before each type of proposal can be enacted.
+    pub enact_delays: EnactDelays,
+
+    /// Optional key that can veto proposals before their enact delay has passed.
+    pub guardian: Option<Pubkey>,
+
+    /// M-of-N council that can approve owner-only actions in place of the owner.
+    pub council: admin::CouncilInfo,
+
+    /// Keys that were granted admin roles in addition to the owner and owner assistant.
+    pub roles: admin::Roles,
+
+    /// Progress of the account migration required by the latest program upgrade.
+    pub migration: MigrationProgress,
+
+    /// Versions that each versioned account type is migrated to.
+    pub migrated_versions: MigratedVersions,
+
+    /// Number of router endpoints, which is used to count the endpoints that must be migrated.
+    ///
+    /// NOTE: Router endpoints registered before the custodian was versioned are only counted once
+    /// they are migrated.
+    pub num_router_endpoints: u32,
 }
 
 impl Custodian {
@@ -29,6 +78,72 @@ impl Custodian {
     pub const SIGNER_SEEDS: &'static [&'static [u8]] = &[Self::SEED_PREFIX, &[Self::BUMP]];
 }
 
+impl Custodian {
+    /// Length of the custodian's data (including its discriminator) before it was versioned.
+    const LEGACY_LEN: usize = 182;
+}
+
+impl Versioned for Custodian {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    fn is_legacy(_key: &Pubkey, data: &[u8]) -> bool {
+        data.len() == Self::LEGACY_LEN
+    }
+}
+
+/// Custodian layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyCustodian {
+    owner: Pubkey,
+    pending_owner: Option<Pubkey>,
+    paused: bool,
+    paused_set_by: Pubkey,
+    owner_assistant: Pubkey,
+    fee_recipient_token: Pubkey,
+    auction_config_id: u32,
+    next_proposal_id: u64,
+}
+
+/// Version 1 adds the version header, route auction configs, configurable enact delays, the
+/// guardian, the council, admin roles and the account migration's progress.
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyCustodian {
+        owner,
+        pending_owner,
+        paused,
+        paused_set_by,
+        owner_assistant,
+        fee_recipient_token,
+        auction_config_id,
+        next_proposal_id,
+    } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    migration::serialize_upgraded(
+        &Custodian {
+            header: VersionHeader { version: 1 },
+            owner,
+            pending_owner,
+            paused,
+            paused_set_by,
+            owner_assistant,
+            fee_recipient_token,
+            auction_config_id,
+            next_proposal_id,
+            latest_auction_config_id: auction_config_id,
+            enact_delays: Default::default(),
+            guardian: None,
+            council: Default::default(),
+            roles: Default::default(),
+            migration: Default::default(),
+            migrated_versions: Default::default(),
+            num_router_endpoints: 0,
+        },
+        Custodian::INIT_SPACE,
+    )
+}
+
 impl admin::Ownable for Custodian {
     fn owner(&self) -> &Pubkey {
         &self.owner
@@ -59,11 +174,32 @@ impl admin::OwnerAssistant for Custodian {
     }
 }
 
+impl admin::RoleBased for Custodian {
+    fn roles(&self) -> &admin::Roles {
+        &self.roles
+    }
+
+    fn roles_mut(&mut self) -> &mut admin::Roles {
+        &mut self.roles
+    }
+}
+
+impl admin::Council for Custodian {
+    fn council(&self) -> &admin::CouncilInfo {
+        &self.council
+    }
+
+    fn council_mut(&mut self) -> &mut admin::CouncilInfo {
+        &mut self.council
+    }
+}
+
 #[cfg(test)]
 mod test {
     use solana_program::pubkey::Pubkey;
 
     use super::*;
+    use anchor_lang::Discriminator;
 
     #[test]
     fn test_bump() {
@@ -76,4 +212,58 @@ mod test {
             "custodian mismatch",
         );
     }
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let owner = Pubkey::new_unique();
+        let legacy = LegacyCustodian {
+            owner,
+            pending_owner: None,
+            paused: true,
+            paused_set_by: owner,
+            owner_assistant: Pubkey::new_unique(),
+            fee_recipient_token: Pubkey::new_unique(),
+            auction_config_id: 2,
+            next_proposal_id: 3,
+        };
+
+        // Legacy custodians were allocated with enough space for a pending owner.
+        let mut legacy_data = Custodian::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+        legacy_data.resize(Custodian::LEGACY_LEN, 0);
+
+        let key = Pubkey::new_unique();
+        assert_eq!(
+            migration::account_version::<Custodian>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        let upgraded = upgrade_v0(&legacy_data[8..]).unwrap();
+        assert_eq!(upgraded.len(), Custodian::INIT_SPACE);
+
+        let mut upgraded_data = Custodian::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgraded);
+        assert_ne!(upgraded_data.len(), Custodian::LEGACY_LEN);
+        assert_eq!(
+            migration::account_version::<Custodian>(&key, &upgraded_data).unwrap(),
+            Custodian::VERSION
+        );
+
+        let custodian = Custodian::try_deserialize(&mut &upgraded_data[..]).unwrap();
+        assert_eq!(custodian.header.version, Custodian::VERSION);
+        assert_eq!(custodian.owner, legacy.owner);
+        assert_eq!(custodian.pending_owner, legacy.pending_owner);
+        assert_eq!(custodian.paused, legacy.paused);
+        assert_eq!(custodian.paused_set_by, legacy.paused_set_by);
+        assert_eq!(custodian.owner_assistant, legacy.owner_assistant);
+        assert_eq!(custodian.fee_recipient_token, legacy.fee_recipient_token);
+        assert_eq!(custodian.auction_config_id, legacy.auction_config_id);
+        assert_eq!(custodian.latest_auction_config_id, legacy.auction_config_id);
+        assert_eq!(custodian.next_proposal_id, legacy.next_proposal_id);
+        assert_eq!(custodian.guardian, None);
+        assert_eq!(custodian.migration, Default::default());
+        assert!(!custodian.migration.is_complete());
+        assert_eq!(custodian.migrated_versions, Default::default());
+        assert_eq!(custodian.num_router_endpoints, 0);
+    }
 }
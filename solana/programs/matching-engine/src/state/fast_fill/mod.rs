@@ -5,7 +5,10 @@ mod sequencer;
 pub use sequencer::*;
 
 use anchor_lang::prelude::*;
-use common::messages::Fill;
+use common::{
+    messages::Fill,
+    migration::{self, UpgradeFn, VersionHeader, Versioned},
+};
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
 pub struct FastFillInfo {
@@ -21,6 +24,10 @@ pub struct FastFillInfo {
     /// Timestamp at the time a fill was issued. When the fast fill is created, it is set using the
     /// current [Clock] unix timestamp.
     pub timestamp: i64,
+
+    /// Mint of the filled amount, which is either the auction's mint or the destination asset
+    /// requested by the order.
+    pub mint: Pubkey,
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
@@ -42,6 +49,8 @@ pub struct FastFillSeeds {
 #[account]
 #[derive(Debug)]
 pub struct FastFill {
+    pub header: VersionHeader,
+
     pub seeds: FastFillSeeds,
 
     /// Whether the [FastFill] has been redeemed via the local Token Router.
@@ -56,6 +65,7 @@ impl FastFill {
 
     pub(crate) fn compute_size(redeemer_message_len: usize) -> usize {
         const FIXED: usize = 8 // DISCRIMINATOR
+            + VersionHeader::INIT_SPACE
             + FastFillSeeds::INIT_SPACE
             + 1 // redeemed
             + FastFillInfo::INIT_SPACE
@@ -65,7 +75,14 @@ impl FastFill {
         redeemer_message_len.saturating_add(FIXED)
     }
 
-    pub fn new(fill: Fill, sequence: u64, bump: u8, prepared_by: Pubkey, amount: u64) -> Self {
+    pub fn new(
+        fill: Fill,
+        sequence: u64,
+        bump: u8,
+        prepared_by: Pubkey,
+        amount: u64,
+        mint: Pubkey,
+    ) -> Self {
         let Fill {
             source_chain,
             order_sender,
@@ -73,6 +90,9 @@ impl FastFill {
             redeemer_message,
         } = fill;
         Self {
+            header: VersionHeader {
+                version: Self::VERSION,
+            },
             seeds: FastFillSeeds {
                 source_chain,
                 order_sender,
@@ -85,8 +105,176 @@ impl FastFill {
                 amount,
                 redeemer: Pubkey::from(redeemer),
                 timestamp: Clock::get().unwrap().unix_timestamp,
+                mint,
             },
             redeemer_message: redeemer_message.into(),
         }
     }
 }
+
+impl Versioned for FastFill {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    /// Fast fills have variable length, so the legacy layout is identified by its seeds (found
+    /// right after the discriminator) deriving the account's address instead.
+    fn is_legacy(key: &Pubkey, data: &[u8]) -> bool {
+        match (
+            data.get(8..10),
+            data.get(10..42),
+            data.get(42..50),
+            data.get(50),
+        ) {
+            (Some(source_chain), Some(order_sender), Some(sequence), Some(bump)) => {
+                // The seeds are serialized little-endian, but the address is derived from their
+                // big-endian encodings.
+                let mut source_chain = source_chain.to_vec();
+                source_chain.reverse();
+                let mut sequence = sequence.to_vec();
+                sequence.reverse();
+
+                Pubkey::create_program_address(
+                    &[
+                        Self::SEED_PREFIX,
+                        &source_chain,
+                        order_sender,
+                        &sequence,
+                        &[*bump],
+                    ],
+                    &crate::id(),
+                )
+                .ok()
+                    == Some(*key)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Fast fill info layout before the fast fill was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyFastFillInfo {
+    prepared_by: Pubkey,
+    amount: u64,
+    redeemer: Pubkey,
+    timestamp: i64,
+}
+
+/// Fast fill layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyFastFill {
+    seeds: FastFillSeeds,
+    redeemed: bool,
+    info: LegacyFastFillInfo,
+    redeemer_message: Vec<u8>,
+}
+
+/// Version 1 adds the version header and the filled amount's mint (which was always USDC).
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyFastFill {
+        seeds,
+        redeemed,
+        info,
+        redeemer_message,
+    } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    let LegacyFastFillInfo {
+        prepared_by,
+        amount,
+        redeemer,
+        timestamp,
+    } = info;
+
+    let space = FastFill::compute_size(redeemer_message.len()).saturating_sub(8);
+
+    migration::serialize_upgraded(
+        &FastFill {
+            header: VersionHeader { version: 1 },
+            seeds,
+            redeemed,
+            info: FastFillInfo {
+                prepared_by,
+                amount,
+                redeemer,
+                timestamp,
+                mint: common::USDC_MINT,
+            },
+            redeemer_message,
+        },
+        space,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let source_chain = 2u16;
+        let order_sender = [3; 32];
+        let sequence = 4u64;
+        let (key, bump) = Pubkey::find_program_address(
+            &[
+                FastFill::SEED_PREFIX,
+                &source_chain.to_be_bytes(),
+                &order_sender,
+                &sequence.to_be_bytes(),
+            ],
+            &crate::id(),
+        );
+
+        let legacy = LegacyFastFill {
+            seeds: FastFillSeeds {
+                source_chain,
+                order_sender,
+                sequence,
+                bump,
+            },
+            redeemed: true,
+            info: LegacyFastFillInfo {
+                prepared_by: Pubkey::new_unique(),
+                amount: 5,
+                redeemer: Pubkey::new_unique(),
+                timestamp: 6,
+            },
+            redeemer_message: b"All your base are belong to us.".to_vec(),
+        };
+
+        let mut legacy_data = FastFill::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+        assert_eq!(
+            migration::account_version::<FastFill>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        // The seeds must derive the account's address.
+        assert!(!FastFill::is_legacy(&Pubkey::new_unique(), &legacy_data));
+
+        let mut upgraded_data = FastFill::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgrade_v0(&legacy_data[8..]).unwrap());
+        assert_eq!(
+            upgraded_data.len(),
+            FastFill::compute_size(legacy.redeemer_message.len())
+        );
+        assert_eq!(
+            migration::account_version::<FastFill>(&key, &upgraded_data).unwrap(),
+            FastFill::VERSION
+        );
+
+        let fast_fill = FastFill::try_deserialize(&mut &upgraded_data[..]).unwrap();
+        assert_eq!(fast_fill.seeds.source_chain, source_chain);
+        assert_eq!(fast_fill.seeds.order_sender, order_sender);
+        assert_eq!(fast_fill.seeds.sequence, sequence);
+        assert_eq!(fast_fill.seeds.bump, bump);
+        assert!(fast_fill.redeemed);
+        assert_eq!(fast_fill.info.prepared_by, legacy.info.prepared_by);
+        assert_eq!(fast_fill.info.amount, legacy.info.amount);
+        assert_eq!(fast_fill.info.redeemer, legacy.info.redeemer);
+        assert_eq!(fast_fill.info.timestamp, legacy.info.timestamp);
+        assert_eq!(fast_fill.info.mint, common::USDC_MINT);
+        assert_eq!(fast_fill.redeemer_message, legacy.redeemer_message);
+    }
+}
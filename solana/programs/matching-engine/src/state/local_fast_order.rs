@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Deposit for a fast market order placed by the Token Router on this network. Because the order
+/// never leaves Solana, this deposit stands in for the CCTP transfer and finalized VAA that would
+/// otherwise be required to prepare the order response.
+///
+/// NOTE: Only the local Token Router can create this account, which it does when it publishes the
+/// fast market order.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct LocalFastOrder {
+    pub bump: u8,
+    pub custody_token_bump: u8,
+
+    /// Who paid the rent for this account and its custody token account.
+    pub prepared_by: Pubkey,
+
+    /// Wormhole sequence of the fast market order message published by the local Token Router.
+    pub fast_vaa_sequence: u64,
+
+    /// Amount deposited into the custody token account, which is the fast market order's amount in.
+    pub amount_in: u64,
+
+    /// Fee paid to whomever prepares the order response.
+    pub base_fee: u64,
+}
+
+impl LocalFastOrder {
+    pub const SEED_PREFIX: &'static [u8] = b"local-fast-order";
+}
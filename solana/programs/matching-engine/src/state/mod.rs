@@ -7,17 +7,44 @@ pub use auction_config::*;
 mod auction_history;
 pub use auction_history::*;
 
+mod auction_tranche;
+pub use auction_tranche::*;
+
+mod cctp_mint;
+pub use cctp_mint::*;
+
+mod council_approval;
+pub use council_approval::*;
+
 mod custodian;
 pub use custodian::*;
 
 mod fast_fill;
 pub use fast_fill::*;
 
+mod local_fast_order;
+pub use local_fast_order::*;
+
+mod offer_commitment;
+pub use offer_commitment::*;
+
 mod prepared_order_response;
 pub use prepared_order_response::*;
 
 mod proposal;
 pub use proposal::*;
 
+mod rate_limit;
+pub use rate_limit::*;
+
+mod registered_solver;
+pub use registered_solver::*;
+
+mod route_auction_config;
+pub use route_auction_config::*;
+
 pub(crate) mod router_endpoint;
 pub use router_endpoint::*;
+
+mod solver_vault;
+pub use solver_vault::*;
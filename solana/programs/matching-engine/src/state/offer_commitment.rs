@@ -0,0 +1,40 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+/// Sealed offer committed to a sealed-bid auction. The committed offer's total deposit is held in
+/// this commitment's custody token account until the offer is revealed (or forfeited if it is never
+/// revealed).
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct OfferCommitment {
+    pub bump: u8,
+    pub custody_token_bump: u8,
+
+    /// Auction this offer was committed to.
+    pub auction: Pubkey,
+
+    /// Token account that funded the deposit. If this offer is revealed as the best offer, this
+    /// token account becomes the auction's best offer token.
+    pub offer_token: Pubkey,
+
+    /// The payer when committing the offer, who will receive the rent for this account and its
+    /// custody token account.
+    pub prepared_by: Pubkey,
+
+    /// Hash of the offer token, offer price and salt (see [OfferCommitment::compute]).
+    pub commitment: [u8; 32],
+}
+
+impl OfferCommitment {
+    pub const SEED_PREFIX: &'static [u8] = b"offer-commitment";
+
+    /// Compute keccak256(offer_token, offer_price, salt). Binding the offer token to the commitment
+    /// prevents another participant from copying this commitment.
+    pub fn compute(offer_token: &Pubkey, offer_price: u64, salt: &[u8; 32]) -> [u8; 32] {
+        keccak::hashv(&[offer_token.as_ref(), &offer_price.to_be_bytes(), salt]).0
+    }
+
+    /// Determine whether the offer price and salt reconcile with this commitment.
+    pub fn matches(&self, offer_price: u64, salt: &[u8; 32]) -> bool {
+        Self::compute(&self.offer_token, offer_price, salt) == self.commitment
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use common::migration::{self, UpgradeFn, VersionHeader, Versioned};
 
-use super::{Auction, EndpointInfo};
+use super::{Auction, EndpointInfo, MessageProtocol};
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct PreparedOrderResponseSeeds {
@@ -20,13 +21,30 @@ pub struct PreparedOrderResponseInfo {
     pub sender: [u8; 32],
     pub redeemer: [u8; 32],
     pub amount_in: u64,
+
+    /// Minimum amount the user must receive. If the amount after fees falls below this value, the
+    /// funds are refunded to the refund address on the source chain instead.
+    pub min_amount_out: u64,
+
+    /// Universal address on the source chain where funds are refunded.
+    pub refund_address: [u8; 32],
+
+    /// Circle-supported mint of the finalized CCTP deposit.
+    pub mint: Pubkey,
+
+    /// Destination asset requested by the order (if any), which can only be delivered by an
+    /// auction's executor. Without an auction, these orders are refunded instead.
+    pub destination_asset: Option<[u8; 32]>,
 }
 
 #[account]
 #[derive(Debug)]
 pub struct PreparedOrderResponse {
+    pub header: VersionHeader,
+
     pub seeds: PreparedOrderResponseSeeds,
     pub info: PreparedOrderResponseInfo,
+    pub from_endpoint: EndpointInfo,
     pub to_endpoint: EndpointInfo,
     pub redeemer_message: Vec<u8>,
 }
@@ -48,6 +66,9 @@ impl PreparedOrderResponse {
     /// trapped funds (which would require an upgrade to fix).
     pub(crate) fn new_auction_placeholder(&self, bump: u8) -> Auction {
         Auction {
+            header: VersionHeader {
+                version: Auction::VERSION,
+            },
             bump,
             vaa_hash: self.seeds.fast_vaa_hash,
             vaa_timestamp: self.fast_vaa_timestamp,
@@ -55,17 +76,207 @@ impl PreparedOrderResponse {
             status: Default::default(),
             prepared_by: self.prepared_by,
             info: Default::default(),
+            mint: self.mint,
         }
     }
 
     pub(crate) fn compute_size(redeemer_message_len: usize) -> usize {
         const FIXED: usize = 8 // DISCRIMINATOR
+            + VersionHeader::INIT_SPACE
             + PreparedOrderResponseSeeds::INIT_SPACE
             + PreparedOrderResponseInfo::INIT_SPACE
-            + EndpointInfo::INIT_SPACE
+            + EndpointInfo::INIT_SPACE // from_endpoint
+            + EndpointInfo::INIT_SPACE // to_endpoint
             + 4 // redeemer_message_len
         ;
 
         redeemer_message_len.saturating_add(FIXED)
     }
 }
+
+impl Versioned for PreparedOrderResponse {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    /// Prepared order responses have variable length, so the legacy layout is identified by its
+    /// seeds (found right after the discriminator) deriving the account's address instead.
+    fn is_legacy(key: &Pubkey, data: &[u8]) -> bool {
+        match (data.get(8..40), data.get(40)) {
+            (Some(fast_vaa_hash), Some(bump)) => {
+                Pubkey::create_program_address(
+                    &[Self::SEED_PREFIX, fast_vaa_hash, &[*bump]],
+                    &crate::id(),
+                )
+                .ok()
+                    == Some(*key)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Prepared order response info layout before the prepared order response was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyPreparedOrderResponseInfo {
+    prepared_by: Pubkey,
+    base_fee_token: Pubkey,
+    fast_vaa_timestamp: u32,
+    source_chain: u16,
+    base_fee: u64,
+    init_auction_fee: u64,
+    sender: [u8; 32],
+    redeemer: [u8; 32],
+    amount_in: u64,
+}
+
+/// Prepared order response layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyPreparedOrderResponse {
+    seeds: PreparedOrderResponseSeeds,
+    info: LegacyPreparedOrderResponseInfo,
+    to_endpoint: EndpointInfo,
+    redeemer_message: Vec<u8>,
+}
+
+/// Version 1 adds the version header, the order's minimum amount out, refund address and
+/// destination asset, the deposit's mint (which was always USDC) and the source endpoint.
+///
+/// NOTE: Legacy orders have no minimum amount out, so they are never refunded to the source
+/// endpoint. Only its chain is known.
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyPreparedOrderResponse {
+        seeds,
+        info,
+        to_endpoint,
+        redeemer_message,
+    } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    let LegacyPreparedOrderResponseInfo {
+        prepared_by,
+        base_fee_token,
+        fast_vaa_timestamp,
+        source_chain,
+        base_fee,
+        init_auction_fee,
+        sender,
+        redeemer,
+        amount_in,
+    } = info;
+
+    let space = PreparedOrderResponse::compute_size(redeemer_message.len()).saturating_sub(8);
+
+    migration::serialize_upgraded(
+        &PreparedOrderResponse {
+            header: VersionHeader { version: 1 },
+            seeds,
+            info: PreparedOrderResponseInfo {
+                prepared_by,
+                base_fee_token,
+                fast_vaa_timestamp,
+                source_chain,
+                base_fee,
+                init_auction_fee,
+                sender,
+                redeemer,
+                amount_in,
+                min_amount_out: 0,
+                refund_address: [0; 32],
+                mint: common::USDC_MINT,
+                destination_asset: None,
+            },
+            from_endpoint: EndpointInfo {
+                chain: source_chain,
+                address: [0; 32],
+                mint_recipient: [0; 32],
+                protocol: MessageProtocol::None,
+            },
+            to_endpoint,
+            redeemer_message,
+        },
+        space,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let fast_vaa_hash = [1; 32];
+        let (key, bump) = Pubkey::find_program_address(
+            &[PreparedOrderResponse::SEED_PREFIX, &fast_vaa_hash],
+            &crate::id(),
+        );
+
+        let legacy = LegacyPreparedOrderResponse {
+            seeds: PreparedOrderResponseSeeds {
+                fast_vaa_hash,
+                bump,
+            },
+            info: LegacyPreparedOrderResponseInfo {
+                prepared_by: Pubkey::new_unique(),
+                base_fee_token: Pubkey::new_unique(),
+                fast_vaa_timestamp: 1,
+                source_chain: 2,
+                base_fee: 3,
+                init_auction_fee: 4,
+                sender: [5; 32],
+                redeemer: [6; 32],
+                amount_in: 7,
+            },
+            to_endpoint: EndpointInfo {
+                chain: 8,
+                address: [9; 32],
+                mint_recipient: [10; 32],
+                protocol: MessageProtocol::Cctp { domain: 11 },
+            },
+            redeemer_message: b"All your base are belong to us.".to_vec(),
+        };
+
+        let mut legacy_data = PreparedOrderResponse::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+        assert_eq!(
+            migration::account_version::<PreparedOrderResponse>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        // The seeds must derive the account's address.
+        assert!(!PreparedOrderResponse::is_legacy(
+            &Pubkey::new_unique(),
+            &legacy_data
+        ));
+
+        let mut upgraded_data = PreparedOrderResponse::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgrade_v0(&legacy_data[8..]).unwrap());
+        assert_eq!(
+            upgraded_data.len(),
+            PreparedOrderResponse::compute_size(legacy.redeemer_message.len())
+        );
+        assert_eq!(
+            migration::account_version::<PreparedOrderResponse>(&key, &upgraded_data).unwrap(),
+            PreparedOrderResponse::VERSION
+        );
+
+        let response = PreparedOrderResponse::try_deserialize(&mut &upgraded_data[..]).unwrap();
+        assert_eq!(response.seeds.fast_vaa_hash, fast_vaa_hash);
+        assert_eq!(response.seeds.bump, bump);
+        assert_eq!(response.prepared_by, legacy.info.prepared_by);
+        assert_eq!(response.base_fee_token, legacy.info.base_fee_token);
+        assert_eq!(response.fast_vaa_timestamp, legacy.info.fast_vaa_timestamp);
+        assert_eq!(response.source_chain, legacy.info.source_chain);
+        assert_eq!(response.base_fee, legacy.info.base_fee);
+        assert_eq!(response.init_auction_fee, legacy.info.init_auction_fee);
+        assert_eq!(response.sender, legacy.info.sender);
+        assert_eq!(response.redeemer, legacy.info.redeemer);
+        assert_eq!(response.amount_in, legacy.info.amount_in);
+        assert_eq!(response.min_amount_out, 0);
+        assert_eq!(response.mint, common::USDC_MINT);
+        assert_eq!(response.destination_asset, None);
+        assert_eq!(response.from_endpoint.chain, legacy.info.source_chain);
+        assert_eq!(response.to_endpoint.chain, legacy.to_endpoint.chain);
+        assert_eq!(response.redeemer_message, legacy.redeemer_message);
+    }
+}
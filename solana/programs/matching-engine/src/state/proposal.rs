@@ -1,6 +1,51 @@
 use anchor_lang::prelude::*;
+use common::{
+    migration::{self, UpgradeFn, VersionHeader, Versioned},
+    wormhole_cctp_solana::wormhole::SOLANA_CHAIN,
+};
 
-use crate::AuctionParameters;
+use crate::{state::LegacyAuctionParameters, AddCctpRouterEndpointArgs, AuctionParameters};
+
+/// Number of slots that must pass before a proposal can be enacted, which is configured separately
+/// for each type of proposal action. A delay of zero means the default delay of one epoch is used.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq, Copy, Default,
+)]
+pub struct EnactDelays {
+    /// Delay for global and route auction parameters proposals.
+    pub auction_parameters: u64,
+
+    /// Delay for proposals that add, update or disable router endpoints.
+    pub router_endpoint: u64,
+
+    pub fee_recipient: u64,
+    pub owner_assistant: u64,
+    pub guardian: u64,
+
+    /// Delay for proposals that update these enact delays.
+    pub enact_delays: u64,
+}
+
+impl EnactDelays {
+    /// Configured enact delay for the proposal action.
+    pub fn get(&self, action: &ProposalAction) -> u64 {
+        match action {
+            ProposalAction::None
+            | ProposalAction::UpdateAuctionParameters { .. }
+            | ProposalAction::UpdateRouteAuctionParameters { .. }
+            | ProposalAction::RemoveRouteAuctionParameters { .. } => self.auction_parameters,
+            ProposalAction::AddCctpRouterEndpoint { .. }
+            | ProposalAction::UpdateCctpRouterEndpoint { .. }
+            | ProposalAction::AddLocalRouterEndpoint { .. }
+            | ProposalAction::UpdateLocalRouterEndpoint { .. }
+            | ProposalAction::DisableRouterEndpoint { .. } => self.router_endpoint,
+            ProposalAction::UpdateFeeRecipient { .. } => self.fee_recipient,
+            ProposalAction::UpdateOwnerAssistant { .. } => self.owner_assistant,
+            ProposalAction::UpdateEnactDelays { .. } => self.enact_delays,
+            ProposalAction::UpdateGuardian { .. } => self.guardian,
+        }
+    }
+}
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq, Copy)]
 pub enum ProposalAction {
@@ -9,11 +54,89 @@ pub enum ProposalAction {
         id: u32,
         parameters: AuctionParameters,
     },
+    UpdateRouteAuctionParameters {
+        source_chain: u16,
+        target_chain: u16,
+        id: u32,
+        parameters: AuctionParameters,
+    },
+    RemoveRouteAuctionParameters {
+        source_chain: u16,
+        target_chain: u16,
+    },
+    AddCctpRouterEndpoint {
+        args: AddCctpRouterEndpointArgs,
+    },
+    UpdateCctpRouterEndpoint {
+        args: AddCctpRouterEndpointArgs,
+    },
+    AddLocalRouterEndpoint {
+        token_router_program: Pubkey,
+    },
+    UpdateLocalRouterEndpoint {
+        token_router_program: Pubkey,
+    },
+    DisableRouterEndpoint {
+        chain: u16,
+    },
+    UpdateFeeRecipient {
+        fee_recipient_token: Pubkey,
+    },
+    UpdateOwnerAssistant {
+        owner_assistant: Pubkey,
+    },
+    UpdateEnactDelays {
+        enact_delays: EnactDelays,
+    },
+    UpdateGuardian {
+        guardian: Option<Pubkey>,
+    },
+}
+
+impl ProposalAction {
+    /// Source and target chains if this action modifies a route's auction config.
+    pub fn route(&self) -> Option<(u16, u16)> {
+        match self {
+            ProposalAction::UpdateRouteAuctionParameters {
+                source_chain,
+                target_chain,
+                ..
+            }
+            | ProposalAction::RemoveRouteAuctionParameters {
+                source_chain,
+                target_chain,
+            } => Some((*source_chain, *target_chain)),
+            _ => None,
+        }
+    }
+
+    /// Chain of the router endpoint if this action adds, updates or disables a router endpoint.
+    pub fn router_endpoint_chain(&self) -> Option<u16> {
+        match self {
+            ProposalAction::AddCctpRouterEndpoint { args }
+            | ProposalAction::UpdateCctpRouterEndpoint { args } => Some(args.chain),
+            ProposalAction::AddLocalRouterEndpoint { .. }
+            | ProposalAction::UpdateLocalRouterEndpoint { .. } => Some(SOLANA_CHAIN),
+            ProposalAction::DisableRouterEndpoint { chain } => Some(*chain),
+            _ => None,
+        }
+    }
+
+    /// CCTP domain of the router endpoint if this action adds or updates a CCTP router endpoint.
+    pub fn cctp_domain(&self) -> Option<u32> {
+        match self {
+            ProposalAction::AddCctpRouterEndpoint { args }
+            | ProposalAction::UpdateCctpRouterEndpoint { args } => Some(args.cctp_domain),
+            _ => None,
+        }
+    }
 }
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Proposal {
+    pub header: VersionHeader,
+
     pub id: u64,
     pub bump: u8,
 
@@ -28,4 +151,149 @@ pub struct Proposal {
 
 impl Proposal {
     pub const SEED_PREFIX: &'static [u8] = b"proposal";
+
+    /// Length of a proposal's data (including its discriminator) before it was versioned.
+    const LEGACY_LEN: usize = 141;
+}
+
+impl Versioned for Proposal {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    fn is_legacy(_key: &Pubkey, data: &[u8]) -> bool {
+        data.len() == Self::LEGACY_LEN
+    }
+}
+
+/// Proposal action before it was versioned, when auction parameters were the only action.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+enum LegacyProposalAction {
+    None,
+    UpdateAuctionParameters {
+        id: u32,
+        parameters: LegacyAuctionParameters,
+    },
+}
+
+/// Proposal layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyProposal {
+    id: u64,
+    bump: u8,
+    action: LegacyProposalAction,
+    by: Pubkey,
+    owner: Pubkey,
+    slot_proposed_at: u64,
+    slot_enact_delay: u64,
+    slot_enacted_at: Option<u64>,
+}
+
+/// Version 1 adds the version header and new proposal actions. Its auction parameters include the
+/// parameters for sealed-bid, extended and split-fill auctions, which are disabled for existing
+/// proposals.
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyProposal {
+        id,
+        bump,
+        action,
+        by,
+        owner,
+        slot_proposed_at,
+        slot_enact_delay,
+        slot_enacted_at,
+    } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    let action = match action {
+        LegacyProposalAction::None => ProposalAction::None,
+        LegacyProposalAction::UpdateAuctionParameters { id, parameters } => {
+            ProposalAction::UpdateAuctionParameters {
+                id,
+                parameters: parameters.into(),
+            }
+        }
+    };
+
+    migration::serialize_upgraded(
+        &Proposal {
+            header: VersionHeader { version: 1 },
+            id,
+            bump,
+            action,
+            by,
+            owner,
+            slot_proposed_at,
+            slot_enact_delay,
+            slot_enacted_at,
+        },
+        Proposal::INIT_SPACE,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let legacy_parameters = LegacyAuctionParameters {
+            user_penalty_reward_bps: 250_000,
+            initial_penalty_bps: 250_000,
+            duration: 2,
+            grace_period: 5,
+            penalty_period: 10,
+            min_offer_delta_bps: 20_000,
+            security_deposit_base: 4_200_000,
+            security_deposit_bps: 5_000,
+        };
+        let legacy = LegacyProposal {
+            id: 2,
+            bump: 253,
+            action: LegacyProposalAction::UpdateAuctionParameters {
+                id: 1,
+                parameters: legacy_parameters,
+            },
+            by: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            slot_proposed_at: 69,
+            slot_enact_delay: 420,
+            slot_enacted_at: Some(421),
+        };
+
+        let mut legacy_data = Proposal::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+        assert_eq!(legacy_data.len(), Proposal::LEGACY_LEN);
+
+        let key = Pubkey::new_unique();
+        assert_eq!(
+            migration::account_version::<Proposal>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        let mut upgraded_data = Proposal::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgrade_v0(&legacy_data[8..]).unwrap());
+        assert_eq!(upgraded_data.len(), 8 + Proposal::INIT_SPACE);
+        assert_eq!(
+            migration::account_version::<Proposal>(&key, &upgraded_data).unwrap(),
+            Proposal::VERSION
+        );
+
+        let proposal = Proposal::try_deserialize(&mut &upgraded_data[..]).unwrap();
+        assert_eq!(proposal.id, legacy.id);
+        assert_eq!(proposal.bump, legacy.bump);
+        assert_eq!(proposal.by, legacy.by);
+        assert_eq!(proposal.owner, legacy.owner);
+        assert_eq!(proposal.slot_proposed_at, legacy.slot_proposed_at);
+        assert_eq!(proposal.slot_enact_delay, legacy.slot_enact_delay);
+        assert_eq!(proposal.slot_enacted_at, legacy.slot_enacted_at);
+
+        assert_eq!(
+            proposal.action,
+            ProposalAction::UpdateAuctionParameters {
+                id: 1,
+                parameters: legacy_parameters.into(),
+            }
+        );
+    }
 }
@@ -0,0 +1,189 @@
+use crate::error::MatchingEngineError;
+use anchor_lang::prelude::*;
+
+/// Direction of the order flow that a rate limit applies to.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RateLimitDirection {
+    /// Fast market orders sent from the chain.
+    Inbound,
+    /// Fast market orders destined for the chain.
+    Outbound,
+}
+
+impl RateLimitDirection {
+    pub fn seed_prefix(&self) -> &'static [u8] {
+        match self {
+            RateLimitDirection::Inbound => RateLimit::INBOUND_SEED_PREFIX,
+            RateLimitDirection::Outbound => RateLimit::OUTBOUND_SEED_PREFIX,
+        }
+    }
+}
+
+/// Rolling-window cap on the notional amount of fast market orders sent from or to a chain. The
+/// capacity replenishes linearly, so the full limit becomes available again one window after it was
+/// used up.
+///
+/// NOTE: Capacity is consumed when an order enters the fast path (i.e. when an auction starts or,
+/// without an auction, when the order response is prepared) and is never given back. An order
+/// that is later refunded or settled without its auction's offer still counts against the limit
+/// until its amount replenishes, which keeps the limit a bound on how much can enter the fast path
+/// within a window.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RateLimit {
+    pub bump: u8,
+    pub direction: RateLimitDirection,
+    pub chain: u16,
+
+    /// Maximum notional amount that can flow within any window.
+    pub limit: u64,
+
+    /// Duration of the rolling window in seconds.
+    pub window_duration: u32,
+
+    /// Remaining capacity after the last order was accounted for.
+    pub capacity_at_last_tx: u64,
+
+    /// Timestamp of the last order that was accounted for.
+    pub last_tx_timestamp: i64,
+}
+
+impl RateLimit {
+    pub const INBOUND_SEED_PREFIX: &'static [u8] = b"inbound-rate-limit";
+    pub const OUTBOUND_SEED_PREFIX: &'static [u8] = b"outbound-rate-limit";
+
+    /// Capacity available at the given timestamp.
+    pub fn capacity_at(&self, timestamp: i64) -> Result<u64> {
+        let elapsed =
+            u64::try_from(timestamp.saturating_sub(self.last_tx_timestamp)).unwrap_or_default();
+
+        let replenished = u128::from(self.limit)
+            .saturating_mul(elapsed.into())
+            .checked_div(self.window_duration.into())
+            .ok_or_else(|| MatchingEngineError::ZeroRateLimitWindow)?;
+
+        Ok(
+            u64::try_from(u128::from(self.capacity_at_last_tx).saturating_add(replenished))
+                .unwrap_or(u64::MAX)
+                .min(self.limit),
+        )
+    }
+
+    /// Notional amount that has flowed within the current window as of the given timestamp.
+    pub fn usage_at(&self, timestamp: i64) -> Result<u64> {
+        self.capacity_at(timestamp)
+            .map(|capacity| self.limit.saturating_sub(capacity))
+    }
+
+    pub fn consume(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        let capacity = self.capacity_at(timestamp)?;
+        require!(amount <= capacity, MatchingEngineError::RateLimitExceeded);
+
+        self.capacity_at_last_tx = capacity.saturating_sub(amount);
+        self.last_tx_timestamp = timestamp;
+
+        Ok(())
+    }
+
+    /// Update the limit and window duration. The current capacity is adjusted by the change in the
+    /// limit so that usage within the current window still counts against the new limit.
+    pub fn set_limit(&mut self, limit: u64, window_duration: u32, timestamp: i64) -> Result<()> {
+        let capacity = self.capacity_at(timestamp)?;
+
+        self.capacity_at_last_tx = if limit >= self.limit {
+            capacity.saturating_add(limit.saturating_sub(self.limit))
+        } else {
+            capacity.saturating_sub(self.limit.saturating_sub(limit))
+        };
+        self.last_tx_timestamp = timestamp;
+        self.limit = limit;
+        self.window_duration = window_duration;
+
+        Ok(())
+    }
+
+    /// Account for an order's amount using an account which may be an existing rate limit. If this
+    /// account does not exist, the flow is not rate limited. The consumed capacity is not given
+    /// back if the order is later refunded (see [RateLimit]).
+    ///
+    /// NOTE: The account passed into this method must be the rate limit PDA for the order's source
+    /// or target chain.
+    pub fn consume_if_exists(acc_info: &AccountInfo, amount: u64) -> Result<()> {
+        if acc_info.owner != &crate::id() {
+            return Ok(());
+        }
+
+        let mut rate_limit = {
+            let data = acc_info.try_borrow_data()?;
+            Self::try_deserialize(&mut &data[..])?
+        };
+        rate_limit.consume(amount, Clock::get()?.unix_timestamp)?;
+
+        let mut data = acc_info.try_borrow_mut_data()?;
+        rate_limit.try_serialize(&mut &mut data[..])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const WINDOW: u32 = 86_400;
+
+    fn rate_limit(limit: u64) -> RateLimit {
+        RateLimit {
+            bump: Default::default(),
+            direction: RateLimitDirection::Inbound,
+            chain: Default::default(),
+            limit,
+            window_duration: WINDOW,
+            capacity_at_last_tx: limit,
+            last_tx_timestamp: Default::default(),
+        }
+    }
+
+    #[test]
+    fn consume_and_replenish() {
+        let mut rate_limit = rate_limit(1_000);
+
+        rate_limit.consume(600, 100).unwrap();
+        assert_eq!(rate_limit.capacity_at(100).unwrap(), 400);
+        assert_eq!(rate_limit.usage_at(100).unwrap(), 600);
+        assert!(rate_limit.consume(401, 100).is_err());
+
+        // Half of the window replenishes half of the limit.
+        let half_window = 100 + i64::from(WINDOW >> 1);
+        assert_eq!(rate_limit.capacity_at(half_window).unwrap(), 900);
+
+        // Capacity never exceeds the limit.
+        assert_eq!(
+            rate_limit.capacity_at(100 + i64::from(WINDOW)).unwrap(),
+            1_000
+        );
+    }
+
+    #[test]
+    fn set_limit_preserves_usage() {
+        let mut rate_limit = rate_limit(1_000);
+        rate_limit.consume(600, 0).unwrap();
+
+        rate_limit.set_limit(2_000, WINDOW, 0).unwrap();
+        assert_eq!(rate_limit.capacity_at(0).unwrap(), 1_400);
+
+        rate_limit.set_limit(500, WINDOW, 0).unwrap();
+        assert_eq!(rate_limit.capacity_at(0).unwrap(), 0);
+        assert!(rate_limit.consume(1, 0).is_err());
+    }
+
+    #[test]
+    fn zero_window_errors() {
+        let mut rate_limit = rate_limit(1_000);
+        rate_limit.window_duration = 0;
+
+        assert_eq!(
+            rate_limit.capacity_at(100).unwrap_err(),
+            MatchingEngineError::ZeroRateLimitWindow.into()
+        );
+        assert!(rate_limit.consume(1, 100).is_err());
+    }
+}
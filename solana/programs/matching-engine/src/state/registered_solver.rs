@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Registration for a solver permitted to place offers on auctions for routes with a solver
+/// allowlist (see [RouterEndpoint::solver_allowlist](crate::state::RouterEndpoint)).
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RegisteredSolver {
+    pub bump: u8,
+
+    /// Solver authority, which is the owner of the offer token (or the solver vault's owner if the
+    /// offer token is a solver vault's custody token account).
+    pub authority: Pubkey,
+
+    /// If true, this solver cannot place offers on auctions for routes with a solver allowlist.
+    pub suspended: bool,
+}
+
+impl RegisteredSolver {
+    pub const SEED_PREFIX: &'static [u8] = b"registered-solver";
+}
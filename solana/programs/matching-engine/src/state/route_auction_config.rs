@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// Account pointing to an [AuctionConfig](super::AuctionConfig) that overrides the global auction
+/// config for auctions of fast market orders sent from the source chain to the target chain.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RouteAuctionConfig {
+    pub bump: u8,
+
+    /// Chain where the fast market order was created.
+    pub source_chain: u16,
+
+    /// Chain where the fast market order is destined.
+    pub target_chain: u16,
+
+    /// Auction config ID used for auctions on this route.
+    pub config_id: u32,
+}
+
+impl RouteAuctionConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"route-auction-config";
+
+    /// Determine the auction config ID for an auction given an account which may be an existing
+    /// route auction config. If this account does not exist, the global auction config ID is
+    /// returned.
+    ///
+    /// NOTE: The account passed into this method must be the route auction config PDA for the
+    /// auction's source and target chains.
+    pub fn config_id_or(acc_info: &AccountInfo, global_config_id: u32) -> Result<u32> {
+        if acc_info.owner != &crate::id() {
+            Ok(global_config_id)
+        } else {
+            let data = acc_info.try_borrow_data()?;
+            let route_config = Self::try_deserialize(&mut &data[..])?;
+            Ok(route_config.config_id)
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use common::migration::{self, UpgradeFn, VersionHeader, Versioned};
 
 /// Protocol used to transfer assets.
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Copy)]
@@ -42,12 +43,78 @@ pub struct EndpointInfo {
     pub protocol: MessageProtocol,
 }
 
+/// Flow of assets through a router endpoint, which can be paused independently of other flows.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointFlow {
+    /// New auctions for fast orders sent from this endpoint.
+    InboundAuctions,
+    /// CCTP transfers to this endpoint.
+    OutboundCctp,
+    /// Fills to Solana for orders sent from this endpoint.
+    LocalFills,
+    /// Redemption of fast fills for orders sent from this endpoint.
+    FastFillRedemption,
+}
+
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct PauseFlag {
+    pub paused: bool,
+
+    /// Indicate who last set the `paused` value.
+    pub set_by: Pubkey,
+}
+
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct EndpointPauseFlags {
+    pub inbound_auctions: PauseFlag,
+    pub outbound_cctp: PauseFlag,
+    pub local_fills: PauseFlag,
+    pub fast_fill_redemption: PauseFlag,
+}
+
+impl EndpointPauseFlags {
+    pub fn get(&self, flow: EndpointFlow) -> &PauseFlag {
+        match flow {
+            EndpointFlow::InboundAuctions => &self.inbound_auctions,
+            EndpointFlow::OutboundCctp => &self.outbound_cctp,
+            EndpointFlow::LocalFills => &self.local_fills,
+            EndpointFlow::FastFillRedemption => &self.fast_fill_redemption,
+        }
+    }
+
+    pub fn get_mut(&mut self, flow: EndpointFlow) -> &mut PauseFlag {
+        match flow {
+            EndpointFlow::InboundAuctions => &mut self.inbound_auctions,
+            EndpointFlow::OutboundCctp => &mut self.outbound_cctp,
+            EndpointFlow::LocalFills => &mut self.local_fills,
+            EndpointFlow::FastFillRedemption => &mut self.fast_fill_redemption,
+        }
+    }
+
+    pub fn is_paused(&self, flow: EndpointFlow) -> bool {
+        self.get(flow).paused
+    }
+}
+
 #[account]
 #[derive(Debug, InitSpace)]
 /// Foreign emitter account data.
 pub struct RouterEndpoint {
+    pub header: VersionHeader,
+
     pub bump: u8,
     pub info: EndpointInfo,
+
+    /// If true, only registered solvers that are not suspended may place offers on auctions for
+    /// orders to or from this endpoint.
+    pub solver_allowlist: bool,
+
+    /// Pause flags for each flow through this endpoint.
+    pub pause_flags: EndpointPauseFlags,
 }
 
 impl std::ops::Deref for RouterEndpoint {
@@ -60,4 +127,134 @@ impl std::ops::Deref for RouterEndpoint {
 
 impl RouterEndpoint {
     pub const SEED_PREFIX: &'static [u8] = b"endpoint";
+
+    /// Length of a router endpoint's data (including its discriminator) before it was versioned.
+    const LEGACY_LEN: usize = 108;
+
+    pub fn require_unpaused(&self, flow: EndpointFlow) -> Result<bool> {
+        require!(
+            !self.pause_flags.is_paused(flow),
+            crate::error::MatchingEngineError::EndpointPaused
+        );
+
+        Ok(true)
+    }
+}
+
+impl Versioned for RouterEndpoint {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    fn is_legacy(_key: &Pubkey, data: &[u8]) -> bool {
+        data.len() == Self::LEGACY_LEN
+    }
+}
+
+/// Router endpoint layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyRouterEndpoint {
+    bump: u8,
+    info: EndpointInfo,
+}
+
+/// Version 1 adds the version header, the solver allowlist and pause flags.
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyRouterEndpoint { bump, info } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    migration::serialize_upgraded(
+        &RouterEndpoint {
+            header: VersionHeader { version: 1 },
+            bump,
+            info,
+            solver_allowlist: false,
+            pause_flags: Default::default(),
+        },
+        RouterEndpoint::INIT_SPACE,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn legacy_endpoint(protocol: MessageProtocol) -> LegacyRouterEndpoint {
+        LegacyRouterEndpoint {
+            bump: 254,
+            info: EndpointInfo {
+                chain: 2,
+                address: [1; 32],
+                mint_recipient: [2; 32],
+                protocol,
+            },
+        }
+    }
+
+    fn upgrade(legacy: &LegacyRouterEndpoint) -> RouterEndpoint {
+        let mut legacy_data = RouterEndpoint::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+
+        // Legacy endpoints were allocated with enough space for the largest message protocol.
+        legacy_data.resize(RouterEndpoint::LEGACY_LEN, 0);
+
+        let key = Pubkey::new_unique();
+        assert_eq!(
+            migration::account_version::<RouterEndpoint>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        let mut upgraded_data = RouterEndpoint::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgrade_v0(&legacy_data[8..]).unwrap());
+        assert_eq!(upgraded_data.len(), 8 + RouterEndpoint::INIT_SPACE);
+        assert_eq!(
+            migration::account_version::<RouterEndpoint>(&key, &upgraded_data).unwrap(),
+            RouterEndpoint::VERSION
+        );
+
+        RouterEndpoint::try_deserialize(&mut &upgraded_data[..]).unwrap()
+    }
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let legacy = legacy_endpoint(MessageProtocol::Cctp { domain: 0 });
+
+        let endpoint = upgrade(&legacy);
+        assert_eq!(endpoint.bump, legacy.bump);
+        assert_eq!(endpoint.chain, legacy.info.chain);
+        assert_eq!(endpoint.address, legacy.info.address);
+        assert_eq!(endpoint.mint_recipient, legacy.info.mint_recipient);
+        assert_eq!(endpoint.protocol, legacy.info.protocol);
+    }
+
+    #[test]
+    fn upgrade_from_legacy_without_solver_allowlist() {
+        for protocol in [
+            MessageProtocol::None,
+            MessageProtocol::Local {
+                program_id: Pubkey::new_unique(),
+            },
+            MessageProtocol::Cctp { domain: 6 },
+        ] {
+            let endpoint = upgrade(&legacy_endpoint(protocol));
+            assert_eq!(endpoint.protocol, protocol);
+            assert!(!endpoint.solver_allowlist);
+        }
+    }
+
+    #[test]
+    fn upgrade_from_legacy_unpaused() {
+        let endpoint = upgrade(&legacy_endpoint(MessageProtocol::Cctp { domain: 0 }));
+        assert_eq!(endpoint.pause_flags, EndpointPauseFlags::default());
+
+        for flow in [
+            EndpointFlow::InboundAuctions,
+            EndpointFlow::OutboundCctp,
+            EndpointFlow::LocalFills,
+            EndpointFlow::FastFillRedemption,
+        ] {
+            assert!(!endpoint.pause_flags.is_paused(flow));
+            assert!(endpoint.require_unpaused(flow).unwrap());
+        }
+    }
 }
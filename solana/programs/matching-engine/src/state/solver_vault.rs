@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MatchingEngineError;
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct PendingWithdrawal {
+    /// Amount the solver intends to withdraw.
+    pub amount: u64,
+
+    /// Slot when the withdrawal can be completed.
+    pub slot_available: u64,
+}
+
+/// Collateral vault for a solver participating in auctions. Tokens deposited into this vault's
+/// custody token account can be used to place and improve offers without transferring tokens for
+/// each offer. Instead, an offer's total deposit is locked while the solver has the best offer
+/// and is unlocked once the solver is outbid.
+///
+/// NOTE: Deposits are performed by transferring tokens directly to the custody token account.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct SolverVault {
+    pub bump: u8,
+
+    /// Solver who can place offers with this vault's collateral and withdraw from it.
+    pub owner: Pubkey,
+
+    /// Custody token account holding this vault's collateral. This token account is used as the
+    /// offer token for auctions.
+    pub custody_token: Pubkey,
+    pub custody_token_bump: u8,
+
+    /// Amount of collateral locked in auctions where this vault has the best offer.
+    pub locked: u64,
+
+    pub pending_withdrawal: Option<PendingWithdrawal>,
+}
+
+impl SolverVault {
+    pub const SEED_PREFIX: &'static [u8] = b"solver-vault";
+
+    /// Collateral that is not locked in any auction.
+    pub fn available(&self, custody_balance: u64) -> u64 {
+        custody_balance.saturating_sub(self.locked)
+    }
+
+    /// Lock collateral for an offer. This method reverts if there is not enough available
+    /// collateral given the custody token balance.
+    pub fn lock(&mut self, amount: u64, custody_balance: u64) -> Result<()> {
+        require!(
+            self.available(custody_balance) >= amount,
+            MatchingEngineError::InsufficientVaultBalance
+        );
+
+        self.locked = self
+            .locked
+            .checked_add(amount)
+            .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+
+        Ok(())
+    }
+
+    /// Unlock collateral from an offer that is no longer the best offer.
+    pub fn unlock(&mut self, amount: u64) {
+        self.locked = self.locked.saturating_sub(amount);
+    }
+}
@@ -4,9 +4,12 @@ use crate::{
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
+use common::{
+    migration::{VersionHeader, Versioned},
+    wormhole_cctp_solana::wormhole::SOLANA_CHAIN,
+};
 
-#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq, Copy)]
 pub struct AddCctpRouterEndpointArgs {
     pub chain: u16,
     pub cctp_domain: u32,
@@ -14,19 +17,17 @@ pub struct AddCctpRouterEndpointArgs {
     pub mint_recipient: Option<[u8; 32]>,
 }
 
-pub(crate) fn handle_add_cctp_router_endpoint(
-    router_endpoint: &mut Account<RouterEndpoint>,
-    args: AddCctpRouterEndpointArgs,
-    router_endpoint_bump: Option<u8>,
-) -> Result<()> {
-    let bump = router_endpoint_bump.unwrap_or_else(|| router_endpoint.bump);
-
+/// Validate the CCTP router endpoint args, returning the endpoint's mint recipient (which defaults
+/// to the endpoint's address).
+pub(crate) fn require_valid_cctp_router_endpoint_args(
+    args: &AddCctpRouterEndpointArgs,
+) -> Result<[u8; 32]> {
     let AddCctpRouterEndpointArgs {
         chain,
-        cctp_domain: domain,
+        cctp_domain: _,
         address,
         mint_recipient,
-    } = args;
+    } = *args;
 
     require!(
         chain != 0 && chain != SOLANA_CHAIN,
@@ -35,18 +36,43 @@ pub(crate) fn handle_add_cctp_router_endpoint(
 
     require!(address != [0; 32], MatchingEngineError::InvalidEndpoint);
 
-    let mint_recipient = match mint_recipient {
+    match mint_recipient {
         Some(mint_recipient) => {
             require!(
                 mint_recipient != [0; 32],
                 MatchingEngineError::InvalidMintRecipient
             );
-            mint_recipient
+            Ok(mint_recipient)
         }
-        None => address,
-    };
+        None => Ok(address),
+    }
+}
+
+pub(crate) fn handle_add_cctp_router_endpoint(
+    router_endpoint: &mut Account<RouterEndpoint>,
+    args: AddCctpRouterEndpointArgs,
+    router_endpoint_bump: Option<u8>,
+) -> Result<()> {
+    let bump = router_endpoint_bump.unwrap_or_else(|| router_endpoint.bump);
+
+    // The solver allowlist and pause flags are managed separately, so preserve whatever values
+    // exist.
+    let solver_allowlist = router_endpoint.solver_allowlist;
+    let pause_flags = router_endpoint.pause_flags;
+
+    let mint_recipient = require_valid_cctp_router_endpoint_args(&args)?;
+
+    let AddCctpRouterEndpointArgs {
+        chain,
+        cctp_domain: domain,
+        address,
+        mint_recipient: _,
+    } = args;
 
     router_endpoint.set_inner(RouterEndpoint {
+        header: VersionHeader {
+            version: RouterEndpoint::VERSION,
+        },
         bump,
         info: EndpointInfo {
             chain,
@@ -54,6 +80,8 @@ pub(crate) fn handle_add_cctp_router_endpoint(
             mint_recipient,
             protocol: MessageProtocol::Cctp { domain },
         },
+        solver_allowlist,
+        pause_flags,
     });
 
     // Done.
@@ -68,8 +96,13 @@ pub(crate) fn handle_add_local_router_endpoint(
     router_endpoint_bump: Option<u8>,
 ) -> Result<()> {
     let bump = router_endpoint_bump.unwrap_or_else(|| router_endpoint.bump);
+    let solver_allowlist = router_endpoint.solver_allowlist;
+    let pause_flags = router_endpoint.pause_flags;
 
     router_endpoint.set_inner(RouterEndpoint {
+        header: VersionHeader {
+            version: RouterEndpoint::VERSION,
+        },
         bump,
         info: EndpointInfo {
             chain: SOLANA_CHAIN,
@@ -79,6 +112,8 @@ pub(crate) fn handle_add_local_router_endpoint(
                 program_id: token_router_program.key(),
             },
         },
+        solver_allowlist,
+        pause_flags,
     });
 
     Ok(())
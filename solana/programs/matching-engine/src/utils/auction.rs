@@ -1,9 +1,10 @@
 use crate::FEE_PRECISION_MAX;
 use crate::{
     error::MatchingEngineError,
-    state::{AuctionInfo, AuctionParameters},
+    state::{AuctionInfo, AuctionParameters, MessageProtocol},
 };
 use anchor_lang::prelude::*;
+use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
 
 #[derive(Debug, Default)]
 pub struct DepositPenalty {
@@ -50,6 +51,49 @@ pub fn compute_deposit_penalty(
     }
 }
 
+/// Compute the portion of the security deposit forfeited by a sealed-bid commitment that was not
+/// revealed. The forfeited amount is the initial penalty applied to the security deposit, which is
+/// split between the user and whoever closes the commitment.
+#[inline]
+pub fn compute_unrevealed_commitment_penalty(
+    params: &AuctionParameters,
+    info: &AuctionInfo,
+) -> DepositPenalty {
+    split_user_penalty_reward(
+        params,
+        mul_bps_unsafe(info.security_deposit, params.initial_penalty_bps),
+    )
+}
+
+/// Compute the new end slot of an auction if an offer placed at the current slot is within the
+/// extension window. The extended end slot is capped at the auction's duration plus the max
+/// extension. Returns `None` if the auction is not extended.
+#[inline]
+pub fn compute_extended_end_slot(
+    params: &AuctionParameters,
+    info: &AuctionInfo,
+    current_slot: u64,
+) -> Option<u64> {
+    let end_slot = info.offer_end_slot(params);
+    let extension_window = u64::from(params.extension_window);
+
+    if end_slot.saturating_sub(current_slot) >= extension_window {
+        None
+    } else {
+        let max_end_slot = info
+            .start_slot
+            .saturating_add(params.duration.into())
+            .saturating_add(params.max_extension.into());
+
+        Some(
+            current_slot
+                .saturating_add(extension_window)
+                .min(max_end_slot),
+        )
+        .filter(|extended_end_slot| *extended_end_slot > end_slot)
+    }
+}
+
 #[inline]
 pub fn compute_min_allowed_offer(params: &AuctionParameters, info: &AuctionInfo) -> u64 {
     info.offer_price
@@ -63,6 +107,292 @@ pub fn compute_notional_security_deposit(params: &AuctionParameters, notional: u
         .saturating_add(mul_bps_unsafe(notional, params.security_deposit_bps))
 }
 
+/// Compute the security deposit an offer must post on top of the amount in, which is the max fee
+/// plus the notional security deposit.
+///
+/// Saturating to u64::MAX is safe here. If the amount really ends up being this large, adding the
+/// amount in to compute the total deposit must be checked.
+#[inline]
+pub fn compute_security_deposit(params: &AuctionParameters, max_fee: u64, amount_in: u64) -> u64 {
+    max_fee.saturating_add(compute_notional_security_deposit(params, amount_in))
+}
+
+/// Compute the amount in of a split-fill auction's tranche. The amount in is split evenly between
+/// tranches, where the last tranche also takes the remainder.
+#[inline]
+pub fn compute_tranche_amount_in(amount_in: u64, num_tranches: u8, tranche_index: u8) -> u64 {
+    let tranche_amount = amount_in
+        .checked_div(num_tranches.into())
+        .unwrap_or_default();
+
+    if tranche_index.saturating_add(1) >= num_tranches {
+        amount_in.saturating_sub(tranche_amount.saturating_mul(tranche_index.into()))
+    } else {
+        tranche_amount
+    }
+}
+
+/// Compute the portion of an amount owed to a part of the whole, rounding down. This is used to
+/// split order fees and repayments between a split-fill auction's tranches.
+#[inline]
+pub fn compute_pro_rata(amount: u64, part: u64, whole: u64) -> u64 {
+    if whole == 0 {
+        return 0;
+    }
+
+    // NOTE: Integer division is safe here because whole is nonzero.
+    #[allow(clippy::arithmetic_side_effects)]
+    let out = u128::from(amount)
+        .saturating_mul(part.min(whole).into())
+        .saturating_div(whole.into());
+
+    // NOTE: Downcasting from u128 to u64 is safe because part is capped at whole, so the value is
+    // never greater than the amount.
+    #[allow(clippy::as_conversions)]
+    #[allow(clippy::cast_possible_truncation)]
+    let out = out as u64;
+
+    out
+}
+
+/// Additional grace period (in slots) for executing an order. We extend the grace period for
+/// locally executed orders. Reserving a sequence number for the fast fill will most likely require
+/// an additional transaction, so this buffer allows the best offer participant to perform his duty
+/// without the risk of getting slashed by another executor.
+#[inline]
+pub fn compute_additional_grace_period(target_protocol: &MessageProtocol) -> Option<u64> {
+    match target_protocol {
+        MessageProtocol::Local { .. } => {
+            crate::EXECUTE_FAST_ORDER_LOCAL_ADDITIONAL_GRACE_PERIOD.into()
+        }
+        _ => None,
+    }
+}
+
+/// CCTP domain where an order's fill is sent. Refunds are sent to the source endpoint, so the order
+/// cannot be refunded via CCTP if the source endpoint is not a CCTP endpoint.
+#[inline]
+pub fn compute_fill_cctp_domain(to_protocol: &MessageProtocol, is_refund: bool) -> Result<u32> {
+    match (to_protocol, is_refund) {
+        (MessageProtocol::Cctp { domain }, _) => Ok(*domain),
+        (_, true) => err!(MatchingEngineError::InvalidRefundEndpoint),
+        (_, false) => err!(MatchingEngineError::InvalidCctpEndpoint),
+    }
+}
+
+/// A refund is delivered to the refund address on the source chain. Because a fast fill can only be
+/// redeemed on Solana, an order can only be refunded locally if it originated from Solana.
+/// Otherwise it must be refunded via CCTP to the source chain.
+#[inline]
+pub fn require_local_fill(source_chain: u16, is_refund: bool) -> Result<()> {
+    require!(
+        !is_refund || source_chain == SOLANA_CHAIN,
+        MatchingEngineError::RefundRequired
+    );
+
+    Ok(())
+}
+
+/// Which token accounts exist (and which are the same account) when an order is executed. These
+/// determine where the auction's custodied funds are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutePayoutAccounts {
+    pub initial_offer_token_exists: bool,
+    pub best_offer_token_exists: bool,
+    pub best_offer_is_initial_offer: bool,
+    pub best_offer_is_executor: bool,
+}
+
+/// Amounts paid out of an auction's custody token account when its order is executed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutePayouts {
+    /// Amount owed to the user, which remains in the custody token account to be bridged or
+    /// filled.
+    pub user_amount: u64,
+
+    /// Init auction fee paid to the initial offer token. This is zero if the initial offer token
+    /// is the best offer token (the fee is included in the best offer amount instead) or if the
+    /// initial offer token does not exist anymore (the fee goes to the executor instead).
+    pub initial_offer_amount: u64,
+
+    /// Security deposit and offer price (less any penalty) returned to the best offer token. If
+    /// the best offer token is the executor token, this is whatever remains in the custody token
+    /// account after paying the user and the initial offer token.
+    pub best_offer_amount: u64,
+
+    /// Whatever remains in the custody token account, which is paid to the executor token.
+    pub executor_amount: u64,
+
+    /// Penalty taken from the best offer's security deposit for executing the order late.
+    pub penalty: Option<u64>,
+}
+
+/// Compute how an auction's custodied funds are paid out when its order is executed at the current
+/// slot.
+pub fn compute_execute_payouts(
+    params: &AuctionParameters,
+    info: &AuctionInfo,
+    accounts: ExecutePayoutAccounts,
+    custodied_amount: u64,
+    init_auction_fee: u64,
+    current_slot: u64,
+    additional_grace_period: Option<u64>,
+) -> Result<ExecutePayouts> {
+    let ExecutePayoutAccounts {
+        initial_offer_token_exists,
+        best_offer_token_exists,
+        best_offer_is_initial_offer,
+        best_offer_is_executor,
+    } = accounts;
+
+    let DepositPenalty {
+        penalty,
+        user_reward,
+    } = compute_deposit_penalty(params, info, current_slot, additional_grace_period);
+
+    let user_amount = info
+        .amount_in
+        .saturating_sub(info.offer_price)
+        .saturating_sub(init_auction_fee)
+        .saturating_add(user_reward)
+        .saturating_add(info.forfeited_user_reward);
+
+    // Keep track of the remaining amount in the custody token account. Whatever remains will go to
+    // the executor.
+    let mut remaining_custodied_amount = custodied_amount.saturating_sub(user_amount);
+
+    // Offer price + security deposit was checked in placing the initial offer.
+    let mut deposit_and_fee = info
+        .offer_price
+        .saturating_add(info.security_deposit)
+        .saturating_sub(user_reward);
+
+    let penalized = penalty > 0;
+
+    if penalized && !best_offer_is_executor {
+        deposit_and_fee = deposit_and_fee.saturating_sub(penalty);
+    }
+
+    // If the initial offer token account doesn't exist anymore, we have nowhere to send the init
+    // auction fee. The executor will get these funds instead.
+    let mut initial_offer_amount = 0;
+    if initial_offer_token_exists {
+        if !best_offer_is_initial_offer {
+            // Pay the auction initiator their fee.
+            initial_offer_amount = init_auction_fee;
+            remaining_custodied_amount =
+                remaining_custodied_amount.saturating_sub(init_auction_fee);
+        } else {
+            // Add it to the reimbursement.
+            deposit_and_fee = deposit_and_fee
+                .checked_add(init_auction_fee)
+                .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+        }
+    }
+
+    let (best_offer_amount, executor_amount) = if best_offer_is_executor {
+        // If the best offer token is equal to the executor token, it gets whatever remains in the
+        // custody token account.
+        (remaining_custodied_amount, 0)
+    } else if best_offer_token_exists {
+        (
+            deposit_and_fee,
+            remaining_custodied_amount.saturating_sub(deposit_and_fee),
+        )
+    } else {
+        // If the best offer token doesn't exist at this point (which would be unusual), we will
+        // reserve these funds for the executor token.
+        (0, remaining_custodied_amount)
+    };
+
+    Ok(ExecutePayouts {
+        user_amount,
+        initial_offer_amount,
+        best_offer_amount,
+        executor_amount,
+        penalty: if penalized { penalty.into() } else { None },
+    })
+}
+
+/// Which token accounts exist (and whether they are the same account) when a completed auction is
+/// settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettlePayoutAccounts {
+    pub base_fee_token_exists: bool,
+    pub best_offer_token_exists: bool,
+    pub base_fee_is_best_offer: bool,
+}
+
+/// Amounts paid out of the prepared custody token account when a completed auction is settled. A
+/// token account that is not paid is `None`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SettlePayouts {
+    pub base_fee_token_amount: Option<u64>,
+    pub best_offer_amount: Option<u64>,
+}
+
+/// Compute how the finalized repayment is divvied up when a completed auction is settled. The base
+/// fee token is only paid if the order was executed with a penalty.
+pub fn compute_settle_payouts(
+    repayment: u64,
+    base_fee: u64,
+    penalized: bool,
+    accounts: SettlePayoutAccounts,
+) -> Result<SettlePayouts> {
+    let SettlePayoutAccounts {
+        base_fee_token_exists,
+        best_offer_token_exists,
+        base_fee_is_best_offer,
+    } = accounts;
+
+    let payouts = if !penalized {
+        // When there is no penalty, we will give everything to the best offer token account. If
+        // the token account happens to not exist anymore, we will revert.
+        require!(
+            best_offer_token_exists,
+            MatchingEngineError::BestOfferTokenRequired
+        );
+
+        SettlePayouts {
+            base_fee_token_amount: None,
+            best_offer_amount: repayment.into(),
+        }
+    } else {
+        // Otherwise, determine how the repayment should be divvied up.
+        match (base_fee_token_exists, best_offer_token_exists) {
+            (true, true) => {
+                if base_fee_is_best_offer {
+                    SettlePayouts {
+                        base_fee_token_amount: None,
+                        best_offer_amount: repayment.into(),
+                    }
+                } else {
+                    SettlePayouts {
+                        base_fee_token_amount: base_fee.into(),
+                        best_offer_amount: repayment.saturating_sub(base_fee).into(),
+                    }
+                }
+            }
+            // If the best offer token account does not exist, we will give everything to the base
+            // fee token account.
+            (true, false) => SettlePayouts {
+                base_fee_token_amount: repayment.into(),
+                best_offer_amount: None,
+            },
+            // If the base fee token account does not exist, we will give everything to the best
+            // offer token account.
+            (false, true) => SettlePayouts {
+                base_fee_token_amount: None,
+                best_offer_amount: repayment.into(),
+            },
+            // Otherwise revert.
+            (false, false) => return err!(MatchingEngineError::BestOfferTokenRequired),
+        }
+    };
+
+    Ok(payouts)
+}
+
 pub fn require_valid_parameters(params: &AuctionParameters) -> Result<()> {
     let AuctionParameters {
         user_penalty_reward_bps,
@@ -73,6 +403,10 @@ pub fn require_valid_parameters(params: &AuctionParameters) -> Result<()> {
         min_offer_delta_bps,
         security_deposit_base,
         security_deposit_bps,
+        reveal_period: _,
+        extension_window,
+        max_extension: _,
+        num_tranches,
     } = params;
 
     require!(*duration > 0, MatchingEngineError::ZeroDuration);
@@ -98,6 +432,18 @@ pub fn require_valid_parameters(params: &AuctionParameters) -> Result<()> {
         *security_deposit_bps <= FEE_PRECISION_MAX,
         MatchingEngineError::SecurityDepositBpsTooLarge
     );
+    require!(
+        *extension_window <= *duration,
+        MatchingEngineError::ExtensionWindowTooLarge
+    );
+    require!(
+        *num_tranches <= crate::MAX_AUCTION_TRANCHES,
+        MatchingEngineError::TooManyTranches
+    );
+    require!(
+        !(params.is_split_fill() && params.is_sealed_bid()),
+        MatchingEngineError::SplitFillSealedBid
+    );
 
     Ok(())
 }
@@ -368,6 +714,326 @@ mod test {
         assert_eq!(allowed_offer, offer_price - 500000);
     }
 
+    #[test]
+    fn improvement_before_extension_window() {
+        let params = AuctionParameters {
+            duration: 10,
+            extension_window: 3,
+            max_extension: 5,
+            ..params_for_test()
+        };
+
+        let (info, current_slot) = set_up(0, Some(7), 0);
+        assert_eq!(
+            compute_extended_end_slot(&params, &info, current_slot),
+            None
+        );
+    }
+
+    #[test]
+    fn improvement_within_extension_window() {
+        let params = AuctionParameters {
+            duration: 10,
+            extension_window: 3,
+            max_extension: 5,
+            ..params_for_test()
+        };
+
+        let (mut info, current_slot) = set_up(0, Some(8), 0);
+        let extended_end_slot = compute_extended_end_slot(&params, &info, current_slot);
+        assert_eq!(extended_end_slot, Some(current_slot + 3));
+
+        // The grace period begins after the extended end slot.
+        info.extended_end_slot = extended_end_slot;
+        assert_eq!(info.auction_end_slot(&params), current_slot + 3);
+        assert_eq!(
+            info.grace_period_end_slot(&params, None),
+            current_slot + 3 + u64::from(params.grace_period)
+        );
+    }
+
+    #[test]
+    fn improvement_extension_capped() {
+        let params = AuctionParameters {
+            duration: 10,
+            extension_window: 3,
+            max_extension: 5,
+            ..params_for_test()
+        };
+
+        // Extended once already to the cap.
+        let (mut info, _) = set_up(0, None, 0);
+        info.extended_end_slot = Some(info.start_slot + 15);
+
+        let current_slot = info.start_slot + 14;
+        assert_eq!(
+            compute_extended_end_slot(&params, &info, current_slot),
+            None
+        );
+
+        // Extension partially capped.
+        info.extended_end_slot = Some(info.start_slot + 13);
+
+        let current_slot = info.start_slot + 12;
+        assert_eq!(
+            compute_extended_end_slot(&params, &info, current_slot),
+            Some(info.start_slot + 15)
+        );
+    }
+
+    #[test]
+    fn extension_disabled() {
+        let params = AuctionParameters {
+            duration: 10,
+            ..params_for_test()
+        };
+
+        let (info, current_slot) = set_up(0, Some(10), 0);
+        assert_eq!(
+            compute_extended_end_slot(&params, &info, current_slot),
+            None
+        );
+    }
+
+    #[test]
+    fn unrevealed_commitment_penalty() {
+        let params = params_for_test();
+
+        let amount = 10000000;
+        let (info, _) = set_up(amount, None, 0);
+
+        let DepositPenalty {
+            penalty,
+            user_reward,
+        } = compute_unrevealed_commitment_penalty(&params, &info);
+
+        assert_eq!(penalty, 750000);
+        assert_eq!(user_reward, 250000);
+    }
+
+    #[test]
+    fn sealed_bid_reveal_period_delays_grace_period() {
+        let params = AuctionParameters {
+            reveal_period: 3,
+            ..params_for_test()
+        };
+
+        let amount = 10000000;
+        let slots_elapsed = params.duration + params.reveal_period + params.grace_period;
+        let (info, current_slot) = set_up(amount, Some(slots_elapsed.into()), 0);
+
+        assert_eq!(
+            info.auction_end_slot(&params),
+            info.offer_end_slot(&params) + u64::from(params.reveal_period)
+        );
+
+        let DepositPenalty {
+            penalty,
+            user_reward,
+        } = compute_deposit_penalty(&params, &info, current_slot, None);
+
+        assert_eq!(penalty, 0);
+        assert_eq!(user_reward, 0);
+    }
+
+    #[test]
+    fn tranche_amounts_sum_to_amount_in() {
+        let amount_in = 1_000_000_007;
+        let num_tranches = 4;
+
+        let amounts = (0..num_tranches)
+            .map(|index| compute_tranche_amount_in(amount_in, num_tranches, index))
+            .collect::<Vec<_>>();
+        assert_eq!(amounts, [250000001, 250000001, 250000001, 250000004]);
+        assert_eq!(amounts.iter().sum::<u64>(), amount_in);
+    }
+
+    #[test]
+    fn pro_rata_repayment() {
+        let amount_in = 1_000_000_007;
+        let repayment = 999_500_000;
+
+        // Each tranche is repaid from what remains of the repayment, so the last tranche collects
+        // whatever is left over from rounding down.
+        let mut remaining = repayment;
+        let mut unsettled = amount_in;
+        for index in 0..4 {
+            let tranche_amount = compute_tranche_amount_in(amount_in, 4, index);
+            let tranche_repayment = compute_pro_rata(remaining, tranche_amount, unsettled);
+            let expected = compute_pro_rata(repayment, tranche_amount, amount_in);
+            assert!(tranche_repayment.abs_diff(expected) < 4);
+
+            remaining -= tranche_repayment;
+            unsettled -= tranche_amount;
+        }
+        assert_eq!(remaining, 0);
+        assert_eq!(unsettled, 0);
+    }
+
+    #[test]
+    fn pro_rata_zero_whole() {
+        assert_eq!(compute_pro_rata(100, 0, 0), 0);
+        assert_eq!(compute_pro_rata(100, 50, 100), 50);
+        assert_eq!(compute_pro_rata(100, 150, 100), 100);
+    }
+
+    #[test]
+    fn execute_payouts_within_grace_period() {
+        let params = params_for_test();
+
+        let amount_in = 10000000;
+        let security_deposit = 1500000;
+        let init_auction_fee = 10000;
+        let slots_elapsed = params.duration + params.grace_period;
+        let (mut info, current_slot) = set_up(security_deposit, Some(slots_elapsed.into()), 100000);
+        info.amount_in = amount_in;
+
+        let payouts = compute_execute_payouts(
+            &params,
+            &info,
+            ExecutePayoutAccounts {
+                initial_offer_token_exists: true,
+                best_offer_token_exists: true,
+                best_offer_is_initial_offer: false,
+                best_offer_is_executor: false,
+            },
+            info.total_deposit(),
+            init_auction_fee,
+            current_slot,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            payouts,
+            ExecutePayouts {
+                user_amount: amount_in - info.offer_price - init_auction_fee,
+                initial_offer_amount: init_auction_fee,
+                best_offer_amount: info.offer_price + security_deposit,
+                executor_amount: 0,
+                penalty: None,
+            }
+        );
+    }
+
+    #[test]
+    fn execute_payouts_penalized() {
+        let params = params_for_test();
+
+        let amount_in = 10000000;
+        let security_deposit = 1500000;
+        let init_auction_fee = 10000;
+        let slots_elapsed = params.duration + params.grace_period + params.penalty_period;
+        let (mut info, current_slot) = set_up(security_deposit, Some(slots_elapsed.into()), 100000);
+        info.amount_in = amount_in;
+
+        let DepositPenalty {
+            penalty,
+            user_reward,
+        } = compute_deposit_penalty(&params, &info, current_slot, None);
+
+        let payouts = compute_execute_payouts(
+            &params,
+            &info,
+            ExecutePayoutAccounts {
+                initial_offer_token_exists: true,
+                best_offer_token_exists: true,
+                best_offer_is_initial_offer: true,
+                best_offer_is_executor: false,
+            },
+            info.total_deposit(),
+            init_auction_fee,
+            current_slot,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            payouts,
+            ExecutePayouts {
+                user_amount: amount_in - info.offer_price - init_auction_fee + user_reward,
+                initial_offer_amount: 0,
+                best_offer_amount: info.offer_price + security_deposit + init_auction_fee
+                    - user_reward
+                    - penalty,
+                executor_amount: penalty,
+                penalty: penalty.into(),
+            }
+        );
+    }
+
+    #[test]
+    fn settle_payouts() {
+        let accounts = SettlePayoutAccounts {
+            base_fee_token_exists: true,
+            best_offer_token_exists: true,
+            base_fee_is_best_offer: false,
+        };
+
+        assert_eq!(
+            compute_settle_payouts(10000000, 1000, false, accounts).unwrap(),
+            SettlePayouts {
+                base_fee_token_amount: None,
+                best_offer_amount: Some(10000000),
+            }
+        );
+        assert_eq!(
+            compute_settle_payouts(10000000, 1000, true, accounts).unwrap(),
+            SettlePayouts {
+                base_fee_token_amount: Some(1000),
+                best_offer_amount: Some(9999000),
+            }
+        );
+        assert!(compute_settle_payouts(
+            10000000,
+            1000,
+            false,
+            SettlePayoutAccounts {
+                best_offer_token_exists: false,
+                ..accounts
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn fill_cctp_domain() {
+        let cctp = MessageProtocol::Cctp { domain: 3 };
+        assert_eq!(compute_fill_cctp_domain(&cctp, false).unwrap(), 3);
+        assert_eq!(compute_fill_cctp_domain(&cctp, true).unwrap(), 3);
+
+        let local = MessageProtocol::Local {
+            program_id: Pubkey::new_unique(),
+        };
+        assert_eq!(
+            compute_fill_cctp_domain(&local, false).unwrap_err(),
+            error!(MatchingEngineError::InvalidCctpEndpoint)
+        );
+
+        // A refund cannot be sent via CCTP to a non-CCTP source endpoint.
+        assert_eq!(
+            compute_fill_cctp_domain(&local, true).unwrap_err(),
+            error!(MatchingEngineError::InvalidRefundEndpoint)
+        );
+        assert_eq!(
+            compute_fill_cctp_domain(&MessageProtocol::None, true).unwrap_err(),
+            error!(MatchingEngineError::InvalidRefundEndpoint)
+        );
+    }
+
+    #[test]
+    fn local_fill() {
+        require_local_fill(2, false).unwrap();
+        require_local_fill(SOLANA_CHAIN, false).unwrap();
+        require_local_fill(SOLANA_CHAIN, true).unwrap();
+
+        // Refunds to another chain cannot be redeemed on Solana.
+        assert_eq!(
+            require_local_fill(2, true).unwrap_err(),
+            error!(MatchingEngineError::RefundRequired)
+        );
+    }
+
     fn set_up(
         security_deposit: u64,
         slots_elapsed: Option<u64>,
@@ -380,12 +1046,17 @@ mod test {
                 custody_token_bump: Default::default(),
                 vaa_sequence: Default::default(),
                 start_slot: START,
+                extended_end_slot: Default::default(),
                 config_id: Default::default(),
                 source_chain: Default::default(),
                 best_offer_token: Default::default(),
+                best_offer_vault: Default::default(),
                 initial_offer_token: Default::default(),
+                solver_allowlist: Default::default(),
                 amount_in: Default::default(),
                 offer_price,
+                forfeited_user_reward: Default::default(),
+                split_fill: Default::default(),
                 redeemer_message_len: Default::default(),
                 destination_asset_info: Default::default(),
             },
@@ -403,6 +1074,10 @@ mod test {
             min_offer_delta_bps: 50000,     // 5%
             security_deposit_base: 1000000, // 1.0 USDC
             security_deposit_bps: 5000,     // 0.5%
+            reveal_period: Default::default(),
+            extension_window: Default::default(),
+            max_extension: Default::default(),
+            num_tranches: Default::default(),
         };
 
         require_valid_parameters(&params).unwrap();
@@ -2,10 +2,16 @@ pub mod admin;
 
 pub mod auction;
 
-use crate::{error::MatchingEngineError, state::RouterEndpoint};
+use crate::{
+    error::MatchingEngineError,
+    state::{MessageProtocol, RegisteredSolver, RouterEndpoint},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use common::wormhole_cctp_solana::wormhole::{VaaAccount, SOLANA_CHAIN};
+use common::{
+    messages::raw::FastMarketOrder,
+    wormhole_cctp_solana::wormhole::{VaaAccount, SOLANA_CHAIN},
+};
 
 pub trait VaaDigest {
     fn digest(&self) -> [u8; 32];
@@ -42,6 +48,49 @@ pub fn require_local_endpoint(endpoint: &RouterEndpoint) -> Result<bool> {
     Ok(true)
 }
 
+/// Require that the solver authority is registered and not suspended. This check is only relevant
+/// for auctions on routes with a solver allowlist.
+pub fn require_registered_solver(
+    registered_solver: Option<&Account<RegisteredSolver>>,
+    solver_authority: &Pubkey,
+) -> Result<()> {
+    let registered_solver =
+        registered_solver.ok_or_else(|| MatchingEngineError::SolverNotRegistered)?;
+
+    require_keys_eq!(
+        registered_solver.authority,
+        *solver_authority,
+        MatchingEngineError::SolverNotRegistered
+    );
+    require!(
+        !registered_solver.suspended,
+        MatchingEngineError::SolverSuspended
+    );
+
+    Ok(())
+}
+
+/// Require that the destination asset requested by a fast market order (if any) can be delivered.
+/// Only orders targeting Solana can request a destination asset, which must differ from the
+/// auction's mint. Because the order's minimum amount out is denominated in this asset, it must
+/// be nonzero.
+pub fn require_valid_destination_asset(
+    order: &FastMarketOrder,
+    target_protocol: &MessageProtocol,
+    mint: &Pubkey,
+) -> Result<()> {
+    if let Some(destination_asset) = order.destination_asset() {
+        require!(
+            matches!(target_protocol, MessageProtocol::Local { .. })
+                && Pubkey::from(destination_asset) != *mint
+                && order.min_amount_out() > 0,
+            MatchingEngineError::InvalidDestinationAsset
+        );
+    }
+
+    Ok(())
+}
+
 pub fn checked_deserialize_token_account(
     acc_info: &AccountInfo,
     expected_mint: &Pubkey,
@@ -4,11 +4,14 @@ use crate::{error::TokenRouterError, state::Custodian};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::{
-    admin::utils::{assistant::only_authorized, ownable::only_owner},
+    admin::{
+        utils::{assistant::only_authorized, ownable::only_owner, role::only_role},
+        Role,
+    },
     messages::raw::LiquidityLayerMessage,
     wormhole_cctp_solana::wormhole::VaaAccount,
 };
-use matching_engine::state::RouterEndpoint;
+use matching_engine::state::{CctpMint, EndpointFlow, RouterEndpoint};
 
 #[derive(Accounts)]
 pub struct Usdc<'info> {
@@ -25,18 +28,70 @@ impl<'info> Deref for Usdc<'info> {
     }
 }
 
+/// Circle-supported mint, which must either be USDC or a mint registered in the Matching Engine's
+/// [CctpMint] account. Fills of a registered mint can be redeemed even if the Matching Engine has
+/// disabled this mint.
+#[derive(Accounts)]
+pub struct SupportedCctpMint<'info> {
+    /// CHECK: This address must equal [USDC_MINT](common::USDC_MINT) or be a registered CCTP mint.
+    pub mint: UncheckedAccount<'info>,
+
+    /// CCTP mint registration, which may not exist if the mint is USDC.
+    ///
+    /// CHECK: Seeds must be \["cctp-mint", mint.key()\] (Matching Engine program).
+    #[account(
+        seeds = [
+            CctpMint::SEED_PREFIX,
+            mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = matching_engine::id(),
+        constraint = {
+            if mint.key() != common::USDC_MINT {
+                require!(
+                    cctp_mint.owner == &matching_engine::id(),
+                    TokenRouterError::CctpMintNotSupported
+                );
+            }
+
+            true
+        }
+    )]
+    pub cctp_mint: UncheckedAccount<'info>,
+}
+
+impl<'info> Deref for SupportedCctpMint<'info> {
+    type Target = UncheckedAccount<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.mint
+    }
+}
+
+/// Custodian's associated token account for the given mint, which is the CCTP mint recipient for
+/// this mint.
+fn cctp_mint_recipient_address(mint: &Pubkey) -> Pubkey {
+    if mint == &common::USDC_MINT {
+        crate::CCTP_MINT_RECIPIENT
+    } else {
+        let custodian =
+            Pubkey::create_program_address(Custodian::SIGNER_SEEDS, &crate::id()).unwrap();
+        anchor_spl::associated_token::get_associated_token_address(&custodian, mint)
+    }
+}
+
 /// Mint recipient token account, which is encoded as the mint recipient in the CCTP message.
 /// The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message
 /// from its custody account to this account.
 ///
-/// CHECK: Mutable. Seeds must be \["custody"\].
+/// CHECK: Mutable. Must be the custodian's associated token account for its mint.
 ///
 /// NOTE: This account must be encoded as the mint recipient in the CCTP message.
 #[derive(Accounts)]
 pub struct CctpMintRecipientMut<'info> {
     #[account(
         mut,
-        address = crate::CCTP_MINT_RECIPIENT
+        address = cctp_mint_recipient_address(&mint_recipient.mint)
     )]
     pub mint_recipient: Box<Account<'info, token::TokenAccount>>,
 }
@@ -164,6 +219,64 @@ pub struct AdminMut<'info> {
     pub custodian: Box<Account<'info, Custodian>>,
 }
 
+/// The owner, owner assistant or a key that was granted the role to pause and unpause the program.
+#[derive(Accounts)]
+pub struct PauserMut<'info> {
+    #[account(
+        constraint = only_role(
+            &custodian,
+            &authority,
+            Role::Pauser,
+            error!(TokenRouterError::MissingRole)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+}
+
+/// The owner, owner assistant or a key that was granted the role to manage fees.
+#[derive(Accounts)]
+pub struct FeeManager<'info> {
+    #[account(
+        constraint = only_role(
+            &custodian,
+            &authority,
+            Role::FeeManager,
+            error!(TokenRouterError::MissingRole)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    pub custodian: CheckedCustodian<'info>,
+}
+
+/// The owner, owner assistant or a key that was granted the role to manage fees.
+#[derive(Accounts)]
+pub struct FeeManagerMut<'info> {
+    #[account(
+        constraint = only_role(
+            &custodian,
+            &authority,
+            Role::FeeManager,
+            error!(TokenRouterError::MissingRole)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+}
+
 /// Registered router endpoint representing a foreign Token Router. This account may have a CCTP
 /// domain encoded if this route is CCTP-enabled. For this instruction, it is required that
 /// [RouterEndpoint::cctp_domain] is `Some(value)`.
@@ -190,6 +303,17 @@ pub struct RegisteredEndpoint<'info> {
     endpoint: Box<Account<'info, RouterEndpoint>>,
 }
 
+impl<'info> RegisteredEndpoint<'info> {
+    pub fn require_unpaused(&self, flow: EndpointFlow) -> Result<bool> {
+        require!(
+            !self.pause_flags.is_paused(flow),
+            TokenRouterError::EndpointPaused
+        );
+
+        Ok(true)
+    }
+}
+
 impl<'info> Deref for RegisteredEndpoint<'info> {
     type Target = Account<'info, RouterEndpoint>;
 
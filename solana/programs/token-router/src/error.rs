@@ -2,6 +2,8 @@
 pub enum TokenRouterError {
     OwnerOnly = 0x2,
     OwnerOrAssistantOnly = 0x4,
+    CouncilMemberOnly = 0x6,
+    MissingRole = 0x8,
 
     U64Overflow = 0x10,
 
@@ -16,11 +18,14 @@ pub enum TokenRouterError {
     InvalidTargetRouter = 0x62,
     EndpointDisabled = 0x64,
     InvalidCctpEndpoint = 0x66,
+    EndpointPaused = 0x68,
 
     Paused = 0x80,
 
     AssistantZeroPubkey = 0x100,
+    RoleHolderZeroPubkey = 0x101,
     ImmutableProgram = 0x102,
+    InvalidFastTransferParameters = 0x104,
 
     InvalidNewOwner = 0x202,
     AlreadyOwner = 0x204,
@@ -28,9 +33,29 @@ pub enum TokenRouterError {
     NotPendingOwner = 0x208,
     EitherSenderOrProgramTransferAuthority = 0x20a,
     DelegatedAmountMismatch = 0x20c,
+    InvalidCouncil = 0x20e,
+    CouncilActionNotApproved = 0x210,
+    TooManyRoleHolders = 0x212,
+    RoleNotGranted = 0x214,
+    MigrationIncomplete = 0x216,
+    AccountNotMigratable = 0x218,
+    MigrationInProgress = 0x21a,
+    MigrationNotStarted = 0x21c,
+
+    CctpMintNotSupported = 0x244,
+    CctpMintMismatch = 0x246,
 
     InsufficientAmount = 0x400,
     MinAmountOutTooHigh = 0x402,
     InvalidRedeemer = 0x404,
     PreparedFillTooLarge = 0x406,
+    FastTransferDisabled = 0x408,
+    AmountTooLarge = 0x40a,
+    InvalidMaxFee = 0x40c,
+    InvalidOrderType = 0x40e,
+    InvalidLimitOrderExpiry = 0x410,
+    LimitOrderExpired = 0x412,
+    LimitOrderNotExpired = 0x414,
+    LimitFeeExceeded = 0x416,
+    InsufficientSolverFunds = 0x418,
 }
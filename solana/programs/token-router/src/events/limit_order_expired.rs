@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct LimitOrderExpired {
+    pub prepared_order: Pubkey,
+    pub limit_order: Pubkey,
+
+    /// Token account receiving the escrowed funds.
+    pub refund_token: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct LimitOrderFilled {
+    pub prepared_order: Pubkey,
+    pub limit_order: Pubkey,
+
+    /// Solver who filled the order.
+    pub filled_by: Pubkey,
+
+    /// Fee collected by the solver, which is at most the limit order's max fee.
+    pub fee: u64,
+
+    /// Amount the solver delivered to the target chain, which is the escrowed amount less the fee.
+    pub amount: u64,
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct LimitOrderPlaced {
+    pub prepared_order: Pubkey,
+    pub limit_order: Pubkey,
+    pub order_sender: Pubkey,
+    pub amount_in: u64,
+    pub max_fee: u64,
+    pub expiry: i64,
+}
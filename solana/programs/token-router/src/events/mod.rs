@@ -0,0 +1,14 @@
+mod limit_order_expired;
+pub use limit_order_expired::*;
+
+mod limit_order_filled;
+pub use limit_order_filled::*;
+
+mod limit_order_placed;
+pub use limit_order_placed::*;
+
+mod role_granted;
+pub use role_granted::*;
+
+mod role_revoked;
+pub use role_revoked::*;
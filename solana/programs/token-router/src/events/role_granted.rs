@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+use common::admin::Role;
+
+#[event]
+pub struct RoleGranted {
+    pub authority: Pubkey,
+    pub role: Role,
+}
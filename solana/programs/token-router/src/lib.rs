@@ -5,9 +5,13 @@ mod composite;
 
 mod error;
 
+mod events;
+
 mod processor;
-pub use processor::PrepareMarketOrderArgs;
 use processor::*;
+pub use processor::{
+    PlaceLimitOrderArgs, PrepareFastMarketOrderArgs, PrepareMarketOrderArgs, StartMigrationArgs,
+};
 
 pub mod state;
 
@@ -93,8 +97,8 @@ pub mod token_router {
         processor::update_owner_assistant(ctx)
     }
 
-    /// This instruction is used to pause or unpause further processing of new transfer. Only the `owner`
-    /// or `owner_assistant` can pause the program.
+    /// This instruction is used to pause or unpause further processing of new transfer. Only the `owner`,
+    /// `owner_assistant` or a holder of the `Pauser` role can pause the program.
     /// # Arguments
     ///
     /// * `ctx`   - `SetPause` context.
@@ -103,8 +107,92 @@ pub mod token_router {
         processor::set_pause(ctx, paused)
     }
 
-    /// This instruction is used for executing logic during an upgrade. This instruction can only be
-    /// called by the `upgrade_manager_program`.
+    /// This instruction is used to update the fast transfer parameters in the `Custodian` account.
+    /// These parameters determine whether fast market orders can be placed and what fees they pay.
+    /// Only the `owner`, `owner_assistant` or a holder of the `FeeManager` role can update these
+    /// parameters.
+    /// # Arguments
+    ///
+    /// * `ctx`    - `UpdateFastTransferParameters` context.
+    /// * `params` - New `FastTransferParameters`. The `max_amount` must be greater than the sum of
+    ///              the `base_fee` and `init_auction_fee`.
+    pub fn update_fast_transfer_parameters(
+        ctx: Context<UpdateFastTransferParameters>,
+        params: state::FastTransferParameters,
+    ) -> Result<()> {
+        processor::update_fast_transfer_parameters(ctx, params)
+    }
+
+    /// This instruction is used to replace the M-of-N council, whose members can collectively
+    /// approve owner-only actions. Providing no members and a zero threshold disables the council.
+    /// Replacing the council invalidates all outstanding approvals. This instruction can only be
+    /// called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`       - `UpdateCouncil` context.
+    /// * `members`   - Council members (up to 8).
+    /// * `threshold` - Number of member approvals required to execute a council action.
+    pub fn update_council(
+        ctx: Context<UpdateCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        processor::update_council(ctx, members, threshold)
+    }
+
+    /// This instruction is used by a council member to approve a council action. The approval
+    /// account for the action is created if it does not exist yet. This instruction can only be
+    /// called by a council member.
+    /// # Arguments
+    ///
+    /// * `ctx`           - `ApproveCouncilAction` context.
+    /// * `action_digest` - Keccak256 hash of the serialized [CouncilAction](state::CouncilAction).
+    pub fn approve_council_action(
+        ctx: Context<ApproveCouncilAction>,
+        action_digest: [u8; 32],
+    ) -> Result<()> {
+        processor::approve_council_action(ctx, action_digest)
+    }
+
+    /// This instruction is used to execute a council action once the council's threshold of
+    /// approvals has been met, in place of the `owner`. The approval account is closed. This
+    /// instruction can only be called by a council member.
+    /// # Arguments
+    ///
+    /// * `ctx`    - `ExecuteCouncilAction` context.
+    /// * `action` - Approved action to execute.
+    pub fn execute_council_action(
+        ctx: Context<ExecuteCouncilAction>,
+        action: state::CouncilAction,
+    ) -> Result<()> {
+        processor::execute_council_action(ctx, action)
+    }
+
+    /// This instruction is used to grant an admin role to the `authority`, which can then perform
+    /// the instructions that the role allows. A `RoleGranted` event is emitted. This instruction can
+    /// only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `GrantRole` context.
+    /// * `role` - Role to grant.
+    pub fn grant_role(ctx: Context<GrantRole>, role: common::admin::Role) -> Result<()> {
+        processor::grant_role(ctx, role)
+    }
+
+    /// This instruction is used to revoke an admin role from the `authority`. A `RoleRevoked` event
+    /// is emitted. This instruction can only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `RevokeRole` context.
+    /// * `role` - Role to revoke.
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: common::admin::Role) -> Result<()> {
+        processor::revoke_role(ctx, role)
+    }
+
+    /// This instruction is used for executing logic during an upgrade. It fails until the upgrade's
+    /// migration has been started and every counted account has been migrated, which prevents the
+    /// upgrade from being committed. Every upgrade must start a migration, even if no accounts need
+    /// to be migrated. This instruction can only be called by the `upgrade_manager_program`.
     /// # Arguments
     ///
     /// * `ctx` - `Migrate` context.
@@ -112,6 +200,32 @@ pub mod token_router {
         processor::migrate(ctx)
     }
 
+    /// This instruction is used to start an account migration after an upgrade. The custodian is
+    /// migrated first if needed. The number of accounts that must be migrated is the number of
+    /// accounts of every versioned type with a new version. A migration cannot be started again
+    /// until the upgrade is committed. This instruction can only be called by the `owner`, which is
+    /// the Upgrade Manager's upgrade authority until the upgrade is committed.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `StartMigration` context.
+    /// * `args` - `StartMigrationArgs`, which specifies the number of accounts of each type.
+    pub fn start_migration(ctx: Context<StartMigration>, args: StartMigrationArgs) -> Result<()> {
+        processor::start_migration(ctx, args)
+    }
+
+    /// This instruction is used to migrate a batch of versioned accounts (passed in as remaining
+    /// accounts) to their current versions after an upgrade. Migrating an account that is already
+    /// migrated does nothing, so batches can be retried across transactions. Accounts can only be
+    /// migrated once the migration has started. This instruction can be called by anyone.
+    /// # Arguments
+    ///
+    /// * `ctx` - `MigrateAccounts` context.
+    pub fn migrate_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateAccounts<'info>>,
+    ) -> Result<()> {
+        processor::migrate_accounts(ctx)
+    }
+
     /// This instruction is used to prepare a `PrepareOrder` account for a market order. The `amount_in`
     /// is transferred from the `source` account to the `prepared_custody_token` account. Anyone
     /// can call this instruction.
@@ -126,6 +240,21 @@ pub mod token_router {
         processor::prepare_market_order(ctx, args)
     }
 
+    /// This instruction is used to prepare a `FastMarketOrder`. Like `prepare_market_order`, the
+    /// order's tokens are transferred to a `prepared_custody_token` account. The order's max fee
+    /// is validated against the `Custodian` account's fast transfer parameters, whose init auction
+    /// fee is written to the `PreparedOrder` account.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `PrepareFastMarketOrder` context.
+    /// * `args` - `PrepareFastMarketOrderArgs` struct, see `prepare_fast.rs` for more info.
+    pub fn prepare_fast_market_order(
+        ctx: Context<PrepareFastMarketOrder>,
+        args: PrepareFastMarketOrderArgs,
+    ) -> Result<()> {
+        processor::prepare_fast_market_order(ctx, args)
+    }
+
     /// This instruction is used to close a `PreparedOrder` account. This allows users to cancel
     /// an outbound transfer in case the order is no longer needed, or they made a mistake
     /// in the order. The `prepared_custody_token` account is closed and the tokens are refunded
@@ -139,9 +268,8 @@ pub mod token_router {
 
     /// This instruction is used to place a `MarketOrder`. This order type transfers tokens
     /// from Solana to another registered Token Router endpoint on a different chain. This
-    /// instruction requires a `prepared_market_order` account to be present. Note: this
-    /// is the only order type on the Solana Token Router currently, and does not pass
-    /// through the matching engine.
+    /// instruction requires a `prepared_market_order` account to be present. This order type
+    /// does not pass through the matching engine.
     /// # Arguments
     ///
     /// * `ctx` - `PlaceMarketOrder` context.
@@ -149,6 +277,55 @@ pub mod token_router {
         processor::place_market_order_cctp(ctx)
     }
 
+    /// This instruction is used to place a `FastMarketOrder`. A `FastMarketOrder` message is
+    /// published, which the matching engine uses to start an auction for the order. Because the
+    /// matching engine lives on Solana, the prepared order's tokens are deposited directly into the
+    /// matching engine (see `deposit_local_fast_order`) instead of being burned via CCTP. The order
+    /// must have been prepared using `prepare_fast_market_order`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `PlaceFastMarketOrder` context.
+    pub fn place_fast_market_order(ctx: Context<PlaceFastMarketOrder>) -> Result<()> {
+        processor::place_fast_market_order(ctx)
+    }
+
+    /// This instruction is used to convert a prepared market order into a limit order. The funds
+    /// remain escrowed in the `prepared_custody_token` account until a solver fills the order or
+    /// the order expires. This instruction can only be called by the `order_sender`.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `PlaceLimitOrder` context.
+    /// * `args` - `PlaceLimitOrderArgs` struct, see `limit_order/place.rs` for more info.
+    pub fn place_limit_order(
+        ctx: Context<PlaceLimitOrder>,
+        args: PlaceLimitOrderArgs,
+    ) -> Result<()> {
+        processor::place_limit_order(ctx, args)
+    }
+
+    /// This instruction is used by a solver to fill a limit order before it expires. The solver
+    /// delivers the escrowed amount less its `fee` from its own token account, which is transferred
+    /// via CCTP to the target chain's Token Router. In exchange, the solver receives the escrowed
+    /// amount. The `fee` cannot exceed the fee currently allowed by the limit order, which grows
+    /// linearly from zero when the order is placed to the order's max fee at expiry.
+    /// # Arguments
+    ///
+    /// * `ctx` - `FillLimitOrderCctp` context.
+    /// * `fee` - Fee collected by the solver.
+    pub fn fill_limit_order_cctp(ctx: Context<FillLimitOrderCctp>, fee: u64) -> Result<()> {
+        processor::fill_limit_order_cctp(ctx, fee)
+    }
+
+    /// This instruction is used to refund the escrowed funds of an expired limit order to the
+    /// `refund_token` account. The `prepared_order`, `limit_order` and `prepared_custody_token`
+    /// accounts are closed. Anyone can call this instruction.
+    /// # Arguments
+    ///
+    /// * `ctx` - `CloseExpiredLimitOrder` context.
+    pub fn close_expired_limit_order(ctx: Context<CloseExpiredLimitOrder>) -> Result<()> {
+        processor::close_expired_limit_order(ctx)
+    }
+
     /// This instruction is used to redeem a `Fill` VAA and redeem tokens from a CCTP transfer. After
     /// the tokens are minted by the CCTP program, they are transferred to a token custody account.
     /// The `prepared_fill` account is populated with information from the `Fill` vaa. This
@@ -0,0 +1,48 @@
+use crate::{composite::*, error::TokenRouterError, state::CouncilApproval};
+use anchor_lang::prelude::*;
+use common::admin::utils::council;
+
+#[derive(Accounts)]
+#[instruction(action_digest: [u8; 32])]
+pub struct ApproveCouncilAction<'info> {
+    /// Council member approving the action. This member pays for the approval account if it does
+    /// not exist yet.
+    #[account(mut)]
+    member: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + CouncilApproval::INIT_SPACE,
+        seeds = [
+            CouncilApproval::SEED_PREFIX,
+            action_digest.as_ref(),
+        ],
+        bump,
+    )]
+    council_approval: Account<'info, CouncilApproval>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn approve_council_action(
+    ctx: Context<ApproveCouncilAction>,
+    action_digest: [u8; 32],
+) -> Result<()> {
+    let member_index = council::only_council_member(
+        &ctx.accounts.custodian,
+        &ctx.accounts.member,
+        error!(TokenRouterError::CouncilMemberOnly),
+    )?;
+
+    let council_approval = &mut ctx.accounts.council_approval;
+    council_approval.bump = ctx.bumps.council_approval;
+    council_approval.action_digest = action_digest;
+
+    council::approve(&ctx.accounts.custodian, council_approval, member_index);
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::{
+    error::TokenRouterError,
+    state::{CouncilAction, CouncilApproval, Custodian},
+};
+use anchor_lang::prelude::*;
+use common::admin::utils::{council, pending_owner};
+
+#[derive(Accounts)]
+#[instruction(action: CouncilAction)]
+pub struct ExecuteCouncilAction<'info> {
+    /// Council member executing the action, who receives the approval account's lamports.
+    #[account(
+        mut,
+        constraint = {
+            council::only_council_member(
+                &custodian,
+                &member,
+                error!(TokenRouterError::CouncilMemberOnly)
+            )?;
+
+            true
+        }
+    )]
+    member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    custodian: Box<Account<'info, Custodian>>,
+
+    /// Approvals for this action, which must meet the council's threshold. This account is closed
+    /// so the approvals cannot be replayed.
+    #[account(
+        mut,
+        close = member,
+        seeds = [
+            CouncilApproval::SEED_PREFIX,
+            council_approval.action_digest.as_ref(),
+        ],
+        bump = council_approval.bump,
+        constraint = council::only_council_approved(
+            &custodian,
+            &council_approval,
+            &action.digest()?,
+            error!(TokenRouterError::CouncilActionNotApproved)
+        )?
+    )]
+    council_approval: Account<'info, CouncilApproval>,
+}
+
+pub fn execute_council_action(
+    ctx: Context<ExecuteCouncilAction>,
+    action: CouncilAction,
+) -> Result<()> {
+    let custodian = &mut ctx.accounts.custodian;
+
+    match action {
+        CouncilAction::SetPause { paused } => {
+            custodian.paused = paused;
+            custodian.paused_set_by = ctx.accounts.member.key();
+        }
+        CouncilAction::SubmitOwnershipTransferRequest { new_owner } => {
+            require_keys_neq!(
+                new_owner,
+                Pubkey::default(),
+                TokenRouterError::InvalidNewOwner
+            );
+            require_keys_neq!(new_owner, custodian.owner, TokenRouterError::AlreadyOwner);
+
+            pending_owner::transfer_ownership(custodian, &new_owner);
+        }
+        CouncilAction::UpdateCouncil { members, threshold } => {
+            require!(
+                council::update_council(custodian, &members, threshold),
+                TokenRouterError::InvalidCouncil
+            );
+        }
+    }
+
+    // Done.
+    Ok(())
+}
@@ -1,6 +1,11 @@
-use crate::{composite::*, error::TokenRouterError, state::Custodian};
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{Custodian, MigratedVersions, PreparedFill},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use common::migration::{VersionHeader, Versioned};
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
 
 #[derive(Accounts)]
@@ -104,11 +109,22 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     }
 
     ctx.accounts.custodian.set_inner(Custodian {
+        header: VersionHeader {
+            version: Custodian::VERSION,
+        },
         paused: false,
         paused_set_by: owner,
         owner,
         pending_owner: None,
         owner_assistant: ctx.accounts.owner_assistant.key(),
+        council: Default::default(),
+        roles: Default::default(),
+        fast_transfer_parameters: Default::default(),
+        migration: Default::default(),
+        // There are no accounts to migrate to their current versions.
+        migrated_versions: MigratedVersions {
+            prepared_fill: PreparedFill::VERSION,
+        },
     });
 
     // Done.
@@ -1,13 +1,193 @@
-use crate::composite::*;
-use anchor_lang::prelude::*;
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{Custodian, PreparedFill},
+};
+use anchor_lang::{prelude::*, Discriminator};
+use common::migration::{self, Versioned};
 
 #[derive(Accounts)]
 pub struct Migrate<'info> {
-    admin: OwnerOnly<'info>,
+    admin: OwnerOnlyMut<'info>,
 }
 
-pub fn migrate(_ctx: Context<Migrate>) -> Result<()> {
-    msg!("Nothing to migrate");
+pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+    let custodian: &mut Custodian = &mut ctx.accounts.admin.custodian;
 
+    // The Upgrade Manager invokes this instruction to commit an upgrade, so an upgrade cannot be
+    // committed until its migration has been started and every counted account has been migrated.
+    require!(
+        custodian.migration.is_complete(),
+        TokenRouterError::MigrationIncomplete
+    );
+
+    // The next upgrade must start its own migration.
+    custodian.migration.finish();
+
+    // Done.
+    Ok(())
+}
+
+/// Number of accounts of each versioned account type, which are found off-chain after the program
+/// is upgraded. Only the counts of account types with new versions are used.
+///
+/// NOTE: Accounts with old versions cannot be closed until they are migrated and new accounts are
+/// created with their current versions, so these counts do not change during a migration.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct StartMigrationArgs {
+    pub num_prepared_fills: u32,
+}
+
+#[derive(Accounts)]
+pub struct StartMigration<'info> {
+    /// This account pays for reallocating the custodian.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    owner: Signer<'info>,
+
+    /// The custodian is migrated first (if needed) because it stores the migration's progress.
+    ///
+    /// CHECK: Mutable. Seeds must be \["emitter"\].
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn start_migration(ctx: Context<StartMigration>, args: StartMigrationArgs) -> Result<()> {
+    let custodian_info = ctx.accounts.custodian.to_account_info();
+
+    migration::migrate_account::<Custodian>(
+        &custodian_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    let mut custodian = {
+        let data = custodian_info.try_borrow_data()?;
+        Custodian::try_deserialize(&mut &data[..])?
+    };
+
+    require_keys_eq!(
+        ctx.accounts.owner.key(),
+        custodian.owner,
+        TokenRouterError::OwnerOnly
+    );
+    require!(
+        !custodian.migration.started,
+        TokenRouterError::MigrationInProgress
+    );
+
+    let StartMigrationArgs { num_prepared_fills } = args;
+
+    // Only account types with new versions need to be migrated. Accounts created from now on are
+    // created with their current versions.
+    let mut num_remaining = 0u32;
+
+    let migrated_versions = &mut custodian.migrated_versions;
+    if migrated_versions.prepared_fill < PreparedFill::VERSION {
+        num_remaining = num_remaining.saturating_add(num_prepared_fills);
+        migrated_versions.prepared_fill = PreparedFill::VERSION;
+    }
+
+    custodian.migration.start(num_remaining);
+
+    custodian.try_serialize(&mut &mut custodian_info.try_borrow_mut_data()?[..])?;
+
+    // Done.
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccounts<'info> {
+    /// This account pays for reallocating migrated accounts.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// The custodian is migrated when the migration starts, so it cannot be deserialized until
+    /// then.
+    ///
+    /// CHECK: Mutable. Seeds must be \["emitter"\].
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn migrate_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateAccounts<'info>>,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let custodian_info = ctx.accounts.custodian.to_account_info();
+
+    let mut custodian = {
+        let data = custodian_info.try_borrow_data()?;
+        require_eq!(
+            migration::account_version::<Custodian>(&custodian_info.key(), &data)?,
+            Custodian::VERSION,
+            TokenRouterError::MigrationNotStarted
+        );
+
+        Custodian::try_deserialize(&mut &data[..])?
+    };
+
+    require!(
+        custodian.migration.started,
+        TokenRouterError::MigrationNotStarted
+    );
+
+    // Accounts that are already at their current version are skipped, so batches can be retried.
+    for acc_info in ctx.remaining_accounts {
+        migrate_versioned_account(acc_info, &payer, &system_program, &mut custodian)?;
+    }
+
+    custodian.try_serialize(&mut &mut custodian_info.try_borrow_mut_data()?[..])?;
+
+    // Done.
+    Ok(())
+}
+
+/// Migrate an account of any versioned type owned by this program (other than the custodian),
+/// updating the migration's progress. Each versioned account type must be added here and counted
+/// when the migration starts.
+fn migrate_versioned_account<'info>(
+    acc_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    custodian: &mut Custodian,
+) -> Result<()> {
+    require!(
+        acc_info.owner == &crate::id() && acc_info.is_writable,
+        TokenRouterError::AccountNotMigratable
+    );
+
+    let discriminator = acc_info
+        .try_borrow_data()?
+        .get(..8)
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| error!(TokenRouterError::AccountNotMigratable))?;
+
+    let legacy_version = if discriminator == PreparedFill::DISCRIMINATOR {
+        migration::migrate_account::<PreparedFill>(acc_info, payer, system_program)?
+    } else {
+        return err!(TokenRouterError::AccountNotMigratable);
+    };
+
+    // Every account with an old version was counted when the migration started.
+    if legacy_version.is_some() {
+        custodian.migration.record_migrated();
+    }
+
+    // Done.
     Ok(())
 }
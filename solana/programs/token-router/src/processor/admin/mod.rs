@@ -1,6 +1,9 @@
 // mod authorize_upgrade;
 // pub use authorize_upgrade::*;
 
+mod council;
+pub use council::*;
+
 mod initialize;
 pub use initialize::*;
 
@@ -10,6 +13,9 @@ pub use migrate::*;
 mod ownership_transfer_request;
 pub use ownership_transfer_request::*;
 
+mod role;
+pub use role::*;
+
 mod set_pause;
 pub use set_pause::*;
 
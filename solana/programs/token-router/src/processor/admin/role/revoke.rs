@@ -0,0 +1,27 @@
+use crate::{composite::*, error::TokenRouterError};
+use anchor_lang::prelude::*;
+use common::admin::{utils::role, Role};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RevokeRole<'info> {
+    admin: OwnerOnlyMut<'info>,
+
+    /// CHECK: Must hold the role being revoked.
+    authority: UncheckedAccount<'info>,
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+
+    require!(
+        role::revoke_role(&mut ctx.accounts.admin.custodian, &authority, role),
+        TokenRouterError::RoleNotGranted
+    );
+
+    // Emit event reflecting the revoked role.
+    emit_cpi!(crate::events::RoleRevoked { authority, role });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::{composite::*, error::TokenRouterError, state::FastTransferParameters};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateFastTransferParameters<'info> {
+    admin: FeeManagerMut<'info>,
+}
+
+pub fn update_fast_transfer_parameters(
+    ctx: Context<UpdateFastTransferParameters>,
+    params: FastTransferParameters,
+) -> Result<()> {
+    // The max amount must be able to cover the fees.
+    require!(
+        params.max_amount > params.base_fee.saturating_add(params.init_auction_fee),
+        TokenRouterError::InvalidFastTransferParameters
+    );
+
+    ctx.accounts.admin.custodian.fast_transfer_parameters = params;
+
+    // Done.
+    Ok(())
+}
@@ -1,2 +1,8 @@
+mod council;
+pub use council::*;
+
+mod fast_transfer_parameters;
+pub use fast_transfer_parameters::*;
+
 mod owner_assistant;
 pub use owner_assistant::*;
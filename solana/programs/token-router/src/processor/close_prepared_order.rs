@@ -1,6 +1,7 @@
 use crate::{
     composite::*,
-    state::{Custodian, PreparedOrder},
+    error::TokenRouterError,
+    state::{Custodian, OrderType, PreparedOrder},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -14,9 +15,14 @@ pub struct ClosePreparedOrder<'info> {
     #[account(address = prepared_order.order_sender)]
     order_sender: Signer<'info>,
 
+    /// Limit orders remain escrowed until they are filled or expired.
     #[account(
         mut,
         close = prepared_by,
+        constraint = !matches!(
+            prepared_order.order_type,
+            OrderType::Limit { .. }
+        ) @ TokenRouterError::InvalidOrderType,
     )]
     prepared_order: Account<'info, PreparedOrder>,
 
@@ -0,0 +1,102 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    events::LimitOrderExpired,
+    state::{Custodian, LimitOrder, PreparedOrder},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+/// Accounts required for [close_expired_limit_order].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseExpiredLimitOrder<'info> {
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+    )]
+    prepared_order: Box<Account<'info, PreparedOrder>>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        seeds = [
+            LimitOrder::SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump = limit_order.seeds.bump,
+        constraint = limit_order.is_expired(
+            Clock::get().unwrap().unix_timestamp
+        ) @ TokenRouterError::LimitOrderNotExpired,
+    )]
+    limit_order: Box<Account<'info, LimitOrder>>,
+
+    /// CHECK: This payer must be the same one encoded in the prepared order.
+    #[account(
+        mut,
+        address = prepared_order.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    /// CHECK: This account must be the same one encoded in the prepared order.
+    #[account(
+        mut,
+        address = prepared_order.refund_token,
+    )]
+    refund_token: UncheckedAccount<'info>,
+
+    /// Custody token account, which escrows the limit order's funds. This account will be closed
+    /// at the end of this instruction.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_order\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump = prepared_order.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+/// This instruction refunds the escrowed funds of an expired limit order to the prepared order's
+/// refund token account. Anyone can call this instruction once the order has expired.
+pub fn close_expired_limit_order(ctx: Context<CloseExpiredLimitOrder>) -> Result<()> {
+    let amount = ctx.accounts.prepared_custody_token.amount;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.prepared_custody_token.to_account_info(),
+                to: ctx.accounts.refund_token.to_account_info(),
+                authority: ctx.accounts.custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(LimitOrderExpired {
+        prepared_order: ctx.accounts.prepared_order.key(),
+        limit_order: ctx.accounts.limit_order.key(),
+        refund_token: ctx.accounts.refund_token.key(),
+        amount,
+    });
+
+    // Finally close token account.
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.prepared_custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: ctx.accounts.custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
@@ -0,0 +1,8 @@
+mod close_expired;
+pub use close_expired::*;
+
+mod fill_cctp;
+pub use fill_cctp::*;
+
+mod place;
+pub use place::*;
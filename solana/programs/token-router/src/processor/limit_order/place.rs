@@ -0,0 +1,126 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    events::LimitOrderPlaced,
+    state::{LimitOrder, LimitOrderInfo, LimitOrderSeeds, OrderType, PreparedOrder},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+/// Accounts required for [place_limit_order].
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: PlaceLimitOrderArgs)]
+pub struct PlaceLimitOrder<'info> {
+    /// This account must be the same pubkey as the one who prepared the order.
+    #[account(
+        mut,
+        address = prepared_order.prepared_by,
+    )]
+    payer: Signer<'info>,
+
+    #[account(constraint = !custodian.paused @ TokenRouterError::Paused)]
+    custodian: CheckedCustodian<'info>,
+
+    /// This signer must be the same one encoded in the prepared order.
+    #[account(address = prepared_order.order_sender)]
+    order_sender: Signer<'info>,
+
+    /// Only market orders can be converted into limit orders.
+    #[account(
+        mut,
+        constraint = matches!(
+            prepared_order.order_type,
+            OrderType::Market { .. }
+        ) @ TokenRouterError::InvalidOrderType,
+    )]
+    prepared_order: Account<'info, PreparedOrder>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LimitOrder::INIT_SPACE,
+        seeds = [
+            LimitOrder::SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump,
+        constraint = {
+            require!(
+                args.expiry > Clock::get().unwrap().unix_timestamp,
+                TokenRouterError::InvalidLimitOrderExpiry
+            );
+
+            // The fee can never exceed the escrowed amount.
+            require!(
+                args.max_fee < prepared_custody_token.amount,
+                TokenRouterError::LimitFeeExceeded
+            );
+
+            true
+        }
+    )]
+    limit_order: Account<'info, LimitOrder>,
+
+    /// CHECK: Seeds must be \["prepared-custody", prepared_order\].
+    #[account(
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump = prepared_order.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Account<'info, token::TokenAccount>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Arguments for [place_limit_order].
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PlaceLimitOrderArgs {
+    /// Maximum fee a solver can collect from the escrowed amount for filling the order. The fee a
+    /// solver is allowed to collect grows linearly from zero to this fee at expiry.
+    pub max_fee: u64,
+
+    /// Unix timestamp after which the order can no longer be filled.
+    pub expiry: i64,
+}
+
+pub fn place_limit_order(ctx: Context<PlaceLimitOrder>, args: PlaceLimitOrderArgs) -> Result<()> {
+    let PlaceLimitOrderArgs { max_fee, expiry } = args;
+
+    let prepared_order = &mut ctx.accounts.prepared_order;
+    let prepared_order_key = prepared_order.key();
+    let order_sender = prepared_order.order_sender;
+
+    // The funds stay escrowed in the prepared custody token account. Marking the prepared order as
+    // a limit order prevents it from being placed as a market order or closed by the order sender.
+    prepared_order.info.order_type = match prepared_order.order_type {
+        OrderType::Market { min_amount_out } => OrderType::Limit { min_amount_out },
+        _ => return err!(TokenRouterError::InvalidOrderType),
+    };
+
+    ctx.accounts.limit_order.set_inner(LimitOrder {
+        seeds: LimitOrderSeeds {
+            prepared_order: prepared_order_key,
+            bump: ctx.bumps.limit_order,
+        },
+        info: LimitOrderInfo {
+            max_fee,
+            placed_at: Clock::get()?.unix_timestamp,
+            expiry,
+        },
+    });
+
+    emit_cpi!(LimitOrderPlaced {
+        prepared_order: prepared_order_key,
+        limit_order: ctx.accounts.limit_order.key(),
+        order_sender,
+        amount_in: ctx.accounts.prepared_custody_token.amount,
+        max_fee,
+        expiry,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -1,5 +1,37 @@
 mod place_cctp;
 pub use place_cctp::*;
 
+mod place_fast;
+pub use place_fast::*;
+
 mod prepare;
 pub use prepare::*;
+
+mod prepare_fast;
+pub use prepare_fast::*;
+
+use crate::{error::TokenRouterError, state::FastTransferParameters};
+use anchor_lang::prelude::*;
+
+fn require_valid_fast_market_order(
+    params: &FastTransferParameters,
+    amount_in: u64,
+    max_fee: u64,
+    init_auction_fee: u64,
+) -> Result<()> {
+    require!(params.enabled, TokenRouterError::FastTransferDisabled);
+    require!(
+        amount_in <= params.max_amount,
+        TokenRouterError::AmountTooLarge
+    );
+
+    // The max fee must cover the base fee and init auction fee with at least one unit left over
+    // for the auction's offer price.
+    require!(
+        max_fee > params.base_fee.saturating_add(init_auction_fee),
+        TokenRouterError::InvalidMaxFee
+    );
+    require!(amount_in > max_fee, TokenRouterError::InsufficientAmount);
+
+    Ok(())
+}
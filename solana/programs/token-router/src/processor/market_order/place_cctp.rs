@@ -1,7 +1,7 @@
 use crate::{
     composite::*,
     error::TokenRouterError,
-    state::{Custodian, PreparedOrder},
+    state::{Custodian, OrderType, PreparedOrder},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -13,6 +13,7 @@ use common::{
     },
     wormhole_io::TypePrefixedPayload,
 };
+use matching_engine::state::EndpointFlow;
 
 /// Accounts required for [place_market_order_cctp].
 #[derive(Accounts)]
@@ -31,9 +32,14 @@ pub struct PlaceMarketOrderCctp<'info> {
     #[account(constraint = !custodian.paused @ TokenRouterError::Paused)]
     custodian: CheckedCustodian<'info>,
 
+    /// Fast market orders must be placed using [place_fast_market_order](super::place_fast_market_order).
     #[account(
         mut,
         close = prepared_by,
+        constraint = matches!(
+            prepared_order.order_type,
+            OrderType::Market { .. }
+        ) @ TokenRouterError::InvalidOrderType,
     )]
     prepared_order: Box<Account<'info, PreparedOrder>>,
 
@@ -67,6 +73,8 @@ pub struct PlaceMarketOrderCctp<'info> {
                 TokenRouterError::InvalidTargetRouter,
             );
 
+            target_router_endpoint.require_unpaused(EndpointFlow::OutboundCctp)?;
+
             true
         }
     )]
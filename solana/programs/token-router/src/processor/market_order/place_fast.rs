@@ -0,0 +1,276 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{Custodian, OrderType, PreparedOrder},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{
+    wormhole_cctp_solana::wormhole::core_bridge_program, wormhole_io::TypePrefixedPayload,
+};
+use matching_engine::state::EndpointFlow;
+
+/// Accounts required for [place_fast_market_order].
+#[derive(Accounts)]
+pub struct PlaceFastMarketOrder<'info> {
+    /// This account must be the same pubkey as the one who prepared the order.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: This account must equal the prepared order's `prepared_by` pubkey.
+    #[account(
+        mut,
+        address = prepared_order.prepared_by
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    #[account(constraint = !custodian.paused @ TokenRouterError::Paused)]
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        constraint = matches!(
+            prepared_order.order_type,
+            OrderType::FastMarket { .. }
+        ) @ TokenRouterError::InvalidOrderType,
+    )]
+    prepared_order: Box<Account<'info, PreparedOrder>>,
+
+    /// Prepared custody token account. This account will be closed at the end of this
+    /// instruction after its tokens are deposited into the Matching Engine.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_order\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump = prepared_order.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// The target router endpoint must be registered so the Matching Engine can fulfill the order.
+    #[account(
+        constraint = {
+            require_eq!(
+                target_router_endpoint.chain,
+                prepared_order.target_chain,
+                TokenRouterError::InvalidTargetRouter,
+            );
+
+            target_router_endpoint.require_unpaused(EndpointFlow::OutboundCctp)?;
+
+            true
+        }
+    )]
+    target_router_endpoint: RegisteredEndpoint<'info>,
+
+    /// CHECK: Seeds must be \["emitter"\] (Matching Engine program).
+    matching_engine_custodian: UncheckedAccount<'info>,
+
+    /// This program's router endpoint registered in the Matching Engine.
+    ///
+    /// CHECK: Seeds must be \["endpoint", SOLANA_CHAIN.to_be_bytes()\] (Matching Engine program).
+    local_router_endpoint: UncheckedAccount<'info>,
+
+    /// Matching Engine account recording this deposit.
+    ///
+    /// CHECK: Mutable. Seeds must be \["local-fast-order", sequence.to_be_bytes()\].
+    #[account(mut)]
+    local_fast_order: UncheckedAccount<'info>,
+
+    /// Matching Engine custody token account holding the deposited tokens.
+    ///
+    /// CHECK: Mutable. Seeds must be \["deposit-custody", local_fast_order\].
+    #[account(mut)]
+    local_fast_order_custody_token: UncheckedAccount<'info>,
+
+    usdc: Usdc<'info>,
+
+    /// CHECK: Seeds must be \["Bridge"\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    core_bridge_config: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["core-msg", prepared_order\].
+    #[account(
+        mut,
+        seeds = [
+            common::CORE_MESSAGE_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump,
+    )]
+    core_message: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["Sequence"\, custodian] (Wormhole Core Bridge program).
+    #[account(mut)]
+    core_emitter_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["fee_collector"\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    core_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal Wormhole Core Bridge program ID.
+    #[account(address = core_bridge_program::id())]
+    core_bridge_program: UncheckedAccount<'info>,
+
+    matching_engine_program: Program<'info, matching_engine::program::MatchingEngine>,
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+
+    /// CHECK: Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.
+    #[account(address = solana_program::sysvar::clock::id())]
+    clock: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.
+    #[account(address = solana_program::sysvar::rent::id())]
+    rent: UncheckedAccount<'info>,
+}
+
+/// This instruction publishes a Wormhole message encoding the fast market order, which the
+/// Matching Engine uses to start an auction. Because the Matching Engine lives on Solana, the
+/// order's tokens are not burned via CCTP. Instead, they are deposited into the Matching Engine,
+/// which uses this deposit (keyed by the fast market order's Wormhole sequence) in place of a CCTP
+/// transfer and finalized VAA to prepare the order response.
+pub fn place_fast_market_order(ctx: Context<PlaceFastMarketOrder>) -> Result<()> {
+    let (min_amount_out, max_fee, init_auction_fee, deadline) =
+        match ctx.accounts.prepared_order.order_type {
+            OrderType::FastMarket {
+                min_amount_out,
+                max_fee,
+                init_auction_fee,
+                deadline,
+            } => (min_amount_out, max_fee, init_auction_fee, deadline),
+            _ => return err!(TokenRouterError::InvalidOrderType),
+        };
+
+    let fast_transfer_parameters = &ctx.accounts.custodian.fast_transfer_parameters;
+    let amount_in = ctx.accounts.prepared_custody_token.amount;
+
+    // Fast transfer parameters may have changed since the order was prepared, so we need to make
+    // sure this order is still valid.
+    super::require_valid_fast_market_order(
+        fast_transfer_parameters,
+        amount_in,
+        max_fee,
+        init_auction_fee,
+    )?;
+
+    // The Core Bridge's sequence tracker stores the emitter's next sequence as a little-endian
+    // u64, which will be the fast market order's sequence. This account does not exist until the
+    // emitter publishes its first message.
+    let fast_vaa_sequence = ctx
+        .accounts
+        .core_emitter_sequence
+        .try_borrow_data()?
+        .get(..8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .unwrap_or_default();
+
+    let redeemer_message = std::mem::take(&mut ctx.accounts.prepared_order.redeemer_message);
+
+    let payer = &ctx.accounts.payer;
+    let custodian = &ctx.accounts.custodian;
+    let prepared_order = &ctx.accounts.prepared_order;
+    let prepared_custody_token = &ctx.accounts.prepared_custody_token;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    let order_info = &prepared_order.info;
+
+    // Publish the fast market order. The max fee encoded in this message excludes the init auction
+    // fee. Because the Matching Engine pays out solvers against the deposit made below, this
+    // message is only observed once the deposit is finalized.
+    core_bridge_program::cpi::post_message(
+        CpiContext::new_with_signer(
+            ctx.accounts.core_bridge_program.to_account_info(),
+            core_bridge_program::cpi::PostMessage {
+                payer: payer.to_account_info(),
+                message: ctx.accounts.core_message.to_account_info(),
+                emitter: custodian.to_account_info(),
+                config: ctx.accounts.core_bridge_config.to_account_info(),
+                emitter_sequence: ctx.accounts.core_emitter_sequence.to_account_info(),
+                fee_collector: ctx.accounts.core_fee_collector.to_account_info(),
+                system_program: system_program.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            &[
+                Custodian::SIGNER_SEEDS,
+                &[
+                    common::CORE_MESSAGE_SEED_PREFIX,
+                    prepared_order.key().as_ref(),
+                    &[ctx.bumps.core_message],
+                ],
+            ],
+        ),
+        core_bridge_program::cpi::PostMessageArgs {
+            nonce: common::WORMHOLE_MESSAGE_NONCE,
+            payload: common::messages::FastMarketOrder {
+                amount_in,
+                min_amount_out: min_amount_out.unwrap_or_default(),
+                target_chain: order_info.target_chain,
+                redeemer: order_info.redeemer,
+                sender: order_info.order_sender.to_bytes(),
+                refund_address: order_info.refund_token.to_bytes(),
+                max_fee: max_fee.saturating_sub(init_auction_fee),
+                init_auction_fee,
+                deadline,
+                redeemer_message: redeemer_message
+                    .try_into()
+                    .map_err(|_| TokenRouterError::RedeemerMessageTooLarge)?,
+                destination_asset: None,
+            }
+            .to_vec(),
+            commitment: core_bridge_program::Commitment::Finalized,
+        },
+    )?;
+
+    // Deposit the order's tokens into the Matching Engine.
+    matching_engine::cpi::deposit_local_fast_order(
+        CpiContext::new_with_signer(
+            ctx.accounts.matching_engine_program.to_account_info(),
+            matching_engine::cpi::accounts::DepositLocalFastOrder {
+                payer: payer.to_account_info(),
+                custodian: matching_engine::cpi::accounts::CheckedCustodian {
+                    custodian: ctx.accounts.matching_engine_custodian.to_account_info(),
+                },
+                token_router_emitter: custodian.to_account_info(),
+                local_endpoint: matching_engine::cpi::accounts::LiveRouterEndpoint {
+                    endpoint: ctx.accounts.local_router_endpoint.to_account_info(),
+                },
+                local_fast_order: ctx.accounts.local_fast_order.to_account_info(),
+                source_token: prepared_custody_token.to_account_info(),
+                custody_token: ctx
+                    .accounts
+                    .local_fast_order_custody_token
+                    .to_account_info(),
+                usdc: matching_engine::cpi::accounts::Usdc {
+                    mint: ctx.accounts.usdc.to_account_info(),
+                },
+                token_program: token_program.to_account_info(),
+                system_program: system_program.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        matching_engine::DepositLocalFastOrderArgs {
+            fast_vaa_sequence,
+            amount_in,
+            base_fee: fast_transfer_parameters.base_fee,
+        },
+    )?;
+
+    // Finally close token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: prepared_custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
@@ -6,6 +6,7 @@ use crate::{
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::TRANSFER_AUTHORITY_SEED_PREFIX;
+use matching_engine::state::EndpointFlow;
 use solana_program::keccak;
 
 /// Accounts required for [prepare_market_order].
@@ -125,6 +126,8 @@ pub struct PrepareMarketOrder<'info> {
                 TokenRouterError::InvalidTargetRouter,
             );
 
+            target_router_endpoint.require_unpaused(EndpointFlow::OutboundCctp)?;
+
             true
         }
     )]
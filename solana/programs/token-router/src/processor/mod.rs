@@ -7,6 +7,9 @@ pub use close_prepared_order::*;
 mod consume_prepared_fill;
 pub use consume_prepared_fill::*;
 
+mod limit_order;
+pub use limit_order::*;
+
 mod market_order;
 pub use market_order::*;
 
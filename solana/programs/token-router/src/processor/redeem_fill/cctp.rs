@@ -7,12 +7,14 @@ use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::{
     messages::raw::{LiquidityLayerDepositMessage, LiquidityLayerMessage, MessageToVec},
+    migration::{VersionHeader, Versioned},
     wormhole_cctp_solana::{
         self,
         cctp::{message_transmitter_program, token_messenger_minter_program},
         cpi::ReceiveMessageArgs,
     },
 };
+use matching_engine::state::EndpointFlow;
 
 #[derive(Accounts)]
 struct CctpReceiveMessage<'info> {
@@ -99,7 +101,7 @@ pub struct RedeemCctpFill<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = prepared_fill,
         seeds = [
             crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
@@ -109,7 +111,7 @@ pub struct RedeemCctpFill<'info> {
     )]
     prepared_custody_token: Account<'info, token::TokenAccount>,
 
-    usdc: Usdc<'info>,
+    mint: SupportedCctpMint<'info>,
 
     /// Registered emitter account representing a Circle Integration on another network.
     ///
@@ -128,11 +130,25 @@ pub struct RedeemCctpFill<'info> {
                 TokenRouterError::InvalidSourceRouter
             );
 
+            source_router_endpoint.require_unpaused(EndpointFlow::LocalFills)?;
+
             true
         }
     )]
     source_router_endpoint: RegisteredEndpoint<'info>,
 
+    /// The mint recipient's mint must be the fill's mint.
+    #[account(
+        constraint = {
+            require_keys_eq!(
+                cctp.mint_recipient.mint,
+                mint.key(),
+                TokenRouterError::CctpMintMismatch
+            );
+
+            true
+        }
+    )]
     cctp: CctpReceiveMessage<'info>,
 
     token_program: Program<'info, token::Token>,
@@ -239,6 +255,9 @@ fn handle_redeem_fill_cctp(ctx: Context<RedeemCctpFill>, args: CctpMessageArgs)
 
     // Set prepared fill data.
     ctx.accounts.prepared_fill.set_inner(PreparedFill {
+        header: VersionHeader {
+            version: PreparedFill::VERSION,
+        },
         seeds: PreparedFillSeeds {
             fill_source: ctx.accounts.fill_vaa.key(),
             bump: ctx.bumps.prepared_fill,
@@ -251,6 +270,8 @@ fn handle_redeem_fill_cctp(ctx: Context<RedeemCctpFill>, args: CctpMessageArgs)
             source_chain: fill.source_chain(),
             order_sender: fill.order_sender(),
             timestamp: vaa.timestamp().into(),
+            mint: ctx.accounts.mint.key(),
+            amount,
         },
         redeemer_message: fill.message_to_vec(),
     });
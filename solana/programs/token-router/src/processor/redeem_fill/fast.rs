@@ -4,6 +4,7 @@ use crate::{
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use common::migration::{VersionHeader, Versioned};
 use matching_engine::state::FastFill;
 
 /// Accounts required for [redeem_fast_fill].
@@ -47,7 +48,7 @@ pub struct RedeemFastFill<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = prepared_fill,
         seeds = [
             crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
@@ -57,7 +58,10 @@ pub struct RedeemFastFill<'info> {
     )]
     prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
 
-    usdc: Usdc<'info>,
+    /// CHECK: This address must equal the fast fill's mint, which is either the auction's mint or
+    /// the destination asset requested by the order.
+    #[account(address = fast_fill.info.mint)]
+    mint: UncheckedAccount<'info>,
 
     /// CHECK: Seeds must be \["emitter"] (Matching Engine program).
     matching_engine_custodian: UncheckedAccount<'info>,
@@ -73,6 +77,19 @@ pub struct RedeemFastFill<'info> {
     #[account(mut)]
     matching_engine_local_custody_token: UncheckedAccount<'info>,
 
+    /// This account must be provided if the fast fill delivers a destination asset.
+    ///
+    /// CHECK: Mutable. Seeds must be \["destination-custody", fast_fill.key()\] (Matching Engine
+    /// program).
+    #[account(mut)]
+    matching_engine_destination_custody_token: Option<UncheckedAccount<'info>>,
+
+    /// This account must be provided if the fast fill delivers a destination asset.
+    ///
+    /// CHECK: Mutable. This address must equal the fast fill's `prepared_by`.
+    #[account(mut)]
+    fast_fill_prepared_by: Option<UncheckedAccount<'info>>,
+
     /// CHECK: Seeds must be \["__event_authority"] (Matching Engine program).
     matching_engine_event_authority: UncheckedAccount<'info>,
 
@@ -114,6 +131,16 @@ fn handle_redeem_fast_fill(ctx: Context<RedeemFastFill>) -> Result<()> {
                 .accounts
                 .matching_engine_local_custody_token
                 .to_account_info(),
+            destination_custody_token: ctx
+                .accounts
+                .matching_engine_destination_custody_token
+                .as_ref()
+                .map(|acc| acc.to_account_info()),
+            fast_fill_prepared_by: ctx
+                .accounts
+                .fast_fill_prepared_by
+                .as_ref()
+                .map(|acc| acc.to_account_info()),
             token_program: ctx.accounts.token_program.to_account_info(),
             event_authority: ctx
                 .accounts
@@ -129,6 +156,9 @@ fn handle_redeem_fast_fill(ctx: Context<RedeemFastFill>) -> Result<()> {
 
     // Set prepared fill data.
     ctx.accounts.prepared_fill.set_inner(PreparedFill {
+        header: VersionHeader {
+            version: PreparedFill::VERSION,
+        },
         seeds: PreparedFillSeeds {
             fill_source: fast_fill.key(),
             bump: ctx.bumps.prepared_fill,
@@ -141,6 +171,8 @@ fn handle_redeem_fast_fill(ctx: Context<RedeemFastFill>) -> Result<()> {
             source_chain: fast_fill.seeds.source_chain,
             order_sender: fast_fill.seeds.order_sender,
             timestamp: fast_fill.info.timestamp,
+            mint: fast_fill.info.mint,
+            amount: fast_fill.info.amount,
         },
         redeemer_message,
     });
@@ -1,8 +1,37 @@
 use anchor_lang::prelude::*;
+use common::migration::{self, MigrationProgress, UpgradeFn, VersionHeader, Versioned};
+
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct FastTransferParameters {
+    /// Boolean indicating whether fast market orders can be placed.
+    pub enabled: bool,
+
+    /// The maximum amount that can be transferred using a fast market order.
+    pub max_amount: u64,
+
+    /// Fee paid to whomever prepares the order response in the Matching Engine.
+    pub base_fee: u64,
+
+    /// Fee paid to the initial bidder of an auction.
+    pub init_auction_fee: u64,
+}
+
+/// Versions of the versioned account types (other than the custodian) that accounts are migrated to,
+/// which are recorded when a migration starts.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct MigratedVersions {
+    pub prepared_fill: u8,
+}
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Custodian {
+    pub header: VersionHeader,
+
     /// Boolean indicating whether outbound transfers are paused.
     pub paused: bool,
 
@@ -16,6 +45,22 @@ pub struct Custodian {
     /// Indicate who last set the `paused` value. When the program is first initialized, this is set
     /// to the `owner`.
     pub paused_set_by: Pubkey,
+
+    /// M-of-N council that can approve owner-only actions in place of the owner.
+    pub council: common::admin::CouncilInfo,
+
+    /// Keys that were granted admin roles in addition to the owner and owner assistant.
+    pub roles: common::admin::Roles,
+
+    /// Parameters used to validate and place fast market orders. Fast market orders are disabled
+    /// until these parameters are set.
+    pub fast_transfer_parameters: FastTransferParameters,
+
+    /// Progress of the account migration required by the latest program upgrade.
+    pub migration: MigrationProgress,
+
+    /// Versions that each versioned account type is migrated to.
+    pub migrated_versions: MigratedVersions,
 }
 
 impl Custodian {
@@ -24,6 +69,60 @@ impl Custodian {
     pub const SIGNER_SEEDS: &'static [&'static [u8]] = &[Self::SEED_PREFIX, &[Self::BUMP]];
 }
 
+impl Custodian {
+    /// Length of the custodian's data (including its discriminator) before it was versioned.
+    const LEGACY_LEN: usize = 138;
+}
+
+impl Versioned for Custodian {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    fn is_legacy(_key: &Pubkey, data: &[u8]) -> bool {
+        data.len() == Self::LEGACY_LEN
+    }
+}
+
+/// Custodian layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyCustodian {
+    paused: bool,
+    owner: Pubkey,
+    pending_owner: Option<Pubkey>,
+    owner_assistant: Pubkey,
+    paused_set_by: Pubkey,
+}
+
+/// Version 1 adds the version header, the council, admin roles, the fast transfer parameters and
+/// the account migration's progress.
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyCustodian {
+        paused,
+        owner,
+        pending_owner,
+        owner_assistant,
+        paused_set_by,
+    } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    migration::serialize_upgraded(
+        &Custodian {
+            header: VersionHeader { version: 1 },
+            paused,
+            owner,
+            pending_owner,
+            owner_assistant,
+            paused_set_by,
+            council: Default::default(),
+            roles: Default::default(),
+            fast_transfer_parameters: Default::default(),
+            migration: Default::default(),
+            migrated_versions: Default::default(),
+        },
+        Custodian::INIT_SPACE,
+    )
+}
+
 impl common::admin::Ownable for Custodian {
     fn owner(&self) -> &Pubkey {
         &self.owner
@@ -54,11 +153,32 @@ impl common::admin::OwnerAssistant for Custodian {
     }
 }
 
+impl common::admin::RoleBased for Custodian {
+    fn roles(&self) -> &common::admin::Roles {
+        &self.roles
+    }
+
+    fn roles_mut(&mut self) -> &mut common::admin::Roles {
+        &mut self.roles
+    }
+}
+
+impl common::admin::Council for Custodian {
+    fn council(&self) -> &common::admin::CouncilInfo {
+        &self.council
+    }
+
+    fn council_mut(&mut self) -> &mut common::admin::CouncilInfo {
+        &mut self.council
+    }
+}
+
 #[cfg(test)]
 mod test {
     use solana_program::pubkey::Pubkey;
 
     use super::*;
+    use anchor_lang::Discriminator;
 
     #[test]
     fn test_bump() {
@@ -71,4 +191,56 @@ mod test {
             "custodian mismatch",
         );
     }
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let owner = Pubkey::new_unique();
+        let legacy = LegacyCustodian {
+            paused: true,
+            owner,
+            pending_owner: None,
+            owner_assistant: Pubkey::new_unique(),
+            paused_set_by: owner,
+        };
+
+        // Legacy custodians were allocated with enough space for a pending owner.
+        let mut legacy_data = Custodian::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+        legacy_data.resize(Custodian::LEGACY_LEN, 0);
+
+        let key = Pubkey::new_unique();
+        assert_eq!(
+            migration::account_version::<Custodian>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        let upgraded = upgrade_v0(&legacy_data[8..]).unwrap();
+        assert_eq!(upgraded.len(), Custodian::INIT_SPACE);
+
+        let mut upgraded_data = Custodian::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgraded);
+        assert_ne!(upgraded_data.len(), Custodian::LEGACY_LEN);
+        assert_eq!(
+            migration::account_version::<Custodian>(&key, &upgraded_data).unwrap(),
+            Custodian::VERSION
+        );
+
+        let custodian = Custodian::try_deserialize(&mut &upgraded_data[..]).unwrap();
+        assert_eq!(custodian.header.version, Custodian::VERSION);
+        assert_eq!(custodian.paused, legacy.paused);
+        assert_eq!(custodian.owner, legacy.owner);
+        assert_eq!(custodian.pending_owner, legacy.pending_owner);
+        assert_eq!(custodian.owner_assistant, legacy.owner_assistant);
+        assert_eq!(custodian.paused_set_by, legacy.paused_set_by);
+
+        // Fast market orders are disabled until the fast transfer parameters are set.
+        assert_eq!(
+            custodian.fast_transfer_parameters,
+            FastTransferParameters::default()
+        );
+
+        // Every account type must be migrated from its legacy layout.
+        assert!(!custodian.migration.is_complete());
+        assert_eq!(custodian.migrated_versions, Default::default());
+    }
 }
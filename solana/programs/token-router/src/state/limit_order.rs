@@ -0,0 +1,240 @@
+use crate::error::TokenRouterError;
+use anchor_lang::prelude::*;
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LimitOrderSeeds {
+    pub prepared_order: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LimitOrderInfo {
+    /// Maximum fee a solver can collect from the escrowed amount for filling the order. The fee a
+    /// solver is allowed to collect grows linearly from zero when the order is placed to this fee
+    /// at expiry.
+    pub max_fee: u64,
+
+    /// Unix timestamp when the order was placed.
+    pub placed_at: i64,
+
+    /// Unix timestamp after which the order can no longer be filled. Once expired, anyone can
+    /// refund the escrowed funds to the prepared order's refund token account.
+    pub expiry: i64,
+}
+
+/// Account reflecting the terms of a limit order. The funds for this order remain escrowed in the
+/// associated prepared order's custody token account until the order is either filled or expired.
+///
+/// NOTE: The lamports used to create this account are returned to the prepared order's
+/// `prepared_by` when this account is closed.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct LimitOrder {
+    pub seeds: LimitOrderSeeds,
+    pub info: LimitOrderInfo,
+}
+
+impl LimitOrder {
+    pub const SEED_PREFIX: &'static [u8] = b"limit-order";
+
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        unix_timestamp >= self.expiry
+    }
+
+    /// Compute the fee a solver is allowed to collect at the given time. Because this fee starts at
+    /// zero and only reaches the max fee at expiry, the order is filled at the lowest fee a solver
+    /// is willing to accept.
+    pub fn compute_allowed_fee(&self, unix_timestamp: i64) -> u64 {
+        let elapsed = unix_timestamp.saturating_sub(self.placed_at);
+        let duration = self.expiry.saturating_sub(self.placed_at);
+
+        if elapsed <= 0 {
+            0
+        } else if elapsed >= duration {
+            self.max_fee
+        } else {
+            // The allowed fee is less than the max fee here, so it always fits in a u64.
+            u128::from(self.max_fee)
+                .saturating_mul(elapsed.unsigned_abs().into())
+                .checked_div(duration.unsigned_abs().into())
+                .and_then(|fee| u64::try_from(fee).ok())
+                .unwrap_or_else(|| self.max_fee)
+        }
+    }
+
+    /// Compute the amount a solver must deliver from its own token account to fill this order,
+    /// which is the escrowed amount less the solver's fee. The fee cannot exceed the fee allowed at
+    /// the given time. This amount must satisfy the order's minimum amount out (if any), and the
+    /// solver's token account must hold at least this amount.
+    pub fn compute_fill_amount(
+        &self,
+        escrowed_amount: u64,
+        fee: u64,
+        min_amount_out: Option<u64>,
+        solver_balance: u64,
+        unix_timestamp: i64,
+    ) -> Result<u64> {
+        require!(
+            fee <= self.compute_allowed_fee(unix_timestamp),
+            TokenRouterError::LimitFeeExceeded
+        );
+
+        let amount = escrowed_amount
+            .checked_sub(fee)
+            .ok_or_else(|| TokenRouterError::InsufficientAmount)?;
+        require!(amount > 0, TokenRouterError::InsufficientAmount);
+
+        if let Some(min_amount_out) = min_amount_out {
+            require!(
+                amount >= min_amount_out,
+                TokenRouterError::MinAmountOutTooHigh
+            );
+        }
+
+        require!(
+            solver_balance >= amount,
+            TokenRouterError::InsufficientSolverFunds
+        );
+
+        Ok(amount)
+    }
+}
+
+impl std::ops::Deref for LimitOrder {
+    type Target = LimitOrderInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.info
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ESCROWED_AMOUNT: u64 = 1_000_000;
+    const PLACED_AT: i64 = 100;
+    const EXPIRY: i64 = 200;
+
+    fn limit_order_for_test() -> LimitOrder {
+        LimitOrder {
+            seeds: LimitOrderSeeds {
+                prepared_order: Pubkey::new_unique(),
+                bump: 255,
+            },
+            info: LimitOrderInfo {
+                max_fee: 10_000,
+                placed_at: PLACED_AT,
+                expiry: EXPIRY,
+            },
+        }
+    }
+
+    #[test]
+    fn fill_amount() {
+        let limit_order = limit_order_for_test();
+
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(ESCROWED_AMOUNT, 10_000, Some(990_000), 990_000, EXPIRY)
+                .unwrap(),
+            990_000
+        );
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(ESCROWED_AMOUNT, 0, None, ESCROWED_AMOUNT, PLACED_AT)
+                .unwrap(),
+            ESCROWED_AMOUNT
+        );
+    }
+
+    #[test]
+    fn cannot_fill_without_solver_funds() {
+        let limit_order = limit_order_for_test();
+
+        // The solver must deliver the escrowed amount less its fee, so it cannot fill the order
+        // with the escrowed funds alone.
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(ESCROWED_AMOUNT, 10_000, None, 989_999, EXPIRY)
+                .unwrap_err(),
+            error!(TokenRouterError::InsufficientSolverFunds)
+        );
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(ESCROWED_AMOUNT, 10_000, None, 0, EXPIRY)
+                .unwrap_err(),
+            error!(TokenRouterError::InsufficientSolverFunds)
+        );
+    }
+
+    #[test]
+    fn cannot_fill_with_fee_exceeding_max_fee() {
+        let limit_order = limit_order_for_test();
+
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(ESCROWED_AMOUNT, 10_001, None, ESCROWED_AMOUNT, EXPIRY)
+                .unwrap_err(),
+            error!(TokenRouterError::LimitFeeExceeded)
+        );
+    }
+
+    #[test]
+    fn allowed_fee() {
+        let limit_order = limit_order_for_test();
+
+        assert_eq!(limit_order.compute_allowed_fee(0), 0);
+        assert_eq!(limit_order.compute_allowed_fee(PLACED_AT), 0);
+        assert_eq!(limit_order.compute_allowed_fee(125), 2_500);
+        assert_eq!(limit_order.compute_allowed_fee(150), 5_000);
+        assert_eq!(limit_order.compute_allowed_fee(199), 9_900);
+        assert_eq!(limit_order.compute_allowed_fee(EXPIRY), 10_000);
+        assert_eq!(limit_order.compute_allowed_fee(i64::MAX), 10_000);
+    }
+
+    #[test]
+    fn cannot_fill_with_fee_exceeding_allowed_fee() {
+        let limit_order = limit_order_for_test();
+
+        // Halfway to expiry, the solver can collect at most half of the max fee.
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(ESCROWED_AMOUNT, 5_000, None, ESCROWED_AMOUNT, 150)
+                .unwrap(),
+            995_000
+        );
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(ESCROWED_AMOUNT, 5_001, None, ESCROWED_AMOUNT, 150)
+                .unwrap_err(),
+            error!(TokenRouterError::LimitFeeExceeded)
+        );
+
+        // The order cannot be filled at the max fee as soon as it is placed.
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(ESCROWED_AMOUNT, 10_000, None, ESCROWED_AMOUNT, PLACED_AT)
+                .unwrap_err(),
+            error!(TokenRouterError::LimitFeeExceeded)
+        );
+    }
+
+    #[test]
+    fn cannot_fill_below_min_amount_out() {
+        let limit_order = limit_order_for_test();
+
+        assert_eq!(
+            limit_order
+                .compute_fill_amount(
+                    ESCROWED_AMOUNT,
+                    10_000,
+                    Some(990_001),
+                    ESCROWED_AMOUNT,
+                    EXPIRY
+                )
+                .unwrap_err(),
+            error!(TokenRouterError::MinAmountOutTooHigh)
+        );
+    }
+}
@@ -1,6 +1,12 @@
+mod council_approval;
+pub use council_approval::*;
+
 mod custodian;
 pub use custodian::*;
 
+mod limit_order;
+pub use limit_order::*;
+
 mod prepared_fill;
 pub use prepared_fill::*;
 
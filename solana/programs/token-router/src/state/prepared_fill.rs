@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use common::migration::{self, UpgradeFn, VersionHeader, Versioned};
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub enum FillType {
@@ -40,11 +41,20 @@ pub struct PreparedFillInfo {
     /// NOTE: This timestamp is not used by the Token Router. It only provides more information for
     /// an integrator so he can perform special handling based on when the fill happened.
     pub timestamp: i64,
+
+    /// Mint of the tokens held in the prepared custody token account. For fast fills, this mint
+    /// may be the destination asset requested by the order instead of a Circle-supported mint.
+    pub mint: Pubkey,
+
+    /// Amount delivered to the prepared custody token account.
+    pub amount: u64,
 }
 
 #[account]
 #[derive(Debug)]
 pub struct PreparedFill {
+    pub header: VersionHeader,
+
     pub seeds: PreparedFillSeeds,
     pub info: PreparedFillInfo,
     pub redeemer_message: Vec<u8>,
@@ -55,6 +65,7 @@ impl PreparedFill {
 
     pub fn compute_size(redeemer_message_len: usize) -> usize {
         const FIXED: usize = 8 // DISCRIMINATOR
+            + VersionHeader::INIT_SPACE
             + PreparedFillSeeds::INIT_SPACE
             + PreparedFillInfo::INIT_SPACE
             + 4 // redeemer_message_len
@@ -71,3 +82,160 @@ impl std::ops::Deref for PreparedFill {
         &self.info
     }
 }
+
+impl Versioned for PreparedFill {
+    const VERSION: u8 = 1;
+
+    const UPGRADES: &'static [UpgradeFn] = &[upgrade_v0];
+
+    /// Prepared fills have variable length, so the legacy layout is identified by its seeds (found
+    /// right after the discriminator) deriving the account's address instead.
+    fn is_legacy(key: &Pubkey, data: &[u8]) -> bool {
+        match (data.get(8..40), data.get(40)) {
+            (Some(fill_source), Some(bump)) => {
+                Pubkey::create_program_address(
+                    &[Self::SEED_PREFIX, fill_source, &[*bump]],
+                    &crate::id(),
+                )
+                .ok()
+                    == Some(*key)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Prepared fill info layout before the prepared fill was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyPreparedFillInfo {
+    prepared_custody_token_bump: u8,
+    prepared_by: Pubkey,
+    fill_type: FillType,
+    source_chain: u16,
+    order_sender: [u8; 32],
+    redeemer: Pubkey,
+    timestamp: i64,
+}
+
+/// Prepared fill layout before it was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyPreparedFill {
+    seeds: PreparedFillSeeds,
+    info: LegacyPreparedFillInfo,
+    redeemer_message: Vec<u8>,
+}
+
+/// Version 1 adds the version header, the mint of the prepared custody token account (which was
+/// always USDC) and the amount delivered.
+///
+/// NOTE: Legacy prepared fills did not record the amount delivered, so it is zero. Consuming a
+/// prepared fill transfers the prepared custody token account's balance regardless.
+fn upgrade_v0(data: &[u8]) -> Result<Vec<u8>> {
+    let LegacyPreparedFill {
+        seeds,
+        info,
+        redeemer_message,
+    } = AnchorDeserialize::deserialize(&mut &data[..])?;
+
+    let LegacyPreparedFillInfo {
+        prepared_custody_token_bump,
+        prepared_by,
+        fill_type,
+        source_chain,
+        order_sender,
+        redeemer,
+        timestamp,
+    } = info;
+
+    let space = PreparedFill::compute_size(redeemer_message.len()).saturating_sub(8);
+
+    migration::serialize_upgraded(
+        &PreparedFill {
+            header: VersionHeader { version: 1 },
+            seeds,
+            info: PreparedFillInfo {
+                prepared_custody_token_bump,
+                prepared_by,
+                fill_type,
+                source_chain,
+                order_sender,
+                redeemer,
+                timestamp,
+                mint: common::USDC_MINT,
+                amount: 0,
+            },
+            redeemer_message,
+        },
+        space,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn upgrade_from_legacy() {
+        let fill_source = Pubkey::new_unique();
+        let (key, bump) = Pubkey::find_program_address(
+            &[PreparedFill::SEED_PREFIX, fill_source.as_ref()],
+            &crate::id(),
+        );
+
+        let legacy = LegacyPreparedFill {
+            seeds: PreparedFillSeeds { fill_source, bump },
+            info: LegacyPreparedFillInfo {
+                prepared_custody_token_bump: 254,
+                prepared_by: Pubkey::new_unique(),
+                fill_type: FillType::FastFill,
+                source_chain: 1,
+                order_sender: [2; 32],
+                redeemer: Pubkey::new_unique(),
+                timestamp: 3,
+            },
+            redeemer_message: b"All your base are belong to us.".to_vec(),
+        };
+
+        let mut legacy_data = PreparedFill::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+        assert_eq!(
+            migration::account_version::<PreparedFill>(&key, &legacy_data).unwrap(),
+            0
+        );
+
+        // The seeds must derive the account's address.
+        assert!(!PreparedFill::is_legacy(
+            &Pubkey::new_unique(),
+            &legacy_data
+        ));
+
+        let mut upgraded_data = PreparedFill::DISCRIMINATOR.to_vec();
+        upgraded_data.extend_from_slice(&upgrade_v0(&legacy_data[8..]).unwrap());
+        assert_eq!(
+            upgraded_data.len(),
+            PreparedFill::compute_size(legacy.redeemer_message.len())
+        );
+        assert_eq!(
+            migration::account_version::<PreparedFill>(&key, &upgraded_data).unwrap(),
+            PreparedFill::VERSION
+        );
+
+        let prepared_fill = PreparedFill::try_deserialize(&mut &upgraded_data[..]).unwrap();
+        assert_eq!(prepared_fill.seeds.fill_source, fill_source);
+        assert_eq!(prepared_fill.seeds.bump, bump);
+        assert_eq!(
+            prepared_fill.prepared_custody_token_bump,
+            legacy.info.prepared_custody_token_bump
+        );
+        assert_eq!(prepared_fill.prepared_by, legacy.info.prepared_by);
+        assert!(matches!(prepared_fill.fill_type, FillType::FastFill));
+        assert_eq!(prepared_fill.source_chain, legacy.info.source_chain);
+        assert_eq!(prepared_fill.order_sender, legacy.info.order_sender);
+        assert_eq!(prepared_fill.redeemer, legacy.info.redeemer);
+        assert_eq!(prepared_fill.timestamp, legacy.info.timestamp);
+        assert_eq!(prepared_fill.mint, common::USDC_MINT);
+        assert_eq!(prepared_fill.amount, 0);
+        assert_eq!(prepared_fill.redeemer_message, legacy.redeemer_message);
+    }
+}
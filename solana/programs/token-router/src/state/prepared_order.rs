@@ -2,7 +2,25 @@ use anchor_lang::prelude::*;
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub enum OrderType {
-    Market { min_amount_out: Option<u64> },
+    Market {
+        min_amount_out: Option<u64>,
+    },
+    FastMarket {
+        min_amount_out: Option<u64>,
+
+        /// Maximum fee the order sender is willing to pay, which includes the init auction fee.
+        max_fee: u64,
+
+        /// Fee paid to the initial bidder of an auction, which was determined by the fast transfer
+        /// parameters when the order was prepared.
+        init_auction_fee: u64,
+
+        /// Unix timestamp by which an auction must start. If zero, there is no deadline.
+        deadline: u32,
+    },
+    Limit {
+        min_amount_out: Option<u64>,
+    },
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
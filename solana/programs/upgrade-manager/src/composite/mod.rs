@@ -2,9 +2,17 @@ use std::ops::Deref;
 
 use crate::{
     error::UpgradeManagerError,
-    state::{UpgradeReceipt, UpgradeStatus},
+    events::UpgradeCommitted,
+    state::{
+        ProgramSnapshot, UpgradeHistory, UpgradeHistoryStatus, UpgradeProposal, UpgradeReceipt,
+        UpgradeStatus,
+    },
+    utils,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{bpf_loader_upgradeable as bpf_loader_upgradeable_ix, program::invoke_signed},
 };
-use anchor_lang::prelude::*;
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
 
 #[derive(Accounts)]
@@ -32,6 +40,29 @@ impl<'info> AsRef<AccountInfo<'info>> for ProgramOwnerOnly<'info> {
     }
 }
 
+/// Upgrade authority of this program, which manages the registry of managed programs.
+#[derive(Accounts)]
+pub struct UpgradeManagerAuthorityOnly<'info> {
+    #[account(
+        constraint = {
+            require!(
+                program_data.upgrade_authority_address == Some(authority.key()),
+                UpgradeManagerError::UpgradeManagerAuthorityOnly,
+            );
+
+            true
+        }
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [crate::id().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::id(),
+    )]
+    program_data: Account<'info, ProgramData>,
+}
+
 #[derive(Accounts)]
 pub struct ExecuteUpgrade<'info> {
     #[account(mut)]
@@ -57,6 +88,81 @@ pub struct ExecuteUpgrade<'info> {
     #[account(mut)]
     pub buffer: UncheckedAccount<'info>,
 
+    /// Proposal to upgrade the program with this buffer. The upgrade can only be executed by the
+    /// proposal's owner once the upgrade delay has passed and only if the buffer's program bytes
+    /// have not changed since the proposal. This account is closed once the upgrade is executed.
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            UpgradeProposal::SEED_PREFIX,
+            program.key().as_ref(),
+            buffer.key().as_ref(),
+        ],
+        bump = proposal.bump,
+        constraint = {
+            require_keys_eq!(
+                proposal.owner,
+                admin.owner.key(),
+                UpgradeManagerError::OwnerMismatch,
+            );
+
+            require!(
+                Clock::get().unwrap().slot >= proposal.earliest_execute_slot,
+                UpgradeManagerError::UpgradeDelayNotExpired,
+            );
+
+            require!(
+                utils::compute_buffer_hash(&buffer)? == proposal.buffer_hash,
+                UpgradeManagerError::BufferMismatch,
+            );
+
+            true
+        }
+    )]
+    pub proposal: Account<'info, UpgradeProposal>,
+
+    /// Snapshot of the deployed program, which must be complete before the program is upgraded so
+    /// that the upgrade can be rolled back. If an upgrade is already uncommitted, this snapshot is
+    /// of the program deployed before that upgrade.
+    #[account(
+        seeds = [
+            ProgramSnapshot::SEED_PREFIX,
+            program.key().as_ref(),
+        ],
+        bump = snapshot.bump,
+        constraint = {
+            require!(
+                snapshot.is_complete(),
+                UpgradeManagerError::SnapshotIncomplete,
+            );
+
+            if receipt.status == UpgradeStatus::None {
+                require_eq!(
+                    snapshot.program_data_slot,
+                    program_data.slot,
+                    UpgradeManagerError::SnapshotMismatch,
+                );
+            }
+
+            true
+        }
+    )]
+    pub snapshot: Account<'info, ProgramSnapshot>,
+
+    /// History of this program's latest upgrades, where this upgrade is recorded.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UpgradeHistory::INIT_SPACE,
+        seeds = [
+            UpgradeHistory::SEED_PREFIX,
+            program.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub history: Box<Account<'info, UpgradeHistory>>,
+
     /// CHECK: Must be BPF Loader Upgradeable's PDA of liquidity layer program's program data.
     #[account(
         mut,
@@ -123,6 +229,39 @@ pub struct CommitUpgrade<'info> {
     )]
     receipt: Account<'info, UpgradeReceipt>,
 
+    /// Snapshot taken before the upgrade, which is no longer needed once the upgrade is committed.
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            ProgramSnapshot::SEED_PREFIX,
+            program.key().as_ref(),
+        ],
+        bump = snapshot.bump,
+    )]
+    snapshot: Account<'info, ProgramSnapshot>,
+
+    /// Backup buffer holding the snapshot. This buffer will have already been closed if the upgrade
+    /// was rolled back.
+    ///
+    /// CHECK: Mutable. This address must equal the snapshot's backup buffer.
+    #[account(
+        mut,
+        address = snapshot.backup_buffer @ UpgradeManagerError::BackupBufferMismatch,
+    )]
+    backup_buffer: UncheckedAccount<'info>,
+
+    /// Upgrade history of this program, whose latest entry is marked committed.
+    #[account(
+        mut,
+        seeds = [
+            UpgradeHistory::SEED_PREFIX,
+            program.key().as_ref(),
+        ],
+        bump = history.bump,
+    )]
+    history: Box<Account<'info, UpgradeHistory>>,
+
     /// CHECK: Must be executable.
     #[account(executable)]
     pub program: UncheckedAccount<'info>,
@@ -133,6 +272,54 @@ pub struct CommitUpgrade<'info> {
         seeds::program = bpf_loader_upgradeable::id(),
     )]
     program_data: Account<'info, ProgramData>,
+
+    bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+}
+
+impl<'info> CommitUpgrade<'info> {
+    /// Mark the latest entry of the upgrade history, which must be uncommitted, as committed.
+    pub fn commit_upgrade_history(&mut self) -> Result<UpgradeCommitted> {
+        let entry = self
+            .history
+            .latest_mut()
+            .filter(|entry| entry.status == UpgradeHistoryStatus::Uncommitted)
+            .ok_or_else(|| error!(UpgradeManagerError::NotUpgraded))?;
+        entry.status = UpgradeHistoryStatus::Committed;
+
+        Ok(UpgradeCommitted {
+            owner: entry.owner,
+            program: self.program.key(),
+            buffer: entry.buffer,
+            buffer_hash: entry.buffer_hash,
+            elf_len: entry.elf_len,
+            executed_slot: entry.slot,
+            slot: Clock::get().unwrap().slot,
+        })
+    }
+
+    /// Close the backup buffer if it still exists, sending its lamports to the recipient.
+    pub fn close_backup_buffer(&self) -> Result<()> {
+        if self.backup_buffer.owner != &bpf_loader_upgradeable::id() {
+            return Ok(());
+        }
+
+        invoke_signed(
+            &bpf_loader_upgradeable_ix::close_any(
+                &self.backup_buffer.key(),
+                &self.recipient.key(),
+                Some(&self.admin.upgrade_authority.key()),
+                None,
+            ),
+            &[
+                self.backup_buffer.to_account_info(),
+                self.recipient.to_account_info(),
+                self.admin.upgrade_authority.to_account_info(),
+                self.bpf_loader_upgradeable_program.to_account_info(),
+            ],
+            &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
+        )
+        .map_err(Into::into)
+    }
 }
 
 #[derive(Accounts)]
@@ -3,4 +3,17 @@ pub enum UpgradeManagerError {
     NotUpgraded = 0x10,
     ProgramDataMismatch = 0x12,
     OwnerMismatch = 0x14,
+    InvalidBuffer = 0x16,
+    BufferMismatch = 0x18,
+    UpgradeDelayNotExpired = 0x1a,
+    InvalidBackupBuffer = 0x1c,
+    BackupBufferMismatch = 0x1e,
+    SnapshotMismatch = 0x20,
+    SnapshotIncomplete = 0x22,
+    SnapshotAlreadyComplete = 0x24,
+    UpgradeUncommitted = 0x26,
+    UpgradeManagerAuthorityOnly = 0x28,
+    InvalidCustodian = 0x2a,
+    CustodianMismatch = 0x2c,
+    MigrationStarted = 0x2e,
 }
@@ -0,0 +1,10 @@
+use crate::state::OwnershipTransferLayout;
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct ManagedProgramUpdated {
+    pub program: Pubkey,
+    pub custodian: Pubkey,
+    pub ownership_transfer: OwnershipTransferLayout,
+}
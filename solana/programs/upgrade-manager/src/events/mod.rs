@@ -0,0 +1,20 @@
+mod managed_program_updated;
+pub use managed_program_updated::*;
+
+mod program_snapshotted;
+pub use program_snapshotted::*;
+
+mod upgrade_committed;
+pub use upgrade_committed::*;
+
+mod upgrade_executed;
+pub use upgrade_executed::*;
+
+mod upgrade_proposal_cancelled;
+pub use upgrade_proposal_cancelled::*;
+
+mod upgrade_proposed;
+pub use upgrade_proposed::*;
+
+mod upgrade_rolled_back;
+pub use upgrade_rolled_back::*;
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct ProgramSnapshotted {
+    pub program: Pubkey,
+    pub backup_buffer: Pubkey,
+
+    /// The slot when the snapshotted program was deployed.
+    pub program_data_slot: u64,
+}
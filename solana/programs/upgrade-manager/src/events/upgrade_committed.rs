@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct UpgradeCommitted {
+    pub owner: Pubkey,
+    pub program: Pubkey,
+    pub buffer: Pubkey,
+
+    /// Keccak256 hash of the deployed program bytes.
+    pub buffer_hash: [u8; 32],
+
+    /// Length of the deployed program bytes.
+    pub elf_len: u32,
+
+    /// The slot when the upgrade was executed.
+    pub executed_slot: u64,
+
+    /// The slot when the upgrade was committed.
+    pub slot: u64,
+}
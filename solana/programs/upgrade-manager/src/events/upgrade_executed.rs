@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct UpgradeExecuted {
+    pub owner: Pubkey,
+    pub program: Pubkey,
+    pub buffer: Pubkey,
+
+    /// Keccak256 hash of the deployed program bytes.
+    pub buffer_hash: [u8; 32],
+
+    /// Length of the deployed program bytes.
+    pub elf_len: u32,
+
+    /// Buffer of the uncommitted upgrade (or rollback) superseded by this upgrade.
+    pub superseded_buffer: Option<Pubkey>,
+
+    /// The slot when the upgrade was executed.
+    pub slot: u64,
+}
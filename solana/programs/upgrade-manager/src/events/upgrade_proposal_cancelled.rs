@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct UpgradeProposalCancelled {
+    pub owner: Pubkey,
+    pub program: Pubkey,
+    pub buffer: Pubkey,
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct UpgradeProposed {
+    pub owner: Pubkey,
+    pub program: Pubkey,
+    pub buffer: Pubkey,
+
+    /// Keccak256 hash of the buffer's program bytes.
+    pub buffer_hash: [u8; 32],
+
+    /// The upgrade cannot be executed before this slot.
+    pub earliest_execute_slot: u64,
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct UpgradeRolledBack {
+    pub owner: Pubkey,
+    pub program: Pubkey,
+    pub backup_buffer: Pubkey,
+
+    /// Keccak256 hash of the redeployed program bytes.
+    pub buffer_hash: [u8; 32],
+
+    /// Length of the redeployed program bytes.
+    pub elf_len: u32,
+
+    /// Buffer of the uncommitted upgrade superseded by this rollback.
+    pub superseded_buffer: Option<Pubkey>,
+
+    /// The slot when the snapshotted program was redeployed.
+    pub slot: u64,
+}
@@ -5,6 +5,8 @@ mod composite;
 
 mod error;
 
+pub mod events;
+
 mod processor;
 use processor::*;
 
@@ -19,10 +21,13 @@ declare_id!(common::UPGRADE_MANAGER_PROGRAM_ID);
 cfg_if::cfg_if! {
     if #[cfg(feature = "mainnet")] {
         const UPGRADE_AUTHORITY_BUMP: u8 = 255;
+        const UPGRADE_DELAY_SLOTS: u64 = 216_000; // ~1 day
     } else if #[cfg(feature = "testnet")] {
         const UPGRADE_AUTHORITY_BUMP: u8 = 255;
+        const UPGRADE_DELAY_SLOTS: u64 = 1_500; // ~10 minutes
     } else if #[cfg(feature = "localnet")] {
         const UPGRADE_AUTHORITY_BUMP: u8 = 255;
+        const UPGRADE_DELAY_SLOTS: u64 = 8;
     }
 }
 
@@ -30,31 +35,93 @@ const UPGRADE_AUTHORITY_SEED_PREFIX: &[u8] = b"upgrade";
 const UPGRADE_AUTHORITY_SIGNER_SEEDS: &[&[u8]] =
     &[UPGRADE_AUTHORITY_SEED_PREFIX, &[UPGRADE_AUTHORITY_BUMP]];
 
+/// Maximum number of program bytes copied to a backup buffer per snapshot instruction. This value
+/// is bounded by the maximum instruction data size of a CPI.
+const SNAPSHOT_CHUNK_SIZE: u32 = 8 * 1024;
+
 #[program]
 pub mod upgrade_manager {
     use super::*;
 
+    // Upgrade Proposals
+
+    pub fn propose_upgrade(ctx: Context<ProposeUpgrade>) -> Result<()> {
+        processor::propose_upgrade(ctx)
+    }
+
+    pub fn cancel_upgrade_proposal(ctx: Context<CancelUpgradeProposal>) -> Result<()> {
+        processor::cancel_upgrade_proposal(ctx)
+    }
+
+    // Snapshots and Rollbacks
+
+    pub fn snapshot_program(ctx: Context<SnapshotProgram>) -> Result<()> {
+        processor::snapshot_program(ctx)
+    }
+
+    pub fn rollback_upgrade(ctx: Context<RollbackUpgrade>) -> Result<()> {
+        processor::rollback_upgrade(ctx)
+    }
+
+    // Managed Programs
+
+    pub fn register_managed_program(
+        ctx: Context<RegisterManagedProgram>,
+        ownership_transfer: state::OwnershipTransferLayout,
+    ) -> Result<()> {
+        processor::register_managed_program(ctx, ownership_transfer)
+    }
+
+    pub fn update_managed_program(
+        ctx: Context<UpdateManagedProgram>,
+        ownership_transfer: state::OwnershipTransferLayout,
+    ) -> Result<()> {
+        processor::update_managed_program(ctx, ownership_transfer)
+    }
+
+    pub fn execute_upgrade(ctx: Context<ExecuteManagedProgramUpgrade>) -> Result<()> {
+        processor::execute_managed_program_upgrade(ctx)
+    }
+
+    pub fn commit_upgrade(ctx: Context<CommitManagedProgramUpgrade>) -> Result<()> {
+        processor::commit_managed_program_upgrade(ctx)
+    }
+
     // Matching Engine
 
     pub fn execute_matching_engine_upgrade(
         ctx: Context<ExecuteMatchingEngineUpgrade>,
     ) -> Result<()> {
-        utils::execute_upgrade(ctx.accounts, &ctx.bumps.execute_upgrade)
+        processor::execute_matching_engine_upgrade(ctx)
     }
 
     pub fn commit_matching_engine_upgrade(ctx: Context<CommitMatchingEngineUpgrade>) -> Result<()> {
         processor::commit_matching_engine_upgrade(ctx)
     }
 
+    pub fn start_matching_engine_migration(
+        ctx: Context<StartMatchingEngineMigration>,
+        args: matching_engine::StartMigrationArgs,
+    ) -> Result<()> {
+        processor::start_matching_engine_migration(ctx, args)
+    }
+
     // Token Router
 
     pub fn execute_token_router_upgrade(ctx: Context<ExecuteTokenRouterUpgrade>) -> Result<()> {
-        utils::execute_upgrade(ctx.accounts, &ctx.bumps.execute_upgrade)
+        processor::execute_token_router_upgrade(ctx)
     }
 
     pub fn commit_token_router_upgrade(ctx: Context<CommitTokenRouterUpgrade>) -> Result<()> {
         processor::commit_token_router_upgrade(ctx)
     }
+
+    pub fn start_token_router_migration(
+        ctx: Context<StartTokenRouterMigration>,
+        args: token_router::StartMigrationArgs,
+    ) -> Result<()> {
+        processor::start_token_router_migration(ctx, args)
+    }
 }
 
 #[cfg(test)]
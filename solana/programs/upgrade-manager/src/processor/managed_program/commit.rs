@@ -0,0 +1,84 @@
+use crate::{
+    composite::*, error::UpgradeManagerError, state::ManagedProgram, UPGRADE_AUTHORITY_SIGNER_SEEDS,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::{invoke, invoke_signed},
+};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CommitManagedProgramUpgrade<'info> {
+    #[account(
+        seeds = [
+            ManagedProgram::SEED_PREFIX,
+            commit_upgrade.program.key().as_ref(),
+        ],
+        bump = managed_program.bump,
+    )]
+    managed_program: Account<'info, ManagedProgram>,
+
+    /// CHECK: Mutable. Must equal the managed program's custodian.
+    #[account(
+        mut,
+        address = managed_program.custodian @ UpgradeManagerError::CustodianMismatch,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    commit_upgrade: CommitUpgrade<'info>,
+}
+
+pub fn commit_managed_program_upgrade(ctx: Context<CommitManagedProgramUpgrade>) -> Result<()> {
+    let event = ctx.accounts.commit_upgrade.commit_upgrade_history()?;
+
+    let managed_program = &ctx.accounts.managed_program;
+    let custodian = &ctx.accounts.custodian;
+    let commit_upgrade = &ctx.accounts.commit_upgrade;
+
+    // The snapshot is no longer needed once the upgrade is committed.
+    commit_upgrade.close_backup_buffer()?;
+
+    let CommitUpgrade { admin, program, .. } = commit_upgrade;
+
+    // The managed program's migrate instruction should fail until every account requiring
+    // migration has been migrated, so the upgrade cannot be committed until then.
+    if let Some(migrate_ix) = managed_program.migrate_ix(&admin.upgrade_authority.key()) {
+        invoke_signed(
+            &migrate_ix,
+            &[
+                admin.upgrade_authority.to_account_info(),
+                custodian.to_account_info(),
+                program.to_account_info(),
+            ],
+            &[UPGRADE_AUTHORITY_SIGNER_SEEDS],
+        )?;
+    }
+
+    invoke_signed(
+        &managed_program.submit_ownership_transfer_request_ix(
+            &admin.upgrade_authority.key(),
+            &admin.owner.key(),
+        ),
+        &[
+            admin.upgrade_authority.to_account_info(),
+            custodian.to_account_info(),
+            admin.owner.to_account_info(),
+            program.to_account_info(),
+        ],
+        &[UPGRADE_AUTHORITY_SIGNER_SEEDS],
+    )?;
+
+    invoke(
+        &managed_program.confirm_ownership_transfer_request_ix(&admin.owner.key()),
+        &[
+            admin.owner.to_account_info(),
+            custodian.to_account_info(),
+            program.to_account_info(),
+        ],
+    )?;
+
+    emit_cpi!(event);
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::{
+    composite::*,
+    error::UpgradeManagerError,
+    state::ManagedProgram,
+    utils::{self, AuthorizeUpgrade},
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::{invoke, invoke_signed},
+};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExecuteManagedProgramUpgrade<'info> {
+    #[account(
+        seeds = [
+            ManagedProgram::SEED_PREFIX,
+            execute_upgrade.program.key().as_ref(),
+        ],
+        bump = managed_program.bump,
+    )]
+    managed_program: Account<'info, ManagedProgram>,
+
+    /// CHECK: Mutable. Must equal the managed program's custodian.
+    #[account(
+        mut,
+        address = managed_program.custodian @ UpgradeManagerError::CustodianMismatch,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    execute_upgrade: ExecuteUpgrade<'info>,
+}
+
+impl<'info> AuthorizeUpgrade<'info> for ExecuteManagedProgramUpgrade<'info> {
+    fn execute_upgrade_composite_mut(&mut self) -> &mut ExecuteUpgrade<'info> {
+        &mut self.execute_upgrade
+    }
+
+    fn authorize_upgrade(&self) -> Result<()> {
+        let admin = &self.execute_upgrade.admin;
+        let program = &self.execute_upgrade.program;
+        let custodian = &self.custodian;
+
+        invoke(
+            &self.managed_program.submit_ownership_transfer_request_ix(
+                &admin.owner.key(),
+                &admin.upgrade_authority.key(),
+            ),
+            &[
+                admin.owner.to_account_info(),
+                custodian.to_account_info(),
+                admin.upgrade_authority.to_account_info(),
+                program.to_account_info(),
+            ],
+        )?;
+
+        invoke_signed(
+            &self
+                .managed_program
+                .confirm_ownership_transfer_request_ix(&admin.upgrade_authority.key()),
+            &[
+                admin.upgrade_authority.to_account_info(),
+                custodian.to_account_info(),
+                program.to_account_info(),
+            ],
+            &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
+        )
+        .map_err(Into::into)
+    }
+}
+
+pub fn execute_managed_program_upgrade(ctx: Context<ExecuteManagedProgramUpgrade>) -> Result<()> {
+    let event = utils::execute_upgrade(ctx.accounts, &ctx.bumps.execute_upgrade)?;
+
+    emit_cpi!(event);
+
+    // Done.
+    Ok(())
+}
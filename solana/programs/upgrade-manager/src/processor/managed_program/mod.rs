@@ -0,0 +1,11 @@
+mod commit;
+pub use commit::*;
+
+mod execute;
+pub use execute::*;
+
+mod register;
+pub use register::*;
+
+mod update;
+pub use update::*;
@@ -0,0 +1,61 @@
+use crate::{
+    composite::*,
+    error::UpgradeManagerError,
+    state::{ManagedProgram, OwnershipTransferLayout},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RegisterManagedProgram<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: UpgradeManagerAuthorityOnly<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ManagedProgram::INIT_SPACE,
+        seeds = [
+            ManagedProgram::SEED_PREFIX,
+            target_program.key().as_ref(),
+        ],
+        bump,
+    )]
+    managed_program: Account<'info, ManagedProgram>,
+
+    /// CHECK: Must be executable.
+    #[account(executable)]
+    target_program: UncheckedAccount<'info>,
+
+    /// CHECK: Must be owned by the managed program.
+    #[account(owner = target_program.key() @ UpgradeManagerError::InvalidCustodian)]
+    custodian: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn register_managed_program(
+    ctx: Context<RegisterManagedProgram>,
+    ownership_transfer: OwnershipTransferLayout,
+) -> Result<()> {
+    let program = ctx.accounts.target_program.key();
+    let custodian = ctx.accounts.custodian.key();
+
+    ctx.accounts.managed_program.set_inner(ManagedProgram {
+        bump: ctx.bumps.managed_program,
+        program,
+        custodian,
+        ownership_transfer,
+    });
+
+    emit_cpi!(crate::events::ManagedProgramUpdated {
+        program,
+        custodian,
+        ownership_transfer,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::{
+    composite::*,
+    error::UpgradeManagerError,
+    state::{ManagedProgram, OwnershipTransferLayout},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpdateManagedProgram<'info> {
+    admin: UpgradeManagerAuthorityOnly<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ManagedProgram::SEED_PREFIX,
+            managed_program.program.as_ref(),
+        ],
+        bump = managed_program.bump,
+    )]
+    managed_program: Account<'info, ManagedProgram>,
+
+    /// CHECK: Must be owned by the managed program.
+    #[account(owner = managed_program.program @ UpgradeManagerError::InvalidCustodian)]
+    custodian: UncheckedAccount<'info>,
+}
+
+pub fn update_managed_program(
+    ctx: Context<UpdateManagedProgram>,
+    ownership_transfer: OwnershipTransferLayout,
+) -> Result<()> {
+    let managed_program = &mut ctx.accounts.managed_program;
+    managed_program.custodian = ctx.accounts.custodian.key();
+    managed_program.ownership_transfer = ownership_transfer;
+
+    emit_cpi!(crate::events::ManagedProgramUpdated {
+        program: managed_program.program,
+        custodian: managed_program.custodian,
+        ownership_transfer,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use crate::{
+    composite::*,
+    error::UpgradeManagerError,
+    state::{ProgramSnapshot, UpgradeReceipt, UpgradeStatus},
+    UPGRADE_AUTHORITY_SIGNER_SEEDS,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct StartMatchingEngineMigration<'info> {
+    /// This account pays for reallocating the Matching Engine custodian.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        constraint = {
+            require_keys_eq!(
+                admin.owner.key(),
+                receipt.owner,
+                UpgradeManagerError::OwnerMismatch,
+            );
+
+            true
+        }
+    )]
+    admin: ProgramOwnerOnly<'info>,
+
+    /// The Matching Engine must have been upgraded, but the upgrade must not be committed yet.
+    #[account(
+        seeds = [
+            UpgradeReceipt::SEED_PREFIX,
+            matching_engine::id().as_ref(),
+        ],
+        bump = receipt.bump,
+        constraint = {
+            require!(
+                matches!(receipt.status, UpgradeStatus::Uncommitted { .. }),
+                UpgradeManagerError::NotUpgraded,
+            );
+
+            true
+        }
+    )]
+    receipt: Account<'info, UpgradeReceipt>,
+
+    /// Snapshot taken before the upgrade, which records that the migration started so that the
+    /// upgrade can no longer be rolled back.
+    #[account(
+        mut,
+        seeds = [
+            ProgramSnapshot::SEED_PREFIX,
+            matching_engine::id().as_ref(),
+        ],
+        bump = snapshot.bump,
+    )]
+    snapshot: Account<'info, ProgramSnapshot>,
+
+    /// CHECK: Mutable. Seeds must be \["emitter"\] (Matching Engine program).
+    #[account(mut)]
+    matching_engine_custodian: UncheckedAccount<'info>,
+
+    matching_engine_program: Program<'info, matching_engine::program::MatchingEngine>,
+    system_program: Program<'info, System>,
+}
+
+pub fn start_matching_engine_migration(
+    ctx: Context<StartMatchingEngineMigration>,
+    args: matching_engine::StartMigrationArgs,
+) -> Result<()> {
+    // The upgrade authority owns the Matching Engine until the upgrade is committed, so it must
+    // start the migration on behalf of the owner.
+    matching_engine::cpi::start_migration(
+        CpiContext::new_with_signer(
+            ctx.accounts.matching_engine_program.to_account_info(),
+            matching_engine::cpi::accounts::StartMigration {
+                payer: ctx.accounts.payer.to_account_info(),
+                owner: ctx.accounts.admin.upgrade_authority.to_account_info(),
+                custodian: ctx.accounts.matching_engine_custodian.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[UPGRADE_AUTHORITY_SIGNER_SEEDS],
+        ),
+        args,
+    )?;
+
+    ctx.accounts.snapshot.migration_started = true;
+
+    // Done.
+    Ok(())
+}
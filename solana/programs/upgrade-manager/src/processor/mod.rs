@@ -1,5 +1,14 @@
+mod managed_program;
+pub use managed_program::*;
+
 mod matching_engine_upgrade;
 pub use matching_engine_upgrade::*;
 
+mod rollback;
+pub use rollback::*;
+
 mod token_router_upgrade;
 pub use token_router_upgrade::*;
+
+mod upgrade_proposal;
+pub use upgrade_proposal::*;
@@ -0,0 +1,5 @@
+mod rollback_upgrade;
+pub use rollback_upgrade::*;
+
+mod snapshot_program;
+pub use snapshot_program::*;
@@ -0,0 +1,160 @@
+use crate::{
+    composite::*,
+    error::UpgradeManagerError,
+    state::{
+        ProgramSnapshot, UpgradeHistory, UpgradeHistoryEntry, UpgradeHistoryStatus, UpgradeReceipt,
+        UpgradeStatus,
+    },
+    utils,
+};
+use anchor_lang::prelude::*;
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
+
+/// Redeploy the program snapshotted before an uncommitted upgrade. The backup buffer is consumed by
+/// the upgrade and its lamports are sent to the recipient. An upgrade cannot be rolled back once
+/// its account migration has started.
+///
+/// NOTE: A program cannot be invoked in the same slot it was deployed, so ownership of the program
+/// is returned to the owner afterwards by committing the upgrade (which is now the rollback).
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RollbackUpgrade<'info> {
+    #[account(
+        constraint = {
+            require_keys_eq!(
+                admin.owner.key(),
+                receipt.owner,
+                UpgradeManagerError::OwnerMismatch,
+            );
+
+            true
+        }
+    )]
+    admin: ProgramOwnerOnly<'info>,
+
+    /// CHECK: This account will receive lamports from the backup buffer.
+    #[account(mut)]
+    recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            UpgradeReceipt::SEED_PREFIX,
+            target_program.key().as_ref(),
+        ],
+        bump = receipt.bump,
+        constraint = matches!(
+            receipt.status,
+            UpgradeStatus::Uncommitted { .. }
+        ) @ UpgradeManagerError::NotUpgraded,
+    )]
+    receipt: Account<'info, UpgradeReceipt>,
+
+    #[account(
+        seeds = [
+            ProgramSnapshot::SEED_PREFIX,
+            target_program.key().as_ref(),
+        ],
+        bump = snapshot.bump,
+        constraint = snapshot.is_complete() @ UpgradeManagerError::SnapshotIncomplete,
+        constraint = !snapshot.migration_started @ UpgradeManagerError::MigrationStarted,
+    )]
+    snapshot: Account<'info, ProgramSnapshot>,
+
+    /// CHECK: Mutable. This address must equal the snapshot's backup buffer.
+    #[account(
+        mut,
+        address = snapshot.backup_buffer @ UpgradeManagerError::BackupBufferMismatch,
+    )]
+    backup_buffer: UncheckedAccount<'info>,
+
+    /// Upgrade history of this program, where the rollback is recorded as an uncommitted upgrade.
+    #[account(
+        mut,
+        seeds = [
+            UpgradeHistory::SEED_PREFIX,
+            target_program.key().as_ref(),
+        ],
+        bump = history.bump,
+    )]
+    history: Box<Account<'info, UpgradeHistory>>,
+
+    /// CHECK: Must be BPF Loader Upgradeable's PDA of liquidity layer program's program data.
+    #[account(
+        mut,
+        seeds = [target_program.key().as_ref()],
+        bump = receipt.program_data_bump,
+        seeds::program = bpf_loader_upgradeable_program,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    /// CHECK: Must be the upgraded program. We cannot use the Program<'info, ..> definition here
+    /// because we cannot set this account to be mutable in that case.
+    #[account(mut)]
+    target_program: UncheckedAccount<'info>,
+
+    bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+
+    sysvars: RequiredSysvars<'info>,
+}
+
+pub fn rollback_upgrade(ctx: Context<RollbackUpgrade>) -> Result<()> {
+    let upgrade_authority = &ctx.accounts.admin.upgrade_authority;
+    let backup_buffer = &ctx.accounts.backup_buffer;
+    let sysvars = &ctx.accounts.sysvars;
+
+    // The backup buffer is closed by the upgrade, so its program bytes must be hashed beforehand.
+    let buffer_hash = utils::compute_buffer_hash(backup_buffer)?;
+    let elf_len = utils::buffer_elf_len(backup_buffer);
+
+    bpf_loader_upgradeable::upgrade(CpiContext::new_with_signer(
+        ctx.accounts
+            .bpf_loader_upgradeable_program
+            .to_account_info(),
+        bpf_loader_upgradeable::Upgrade {
+            program: ctx.accounts.target_program.to_account_info(),
+            program_data: ctx.accounts.program_data.to_account_info(),
+            buffer: backup_buffer.to_account_info(),
+            authority: upgrade_authority.to_account_info(),
+            spill: ctx.accounts.recipient.to_account_info(),
+            rent: sysvars.rent.to_account_info(),
+            clock: sysvars.clock.to_account_info(),
+        },
+        &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
+    ))?;
+
+    // The rollback is now the uncommitted upgrade, which must be committed to return ownership of
+    // the program to its owner.
+    let slot = Clock::get().unwrap().slot;
+    let owner = ctx.accounts.receipt.owner;
+    let backup_buffer = backup_buffer.key();
+    ctx.accounts.receipt.status = UpgradeStatus::Uncommitted {
+        buffer: backup_buffer,
+        slot,
+    };
+
+    let superseded_buffer = utils::append_upgrade_history(
+        &mut ctx.accounts.history,
+        UpgradeHistoryEntry {
+            owner,
+            buffer: backup_buffer,
+            buffer_hash,
+            elf_len,
+            slot,
+            status: UpgradeHistoryStatus::Uncommitted,
+        },
+    );
+
+    emit_cpi!(crate::events::UpgradeRolledBack {
+        owner,
+        program: ctx.accounts.target_program.key(),
+        backup_buffer,
+        buffer_hash,
+        elf_len,
+        superseded_buffer,
+        slot,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use crate::{
+    error::UpgradeManagerError,
+    state::{ProgramSnapshot, UpgradeReceipt},
+    utils,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        bpf_loader_upgradeable::{self as bpf_loader_upgradeable_ix, UpgradeableLoaderState},
+        program::invoke_signed,
+    },
+};
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable::BpfLoaderUpgradeable;
+
+/// Copy the next chunk of the deployed program's bytes into the backup buffer. The backup buffer
+/// must be created beforehand with the upgrade authority as its authority, and it must be sized to
+/// hold exactly the program data's program bytes.
+///
+/// NOTE: Anyone can snapshot a program. Because the bytes are copied from the program data and
+/// only the upgrade authority can write to the backup buffer, the snapshot cannot be tampered with.
+#[derive(Accounts)]
+#[event_cpi]
+pub struct SnapshotProgram<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Upgrade authority, which is the backup buffer's authority.
+    #[account(address = common::UPGRADE_MANAGER_AUTHORITY)]
+    upgrade_authority: UncheckedAccount<'info>,
+
+    /// A program cannot be snapshotted while an upgrade is uncommitted. Otherwise the snapshot of
+    /// the program deployed before the upgrade would be lost.
+    ///
+    /// CHECK: Seeds must be \["receipt", target_program.key()\].
+    #[account(
+        seeds = [
+            UpgradeReceipt::SEED_PREFIX,
+            target_program.key().as_ref(),
+        ],
+        bump,
+        constraint = receipt.data_is_empty() @ UpgradeManagerError::UpgradeUncommitted,
+    )]
+    receipt: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProgramSnapshot::INIT_SPACE,
+        seeds = [
+            ProgramSnapshot::SEED_PREFIX,
+            target_program.key().as_ref(),
+        ],
+        bump,
+    )]
+    snapshot: Account<'info, ProgramSnapshot>,
+
+    /// CHECK: Mutable. This account must be the snapshot's backup buffer if the snapshot already
+    /// exists.
+    #[account(mut)]
+    backup_buffer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [target_program.key().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable_program,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    /// CHECK: Must be executable.
+    #[account(executable)]
+    target_program: UncheckedAccount<'info>,
+
+    bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+    system_program: Program<'info, System>,
+}
+
+pub fn snapshot_program(ctx: Context<SnapshotProgram>) -> Result<()> {
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    let program_data_info = ctx.accounts.program_data.to_account_info();
+    let program_data_slot = ctx.accounts.program_data.slot;
+    let backup_buffer = &ctx.accounts.backup_buffer;
+
+    // If the snapshot was just created, start copying to the backup buffer.
+    if ctx.accounts.snapshot.backup_buffer == Pubkey::default() {
+        let data_len = program_data_info.data_len().saturating_sub(metadata_len);
+        utils::require_backup_buffer(backup_buffer, data_len)?;
+
+        ctx.accounts.snapshot.set_inner(ProgramSnapshot {
+            bump: ctx.bumps.snapshot,
+            backup_buffer: backup_buffer.key(),
+            program_data_slot,
+            data_len: u32::try_from(data_len).unwrap(),
+            bytes_written: Default::default(),
+            migration_started: false,
+        });
+    }
+
+    let snapshot = &mut ctx.accounts.snapshot;
+    require_keys_eq!(
+        snapshot.backup_buffer,
+        backup_buffer.key(),
+        UpgradeManagerError::BackupBufferMismatch
+    );
+    require_eq!(
+        snapshot.program_data_slot,
+        program_data_slot,
+        UpgradeManagerError::SnapshotMismatch
+    );
+    require!(
+        !snapshot.is_complete(),
+        UpgradeManagerError::SnapshotAlreadyComplete
+    );
+
+    let offset = snapshot.bytes_written;
+    let end = offset
+        .saturating_add(crate::SNAPSHOT_CHUNK_SIZE)
+        .min(snapshot.data_len);
+    let bytes = {
+        let data = program_data_info.try_borrow_data()?;
+        data[metadata_len.saturating_add(usize::try_from(offset).unwrap())
+            ..metadata_len.saturating_add(usize::try_from(end).unwrap())]
+            .to_vec()
+    };
+
+    invoke_signed(
+        &bpf_loader_upgradeable_ix::write(
+            &backup_buffer.key(),
+            &ctx.accounts.upgrade_authority.key(),
+            offset,
+            bytes,
+        ),
+        &[
+            backup_buffer.to_account_info(),
+            ctx.accounts.upgrade_authority.to_account_info(),
+            ctx.accounts
+                .bpf_loader_upgradeable_program
+                .to_account_info(),
+        ],
+        &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
+    )?;
+
+    snapshot.bytes_written = end;
+
+    if snapshot.is_complete() {
+        emit_cpi!(crate::events::ProgramSnapshotted {
+            program: ctx.accounts.target_program.key(),
+            backup_buffer: backup_buffer.key(),
+            program_data_slot,
+        });
+    }
+
+    // Done.
+    Ok(())
+}
@@ -2,6 +2,7 @@ use crate::{composite::*, UPGRADE_AUTHORITY_SIGNER_SEEDS};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct CommitTokenRouterUpgrade<'info> {
     /// CHECK: Seeds must be \["emitter"\] (Token Router program).
     #[account(mut)]
@@ -21,18 +22,26 @@ pub struct CommitTokenRouterUpgrade<'info> {
 }
 
 pub fn commit_token_router_upgrade(ctx: Context<CommitTokenRouterUpgrade>) -> Result<()> {
+    let event = ctx.accounts.commit_upgrade.commit_upgrade_history()?;
+
     let custodian = &ctx.accounts.token_router_custodian;
-    let CommitUpgrade { admin, program, .. } = &ctx.accounts.commit_upgrade;
+    let commit_upgrade = &ctx.accounts.commit_upgrade;
+
+    // The snapshot is no longer needed once the upgrade is committed.
+    commit_upgrade.close_backup_buffer()?;
 
+    let CommitUpgrade { admin, program, .. } = commit_upgrade;
+
+    // Migrating fails until the migration has been started and every account requiring migration
+    // has been migrated, so the upgrade cannot be committed until then.
+    //
     // NOTE: We do not want to pass in any remaining accounts to this instruction.
     token_router::cpi::migrate(CpiContext::new_with_signer(
         program.to_account_info(),
         token_router::cpi::accounts::Migrate {
-            admin: token_router::cpi::accounts::OwnerOnly {
+            admin: token_router::cpi::accounts::OwnerOnlyMut {
                 owner: admin.upgrade_authority.to_account_info(),
-                custodian: token_router::cpi::accounts::CheckedCustodian {
-                    custodian: custodian.to_account_info(),
-                },
+                custodian: custodian.to_account_info(),
             },
         },
         &[UPGRADE_AUTHORITY_SIGNER_SEEDS],
@@ -56,5 +65,10 @@ pub fn commit_token_router_upgrade(ctx: Context<CommitTokenRouterUpgrade>) -> Re
             pending_owner: admin.owner.to_account_info(),
             custodian: custodian.to_account_info(),
         },
-    ))
+    ))?;
+
+    emit_cpi!(event);
+
+    // Done.
+    Ok(())
 }
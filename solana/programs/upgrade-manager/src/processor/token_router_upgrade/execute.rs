@@ -1,7 +1,11 @@
-use crate::{composite::*, utils::AuthorizeUpgrade};
+use crate::{
+    composite::*,
+    utils::{self, AuthorizeUpgrade},
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct ExecuteTokenRouterUpgrade<'info> {
     /// CHECK: Seeds must be \["emitter"\] (Token Router program).
     #[account(mut)]
@@ -51,3 +55,12 @@ impl<'info> AuthorizeUpgrade<'info> for ExecuteTokenRouterUpgrade<'info> {
         ))
     }
 }
+
+pub fn execute_token_router_upgrade(ctx: Context<ExecuteTokenRouterUpgrade>) -> Result<()> {
+    let event = utils::execute_upgrade(ctx.accounts, &ctx.bumps.execute_upgrade)?;
+
+    emit_cpi!(event);
+
+    // Done.
+    Ok(())
+}
@@ -3,3 +3,6 @@ pub use execute::*;
 
 mod commit;
 pub use commit::*;
+
+mod start_migration;
+pub use start_migration::*;
@@ -0,0 +1,44 @@
+use crate::{error::UpgradeManagerError, state::UpgradeProposal};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelUpgradeProposal<'info> {
+    /// Owner who proposed the upgrade. This account receives the lamports from the proposal.
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            UpgradeProposal::SEED_PREFIX,
+            proposal.program.as_ref(),
+            proposal.buffer.as_ref(),
+        ],
+        bump = proposal.bump,
+        constraint = {
+            require_keys_eq!(
+                proposal.owner,
+                owner.key(),
+                UpgradeManagerError::OwnerMismatch,
+            );
+
+            true
+        }
+    )]
+    proposal: Account<'info, UpgradeProposal>,
+}
+
+pub fn cancel_upgrade_proposal(ctx: Context<CancelUpgradeProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    emit_cpi!(crate::events::UpgradeProposalCancelled {
+        owner: proposal.owner,
+        program: proposal.program,
+        buffer: proposal.buffer,
+    });
+
+    // Done.
+    Ok(())
+}
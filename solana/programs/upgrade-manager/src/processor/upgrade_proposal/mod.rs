@@ -0,0 +1,5 @@
+mod cancel;
+pub use cancel::*;
+
+mod propose;
+pub use propose::*;
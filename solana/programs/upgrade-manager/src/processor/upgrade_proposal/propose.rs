@@ -0,0 +1,61 @@
+use crate::{state::UpgradeProposal, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeUpgrade<'info> {
+    /// Owner of the program, who must also execute this upgrade.
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + UpgradeProposal::INIT_SPACE,
+        seeds = [
+            UpgradeProposal::SEED_PREFIX,
+            target_program.key().as_ref(),
+            buffer.key().as_ref(),
+        ],
+        bump,
+    )]
+    proposal: Account<'info, UpgradeProposal>,
+
+    /// CHECK: Must be a buffer owned by BPF Loader Upgradeable. Its program bytes are hashed so
+    /// the upgrade can only be executed with the same bytes.
+    buffer: UncheckedAccount<'info>,
+
+    /// CHECK: Must be executable.
+    #[account(executable)]
+    target_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn propose_upgrade(ctx: Context<ProposeUpgrade>) -> Result<()> {
+    let buffer_hash = utils::compute_buffer_hash(&ctx.accounts.buffer)?;
+
+    let slot_proposed_at = Clock::get().unwrap().slot;
+    let earliest_execute_slot = slot_proposed_at.saturating_add(crate::UPGRADE_DELAY_SLOTS);
+
+    ctx.accounts.proposal.set_inner(UpgradeProposal {
+        bump: ctx.bumps.proposal,
+        owner: ctx.accounts.owner.key(),
+        program: ctx.accounts.target_program.key(),
+        buffer: ctx.accounts.buffer.key(),
+        buffer_hash,
+        slot_proposed_at,
+        earliest_execute_slot,
+    });
+
+    emit_cpi!(crate::events::UpgradeProposed {
+        owner: ctx.accounts.owner.key(),
+        program: ctx.accounts.target_program.key(),
+        buffer: ctx.accounts.buffer.key(),
+        buffer_hash,
+        earliest_execute_slot,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+
+/// Instruction data (selectors) of a managed program's admin instructions, which must follow the
+/// ownership interface found in `common::admin`.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct OwnershipTransferLayout {
+    /// Selector of the instruction to request an ownership transfer. Its accounts must be the
+    /// owner (signer), the custodian (writable) and the new owner.
+    pub submit_selector: [u8; 8],
+
+    /// Selector of the instruction to confirm an ownership transfer. Its accounts must be the
+    /// pending owner (signer) and the custodian (writable).
+    pub confirm_selector: [u8; 8],
+
+    /// Selector of the instruction invoked by the owner when an upgrade is committed. Its accounts
+    /// must be the owner (signer) and the custodian (writable). If this value is `None`, nothing is
+    /// invoked.
+    pub migrate_selector: Option<[u8; 8]>,
+}
+
+/// A program registered to be upgraded by this program using the generic upgrade instructions.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ManagedProgram {
+    pub bump: u8,
+
+    pub program: Pubkey,
+
+    /// Account of the managed program storing its owner and pending owner.
+    pub custodian: Pubkey,
+
+    pub ownership_transfer: OwnershipTransferLayout,
+}
+
+impl ManagedProgram {
+    pub const SEED_PREFIX: &'static [u8] = b"managed-program";
+
+    pub fn submit_ownership_transfer_request_ix(
+        &self,
+        owner: &Pubkey,
+        new_owner: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program,
+            accounts: vec![
+                AccountMeta::new_readonly(*owner, true),
+                AccountMeta::new(self.custodian, false),
+                AccountMeta::new_readonly(*new_owner, false),
+            ],
+            data: self.ownership_transfer.submit_selector.to_vec(),
+        }
+    }
+
+    pub fn confirm_ownership_transfer_request_ix(&self, pending_owner: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: self.program,
+            accounts: vec![
+                AccountMeta::new_readonly(*pending_owner, true),
+                AccountMeta::new(self.custodian, false),
+            ],
+            data: self.ownership_transfer.confirm_selector.to_vec(),
+        }
+    }
+
+    pub fn migrate_ix(&self, owner: &Pubkey) -> Option<Instruction> {
+        self.ownership_transfer
+            .migrate_selector
+            .map(|migrate_selector| Instruction {
+                program_id: self.program,
+                accounts: vec![
+                    AccountMeta::new_readonly(*owner, true),
+                    AccountMeta::new(self.custodian, false),
+                ],
+                data: migrate_selector.to_vec(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::InstructionData;
+
+    fn selector(data: impl InstructionData) -> [u8; 8] {
+        data.data().try_into().unwrap()
+    }
+
+    #[test]
+    fn matching_engine_ownership_transfer_layout() {
+        let owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let custodian = Pubkey::new_unique();
+
+        let managed_program = ManagedProgram {
+            bump: 255,
+            program: matching_engine::id(),
+            custodian,
+            ownership_transfer: OwnershipTransferLayout {
+                submit_selector: selector(
+                    matching_engine::instruction::SubmitOwnershipTransferRequest {},
+                ),
+                confirm_selector: selector(
+                    matching_engine::instruction::ConfirmOwnershipTransferRequest {},
+                ),
+                migrate_selector: selector(matching_engine::instruction::Migrate {}).into(),
+            },
+        };
+
+        assert_eq!(
+            managed_program.submit_ownership_transfer_request_ix(&owner, &new_owner),
+            Instruction {
+                program_id: matching_engine::id(),
+                accounts: matching_engine::accounts::SubmitOwnershipTransferRequest {
+                    admin: matching_engine::accounts::OwnerOnlyMut { owner, custodian },
+                    new_owner,
+                }
+                .to_account_metas(None),
+                data: matching_engine::instruction::SubmitOwnershipTransferRequest {}.data(),
+            }
+        );
+        assert_eq!(
+            managed_program.confirm_ownership_transfer_request_ix(&new_owner),
+            Instruction {
+                program_id: matching_engine::id(),
+                accounts: matching_engine::accounts::ConfirmOwnershipTransferRequest {
+                    pending_owner: new_owner,
+                    custodian,
+                }
+                .to_account_metas(None),
+                data: matching_engine::instruction::ConfirmOwnershipTransferRequest {}.data(),
+            }
+        );
+        assert_eq!(
+            managed_program.migrate_ix(&owner),
+            Some(Instruction {
+                program_id: matching_engine::id(),
+                accounts: matching_engine::accounts::Migrate {
+                    admin: matching_engine::accounts::OwnerOnlyMut { owner, custodian },
+                }
+                .to_account_metas(None),
+                data: matching_engine::instruction::Migrate {}.data(),
+            })
+        );
+    }
+}
@@ -1,2 +1,14 @@
+mod managed_program;
+pub use managed_program::*;
+
+mod program_snapshot;
+pub use program_snapshot::*;
+
+mod upgrade_history;
+pub use upgrade_history::*;
+
+mod upgrade_proposal;
+pub use upgrade_proposal::*;
+
 mod upgrade_receipt;
 pub use upgrade_receipt::*;
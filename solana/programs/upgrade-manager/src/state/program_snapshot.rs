@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Snapshot of a deployed program, which is copied into a backup buffer so an upgrade can be
+/// rolled back. A snapshot must be complete before the program can be upgraded. This account is
+/// closed when the upgrade is committed.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ProgramSnapshot {
+    pub bump: u8,
+
+    /// Buffer (whose authority is the upgrade authority) which the program bytes are copied to.
+    pub backup_buffer: Pubkey,
+
+    /// The slot when the snapshotted program was deployed.
+    pub program_data_slot: u64,
+
+    /// Number of program bytes (excluding the program data's metadata) to copy.
+    pub data_len: u32,
+
+    /// Number of program bytes copied so far.
+    pub bytes_written: u32,
+
+    /// Whether the upgraded program's account migration was started. Migrated accounts cannot be
+    /// read by the snapshotted program, so the upgrade can no longer be rolled back.
+    pub migration_started: bool,
+}
+
+impl ProgramSnapshot {
+    pub const SEED_PREFIX: &'static [u8] = b"snapshot";
+
+    pub fn is_complete(&self) -> bool {
+        self.bytes_written == self.data_len
+    }
+}
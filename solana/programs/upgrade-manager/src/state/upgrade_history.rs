@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+/// Status of an upgrade found in a program's upgrade history.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum UpgradeHistoryStatus {
+    /// The upgrade has been executed, but not committed.
+    Uncommitted,
+    /// The upgrade was committed, which returned ownership of the program to its owner.
+    Committed,
+    /// Another upgrade (or rollback) was executed before this upgrade was committed.
+    Superseded,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct UpgradeHistoryEntry {
+    /// Who executed the upgrade.
+    pub owner: Pubkey,
+
+    /// Buffer deployed by the upgrade. This buffer is closed by the upgrade.
+    pub buffer: Pubkey,
+
+    /// Keccak256 hash of the buffer's program bytes (excluding the buffer's metadata).
+    pub buffer_hash: [u8; 32],
+
+    /// Length of the buffer's program bytes.
+    pub elf_len: u32,
+
+    /// The slot when the upgrade was executed.
+    pub slot: u64,
+
+    pub status: UpgradeHistoryStatus,
+}
+
+/// History of a program's latest upgrades, which is a fixed-size ring buffer so that its size is
+/// bounded. Once the history is full, each new entry overwrites the oldest entry.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct UpgradeHistory {
+    pub bump: u8,
+
+    pub program: Pubkey,
+
+    /// Number of upgrades ever recorded, which is the index of the next entry.
+    pub num_entries: u64,
+
+    /// Ring buffer of the latest upgrades, where the entry at index `i` is stored at
+    /// `i % MAX_ENTRIES`. Entries that were never written are zeroed.
+    pub entries: [UpgradeHistoryEntry; UpgradeHistory::MAX_ENTRIES],
+}
+
+impl UpgradeHistory {
+    pub const SEED_PREFIX: &'static [u8] = b"history";
+
+    /// Number of upgrades kept in the history.
+    pub const MAX_ENTRIES: usize = 16;
+
+    /// Entry at the given index, if it has been recorded and has not been overwritten.
+    pub fn entry(&self, index: u64) -> Option<&UpgradeHistoryEntry> {
+        let num_overwritten = self
+            .num_entries
+            .saturating_sub(u64::try_from(Self::MAX_ENTRIES).unwrap());
+        if index < num_overwritten || index >= self.num_entries {
+            return None;
+        }
+
+        self.entries.get(Self::position(index))
+    }
+
+    /// Latest entry, if any upgrade has been recorded.
+    pub fn latest_mut(&mut self) -> Option<&mut UpgradeHistoryEntry> {
+        let index = self.num_entries.checked_sub(1)?;
+        self.entries.get_mut(Self::position(index))
+    }
+
+    /// Record an entry, overwriting the oldest entry if the history is full.
+    pub fn push(&mut self, entry: UpgradeHistoryEntry) {
+        if let Some(slot) = self.entries.get_mut(Self::position(self.num_entries)) {
+            *slot = entry;
+        }
+        self.num_entries = self.num_entries.saturating_add(1);
+    }
+
+    fn position(index: u64) -> usize {
+        usize::try_from(index)
+            .unwrap()
+            .checked_rem(Self::MAX_ENTRIES)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(slot: u64) -> UpgradeHistoryEntry {
+        UpgradeHistoryEntry {
+            owner: Pubkey::new_unique(),
+            buffer: Pubkey::new_unique(),
+            buffer_hash: [1; 32],
+            elf_len: 420_690,
+            slot,
+            status: UpgradeHistoryStatus::Committed,
+        }
+    }
+
+    #[test]
+    fn init_space() {
+        let history = UpgradeHistory {
+            bump: 255,
+            program: Pubkey::new_unique(),
+            num_entries: 0,
+            entries: [entry(0); UpgradeHistory::MAX_ENTRIES],
+        };
+        assert_eq!(
+            history.try_to_vec().unwrap().len(),
+            UpgradeHistory::INIT_SPACE
+        );
+    }
+
+    #[test]
+    fn overwrite_oldest_entry() {
+        let mut history = UpgradeHistory {
+            bump: 255,
+            program: Pubkey::new_unique(),
+            num_entries: 0,
+            entries: [entry(0); UpgradeHistory::MAX_ENTRIES],
+        };
+        assert_eq!(history.latest_mut(), None);
+        assert_eq!(history.entry(0), None);
+
+        let max_entries = u64::try_from(UpgradeHistory::MAX_ENTRIES).unwrap();
+        for slot in 1..=max_entries.saturating_add(2) {
+            history.push(entry(slot));
+            assert_eq!(history.latest_mut().map(|latest| latest.slot), Some(slot));
+        }
+        assert_eq!(history.num_entries, max_entries.saturating_add(2));
+
+        // The first two entries were overwritten.
+        assert_eq!(history.entry(0), None);
+        assert_eq!(history.entry(1), None);
+        assert_eq!(history.entry(2).map(|entry| entry.slot), Some(3));
+        assert_eq!(
+            history
+                .entry(max_entries.saturating_add(1))
+                .map(|entry| entry.slot),
+            Some(max_entries.saturating_add(2))
+        );
+        assert_eq!(history.entry(max_entries.saturating_add(2)), None);
+    }
+}
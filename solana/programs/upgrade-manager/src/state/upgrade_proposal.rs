@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// An upgrade proposed by a program's owner. The upgrade can only be executed by the same owner
+/// once the upgrade delay has passed and only if the buffer's program bytes are unchanged.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct UpgradeProposal {
+    pub bump: u8,
+
+    /// Who proposed this upgrade.
+    pub owner: Pubkey,
+
+    pub program: Pubkey,
+    pub buffer: Pubkey,
+
+    /// Keccak256 hash of the buffer's program bytes (excluding the buffer's metadata).
+    pub buffer_hash: [u8; 32],
+
+    /// The slot when this upgrade was proposed.
+    pub slot_proposed_at: u64,
+
+    /// The upgrade cannot be executed before this slot.
+    pub earliest_execute_slot: u64,
+}
+
+impl UpgradeProposal {
+    pub const SEED_PREFIX: &'static [u8] = b"proposal";
+}
@@ -1,11 +1,95 @@
 use crate::{
     composite::*,
     error::UpgradeManagerError,
-    state::{UpgradeReceipt, UpgradeStatus},
+    events::UpgradeExecuted,
+    state::{
+        UpgradeHistory, UpgradeHistoryEntry, UpgradeHistoryStatus, UpgradeReceipt, UpgradeStatus,
+    },
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, keccak},
 };
-use anchor_lang::prelude::*;
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
 
+/// Discriminant of the buffer variant of BPF Loader Upgradeable's account state.
+const BUFFER_STATE_DISCRIMINANT: [u8; 4] = [1, 0, 0, 0];
+
+/// Compute the keccak256 hash of a buffer's program bytes. The buffer's metadata is excluded
+/// because its authority changes when an upgrade is executed.
+pub fn compute_buffer_hash(buffer: &AccountInfo) -> Result<[u8; 32]> {
+    require_keys_eq!(
+        *buffer.owner,
+        bpf_loader_upgradeable::id(),
+        UpgradeManagerError::InvalidBuffer
+    );
+
+    let data = buffer.try_borrow_data()?;
+    require!(
+        data.get(..4) == Some(&BUFFER_STATE_DISCRIMINANT[..]),
+        UpgradeManagerError::InvalidBuffer
+    );
+
+    let program_bytes = data
+        .get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+        .filter(|program_bytes| !program_bytes.is_empty())
+        .ok_or_else(|| error!(UpgradeManagerError::InvalidBuffer))?;
+
+    Ok(keccak::hash(program_bytes).0)
+}
+
+/// Require that the backup buffer is a buffer whose authority is the upgrade authority and whose
+/// size is exactly what is needed to hold `data_len` program bytes. Because every program byte is
+/// overwritten by the snapshot, anything written to this buffer beforehand does not matter.
+pub fn require_backup_buffer(backup_buffer: &AccountInfo, data_len: usize) -> Result<()> {
+    require_keys_eq!(
+        *backup_buffer.owner,
+        bpf_loader_upgradeable::id(),
+        UpgradeManagerError::InvalidBackupBuffer
+    );
+
+    let data = backup_buffer.try_borrow_data()?;
+    require!(
+        data.len() == UpgradeableLoaderState::size_of_buffer(data_len)
+            && data.get(..4) == Some(&BUFFER_STATE_DISCRIMINANT[..])
+            && data.get(4) == Some(&1)
+            && data.get(5..UpgradeableLoaderState::size_of_buffer_metadata())
+                == Some(common::UPGRADE_MANAGER_AUTHORITY.as_ref()),
+        UpgradeManagerError::InvalidBackupBuffer
+    );
+
+    Ok(())
+}
+
+/// Length of a buffer's program bytes (excluding the buffer's metadata).
+pub fn buffer_elf_len(buffer: &AccountInfo) -> u32 {
+    u32::try_from(
+        buffer
+            .data_len()
+            .saturating_sub(UpgradeableLoaderState::size_of_buffer_metadata()),
+    )
+    .unwrap()
+}
+
+/// Record an entry in a program's upgrade history. If the latest entry is uncommitted, it is marked
+/// superseded and its buffer is returned.
+pub fn append_upgrade_history(
+    history: &mut UpgradeHistory,
+    entry: UpgradeHistoryEntry,
+) -> Option<Pubkey> {
+    let superseded_buffer = history
+        .latest_mut()
+        .filter(|latest| latest.status == UpgradeHistoryStatus::Uncommitted)
+        .map(|latest| {
+            latest.status = UpgradeHistoryStatus::Superseded;
+            latest.buffer
+        });
+
+    history.push(entry);
+
+    superseded_buffer
+}
+
 pub trait AuthorizeUpgrade<'info> {
     fn execute_upgrade_composite_mut(&mut self) -> &mut ExecuteUpgrade<'info>;
 
@@ -19,7 +103,10 @@ where
     accounts.execute_upgrade_composite_mut()
 }
 
-pub fn execute_upgrade<'info, A>(accounts: &mut A, bumps: &ExecuteUpgradeBumps) -> Result<()>
+pub fn execute_upgrade<'info, A>(
+    accounts: &mut A,
+    bumps: &ExecuteUpgradeBumps,
+) -> Result<UpgradeExecuted>
 where
     A: AuthorizeUpgrade<'info>,
 {
@@ -29,7 +116,10 @@ where
     }
 }
 
-fn handle_new_upgrade<'info, A>(accounts: &mut A, bumps: &ExecuteUpgradeBumps) -> Result<()>
+fn handle_new_upgrade<'info, A>(
+    accounts: &mut A,
+    bumps: &ExecuteUpgradeBumps,
+) -> Result<UpgradeExecuted>
 where
     A: AuthorizeUpgrade<'info>,
 {
@@ -37,7 +127,10 @@ where
     handle_upgrade(accounts, bumps)
 }
 
-fn handle_upgrade_with_status<'info, A>(accounts: &mut A, bumps: &ExecuteUpgradeBumps) -> Result<()>
+fn handle_upgrade_with_status<'info, A>(
+    accounts: &mut A,
+    bumps: &ExecuteUpgradeBumps,
+) -> Result<UpgradeExecuted>
 where
     A: AuthorizeUpgrade<'info>,
 {
@@ -58,7 +151,10 @@ where
     handle_upgrade(accounts, bumps)
 }
 
-fn handle_upgrade<'info, A>(accounts: &mut A, bumps: &ExecuteUpgradeBumps) -> Result<()>
+fn handle_upgrade<'info, A>(
+    accounts: &mut A,
+    bumps: &ExecuteUpgradeBumps,
+) -> Result<UpgradeExecuted>
 where
     A: AuthorizeUpgrade<'info>,
 {
@@ -67,6 +163,8 @@ where
         admin,
         receipt,
         buffer,
+        proposal,
+        history,
         program_data,
         program,
         bpf_loader_upgradeable_program,
@@ -79,16 +177,21 @@ where
         upgrade_authority,
     } = admin;
 
+    let slot = Clock::get().unwrap().slot;
+
     receipt.set_inner(UpgradeReceipt {
         bump: bumps.receipt,
         program_data_bump: bumps.program_data,
         owner: owner.key(),
         status: UpgradeStatus::Uncommitted {
             buffer: buffer.key(),
-            slot: Clock::get().unwrap().slot,
+            slot,
         },
     });
 
+    // The buffer is closed by the upgrade, so its length must be read beforehand.
+    let elf_len = buffer_elf_len(buffer);
+
     // First set the buffer's authority to the upgrade authority.
     bpf_loader_upgradeable::set_buffer_authority_checked(CpiContext::new_with_signer(
         bpf_loader_upgradeable_program.to_account_info(),
@@ -112,5 +215,30 @@ where
             clock: sysvars.clock.to_account_info(),
         },
         &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
-    ))
+    ))?;
+
+    history.bump = bumps.history;
+    history.program = program.key();
+
+    let superseded_buffer = append_upgrade_history(
+        history,
+        UpgradeHistoryEntry {
+            owner: owner.key(),
+            buffer: buffer.key(),
+            buffer_hash: proposal.buffer_hash,
+            elf_len,
+            slot,
+            status: UpgradeHistoryStatus::Uncommitted,
+        },
+    );
+
+    Ok(UpgradeExecuted {
+        owner: owner.key(),
+        program: program.key(),
+        buffer: buffer.key(),
+        buffer_hash: proposal.buffer_hash,
+        elf_len,
+        superseded_buffer,
+        slot,
+    })
 }
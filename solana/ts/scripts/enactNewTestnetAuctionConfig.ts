@@ -21,6 +21,10 @@ const AUCTION_PARAMS: AuctionParameters = {
     minOfferDeltaBps: 50_000, // 5%
     securityDepositBase: uint64ToBN(1_000_000), // 1 USDC
     securityDepositBps: 5_000, // 0.5%
+    revealPeriod: 0,
+    extensionWindow: 0,
+    maxExtension: 0,
+    numTranches: 0,
 };
 
 // Here we go.
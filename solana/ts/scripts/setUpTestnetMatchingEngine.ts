@@ -26,6 +26,10 @@ const AUCTION_PARAMS: AuctionParameters = {
     minOfferDeltaBps: 50000, // 5%
     securityDepositBase: uint64ToBN(1000000n), // 1 USDC
     securityDepositBps: 5000, // 0.5%
+    revealPeriod: 0,
+    extensionWindow: 0,
+    maxExtension: 0,
+    numTranches: 0,
 };
 
 // Here we go.
@@ -52,6 +52,92 @@ export type MatchingEngine = {
       ],
       "args": []
     },
+    {
+      "name": "addCctpMint",
+      "docs": [
+        "This instruction is used to register a Circle-supported mint other than USDC, which allows",
+        "auctions and order responses to use this mint. It also creates the custodian's associated",
+        "token account for this mint, which is the CCTP mint recipient for this mint. This",
+        "instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `AddCctpMint` context."
+      ],
+      "discriminator": [
+        59,
+        34,
+        219,
+        188,
+        138,
+        77,
+        111,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "cctpMint",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Circle-supported mint other than USDC, which is always supported."
+          ]
+        },
+        {
+          "name": "mintRecipient",
+          "docs": [
+            "Custodian's associated token account for this mint, which will be encoded as the mint",
+            "recipient in CCTP messages sent to this program."
+          ],
+          "writable": true
+        },
+        {
+          "name": "feeRecipientToken"
+        },
+        {
+          "name": "auctionConfig",
+          "docs": [
+            "Auction config used for auctions of this mint without a route override. If not provided,",
+            "the global auction config is used."
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addCctpRouterEndpoint",
       "docs": [
@@ -375,84 +461,64 @@ export type MatchingEngine = {
       "args": []
     },
     {
-      "name": "completeFastFill",
+      "name": "closeUnfilledAuctionTranche",
       "docs": [
-        "This instruction is used to complete the fast fill after the `FastFill` account has been",
-        "created. The Token Router program on Solana will invoke this instruction to complete the",
-        "fast fill, marking it as redeemed. Tokens will be deposited into the local endpoint's",
-        "custody account.",
+        "This instruction is used to close a tranche of a split-fill auction that ended before every",
+        "tranche had an offer. The tranche's total deposit is refunded to its best offer token. Once",
+        "every placed tranche is closed, the auction is closed. This instruction can be called by",
+        "anyone.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `CompleteFastFill` context."
+        "* `ctx` - `CloseUnfilledAuctionTranche` context."
       ],
       "discriminator": [
-        113,
-        252,
-        68,
-        134,
-        84,
-        61,
-        113,
-        203
+        195,
+        131,
+        161,
+        99,
+        17,
+        181,
+        87,
+        19
       ],
       "accounts": [
         {
-          "name": "custodian",
-          "accounts": [
-            {
-              "name": "custodian"
-            }
-          ]
+          "name": "auction",
+          "writable": true
         },
         {
-          "name": "fastFill",
-          "docs": [
-            "Fast fill account.",
-            "",
-            "NOTE: This account may have been closed if the fast fill was already redeemed, so this",
-            "deserialization will fail in this case.",
-            "",
-            "Seeds must be \\[\"fast-fill\", source_chain, order_sender, sequence\\]."
-          ],
+          "name": "config"
+        },
+        {
+          "name": "auctionTranche",
           "writable": true
         },
         {
-          "name": "tokenRouterEmitter",
-          "docs": [
-            "Only the registered local Token Router program can call this instruction. It is allowed to",
-            "invoke this instruction by using its emitter (i.e. its Custodian account) as a signer. We",
-            "double-check that this signer is the same one registered for the local router endpoint."
-          ],
-          "signer": true
+          "name": "custodyToken",
+          "writable": true
         },
         {
-          "name": "tokenRouterCustodyToken",
+          "name": "bestOfferToken",
           "writable": true
         },
         {
-          "name": "path",
-          "accounts": [
-            {
-              "name": "fromEndpoint",
-              "accounts": [
-                {
-                  "name": "endpoint"
-                }
-              ]
-            },
-            {
-              "name": "toEndpoint",
-              "accounts": [
-                {
-                  "name": "endpoint"
-                }
-              ]
-            }
-          ]
+          "name": "executorToken",
+          "docs": [
+            "Token account receiving the tranche's total deposit if the best offer token does not exist",
+            "anymore."
+          ],
+          "writable": true
         },
         {
-          "name": "localCustodyToken",
+          "name": "preparedBy",
+          "writable": true
+        },
+        {
+          "name": "auctionPreparedBy",
+          "docs": [
+            "tranche, this account receives the rent for the auction and its custody token account."
+          ],
           "writable": true
         },
         {
@@ -468,379 +534,422 @@ export type MatchingEngine = {
       "args": []
     },
     {
-      "name": "confirmOwnershipTransferRequest",
+      "name": "closeUnrevealedOfferCommitment",
       "docs": [
-        "This instruction confirms the ownership transfer request and sets the new `owner` in the",
-        "`Custodian` account. This instruction can only be called by the `pending_owner`. The",
-        "`pending_owner` must be the same as the `pending_owner` in the `Custodian` account.",
+        "This instruction is used to close an offer commitment that was not revealed by the end of a",
+        "sealed-bid auction's reveal period. Part of the commitment's security deposit is forfeited",
+        "and split between the `executor_token` and the user (or the fee recipient if the order was",
+        "already executed). The rest of the deposit is returned to the offer token. This instruction",
+        "can be called by anyone.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ConfirmOwnershipTransferRequest` context."
+        "* `ctx` - `CloseUnrevealedOfferCommitment` context."
       ],
       "discriminator": [
-        118,
-        148,
-        109,
-        68,
-        201,
-        30,
-        139,
-        53
+        175,
+        175,
+        6,
+        211,
+        81,
+        76,
+        197,
+        169
       ],
       "accounts": [
         {
-          "name": "pendingOwner",
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "auctionCustodyToken",
           "docs": [
-            "Must be the pending owner of the program set in the [`OwnerConfig`]",
-            "account."
+            "Auction custody token account, which receives the user reward if the auction has not been",
+            "executed yet.",
+            ""
           ],
-          "signer": true
+          "writable": true
         },
         {
-          "name": "custodian",
+          "name": "offerCommitment",
+          "writable": true
+        },
+        {
+          "name": "commitmentCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "offerToken",
+          "writable": true
+        },
+        {
+          "name": "executorToken",
+          "docs": [
+            "Token account receiving the penalty for closing this unrevealed commitment."
+          ],
+          "writable": true
+        },
+        {
+          "name": "feeRecipientToken",
+          "docs": [
+            "Receives the user reward if the auction has already been executed."
+          ],
+          "writable": true
+        },
+        {
+          "name": "cctpMint",
+          "docs": [
+            "CCTP mint registration for the auction's mint, which may not exist if the mint is USDC.",
+            ""
+          ]
+        },
+        {
+          "name": "preparedBy",
           "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "createFirstAuctionHistory",
+      "name": "commitOffer",
       "docs": [
-        "DEPRECATED. This instruction does not exist anymore.",
-        "",
-        "This instruction is used to create the first `AuctionHistory` account, whose PDA is derived",
-        "using ID == 0.",
+        "This instruction is used to commit a sealed offer to a sealed-bid auction during the",
+        "auction duration. The commitment is the keccak256 hash of the offer token, the offer price",
+        "(big-endian) and a salt. The committed offer's total deposit is transferred to a custody",
+        "token account owned by the `OfferCommitment` account. This instruction will revert if the",
+        "auction is not a sealed-bid auction.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `CreateFirstAuctionHistory` context."
+        "* `ctx`        - `CommitOffer` context.",
+        "* `commitment` - Hash of the offer token, offer price and salt."
       ],
       "discriminator": [
-        225,
-        203,
-        74,
-        36,
-        233,
-        11,
-        54,
-        75
+        242,
+        197,
+        194,
+        41,
+        201,
+        191,
+        3,
+        22
       ],
       "accounts": [
         {
-          "name": "dummy"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createNewAuctionHistory",
-      "docs": [
-        "DEPRECATED. This instruction does not exist anymore.",
-        "",
-        "This instruction is used to create a new `AuctionHistory` account. The PDA is derived using",
-        "its ID. A new history account can be created only when the current one is full (number of",
-        "entries equals the hard-coded max entries).",
-        "",
-        "# Arguments",
-        "",
-        "* `ctx` - `CreateNewAuctionHistory` context."
-      ],
-      "discriminator": [
-        63,
-        157,
-        83,
-        157,
-        231,
-        58,
-        207,
-        227
-      ],
-      "accounts": [
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
         {
-          "name": "dummy"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "disableRouterEndpoint",
-      "docs": [
-        "This instruction is used to disable a router endpoint. This instruction does not close the",
-        "account, it only sets the `protocol` to `None` and clears the `address` and",
-        "`mint_recipient`. This instruction can only be called by the `owner`.",
-        "",
-        "# Arguments",
-        "",
-        "* `ctx` - `DisableRouterEndpoint` context."
-      ],
-      "discriminator": [
-        119,
-        145,
-        105,
-        95,
-        65,
-        77,
-        61,
-        69
-      ],
-      "accounts": [
+          "name": "transferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA.",
+            ""
+          ]
+        },
         {
-          "name": "admin",
+          "name": "activeAuction",
           "accounts": [
             {
-              "name": "owner",
-              "signer": true
+              "name": "auction",
+              "writable": true
             },
             {
-              "name": "custodian",
-              "accounts": [
-                {
-                  "name": "custodian"
-                }
-              ]
-            }
-          ]
-        },
-        {
-          "name": "routerEndpoint",
-          "accounts": [
+              "name": "custodyToken",
+              "writable": true
+            },
             {
-              "name": "endpoint",
+              "name": "config"
+            },
+            {
+              "name": "bestOfferToken",
               "writable": true
             }
           ]
+        },
+        {
+          "name": "offerToken",
+          "writable": true
+        },
+        {
+          "name": "registeredSolver",
+          "docs": [
+            "Registration of the solver committing this offer. This account must be provided if the",
+            "auction only allows registered solvers."
+          ],
+          "optional": true
+        },
+        {
+          "name": "offerCommitment",
+          "docs": [
+            "Only one offer can be committed per offer token for each auction."
+          ],
+          "writable": true
+        },
+        {
+          "name": "commitmentCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
     },
     {
-      "name": "executeFastOrderCctp",
+      "name": "completeFastFill",
       "docs": [
-        "This instruction is used to execute the fast order after the auction period has ended.",
-        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
-        "incur a penalty. Once executed, a CCTP transfer will be sent to the recipient encoded in the",
-        "`FastMarketOrder` VAA on the target chain.",
+        "This instruction is used to complete the fast fill after the `FastFill` account has been",
+        "created. The Token Router program on Solana will invoke this instruction to complete the",
+        "fast fill, marking it as redeemed. Tokens will be deposited into the local endpoint's",
+        "custody account.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ExecuteFastOrderCctp` context."
+        "* `ctx` - `CompleteFastFill` context."
       ],
       "discriminator": [
-        176,
-        38,
-        30,
-        17,
-        230,
-        78,
-        206,
-        157
+        113,
+        252,
+        68,
+        134,
+        84,
+        61,
+        113,
+        203
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
         },
         {
-          "name": "coreMessage",
+          "name": "fastFill",
+          "docs": [
+            "Fast fill account.",
+            "",
+            "NOTE: This account may have been closed if the fast fill was already redeemed, so this",
+            "deserialization will fail in this case.",
+            "",
+            "Seeds must be \\[\"fast-fill\", source_chain, order_sender, sequence\\]."
+          ],
           "writable": true
         },
         {
-          "name": "cctpMessage",
-          "writable": true
+          "name": "tokenRouterEmitter",
+          "docs": [
+            "Only the registered local Token Router program can call this instruction. It is allowed to",
+            "invoke this instruction by using its emitter (i.e. its Custodian account) as a signer. We",
+            "double-check that this signer is the same one registered for the local router endpoint."
+          ],
+          "signer": true
         },
         {
-          "name": "custodian",
-          "accounts": [
-            {
-              "name": "custodian"
-            }
-          ]
+          "name": "tokenRouterCustodyToken",
+          "writable": true
         },
         {
-          "name": "executeOrder",
+          "name": "path",
           "accounts": [
             {
-              "name": "fastVaa",
+              "name": "fromEndpoint",
               "accounts": [
                 {
-                  "name": "vaa"
+                  "name": "endpoint"
                 }
               ]
             },
             {
-              "name": "activeAuction",
+              "name": "toEndpoint",
               "accounts": [
                 {
-                  "name": "auction",
-                  "writable": true
-                },
-                {
-                  "name": "custodyToken",
-                  "writable": true
-                },
-                {
-                  "name": "config"
-                },
-                {
-                  "name": "bestOfferToken",
-                  "writable": true
+                  "name": "endpoint"
                 }
               ]
-            },
-            {
-              "name": "executorToken",
-              "docs": [
-                "Must be a token account, whose mint is [common::USDC_MINT]."
-              ],
-              "writable": true
-            },
-            {
-              "name": "initialOfferToken",
-              "writable": true
-            },
-            {
-              "name": "initialParticipant",
-              "writable": true
             }
           ]
         },
         {
-          "name": "toRouterEndpoint",
-          "accounts": [
-            {
-              "name": "endpoint"
-            }
-          ]
+          "name": "localCustodyToken",
+          "writable": true
         },
         {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "config",
-              "writable": true
-            },
-            {
-              "name": "emitterSequence",
-              "writable": true
-            },
-            {
-              "name": "feeCollector",
-              "writable": true
-            },
-            {
-              "name": "coreBridgeProgram"
-            }
-          ]
+          "name": "tokenProgram"
         },
         {
-          "name": "cctp",
-          "accounts": [
-            {
-              "name": "mint",
-              "docs": [
-                "Circle-supported mint.",
-                "",
-                "Token Messenger Minter program's local token account."
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterSenderAuthority"
-            },
-            {
-              "name": "messageTransmitterConfig",
-              "writable": true
-            },
-            {
-              "name": "tokenMessenger"
-            },
-            {
-              "name": "remoteTokenMessenger",
-              "docs": [
-                "Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "tokenMinter",
-              "docs": [
-                "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "localToken",
-              "docs": [
-                "Local token account, which this program uses to validate the `mint` used to burn.",
-                ""
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterEventAuthority"
-            },
-            {
-              "name": "tokenMessengerMinterProgram"
-            },
-            {
-              "name": "messageTransmitterProgram"
-            }
-          ]
+          "name": "eventAuthority"
         },
         {
-          "name": "systemProgram"
-        },
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "confirmOwnershipTransferRequest",
+      "docs": [
+        "This instruction confirms the ownership transfer request and sets the new `owner` in the",
+        "`Custodian` account. This instruction can only be called by the `pending_owner`. The",
+        "`pending_owner` must be the same as the `pending_owner` in the `Custodian` account.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `ConfirmOwnershipTransferRequest` context."
+      ],
+      "discriminator": [
+        118,
+        148,
+        109,
+        68,
+        201,
+        30,
+        139,
+        53
+      ],
+      "accounts": [
         {
-          "name": "tokenProgram"
+          "name": "pendingOwner",
+          "docs": [
+            "Must be the pending owner of the program set in the [`OwnerConfig`]",
+            "account."
+          ],
+          "signer": true
         },
         {
-          "name": "sysvars",
-          "accounts": [
-            {
-              "name": "clock",
-              "docs": [
-                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
-                ""
-              ]
-            },
-            {
-              "name": "rent",
-              "docs": [
-                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
-                ""
-              ]
-            }
-          ]
-        },
+          "name": "custodian",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createFirstAuctionHistory",
+      "docs": [
+        "DEPRECATED. This instruction does not exist anymore.",
+        "",
+        "This instruction is used to create the first `AuctionHistory` account, whose PDA is derived",
+        "using ID == 0.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `CreateFirstAuctionHistory` context."
+      ],
+      "discriminator": [
+        225,
+        203,
+        74,
+        36,
+        233,
+        11,
+        54,
+        75
+      ],
+      "accounts": [
         {
-          "name": "eventAuthority"
-        },
+          "name": "dummy"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createNewAuctionHistory",
+      "docs": [
+        "DEPRECATED. This instruction does not exist anymore.",
+        "",
+        "This instruction is used to create a new `AuctionHistory` account. The PDA is derived using",
+        "its ID. A new history account can be created only when the current one is full (number of",
+        "entries equals the hard-coded max entries).",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `CreateNewAuctionHistory` context."
+      ],
+      "discriminator": [
+        63,
+        157,
+        83,
+        157,
+        231,
+        58,
+        207,
+        227
+      ],
+      "accounts": [
         {
-          "name": "program"
+          "name": "dummy"
         }
       ],
       "args": []
     },
     {
-      "name": "executeFastOrderLocal",
+      "name": "depositLocalFastOrder",
       "docs": [
-        "This instruction is used to execute the fast order after the auction period has ended.",
-        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
-        "incur a penalty. Once executed, a `FastFill` account will be created.",
+        "This instruction is used by the local Token Router to deposit the funds for a fast market",
+        "order placed on Solana. The Token Router invokes this instruction right after it publishes",
+        "the `FastMarketOrder` message, whose sequence is used to derive the `local_fast_order`",
+        "account. Only the Token Router's emitter (registered as the local router endpoint) can",
+        "sign for this deposit.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ExecuteFastOrderLocal` context."
+        "* `ctx`  - `DepositLocalFastOrder` context.",
+        "* `args` - Deposit args, which has the fast market order's sequence, amount and base fee."
       ],
       "discriminator": [
-        140,
-        206,
-        26,
-        243,
-        243,
-        66,
-        24,
-        240
+        54,
+        237,
+        251,
+        17,
+        97,
+        129,
+        220,
+        124
       ],
       "accounts": [
         {
@@ -857,444 +966,378 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "executeOrder",
+          "name": "tokenRouterEmitter",
+          "docs": [
+            "The local Token Router's emitter (a.k.a. its custodian), which must sign for the deposit."
+          ],
+          "signer": true
+        },
+        {
+          "name": "localEndpoint",
           "accounts": [
             {
-              "name": "fastVaa",
-              "accounts": [
-                {
-                  "name": "vaa"
-                }
-              ]
-            },
-            {
-              "name": "activeAuction",
-              "accounts": [
-                {
-                  "name": "auction",
-                  "writable": true
-                },
-                {
-                  "name": "custodyToken",
-                  "writable": true
-                },
-                {
-                  "name": "config"
-                },
-                {
-                  "name": "bestOfferToken",
-                  "writable": true
-                }
-              ]
-            },
-            {
-              "name": "executorToken",
-              "docs": [
-                "Must be a token account, whose mint is [common::USDC_MINT]."
-              ],
-              "writable": true
-            },
-            {
-              "name": "initialOfferToken",
-              "writable": true
-            },
-            {
-              "name": "initialParticipant",
-              "writable": true
+              "name": "endpoint"
             }
           ]
         },
         {
-          "name": "reservedSequence",
-          "docs": [
-            "This account will be closed at the end of this instruction instead of using the close",
-            "account directive here.",
-            "",
-            "NOTE: We do not need to do a VAA hash check because that was already performed when the",
-            "reserved sequence was created."
-          ],
+          "name": "localFastOrder",
           "writable": true
         },
         {
-          "name": "reserveBeneficiary",
+          "name": "sourceToken",
           "docs": [
-            "When the reserved sequence account was created, the beneficiary was set to the best offer",
-            "token's owner if it existed (and if not, to whomever executed the reserve fast fill sequence",
-            "instruction). This account will receive the lamports from the reserved sequence account.",
-            ""
+            "Token account owned by the local Token Router's emitter, from which the amount in is",
+            "deposited."
           ],
           "writable": true
         },
         {
-          "name": "fastFill",
-          "writable": true
-        },
-        {
-          "name": "localCustodyToken",
+          "name": "custodyToken",
           "writable": true
         },
         {
-          "name": "systemProgram"
+          "name": "usdc",
+          "accounts": [
+            {
+              "name": "mint"
+            }
+          ]
         },
         {
           "name": "tokenProgram"
         },
         {
-          "name": "sysvars",
-          "accounts": [
-            {
-              "name": "clock",
-              "docs": [
-                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
-                ""
-              ]
-            },
-            {
-              "name": "rent",
-              "docs": [
-                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
-                ""
-              ]
-            }
-          ]
-        },
-        {
-          "name": "eventAuthority"
-        },
-        {
-          "name": "program"
+          "name": "systemProgram"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "depositLocalFastOrderArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "improveOffer",
+      "name": "disableRouterEndpoint",
       "docs": [
-        "This instruction is used to improve an existing auction offer. The `offer_price` must be",
-        "greater than the current `offer_price` in the auction. This instruction will revert if the",
-        "`offer_price` is less than the current `offer_price`. This instruction can be called by",
-        "anyone.",
+        "This instruction is used to disable a router endpoint. This instruction does not close the",
+        "account, it only sets the `protocol` to `None` and clears the `address` and",
+        "`mint_recipient`. This instruction can only be called by the `owner`.",
         "",
         "# Arguments",
         "",
-        "* `ctx`         - `ImproveOffer` context.",
-        "* `offer_price` - The fee that the caller is willing to accept in order for fufilling the",
-        "fast order. This fee is paid in USDC."
+        "* `ctx` - `DisableRouterEndpoint` context."
       ],
       "discriminator": [
-        171,
-        112,
-        46,
-        172,
-        194,
-        135,
-        23,
-        102
+        119,
+        145,
+        105,
+        95,
+        65,
+        77,
+        61,
+        69
       ],
       "accounts": [
         {
-          "name": "transferAuthority",
-          "docs": [
-            "The auction participant needs to set approval to this PDA.",
-            ""
-          ]
-        },
-        {
-          "name": "activeAuction",
+          "name": "admin",
           "accounts": [
             {
-              "name": "auction",
-              "writable": true
-            },
-            {
-              "name": "custodyToken",
-              "writable": true
-            },
-            {
-              "name": "config"
+              "name": "owner",
+              "signer": true
             },
             {
-              "name": "bestOfferToken",
-              "writable": true
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
             }
           ]
         },
         {
-          "name": "offerToken"
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "eventAuthority"
-        },
-        {
-          "name": "program"
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
         }
       ],
-      "args": [
-        {
-          "name": "offerPrice",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initialize",
+      "name": "executeAuctionTranche",
       "docs": [
-        "This instruction is be used to generate the program's `custodian` and `auction_config`",
-        "configs. It also reates the `owner` and `fee_recipient` accounts. Finally, it sets the",
-        "upgrade authority to the `upgrade_manager_authority`. Upgrades are managed by the",
-        "`upgrade_manager_program`.",
+        "This instruction is used to execute a tranche of a split-fill auction after the auction",
+        "period has ended and every tranche has an offer. It should be executed before the",
+        "`grace_period` has ended, otherwise the tranche's best offer will incur a penalty. The",
+        "tranche's participants are paid and the amount owed to the user is held in the auction's",
+        "custody token account. Once every tranche is executed, the fast order can be executed with",
+        "`execute_fast_order_cctp` or `execute_fast_order_local`.",
         "",
         "# Arguments",
         "",
-        "* `ctx`  - `Initialize` context.",
-        "* `args` - Initialize args, which has the initial [AuctionParameters]."
+        "* `ctx` - `ExecuteAuctionTranche` context."
       ],
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        191,
+        110,
+        197,
+        124,
+        199,
+        147,
+        6,
+        108
       ],
       "accounts": [
         {
-          "name": "owner",
-          "docs": [
-            "Owner of the program, who presumably deployed this program."
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "custodian",
-          "docs": [
-            "Custodian account, which saves program data useful for other",
-            "instructions."
-          ],
-          "writable": true
-        },
-        {
-          "name": "auctionConfig",
+          "name": "auction",
           "writable": true
         },
         {
-          "name": "ownerAssistant",
-          "docs": [
-            "TODO: do we prevent the owner from being the owner assistant?"
-          ]
+          "name": "config"
         },
         {
-          "name": "feeRecipient"
+          "name": "auctionTranche",
+          "writable": true
         },
         {
-          "name": "feeRecipientToken"
+          "name": "custodyToken",
+          "writable": true
         },
         {
-          "name": "cctpMintRecipient",
+          "name": "bestOfferToken",
           "writable": true
         },
         {
-          "name": "usdc",
-          "accounts": [
-            {
-              "name": "mint"
-            }
-          ]
+          "name": "initialOfferToken",
+          "writable": true
         },
         {
-          "name": "programData",
+          "name": "executorToken",
           "docs": [
-            "We use the program data to make sure this owner is the upgrade authority (the true owner,",
-            "who deployed this program)."
+            "Must be a token account, whose mint is the auction's mint."
           ],
           "writable": true
         },
         {
-          "name": "upgradeManagerAuthority"
-        },
-        {
-          "name": "upgradeManagerProgram"
-        },
-        {
-          "name": "bpfLoaderUpgradeableProgram"
-        },
-        {
-          "name": "systemProgram"
+          "name": "tokenProgram"
         },
         {
-          "name": "tokenProgram"
+          "name": "eventAuthority"
         },
         {
-          "name": "associatedTokenProgram"
+          "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "initializeArgs"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "migrate",
+      "name": "executeFastOrderCctp",
       "docs": [
-        "This instruction is used for executing logic during an upgrade. This instruction can only be",
-        "called by the `upgrade_manager_program`.",
+        "This instruction is used to execute the fast order after the auction period has ended.",
+        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
+        "incur a penalty. Once executed, a CCTP transfer will be sent to the recipient encoded in the",
+        "`FastMarketOrder` VAA on the target chain.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `Migrate` context."
+        "* `ctx` - `ExecuteFastOrderCctp` context."
       ],
       "discriminator": [
-        155,
-        234,
-        231,
-        146,
-        236,
-        158,
-        162,
-        30
+        176,
+        38,
+        30,
+        17,
+        230,
+        78,
+        206,
+        157
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "cctpMessage",
+          "writable": true
+        },
+        {
+          "name": "custodian",
           "accounts": [
             {
-              "name": "owner",
-              "signer": true
-            },
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "executeOrder",
+          "accounts": [
             {
-              "name": "custodian",
+              "name": "fastVaa",
               "accounts": [
                 {
-                  "name": "custodian"
+                  "name": "vaa"
                 }
               ]
-            }
-          ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "placeInitialOfferCctp",
-      "docs": [
-        "This instruction is used to create a new auction given a valid `FastMarketOrder` vaa. This",
-        "instruction will record information about the auction and transfer funds from the payer to",
-        "an auction-specific token custody account. This instruction can be called by anyone.",
-        "# Arguments",
-        "",
-        "* `ctx`         - `PlaceInitialOfferCctp` context.",
-        "* `offer_price` - The fee that the caller is willing to accept in order for fufilling the",
-        "fast order. This fee is paid in USDC."
-      ],
-      "discriminator": [
-        157,
-        156,
-        175,
-        35,
-        91,
-        249,
-        1,
-        129
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "transferAuthority",
-          "docs": [
-            "The auction participant needs to set approval to this PDA.",
-            ""
+            },
+            {
+              "name": "activeAuction",
+              "accounts": [
+                {
+                  "name": "auction",
+                  "writable": true
+                },
+                {
+                  "name": "custodyToken",
+                  "writable": true
+                },
+                {
+                  "name": "config"
+                },
+                {
+                  "name": "bestOfferToken",
+                  "writable": true
+                }
+              ]
+            },
+            {
+              "name": "executorToken",
+              "docs": [
+                "Must be a token account, whose mint is [common::USDC_MINT]."
+              ],
+              "writable": true
+            },
+            {
+              "name": "initialOfferToken",
+              "writable": true
+            },
+            {
+              "name": "initialParticipant",
+              "writable": true
+            }
           ]
         },
         {
-          "name": "custodian",
+          "name": "toRouterEndpoint",
           "accounts": [
             {
-              "name": "custodian"
+              "name": "endpoint"
             }
           ]
         },
         {
-          "name": "auctionConfig"
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "config",
+              "writable": true
+            },
+            {
+              "name": "emitterSequence",
+              "writable": true
+            },
+            {
+              "name": "feeCollector",
+              "writable": true
+            },
+            {
+              "name": "coreBridgeProgram"
+            }
+          ]
         },
         {
-          "name": "fastOrderPath",
+          "name": "cctp",
           "accounts": [
             {
-              "name": "fastVaa",
-              "accounts": [
-                {
-                  "name": "vaa"
-                }
+              "name": "mint",
+              "docs": [
+                "Circle-supported mint.",
+                "",
+                "Token Messenger Minter program's local token account."
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterSenderAuthority"
+            },
+            {
+              "name": "messageTransmitterConfig",
+              "writable": true
+            },
+            {
+              "name": "tokenMessenger"
+            },
+            {
+              "name": "remoteTokenMessenger",
+              "docs": [
+                "Messenger Minter program)."
               ]
             },
             {
-              "name": "path",
-              "accounts": [
-                {
-                  "name": "fromEndpoint",
-                  "accounts": [
-                    {
-                      "name": "endpoint"
-                    }
-                  ]
-                },
-                {
-                  "name": "toEndpoint",
-                  "accounts": [
-                    {
-                      "name": "endpoint"
-                    }
-                  ]
-                }
+              "name": "tokenMinter",
+              "docs": [
+                "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
               ]
+            },
+            {
+              "name": "localToken",
+              "docs": [
+                "Local token account, which this program uses to validate the `mint` used to burn.",
+                ""
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterEventAuthority"
+            },
+            {
+              "name": "tokenMessengerMinterProgram"
+            },
+            {
+              "name": "messageTransmitterProgram"
             }
           ]
         },
         {
-          "name": "auction",
-          "docs": [
-            "This account should only be created once, and should never be changed to",
-            "init_if_needed. Otherwise someone can game an existing auction."
-          ],
-          "writable": true
-        },
-        {
-          "name": "offerToken"
+          "name": "systemProgram"
         },
         {
-          "name": "auctionCustodyToken",
-          "writable": true
+          "name": "tokenProgram"
         },
         {
-          "name": "usdc",
+          "name": "sysvars",
           "accounts": [
             {
-              "name": "mint"
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
             }
           ]
         },
-        {
-          "name": "systemProgram"
-        },
-        {
-          "name": "tokenProgram"
-        },
         {
           "name": "eventAuthority"
         },
@@ -1302,35 +1345,31 @@ export type MatchingEngine = {
           "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "offerPrice",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "prepareOrderResponseCctp",
+      "name": "executeFastOrderLocal",
       "docs": [
-        "This instruction is used to prepare the order response for a CCTP transfer. This instruction",
-        "will redeem the finalized transfer associated with a particular auction, and deposit the",
-        "funds to the `prepared_custody_token` account that is created during execution. This",
-        "instruction will create a `PreparedOrderResponse` account that will be used to settle the",
-        "auction.",
+        "This instruction is used to execute the fast order after the auction period has ended.",
+        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
+        "incur a penalty. Once executed, a `FastFill` account will be created. If the amount after",
+        "fees is less than the order's `min_amount_out`, the fast fill's redeemer is the order's",
+        "`refund_address` instead, which is only allowed if the order originated from Solana. Otherwise",
+        "this instruction will revert because the order must be refunded to the source chain.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `PrepareOrderResponseCctp` context."
+        "* `ctx` - `ExecuteFastOrderLocal` context."
       ],
       "discriminator": [
-        221,
-        178,
-        184,
-        43,
-        247,
-        248,
-        90,
-        160
+        140,
+        206,
+        26,
+        243,
+        243,
+        66,
+        24,
+        240
       ],
       "accounts": [
         {
@@ -1347,7 +1386,7 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "fastOrderPath",
+          "name": "executeOrder",
           "accounts": [
             {
               "name": "fastVaa",
@@ -1358,203 +1397,163 @@ export type MatchingEngine = {
               ]
             },
             {
-              "name": "path",
+              "name": "activeAuction",
               "accounts": [
                 {
-                  "name": "fromEndpoint",
-                  "accounts": [
-                    {
-                      "name": "endpoint"
-                    }
-                  ]
+                  "name": "auction",
+                  "writable": true
                 },
                 {
-                  "name": "toEndpoint",
-                  "accounts": [
-                    {
-                      "name": "endpoint"
-                    }
-                  ]
+                  "name": "custodyToken",
+                  "writable": true
+                },
+                {
+                  "name": "config"
+                },
+                {
+                  "name": "bestOfferToken",
+                  "writable": true
                 }
               ]
-            }
-          ]
-        },
-        {
-          "name": "finalizedVaa",
-          "accounts": [
+            },
             {
-              "name": "vaa"
-            }
-          ]
-        },
-        {
-          "name": "preparedOrderResponse",
-          "writable": true
+              "name": "executorToken",
+              "docs": [
+                "Must be a token account, whose mint is [common::USDC_MINT]."
+              ],
+              "writable": true
+            },
+            {
+              "name": "initialOfferToken",
+              "writable": true
+            },
+            {
+              "name": "initialParticipant",
+              "writable": true
+            }
+          ]
         },
         {
-          "name": "preparedCustodyToken",
+          "name": "reservedSequence",
+          "docs": [
+            "This account will be closed at the end of this instruction instead of using the close",
+            "account directive here.",
+            "",
+            "NOTE: We do not need to do a VAA hash check because that was already performed when the",
+            "reserved sequence was created."
+          ],
           "writable": true
         },
         {
-          "name": "baseFeeToken",
+          "name": "reserveBeneficiary",
           "docs": [
-            "This token account will be the one that collects the base fee only if an auction's order",
-            "was executed late. Otherwise, the protocol's fee recipient token account will be used for",
-            "non-existent auctions and the best offer token account will be used for orders executed on",
-            "time."
-          ]
+            "When the reserved sequence account was created, the beneficiary was set to the best offer",
+            "token's owner if it existed (and if not, to whomever executed the reserve fast fill sequence",
+            "instruction). This account will receive the lamports from the reserved sequence account.",
+            ""
+          ],
+          "writable": true
         },
         {
-          "name": "usdc",
-          "accounts": [
-            {
-              "name": "mint"
-            }
-          ]
+          "name": "fastFill",
+          "writable": true
         },
         {
-          "name": "cctp",
+          "name": "localCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "sysvars",
           "accounts": [
             {
-              "name": "mintRecipient",
-              "accounts": [
-                {
-                  "name": "mintRecipient",
-                  "writable": true
-                }
-              ]
-            },
-            {
-              "name": "messageTransmitterAuthority"
-            },
-            {
-              "name": "messageTransmitterConfig"
-            },
-            {
-              "name": "usedNonces",
-              "docs": [
-                "first_nonce.to_string()\\] (CCTP Message Transmitter program)."
-              ],
-              "writable": true
-            },
-            {
-              "name": "messageTransmitterEventAuthority"
-            },
-            {
-              "name": "tokenMessenger"
-            },
-            {
-              "name": "remoteTokenMessenger",
+              "name": "clock",
               "docs": [
-                "Messenger Minter program)."
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
               ]
             },
             {
-              "name": "tokenMinter"
-            },
-            {
-              "name": "localToken",
+              "name": "rent",
               "docs": [
-                "Token Messenger Minter's Local Token account. This program uses the mint of this account to",
-                "validate the `mint_recipient` token account's mint.",
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
                 ""
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenPair",
-              "docs": [
-                "Token Messenger Minter program)."
               ]
-            },
-            {
-              "name": "tokenMessengerMinterCustodyToken",
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterEventAuthority"
-            },
-            {
-              "name": "tokenMessengerMinterProgram"
-            },
-            {
-              "name": "messageTransmitterProgram"
             }
           ]
         },
         {
-          "name": "tokenProgram"
+          "name": "eventAuthority"
         },
         {
-          "name": "systemProgram"
+          "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "cctpMessageArgs"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "proposeAuctionParameters",
+      "name": "improveOffer",
       "docs": [
-        "This instruction is used to propose new auction parameters. A proposal cannot be enacted",
-        "until one epoch has passed. This instruction can only be called by the `owner` or",
-        "`owner_assistant`.",
+        "This instruction is used to improve an existing auction offer. The `offer_price` must be",
+        "greater than the current `offer_price` in the auction. This instruction will revert if the",
+        "`offer_price` is less than the current `offer_price`. This instruction can be called by",
+        "anyone.",
         "",
         "# Arguments",
         "",
-        "* `ctx`    - `ProposeAuctionParameters` context.",
-        "* `params` - The new `AuctionParameters`, see `auction_config.rs`."
+        "* `ctx`         - `ImproveOffer` context.",
+        "* `offer_price` - The fee that the caller is willing to accept in order for fufilling the",
+        "fast order. This fee is paid in USDC."
       ],
       "discriminator": [
-        86,
-        19,
-        21,
-        43,
-        32,
-        106,
-        249,
-        80
+        171,
+        112,
+        46,
+        172,
+        194,
+        135,
+        23,
+        102
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
+          "name": "transferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA.",
+            ""
+          ]
         },
         {
-          "name": "admin",
+          "name": "activeAuction",
           "accounts": [
             {
-              "name": "ownerOrAssistant",
-              "signer": true
+              "name": "auction",
+              "writable": true
             },
             {
-              "name": "custodian",
-              "accounts": [
-                {
-                  "name": "custodian"
-                }
-              ]
+              "name": "custodyToken",
+              "writable": true
+            },
+            {
+              "name": "config"
+            },
+            {
+              "name": "bestOfferToken",
+              "writable": true
             }
           ]
         },
         {
-          "name": "proposal",
+          "name": "offerToken",
           "writable": true
         },
         {
-          "name": "systemProgram"
-        },
-        {
-          "name": "epochSchedule"
+          "name": "tokenProgram"
         },
         {
           "name": "eventAuthority"
@@ -1565,123 +1564,74 @@ export type MatchingEngine = {
       ],
       "args": [
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "auctionParameters"
-            }
-          }
+          "name": "offerPrice",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "reserveFastFillSequenceActiveAuction",
+      "name": "improveTrancheOffer",
       "docs": [
-        "This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders",
-        "that have been fulfilled and are destined for Solana and are seeded by source chain, order",
-        "sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,",
-        "emitter address and sequence number).",
-        "",
-        "Prior to executing `execute_fast_order_local` after the duration of an auction, the winning",
-        "auction participant should call this instruction to reserve the fast fill's sequence number.",
-        "This sequence number is warehoused in the `ReservedFastFillSequence` account and will be",
-        "closed when the order is executed.",
-        "",
-        "Auction participants can listen to the `FastFillSequenceReserved` event to track when he",
-        "(or associated payer) called this instruction so he can execute local orders easily.",
+        "This instruction is used to improve an existing offer on a tranche of a split-fill auction.",
+        "The `offer_price` must be less than the tranche's current offer price by at least the",
+        "auction's minimum offer delta. The previous best offer of the tranche is refunded its total",
+        "deposit.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ReserveFastFillSequenceActiveAuction` context."
+        "* `ctx`         - `ImproveTrancheOffer` context.",
+        "* `offer_price` - New fee offer for this tranche."
       ],
       "discriminator": [
-        206,
-        255,
-        241,
-        68,
-        224,
-        129,
+        23,
+        191,
+        119,
+        78,
+        163,
+        146,
         210,
-        187
+        101
       ],
       "accounts": [
         {
-          "name": "reserveSequence",
-          "accounts": [
-            {
-              "name": "payer",
-              "writable": true,
-              "signer": true
-            },
-            {
-              "name": "fastOrderPath",
-              "accounts": [
-                {
-                  "name": "fastVaa",
-                  "accounts": [
-                    {
-                      "name": "vaa"
-                    }
-                  ]
-                },
-                {
-                  "name": "path",
-                  "accounts": [
-                    {
-                      "name": "fromEndpoint",
-                      "accounts": [
-                        {
-                          "name": "endpoint"
-                        }
-                      ]
-                    },
-                    {
-                      "name": "toEndpoint",
-                      "accounts": [
-                        {
-                          "name": "endpoint"
-                        }
-                      ]
-                    }
-                  ]
-                }
-              ]
-            },
-            {
-              "name": "sequencer",
-              "docs": [
-                "This sequencer determines the next reserved sequence. If it does not exist for a given",
-                "source chain and sender, it will be created.",
-                "",
-                "Auction participants may want to consider pricing the creation of this account into their",
-                "offer prices by checking whether this sequencer already exists for those orders destined for",
-                "Solana."
-              ],
-              "writable": true
-            },
-            {
-              "name": "reserved",
-              "docs": [
-                "This account will be used to determine the sequence of the next fast fill. When a local",
-                "order is executed or an non-existent auction is settled, this account will be closed."
-              ],
-              "writable": true
-            },
-            {
-              "name": "auction",
-              "docs": [
-                "must have been created by this point. Otherwise the auction account must reflect a completed",
-                "auction."
-              ],
-              "writable": true
-            },
-            {
-              "name": "systemProgram"
-            }
+          "name": "transferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA.",
+            "",
+            "offer_price.to_be_bytes()\\]."
           ]
         },
         {
-          "name": "auctionConfig"
+          "name": "auction"
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "auctionTranche",
+          "writable": true
+        },
+        {
+          "name": "custodyToken",
+          "writable": true
+        },
+        {
+          "name": "bestOfferToken",
+          "writable": true
+        },
+        {
+          "name": "offerToken"
+        },
+        {
+          "name": "registeredSolver",
+          "docs": [
+            "Registration of the solver placing this offer. This account must be provided if the auction",
+            "only allows registered solvers."
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
         },
         {
           "name": "eventAuthority"
@@ -1690,242 +1640,326 @@ export type MatchingEngine = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "offerPrice",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "reserveFastFillSequenceNoAuction",
+      "name": "initialize",
       "docs": [
-        "This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders",
-        "that have been fulfilled and are destined for Solana and are seeded by source chain, order",
-        "sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,",
-        "emitter address and sequence number).",
-        "",
-        "Prior to executing `settle_auction_none_local` if there is no auction, whomever prepared the",
-        "order response should call this instruction to reserve the fast fill's sequence number.",
-        "This sequence number is warehoused in the `ReservedFastFillSequence` account and will be",
-        "closed when the funds are finally settled.",
-        "",
-        "NOTE: This instruction is expected to be in the same transaction as the one that executes",
-        "the prepare order response instruction. If it is not, there is a risk that after preparing",
-        "the order response that someone starts an auction. This scenario risks the preparer's rent",
-        "that he paid because the winning auction participant can take his lamports when he calls",
-        "settle auction complete. Although this is an unlikely scenario, it is possible if there is",
-        "no deadline specified to start the auction and no participants use the fast VAA to start an",
-        "auction until the finalized VAA exists (which guarantees that the funds have finalized on",
-        "the source network).",
+        "This instruction is be used to generate the program's `custodian` and `auction_config`",
+        "configs. It also reates the `owner` and `fee_recipient` accounts. Finally, it sets the",
+        "upgrade authority to the `upgrade_manager_authority`. Upgrades are managed by the",
+        "`upgrade_manager_program`.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ReserveFastFillSequenceNoAuction` context."
+        "* `ctx`  - `Initialize` context.",
+        "* `args` - Initialize args, which has the initial [AuctionParameters]."
       ],
       "discriminator": [
-        61,
-        148,
-        140,
-        87,
-        186,
-        87,
-        212,
-        239
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
       ],
       "accounts": [
         {
-          "name": "reserveSequence",
+          "name": "owner",
+          "docs": [
+            "Owner of the program, who presumably deployed this program."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "docs": [
+            "Custodian account, which saves program data useful for other",
+            "instructions."
+          ],
+          "writable": true
+        },
+        {
+          "name": "auctionConfig",
+          "writable": true
+        },
+        {
+          "name": "ownerAssistant",
+          "docs": [
+            "TODO: do we prevent the owner from being the owner assistant?"
+          ]
+        },
+        {
+          "name": "feeRecipient"
+        },
+        {
+          "name": "feeRecipientToken"
+        },
+        {
+          "name": "cctpMintRecipient",
+          "writable": true
+        },
+        {
+          "name": "usdc",
           "accounts": [
             {
-              "name": "payer",
-              "writable": true,
-              "signer": true
-            },
-            {
-              "name": "fastOrderPath",
-              "accounts": [
-                {
-                  "name": "fastVaa",
-                  "accounts": [
-                    {
-                      "name": "vaa"
-                    }
-                  ]
-                },
-                {
-                  "name": "path",
-                  "accounts": [
-                    {
-                      "name": "fromEndpoint",
-                      "accounts": [
-                        {
-                          "name": "endpoint"
-                        }
-                      ]
-                    },
-                    {
-                      "name": "toEndpoint",
-                      "accounts": [
-                        {
-                          "name": "endpoint"
-                        }
-                      ]
-                    }
-                  ]
-                }
-              ]
-            },
-            {
-              "name": "sequencer",
-              "docs": [
-                "This sequencer determines the next reserved sequence. If it does not exist for a given",
-                "source chain and sender, it will be created.",
-                "",
-                "Auction participants may want to consider pricing the creation of this account into their",
-                "offer prices by checking whether this sequencer already exists for those orders destined for",
-                "Solana."
-              ],
-              "writable": true
-            },
-            {
-              "name": "reserved",
-              "docs": [
-                "This account will be used to determine the sequence of the next fast fill. When a local",
-                "order is executed or an non-existent auction is settled, this account will be closed."
-              ],
-              "writable": true
-            },
-            {
-              "name": "auction",
-              "docs": [
-                "must have been created by this point. Otherwise the auction account must reflect a completed",
-                "auction."
-              ],
-              "writable": true
-            },
-            {
-              "name": "systemProgram"
+              "name": "mint"
             }
           ]
         },
         {
-          "name": "preparedOrderResponse",
+          "name": "programData",
           "docs": [
-            "The preparer will be the beneficiary of the reserved fast fill sequence account when it is",
-            "closed. This instruction will not allow this account to be provided if there is an existing",
-            "auction, which would enforce the order be executed when it is time to complete the auction."
-          ]
+            "We use the program data to make sure this owner is the upgrade authority (the true owner,",
+            "who deployed this program)."
+          ],
+          "writable": true
         },
         {
-          "name": "eventAuthority"
+          "name": "upgradeManagerAuthority"
         },
         {
-          "name": "program"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "setPause",
-      "docs": [
-        "This instruction is used to pause or unpause further processing of new auctions. Only the",
-        "`owner` or `owner_assistant` can pause the program.",
-        "",
-        "# Arguments",
+          "name": "upgradeManagerProgram"
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "initializeArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrate",
+      "docs": [
+        "This instruction is used for executing logic during an upgrade. This instruction can only be",
+        "called by the `upgrade_manager_program`.",
         "",
-        "* `ctx`   - `SetPause` context.",
-        "* `pause` - Boolean indicating whether to pause the program."
+        "# Arguments",
+        "",
+        "* `ctx` - `Migrate` context."
       ],
       "discriminator": [
-        63,
-        32,
-        154,
-        2,
-        56,
-        103,
-        79,
-        45
+        155,
+        234,
+        231,
+        146,
+        236,
+        158,
+        162,
+        30
       ],
       "accounts": [
         {
           "name": "admin",
           "accounts": [
             {
-              "name": "ownerOrAssistant",
+              "name": "owner",
               "signer": true
             },
             {
               "name": "custodian",
-              "writable": true
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
             }
           ]
         }
       ],
-      "args": [
-        {
-          "name": "pause",
-          "type": "bool"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "settleAuctionComplete",
+      "name": "placeInitialOfferCctp",
       "docs": [
-        "This instruction is used to settle the acution after the `FastMarketOrder` has been",
-        "executed, and the `PreparedOrderResponse` has been created. This instruction will settle the",
-        "auction by transferring the funds from the `prepared_custody_token` account to the best",
-        "offer account.",
-        "",
+        "This instruction is used to create a new auction given a valid `FastMarketOrder` vaa. This",
+        "instruction will record information about the auction and transfer funds from the payer to",
+        "an auction-specific token custody account. The auction uses the route's auction config if",
+        "one exists for the order's source and target chains. Otherwise the global auction config is",
+        "used. For sealed-bid auctions, the initial offer is public and subsequent offers are",
+        "committed via `commit_offer`. This instruction can be called by anyone.",
         "# Arguments",
         "",
-        "* `ctx` - `SettleAuctionComplete` context."
+        "* `ctx`         - `PlaceInitialOfferCctp` context.",
+        "* `offer_price` - The fee that the caller is willing to accept in order for fufilling the",
+        "fast order. This fee is paid in USDC."
       ],
       "discriminator": [
-        84,
-        39,
-        0,
-        132,
-        21,
-        101,
-        222,
-        137
+        157,
+        156,
+        175,
+        35,
+        91,
+        249,
+        1,
+        129
       ],
       "accounts": [
         {
-          "name": "beneficiary",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "transferAuthority",
           "docs": [
-            "finalized VAA."
+            "The auction participant needs to set approval to this PDA.",
+            ""
+          ]
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "auctionConfig",
+          "docs": [
+            "If the route has an auction config override, this account must be the overriding config.",
+            "Otherwise if the mint has an auction config override, this account must be the mint's",
+            "config. Otherwise it must be the global auction config."
+          ]
+        },
+        {
+          "name": "routeAuctionConfig",
+          "docs": [
+            "Route auction config for this fast market order's source and target chains. This account",
+            "may not exist, in which case the global auction config is used.",
+            ""
+          ]
+        },
+        {
+          "name": "inboundRateLimit",
+          "docs": [
+            "Rate limit for fast market orders sent from the source chain. This account may not exist,",
+            "in which case orders from this chain are not rate limited.",
+            ""
           ],
           "writable": true
         },
         {
-          "name": "baseFeeToken",
+          "name": "outboundRateLimit",
           "docs": [
-            "This token account will receive the base fee only if there was a penalty when executing the",
-            "order. If it does not exist when there is a penalty, this instruction handler will revert.",
+            "Rate limit for fast market orders destined for the target chain. This account may not",
+            "exist, in which case orders to this chain are not rate limited.",
             ""
           ],
           "writable": true
         },
         {
-          "name": "bestOfferToken",
+          "name": "fastOrderPath",
+          "accounts": [
+            {
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
+            },
+            {
+              "name": "path",
+              "accounts": [
+                {
+                  "name": "fromEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                },
+                {
+                  "name": "toEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "auction",
           "docs": [
-            "Destination token account, which the redeemer may not own. But because the redeemer is a",
-            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
-            "to any account he chooses (this one).",
-            "",
-            "of the tokens to the base fee token account."
+            "This account should only be created once, and should never be changed to",
+            "init_if_needed. Otherwise someone can game an existing auction."
           ],
           "writable": true
         },
         {
-          "name": "preparedOrderResponse",
+          "name": "offerToken",
           "writable": true
         },
         {
-          "name": "preparedCustodyToken",
-          "writable": true
+          "name": "solverVault",
+          "docs": [
+            "If provided, the offer's total deposit is locked in this solver vault instead of being",
+            "transferred to the auction's custody token account. The offer token must be this vault's",
+            "custody token account and the payer must be the vault's owner."
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "auction",
+          "name": "registeredSolver",
+          "docs": [
+            "Registration of the solver placing this offer. This account must be provided if either of",
+            "the route's router endpoints has a solver allowlist."
+          ],
+          "optional": true
+        },
+        {
+          "name": "auctionCustodyToken",
           "writable": true
         },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "cctpMint",
+              "docs": [
+                "CCTP mint registration, which may not exist if the mint is USDC.",
+                ""
+              ]
+            }
+          ]
+        },
+        {
+          "name": "systemProgram"
+        },
         {
           "name": "tokenProgram"
         },
@@ -1936,29 +1970,38 @@ export type MatchingEngine = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "offerPrice",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "settleAuctionNoneCctp",
+      "name": "placeInitialTrancheOfferCctp",
       "docs": [
-        "This instruction is used to route funds to the `recipient` for a `FastMarketOrder` with",
-        "no corresponding auction on Solana. This instruction can be called by anyone, but the sum of",
-        "`init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to",
-        "the `fee_recipient`. This instruction generates a `Fill` message.",
+        "This instruction is used to place the initial offer on a tranche of a split-fill auction.",
+        "The first tranche offer placed starts the auction. Each tranche is a portion of the",
+        "`FastMarketOrder`'s `amount_in`, and its offer price cannot exceed the tranche's portion of",
+        "the order's `max_fee`. The offer authority must approve the tranche's transfer authority to",
+        "transfer the tranche's total deposit to the auction's custody token account.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `SettleAuctionNoneCctp` context."
+        "* `ctx`           - `PlaceInitialTrancheOfferCctp` context.",
+        "* `tranche_index` - Index of the tranche, which must be less than the auction's number of",
+        "tranches.",
+        "* `offer_price`   - Fee offer for this tranche."
       ],
       "discriminator": [
-        120,
-        236,
-        82,
-        121,
-        242,
-        118,
-        74,
-        161
+        26,
+        253,
+        61,
+        133,
+        48,
+        97,
+        196,
+        79
       ],
       "accounts": [
         {
@@ -1967,12 +2010,12 @@ export type MatchingEngine = {
           "signer": true
         },
         {
-          "name": "coreMessage",
-          "writable": true
-        },
-        {
-          "name": "cctpMessage",
-          "writable": true
+          "name": "transferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA.",
+            "",
+            "offer_price.to_be_bytes()\\]."
+          ]
         },
         {
           "name": "custodian",
@@ -1983,137 +2026,130 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "feeRecipientToken",
+          "name": "auctionConfig",
           "docs": [
-            "Destination token account, which the redeemer may not own. But because the redeemer is a",
-            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
-            "to any account he chooses (this one).",
+            "If the auction has not started yet, this account must be the route's auction config (see",
+            "[PlaceInitialOfferCctp](crate::processor::PlaceInitialOfferCctp)). Otherwise it must be the",
+            "auction's config."
+          ]
+        },
+        {
+          "name": "routeAuctionConfig",
+          "docs": [
+            "Route auction config for this fast market order's source and target chains. This account",
+            "may not exist, in which case the global auction config is used.",
+            ""
+          ]
+        },
+        {
+          "name": "inboundRateLimit",
+          "docs": [
+            "Rate limit for fast market orders sent from the source chain. This account may not exist,",
+            "in which case orders from this chain are not rate limited.",
             ""
           ],
           "writable": true
         },
         {
-          "name": "prepared",
-          "accounts": [
-            {
-              "name": "by",
-              "writable": true
-            },
+          "name": "outboundRateLimit",
+          "docs": [
+            "Rate limit for fast market orders destined for the target chain. This account may not",
+            "exist, in which case orders to this chain are not rate limited.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "fastOrderPath",
+          "accounts": [
             {
-              "name": "orderResponse",
-              "writable": true
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
             },
             {
-              "name": "custodyToken",
-              "writable": true
+              "name": "path",
+              "accounts": [
+                {
+                  "name": "fromEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                },
+                {
+                  "name": "toEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                }
+              ]
             }
           ]
         },
         {
           "name": "auction",
           "docs": [
-            "There should be no account data here because an auction was never created."
+            "The auction is created when the initial offer is placed on any of its tranches. Because this",
+            "account may already exist, the instruction handler must verify that an existing auction is",
+            "an active split-fill auction before an offer is placed on another tranche."
           ],
           "writable": true
         },
         {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "config",
-              "writable": true
-            },
-            {
-              "name": "emitterSequence",
-              "writable": true
-            },
-            {
-              "name": "feeCollector",
-              "writable": true
-            },
-            {
-              "name": "coreBridgeProgram"
-            }
-          ]
+          "name": "auctionTranche",
+          "docs": [
+            "This account should only be created once, and should never be changed to",
+            "init_if_needed. Otherwise someone can game an existing tranche."
+          ],
+          "writable": true
         },
         {
-          "name": "cctp",
-          "accounts": [
-            {
-              "name": "mint",
-              "docs": [
-                "Circle-supported mint.",
-                "",
-                "Token Messenger Minter program's local token account."
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterSenderAuthority"
-            },
-            {
-              "name": "messageTransmitterConfig",
-              "writable": true
-            },
-            {
-              "name": "tokenMessenger"
-            },
-            {
-              "name": "remoteTokenMessenger",
-              "docs": [
-                "Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "tokenMinter",
-              "docs": [
-                "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "localToken",
-              "docs": [
-                "Local token account, which this program uses to validate the `mint` used to burn.",
-                ""
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterEventAuthority"
-            },
-            {
-              "name": "tokenMessengerMinterProgram"
-            },
-            {
-              "name": "messageTransmitterProgram"
-            }
-          ]
+          "name": "offerToken",
+          "writable": true
         },
         {
-          "name": "tokenProgram"
+          "name": "registeredSolver",
+          "docs": [
+            "Registration of the solver placing this offer. This account must be provided if either of",
+            "the route's router endpoints has a solver allowlist."
+          ],
+          "optional": true
         },
         {
-          "name": "systemProgram"
+          "name": "auctionCustodyToken",
+          "docs": [
+            "Every tranche's total deposit is held in the auction's custody token account."
+          ],
+          "writable": true
         },
         {
-          "name": "sysvars",
+          "name": "mint",
           "accounts": [
             {
-              "name": "clock",
-              "docs": [
-                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
-                ""
-              ]
+              "name": "mint"
             },
             {
-              "name": "rent",
+              "name": "cctpMint",
               "docs": [
-                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                "CCTP mint registration, which may not exist if the mint is USDC.",
                 ""
               ]
             }
           ]
         },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
         {
           "name": "eventAuthority"
         },
@@ -2121,29 +2157,39 @@ export type MatchingEngine = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "trancheIndex",
+          "type": "u8"
+        },
+        {
+          "name": "offerPrice",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "settleAuctionNoneLocal",
+      "name": "prepareOrderResponseCctp",
       "docs": [
-        "This instruction is used to settle a `FastMarketOrder` with no corresponding auction. This",
-        "instruction can be called by anyone, but the sum of `init_auction_fee` and `base_fee`",
-        "associated with relaying a finalized VAA will be paid to the `fee_recipient`. This",
-        "instruction creates a `FastFill` account.",
+        "This instruction is used to prepare the order response for a CCTP transfer. This instruction",
+        "will redeem the finalized transfer associated with a particular auction, and deposit the",
+        "funds to the `prepared_custody_token` account that is created during execution. This",
+        "instruction will create a `PreparedOrderResponse` account that will be used to settle the",
+        "auction.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `SettleAuctionNoneLocal` context."
+        "* `ctx` - `PrepareOrderResponseCctp` context."
       ],
       "discriminator": [
-        253,
-        213,
-        132,
-        148,
-        31,
-        119,
-        215,
-        162
+        221,
+        178,
+        184,
+        43,
+        247,
+        248,
+        90,
+        160
       ],
       "accounts": [
         {
@@ -2160,160 +2206,340 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "feeRecipientToken",
-          "docs": [
-            "Destination token account, which the redeemer may not own. But because the redeemer is a",
-            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
-            "to any account he chooses (this one).",
-            ""
-          ],
-          "writable": true
-        },
-        {
-          "name": "prepared",
+          "name": "fastOrderPath",
           "accounts": [
             {
-              "name": "by",
-              "writable": true
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
             },
             {
-              "name": "orderResponse",
-              "writable": true
-            },
+              "name": "path",
+              "accounts": [
+                {
+                  "name": "fromEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                },
+                {
+                  "name": "toEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "finalizedVaa",
+          "accounts": [
             {
-              "name": "custodyToken",
-              "writable": true
+              "name": "vaa"
             }
           ]
         },
         {
-          "name": "auction",
-          "docs": [
-            "This account will have been created using the reserve fast fill sequence (no auction)",
-            "instruction. We need to make sure that this account has not been used in an auction."
-          ],
+          "name": "preparedOrderResponse",
           "writable": true
         },
         {
-          "name": "reservedSequence",
-          "docs": [
-            "This account will be closed at the end of this instruction instead of using the close",
-            "account directive here.",
-            "",
-            "If we could reference the beneficiary using `prepared.by`, this would be a different story.",
-            "",
-            "NOTE: We do not need to do a VAA hash check because that was already performed when the",
-            "reserved sequence was created."
-          ],
+          "name": "preparedCustodyToken",
           "writable": true
         },
         {
-          "name": "fastFill",
-          "writable": true
+          "name": "baseFeeToken",
+          "docs": [
+            "This token account will be the one that collects the base fee only if an auction's order",
+            "was executed late. Otherwise, the protocol's fee recipient token account will be used for",
+            "non-existent auctions and the best offer token account will be used for orders executed on",
+            "time."
+          ]
         },
         {
-          "name": "localCustodyToken",
-          "writable": true
+          "name": "auction",
+          "docs": [
+            "Auction for the fast market order. If this account does not exist, the order was never",
+            "auctioned and its amount counts against the rate limits when the order response is prepared.",
+            ""
+          ]
         },
         {
-          "name": "tokenProgram"
+          "name": "inboundRateLimit",
+          "writable": true
         },
         {
-          "name": "systemProgram"
+          "name": "outboundRateLimit",
+          "writable": true
         },
         {
-          "name": "sysvars",
+          "name": "mint",
           "accounts": [
             {
-              "name": "clock",
+              "name": "mint"
+            },
+            {
+              "name": "cctpMint",
               "docs": [
-                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                "CCTP mint registration, which may not exist if the mint is USDC.",
                 ""
               ]
+            }
+          ]
+        },
+        {
+          "name": "cctp",
+          "accounts": [
+            {
+              "name": "mintRecipient",
+              "accounts": [
+                {
+                  "name": "mintRecipient",
+                  "writable": true
+                }
+              ]
             },
             {
-              "name": "rent",
+              "name": "messageTransmitterAuthority"
+            },
+            {
+              "name": "messageTransmitterConfig"
+            },
+            {
+              "name": "usedNonces",
               "docs": [
-                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                "first_nonce.to_string()\\] (CCTP Message Transmitter program)."
+              ],
+              "writable": true
+            },
+            {
+              "name": "messageTransmitterEventAuthority"
+            },
+            {
+              "name": "tokenMessenger"
+            },
+            {
+              "name": "remoteTokenMessenger",
+              "docs": [
+                "Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "tokenMinter"
+            },
+            {
+              "name": "localToken",
+              "docs": [
+                "Token Messenger Minter's Local Token account. This program uses the mint of this account to",
+                "validate the `mint_recipient` token account's mint.",
                 ""
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenPair",
+              "docs": [
+                "Token Messenger Minter program)."
               ]
+            },
+            {
+              "name": "tokenMessengerMinterCustodyToken",
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterEventAuthority"
+            },
+            {
+              "name": "tokenMessengerMinterProgram"
+            },
+            {
+              "name": "messageTransmitterProgram"
             }
           ]
         },
         {
-          "name": "eventAuthority"
+          "name": "tokenProgram"
         },
         {
-          "name": "program"
+          "name": "systemProgram"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "cctpMessageArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "submitOwnershipTransferRequest",
+      "name": "prepareOrderResponseLocal",
       "docs": [
-        "This instruction sets the `pending_owner` field in the `Custodian` account. This instruction",
-        "can only be called by the `owner`. The `pending_owner` address must be valid, meaning it",
-        "cannot be the zero address or the current owner.",
+        "This instruction is used to prepare the order response for a `FastMarketOrder` placed by",
+        "the local Token Router. Instead of redeeming a CCTP message, this instruction transfers the",
+        "funds deposited via `deposit_local_fast_order` to the `prepared_custody_token` account and",
+        "creates the `PreparedOrderResponse` account used to settle the auction.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `SubmitOwnershipTransferRequest` context."
+        "* `ctx` - `PrepareOrderResponseLocal` context."
       ],
       "discriminator": [
-        215,
-        13,
-        88,
-        199,
-        48,
         195,
-        19,
-        225
+        13,
+        5,
+        151,
+        62,
+        123,
+        11,
+        17
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
           "accounts": [
             {
-              "name": "owner",
-              "signer": true
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "fastOrderPath",
+          "accounts": [
+            {
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
             },
             {
-              "name": "custodian",
-              "writable": true
+              "name": "path",
+              "accounts": [
+                {
+                  "name": "fromEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                },
+                {
+                  "name": "toEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                }
+              ]
             }
           ]
         },
         {
-          "name": "newOwner",
+          "name": "localFastOrder",
           "docs": [
-            "New Owner.",
+            "Deposit made by the local Token Router when it published the fast market order. This",
+            "account (along with its custody token account) is closed by this instruction."
+          ],
+          "writable": true
+        },
+        {
+          "name": "localFastOrderPreparedBy",
+          "writable": true
+        },
+        {
+          "name": "localFastOrderCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "preparedOrderResponse",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "baseFeeToken",
+          "docs": [
+            "This token account will be the one that collects the base fee only if an auction's order",
+            "was executed late. Otherwise, the protocol's fee recipient token account will be used for",
+            "non-existent auctions and the best offer token account will be used for orders executed on",
+            "time."
+          ]
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "Auction for the fast market order. If this account does not exist, the order was never",
+            "auctioned and its amount counts against the rate limits when the order response is prepared.",
             ""
           ]
+        },
+        {
+          "name": "inboundRateLimit",
+          "writable": true
+        },
+        {
+          "name": "outboundRateLimit",
+          "writable": true
+        },
+        {
+          "name": "usdc",
+          "accounts": [
+            {
+              "name": "mint"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
         }
       ],
       "args": []
     },
     {
-      "name": "updateAuctionParameters",
+      "name": "proposeAuctionParameters",
       "docs": [
-        "This instruction is used to enact an existing auction update proposal. It can only be",
-        "executed after the `slot_enact_delay` has passed. This instruction can only be called by the",
-        "`owner`.",
+        "This instruction is used to propose new auction parameters. A proposal cannot be enacted",
+        "until one epoch has passed. This instruction can only be called by the `owner` or",
+        "`owner_assistant`.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateAuctionParameters` context."
+        "* `ctx`    - `ProposeAuctionParameters` context.",
+        "* `params` - The new `AuctionParameters`, see `auction_config.rs`."
       ],
       "discriminator": [
-        10,
-        33,
-        10,
-        75,
-        17,
-        63,
+        86,
+        19,
         21,
-        245
+        43,
+        32,
+        106,
+        249,
+        80
       ],
       "accounts": [
         {
@@ -2325,12 +2551,16 @@ export type MatchingEngine = {
           "name": "admin",
           "accounts": [
             {
-              "name": "owner",
+              "name": "ownerOrAssistant",
               "signer": true
             },
             {
               "name": "custodian",
-              "writable": true
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
             }
           ]
         },
@@ -2339,11 +2569,10 @@ export type MatchingEngine = {
           "writable": true
         },
         {
-          "name": "auctionConfig",
-          "writable": true
+          "name": "systemProgram"
         },
         {
-          "name": "systemProgram"
+          "name": "epochSchedule"
         },
         {
           "name": "eventAuthority"
@@ -2352,36 +2581,54 @@ export type MatchingEngine = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "auctionParameters"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "updateCctpRouterEndpoint",
+      "name": "proposeRouteAuctionParameters",
       "docs": [
-        "This instruction is used to update a CCTP router endpoint. It allows the caller to change",
-        "the `address`, `mint_recipient`, and `domain`. This instruction can only be called by the",
-        "`owner`.",
+        "This instruction is used to propose auction parameters for a specific route, which override",
+        "the global auction parameters for fast market orders sent from the `source_chain` to the",
+        "`target_chain`. If no parameters are specified, the proposal removes the route's override.",
+        "A proposal cannot be enacted until one epoch has passed. This instruction can only be",
+        "called by the `owner`, `owner_assistant` or a holder of the `AuctionParameterProposer`",
+        "role.",
         "",
         "# Arguments",
         "",
-        "* `ctx`  - `UpdateCctpRouterEndpoint` context.",
-        "* `args` - The `AddCctpRouterEndpointArgs`, see `admin.rs`."
+        "* `ctx`  - `ProposeRouteAuctionParameters` context.",
+        "* `args` - `ProposeRouteAuctionParametersArgs`, which specifies the route and its optional",
+        "new `AuctionParameters`."
       ],
       "discriminator": [
-        34,
-        122,
-        31,
-        38,
-        73,
-        126,
-        94,
-        127
+        92,
+        241,
+        76,
+        238,
+        30,
+        40,
+        70,
+        67
       ],
       "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "admin",
           "accounts": [
             {
-              "name": "owner",
+              "name": "authority",
               "signer": true
             },
             {
@@ -2395,19 +2642,20 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "routerEndpoint",
-          "accounts": [
-            {
-              "name": "endpoint",
-              "writable": true
-            }
-          ]
+          "name": "proposal",
+          "writable": true
         },
         {
-          "name": "remoteTokenMessenger",
-          "docs": [
-            "Messenger Minter program)."
-          ]
+          "name": "systemProgram"
+        },
+        {
+          "name": "epochSchedule"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
       "args": [
@@ -2415,38 +2663,48 @@ export type MatchingEngine = {
           "name": "args",
           "type": {
             "defined": {
-              "name": "addCctpRouterEndpointArgs"
+              "name": "proposeRouteAuctionParametersArgs"
             }
           }
         }
       ]
     },
     {
-      "name": "updateFeeRecipient",
+      "name": "removeRouteAuctionParameters",
       "docs": [
-        "This instruction is used to update the `fee_recipient` field in the `Custodian` account.",
-        "This instruction can only be called by the `owner` or `owner_assistant`.",
+        "This instruction is used to enact an existing proposal to remove a route's auction",
+        "parameters override. Auctions on this route will use the global auction config afterwards.",
+        "It can only be executed after the `slot_enact_delay` has passed. This instruction can only",
+        "be called by the `owner`.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateFeeRecipient` context."
+        "* `ctx` - `RemoveRouteAuctionParameters` context."
       ],
       "discriminator": [
-        249,
-        0,
-        198,
-        35,
-        183,
-        123,
-        57,
-        188
+        167,
+        132,
+        124,
+        173,
+        67,
+        1,
+        23,
+        124
       ],
       "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "This account will receive the lamports from closing the route auction config."
+          ],
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "admin",
           "accounts": [
             {
-              "name": "ownerOrAssistant",
+              "name": "owner",
               "signer": true
             },
             {
@@ -2456,195 +2714,1394 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "newFeeRecipientToken"
+          "name": "proposal",
+          "writable": true
         },
         {
-          "name": "newFeeRecipient",
-          "docs": [
-            "New Fee Recipient.",
-            ""
-          ]
+          "name": "routeAuctionConfig",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "updateLocalRouterEndpoint",
+      "name": "reserveFastFillSequenceActiveAuction",
       "docs": [
-        "This instruction is used to update a Local router endpoint. It allows the caller to change",
-        "the `address` and `mint_recipient`. This instruction can only be called by the `owner`.",
+        "This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders",
+        "that have been fulfilled and are destined for Solana and are seeded by source chain, order",
+        "sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,",
+        "emitter address and sequence number).",
+        "",
+        "Prior to executing `execute_fast_order_local` after the duration of an auction, the winning",
+        "auction participant should call this instruction to reserve the fast fill's sequence number.",
+        "This sequence number is warehoused in the `ReservedFastFillSequence` account and will be",
+        "closed when the order is executed.",
+        "",
+        "Auction participants can listen to the `FastFillSequenceReserved` event to track when he",
+        "(or associated payer) called this instruction so he can execute local orders easily.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateLocalRouterEndpoint` context."
+        "* `ctx` - `ReserveFastFillSequenceActiveAuction` context."
       ],
       "discriminator": [
-        222,
-        237,
-        142,
-        228,
-        88,
-        3,
-        49,
-        102
+        206,
+        255,
+        241,
+        68,
+        224,
+        129,
+        210,
+        187
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "reserveSequence",
           "accounts": [
             {
-              "name": "owner",
+              "name": "payer",
+              "writable": true,
               "signer": true
             },
             {
-              "name": "custodian",
+              "name": "fastOrderPath",
               "accounts": [
                 {
-                  "name": "custodian"
+                  "name": "fastVaa",
+                  "accounts": [
+                    {
+                      "name": "vaa"
+                    }
+                  ]
+                },
+                {
+                  "name": "path",
+                  "accounts": [
+                    {
+                      "name": "fromEndpoint",
+                      "accounts": [
+                        {
+                          "name": "endpoint"
+                        }
+                      ]
+                    },
+                    {
+                      "name": "toEndpoint",
+                      "accounts": [
+                        {
+                          "name": "endpoint"
+                        }
+                      ]
+                    }
+                  ]
                 }
               ]
-            }
-          ]
-        },
-        {
-          "name": "routerEndpoint",
-          "accounts": [
+            },
             {
-              "name": "endpoint",
+              "name": "sequencer",
+              "docs": [
+                "This sequencer determines the next reserved sequence. If it does not exist for a given",
+                "source chain and sender, it will be created.",
+                "",
+                "Auction participants may want to consider pricing the creation of this account into their",
+                "offer prices by checking whether this sequencer already exists for those orders destined for",
+                "Solana."
+              ],
               "writable": true
-            }
-          ]
-        },
-        {
-          "name": "local",
-          "accounts": [
+            },
             {
-              "name": "tokenRouterProgram",
+              "name": "reserved",
               "docs": [
-                "emitter (router endpoint) address."
-              ]
+                "This account will be used to determine the sequence of the next fast fill. When a local",
+                "order is executed or an non-existent auction is settled, this account will be closed."
+              ],
+              "writable": true
             },
             {
-              "name": "tokenRouterEmitter"
+              "name": "auction",
+              "docs": [
+                "must have been created by this point. Otherwise the auction account must reflect a completed",
+                "auction."
+              ],
+              "writable": true
             },
             {
-              "name": "tokenRouterMintRecipient"
+              "name": "systemProgram"
             }
           ]
+        },
+        {
+          "name": "auctionConfig"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "updateOwnerAssistant",
+      "name": "reserveFastFillSequenceNoAuction",
       "docs": [
-        "This instruction is used to update the `owner_assistant` field in the `Custodian` account.",
-        "This instruction can only be called by the `owner`.",
+        "This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders",
+        "that have been fulfilled and are destined for Solana and are seeded by source chain, order",
+        "sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,",
+        "emitter address and sequence number).",
+        "",
+        "Prior to executing `settle_auction_none_local` if there is no auction, whomever prepared the",
+        "order response should call this instruction to reserve the fast fill's sequence number.",
+        "This sequence number is warehoused in the `ReservedFastFillSequence` account and will be",
+        "closed when the funds are finally settled.",
+        "",
+        "NOTE: This instruction is expected to be in the same transaction as the one that executes",
+        "the prepare order response instruction. If it is not, there is a risk that after preparing",
+        "the order response that someone starts an auction. This scenario risks the preparer's rent",
+        "that he paid because the winning auction participant can take his lamports when he calls",
+        "settle auction complete. Although this is an unlikely scenario, it is possible if there is",
+        "no deadline specified to start the auction and no participants use the fast VAA to start an",
+        "auction until the finalized VAA exists (which guarantees that the funds have finalized on",
+        "the source network).",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateOwnerAssistant` context."
+        "* `ctx` - `ReserveFastFillSequenceNoAuction` context."
       ],
       "discriminator": [
-        153,
-        83,
-        175,
-        53,
-        168,
-        34,
-        131,
-        22
+        61,
+        148,
+        140,
+        87,
+        186,
+        87,
+        212,
+        239
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "reserveSequence",
           "accounts": [
             {
-              "name": "owner",
+              "name": "payer",
+              "writable": true,
               "signer": true
             },
             {
-              "name": "custodian",
-              "writable": true
-            }
-          ]
-        },
-        {
-          "name": "newOwnerAssistant",
+              "name": "fastOrderPath",
+              "accounts": [
+                {
+                  "name": "fastVaa",
+                  "accounts": [
+                    {
+                      "name": "vaa"
+                    }
+                  ]
+                },
+                {
+                  "name": "path",
+                  "accounts": [
+                    {
+                      "name": "fromEndpoint",
+                      "accounts": [
+                        {
+                          "name": "endpoint"
+                        }
+                      ]
+                    },
+                    {
+                      "name": "toEndpoint",
+                      "accounts": [
+                        {
+                          "name": "endpoint"
+                        }
+                      ]
+                    }
+                  ]
+                }
+              ]
+            },
+            {
+              "name": "sequencer",
+              "docs": [
+                "This sequencer determines the next reserved sequence. If it does not exist for a given",
+                "source chain and sender, it will be created.",
+                "",
+                "Auction participants may want to consider pricing the creation of this account into their",
+                "offer prices by checking whether this sequencer already exists for those orders destined for",
+                "Solana."
+              ],
+              "writable": true
+            },
+            {
+              "name": "reserved",
+              "docs": [
+                "This account will be used to determine the sequence of the next fast fill. When a local",
+                "order is executed or an non-existent auction is settled, this account will be closed."
+              ],
+              "writable": true
+            },
+            {
+              "name": "auction",
+              "docs": [
+                "must have been created by this point. Otherwise the auction account must reflect a completed",
+                "auction."
+              ],
+              "writable": true
+            },
+            {
+              "name": "systemProgram"
+            }
+          ]
+        },
+        {
+          "name": "preparedOrderResponse",
           "docs": [
-            "New Assistant.",
-            ""
+            "The preparer will be the beneficiary of the reserved fast fill sequence account when it is",
+            "closed. This instruction will not allow this account to be provided if there is an existing",
+            "auction, which would enforce the order be executed when it is time to complete the auction."
           ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "auction",
-      "discriminator": [
-        218,
-        94,
-        247,
-        242,
-        126,
-        233,
-        131,
-        81
-      ]
-    },
-    {
-      "name": "auctionConfig",
-      "discriminator": [
-        195,
-        54,
-        8,
-        51,
-        28,
-        231,
-        33,
-        142
-      ]
     },
     {
-      "name": "custodian",
+      "name": "revealOffer",
+      "docs": [
+        "This instruction is used to reveal a committed offer during the reveal period of a",
+        "sealed-bid auction. If the revealed offer price is lower than the best offer, the committed",
+        "deposit becomes the best offer's deposit and the previous best offer's deposit is returned.",
+        "Otherwise the committed deposit is returned to the offer token. This instruction can be",
+        "called by anyone who knows the offer price and salt.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`         - `RevealOffer` context.",
+        "* `offer_price` - The committed offer price.",
+        "* `salt`        - The salt used to compute the commitment."
+      ],
       "discriminator": [
-        132,
-        228,
-        139,
-        184,
-        112,
         228,
-        108,
-        240
+        187,
+        105,
+        12,
+        11,
+        128,
+        138,
+        113
+      ],
+      "accounts": [
+        {
+          "name": "activeAuction",
+          "accounts": [
+            {
+              "name": "auction",
+              "writable": true
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            },
+            {
+              "name": "config"
+            },
+            {
+              "name": "bestOfferToken",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "offerCommitment",
+          "writable": true
+        },
+        {
+          "name": "commitmentCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "offerToken",
+          "docs": [
+            "If the revealed offer is not the best offer, its deposit is returned to this token account."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bestOfferVault",
+          "docs": [
+            "Solver vault of the current best offer. This account must be provided if the best offer's",
+            "total deposit is locked in a solver vault."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "preparedBy",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "offerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
       ]
     },
     {
-      "name": "fastFill",
+      "name": "setPause",
+      "docs": [
+        "This instruction is used to pause or unpause further processing of new auctions. Only the",
+        "`owner` or `owner_assistant` can pause the program.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`   - `SetPause` context.",
+        "* `pause` - Boolean indicating whether to pause the program."
+      ],
       "discriminator": [
-        89,
-        120,
-        166,
-        41,
-        106,
-        227,
-        218,
-        121
+        63,
+        32,
+        154,
+        2,
+        56,
+        103,
+        79,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "ownerOrAssistant",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
       ]
     },
     {
-      "name": "fastFillSequencer",
+      "name": "settleAuctionComplete",
+      "docs": [
+        "This instruction is used to settle the acution after the `FastMarketOrder` has been",
+        "executed, and the `PreparedOrderResponse` has been created. This instruction will settle the",
+        "auction by transferring the funds from the `prepared_custody_token` account to the best",
+        "offer account.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SettleAuctionComplete` context."
+      ],
       "discriminator": [
-        70,
-        193,
-        18,
-        127,
-        227,
-        183,
-        46,
-        177
-      ]
+        84,
+        39,
+        0,
+        132,
+        21,
+        101,
+        222,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "beneficiary",
+          "docs": [
+            "finalized VAA."
+          ],
+          "writable": true
+        },
+        {
+          "name": "baseFeeToken",
+          "docs": [
+            "This token account will receive the base fee only if there was a penalty when executing the",
+            "order. If it does not exist when there is a penalty, this instruction handler will revert.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "bestOfferToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. But because the redeemer is a",
+            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
+            "to any account he chooses (this one).",
+            "",
+            "of the tokens to the base fee token account."
+          ],
+          "writable": true
+        },
+        {
+          "name": "preparedOrderResponse",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleAuctionNoneCctp",
+      "docs": [
+        "This instruction is used to route funds to the `recipient` for a `FastMarketOrder` with",
+        "no corresponding auction on Solana. This instruction can be called by anyone, but the sum of",
+        "`init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to",
+        "the `fee_recipient`. This instruction generates a `Fill` message. If the amount after fees",
+        "is less than the order's `min_amount_out` (or if the order requests a destination asset,",
+        "which requires an auction), the `Fill` is sent to the order's `refund_address` on the source",
+        "chain instead.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SettleAuctionNoneCctp` context."
+      ],
+      "discriminator": [
+        120,
+        236,
+        82,
+        121,
+        242,
+        118,
+        74,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "cctpMessage",
+          "writable": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "feeRecipientToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. But because the redeemer is a",
+            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
+            "to any account he chooses (this one).",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "cctpMint",
+          "docs": [
+            "CCTP mint registration for the order's mint, which may not exist if the mint is USDC.",
+            ""
+          ]
+        },
+        {
+          "name": "prepared",
+          "accounts": [
+            {
+              "name": "by",
+              "writable": true
+            },
+            {
+              "name": "orderResponse",
+              "writable": true
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "There should be no account data here because an auction was never created."
+          ],
+          "writable": true
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "config",
+              "writable": true
+            },
+            {
+              "name": "emitterSequence",
+              "writable": true
+            },
+            {
+              "name": "feeCollector",
+              "writable": true
+            },
+            {
+              "name": "coreBridgeProgram"
+            }
+          ]
+        },
+        {
+          "name": "cctp",
+          "accounts": [
+            {
+              "name": "mint",
+              "docs": [
+                "Circle-supported mint.",
+                "",
+                "Token Messenger Minter program's local token account."
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterSenderAuthority"
+            },
+            {
+              "name": "messageTransmitterConfig",
+              "writable": true
+            },
+            {
+              "name": "tokenMessenger"
+            },
+            {
+              "name": "remoteTokenMessenger",
+              "docs": [
+                "Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "tokenMinter",
+              "docs": [
+                "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "localToken",
+              "docs": [
+                "Local token account, which this program uses to validate the `mint` used to burn.",
+                ""
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterEventAuthority"
+            },
+            {
+              "name": "tokenMessengerMinterProgram"
+            },
+            {
+              "name": "messageTransmitterProgram"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
+            }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleAuctionNoneLocal",
+      "docs": [
+        "This instruction is used to settle a `FastMarketOrder` with no corresponding auction. This",
+        "instruction can be called by anyone, but the sum of `init_auction_fee` and `base_fee`",
+        "associated with relaying a finalized VAA will be paid to the `fee_recipient`. This",
+        "instruction creates a `FastFill` account. If the amount after fees is less than the order's",
+        "`min_amount_out` (or if the order requests a destination asset, which requires an auction),",
+        "the fast fill's redeemer is the order's `refund_address` instead, which is only allowed if",
+        "the order originated from Solana. Otherwise this instruction will revert because the order",
+        "must be refunded to the source chain.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SettleAuctionNoneLocal` context."
+      ],
+      "discriminator": [
+        253,
+        213,
+        132,
+        148,
+        31,
+        119,
+        215,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "feeRecipientToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. But because the redeemer is a",
+            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
+            "to any account he chooses (this one).",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "cctpMint",
+          "docs": [
+            "CCTP mint registration for the order's mint, which may not exist if the mint is USDC.",
+            ""
+          ]
+        },
+        {
+          "name": "prepared",
+          "accounts": [
+            {
+              "name": "by",
+              "writable": true
+            },
+            {
+              "name": "orderResponse",
+              "writable": true
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "This account will have been created using the reserve fast fill sequence (no auction)",
+            "instruction. We need to make sure that this account has not been used in an auction."
+          ],
+          "writable": true
+        },
+        {
+          "name": "reservedSequence",
+          "docs": [
+            "This account will be closed at the end of this instruction instead of using the close",
+            "account directive here.",
+            "",
+            "If we could reference the beneficiary using `prepared.by`, this would be a different story.",
+            "",
+            "NOTE: We do not need to do a VAA hash check because that was already performed when the",
+            "reserved sequence was created."
+          ],
+          "writable": true
+        },
+        {
+          "name": "fastFill",
+          "writable": true
+        },
+        {
+          "name": "localCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
+            }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitOwnershipTransferRequest",
+      "docs": [
+        "This instruction sets the `pending_owner` field in the `Custodian` account. This instruction",
+        "can only be called by the `owner`. The `pending_owner` address must be valid, meaning it",
+        "cannot be the zero address or the current owner.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SubmitOwnershipTransferRequest` context."
+      ],
+      "discriminator": [
+        215,
+        13,
+        88,
+        199,
+        48,
+        195,
+        19,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "newOwner",
+          "docs": [
+            "New Owner.",
+            ""
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateAuctionParameters",
+      "docs": [
+        "This instruction is used to enact an existing auction update proposal. It can only be",
+        "executed after the `slot_enact_delay` has passed. This instruction can only be called by the",
+        "`owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateAuctionParameters` context."
+      ],
+      "discriminator": [
+        10,
+        33,
+        10,
+        75,
+        17,
+        63,
+        21,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "auctionConfig",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateCctpMint",
+      "docs": [
+        "This instruction is used to enable or disable a registered CCTP mint and to update its fee",
+        "recipient token account and auction config override. This instruction can only be called by",
+        "the `owner` or `owner_assistant`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`     - `UpdateCctpMint` context.",
+        "* `enabled` - Whether new auctions and order responses can use this mint."
+      ],
+      "discriminator": [
+        183,
+        92,
+        236,
+        224,
+        21,
+        153,
+        234,
+        106
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "ownerOrAssistant",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "cctpMint",
+          "writable": true
+        },
+        {
+          "name": "feeRecipientToken"
+        },
+        {
+          "name": "auctionConfig",
+          "docs": [
+            "Auction config used for auctions of this mint without a route override. If not provided,",
+            "the global auction config is used."
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateCctpRouterEndpoint",
+      "docs": [
+        "This instruction is used to update a CCTP router endpoint. It allows the caller to change",
+        "the `address`, `mint_recipient`, and `domain`. This instruction can only be called by the",
+        "`owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `UpdateCctpRouterEndpoint` context.",
+        "* `args` - The `AddCctpRouterEndpointArgs`, see `admin.rs`."
+      ],
+      "discriminator": [
+        34,
+        122,
+        31,
+        38,
+        73,
+        126,
+        94,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "remoteTokenMessenger",
+          "docs": [
+            "Messenger Minter program)."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "addCctpRouterEndpointArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeRecipient",
+      "docs": [
+        "This instruction is used to update the `fee_recipient` field in the `Custodian` account.",
+        "This instruction can only be called by the `owner` or `owner_assistant`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateFeeRecipient` context."
+      ],
+      "discriminator": [
+        249,
+        0,
+        198,
+        35,
+        183,
+        123,
+        57,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "ownerOrAssistant",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "newFeeRecipientToken"
+        },
+        {
+          "name": "newFeeRecipient",
+          "docs": [
+            "New Fee Recipient.",
+            ""
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateLocalRouterEndpoint",
+      "docs": [
+        "This instruction is used to update a Local router endpoint. It allows the caller to change",
+        "the `address` and `mint_recipient`. This instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateLocalRouterEndpoint` context."
+      ],
+      "discriminator": [
+        222,
+        237,
+        142,
+        228,
+        88,
+        3,
+        49,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "local",
+          "accounts": [
+            {
+              "name": "tokenRouterProgram",
+              "docs": [
+                "emitter (router endpoint) address."
+              ]
+            },
+            {
+              "name": "tokenRouterEmitter"
+            },
+            {
+              "name": "tokenRouterMintRecipient"
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateOwnerAssistant",
+      "docs": [
+        "This instruction is used to update the `owner_assistant` field in the `Custodian` account.",
+        "This instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateOwnerAssistant` context."
+      ],
+      "discriminator": [
+        153,
+        83,
+        175,
+        53,
+        168,
+        34,
+        131,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "newOwnerAssistant",
+          "docs": [
+            "New Assistant.",
+            ""
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateRouteAuctionParameters",
+      "docs": [
+        "This instruction is used to enact an existing route auction parameters proposal, which",
+        "creates a new auction config and points the route's auction config to it. It can only be",
+        "executed after the `slot_enact_delay` has passed. This instruction can only be called by",
+        "the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateRouteAuctionParameters` context."
+      ],
+      "discriminator": [
+        203,
+        1,
+        79,
+        25,
+        210,
+        104,
+        24,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "routeAuctionConfig",
+          "writable": true
+        },
+        {
+          "name": "auctionConfig",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "auction",
+      "discriminator": [
+        218,
+        94,
+        247,
+        242,
+        126,
+        233,
+        131,
+        81
+      ]
+    },
+    {
+      "name": "auctionConfig",
+      "discriminator": [
+        195,
+        54,
+        8,
+        51,
+        28,
+        231,
+        33,
+        142
+      ]
+    },
+    {
+      "name": "auctionTranche",
+      "discriminator": [
+        76,
+        171,
+        183,
+        36,
+        98,
+        0,
+        253,
+        150
+      ]
+    },
+    {
+      "name": "cctpMint",
+      "discriminator": [
+        253,
+        248,
+        220,
+        225,
+        159,
+        7,
+        92,
+        27
+      ]
+    },
+    {
+      "name": "custodian",
+      "discriminator": [
+        132,
+        228,
+        139,
+        184,
+        112,
+        228,
+        108,
+        240
+      ]
+    },
+    {
+      "name": "fastFill",
+      "discriminator": [
+        89,
+        120,
+        166,
+        41,
+        106,
+        227,
+        218,
+        121
+      ]
+    },
+    {
+      "name": "fastFillSequencer",
+      "discriminator": [
+        70,
+        193,
+        18,
+        127,
+        227,
+        183,
+        46,
+        177
+      ]
+    },
+    {
+      "name": "localFastOrder",
+      "discriminator": [
+        110,
+        232,
+        238,
+        228,
+        116,
+        252,
+        241,
+        9
+      ]
+    },
+    {
+      "name": "offerCommitment",
+      "discriminator": [
+        217,
+        228,
+        242,
+        40,
+        128,
+        112,
+        173,
+        34
+      ]
     },
     {
       "name": "preparedOrderResponse",
@@ -2698,6 +4155,19 @@ export type MatchingEngine = {
         179
       ]
     },
+    {
+      "name": "routeAuctionConfig",
+      "discriminator": [
+        144,
+        54,
+        254,
+        88,
+        151,
+        9,
+        46,
+        46
+      ]
+    },
     {
       "name": "routerEndpoint",
       "discriminator": [
@@ -2739,6 +4209,45 @@ export type MatchingEngine = {
         147
       ]
     },
+    {
+      "name": "auctionTrancheExecuted",
+      "discriminator": [
+        43,
+        106,
+        39,
+        165,
+        73,
+        108,
+        95,
+        69
+      ]
+    },
+    {
+      "name": "auctionTrancheRefunded",
+      "discriminator": [
+        97,
+        235,
+        186,
+        247,
+        104,
+        46,
+        187,
+        43
+      ]
+    },
+    {
+      "name": "auctionTrancheUpdated",
+      "discriminator": [
+        63,
+        242,
+        63,
+        7,
+        10,
+        91,
+        241,
+        107
+      ]
+    },
     {
       "name": "auctionUpdated",
       "discriminator": [
@@ -2804,6 +4313,45 @@ export type MatchingEngine = {
         193
       ]
     },
+    {
+      "name": "offerCommitmentForfeited",
+      "discriminator": [
+        26,
+        238,
+        185,
+        62,
+        255,
+        240,
+        171,
+        63
+      ]
+    },
+    {
+      "name": "offerCommitted",
+      "discriminator": [
+        114,
+        173,
+        213,
+        42,
+        140,
+        132,
+        136,
+        148
+      ]
+    },
+    {
+      "name": "offerRevealed",
+      "discriminator": [
+        229,
+        64,
+        214,
+        43,
+        86,
+        41,
+        27,
+        19
+      ]
+    },
     {
       "name": "orderExecuted",
       "discriminator": [
@@ -2956,6 +4504,15 @@ export type MatchingEngine = {
       "name": "securityDepositBpsTooLarge",
       "msg": "Value exceeds 1000000"
     },
+    {
+      "code": 6274,
+      "name": "tooManyTranches",
+      "msg": "Value exceeds 8"
+    },
+    {
+      "code": 6276,
+      "name": "splitFillSealedBid"
+    },
     {
       "code": 6514,
       "name": "invalidNewOwner"
@@ -2984,6 +4541,18 @@ export type MatchingEngine = {
       "code": 6578,
       "name": "invalidMintRecipient"
     },
+    {
+      "code": 6580,
+      "name": "cctpMintNotSupported"
+    },
+    {
+      "code": 6582,
+      "name": "cctpMintMismatch"
+    },
+    {
+      "code": 6584,
+      "name": "invalidCctpMint"
+    },
     {
       "code": 6768,
       "name": "proposalAlreadyEnacted"
@@ -3097,57 +4666,435 @@ export type MatchingEngine = {
       "name": "auctionAlreadySettled"
     },
     {
-      "code": 7084,
-      "name": "invalidBaseFeeToken"
+      "code": 7084,
+      "name": "invalidBaseFeeToken"
+    },
+    {
+      "code": 7086,
+      "name": "baseFeeTokenRequired"
+    },
+    {
+      "code": 7098,
+      "name": "notSealedBidAuction"
+    },
+    {
+      "code": 7100,
+      "name": "sealedBidAuction"
+    },
+    {
+      "code": 7102,
+      "name": "revealPeriodNotStarted"
+    },
+    {
+      "code": 7104,
+      "name": "revealPeriodExpired"
+    },
+    {
+      "code": 7106,
+      "name": "revealPeriodNotExpired"
+    },
+    {
+      "code": 7108,
+      "name": "invalidOfferReveal"
+    },
+    {
+      "code": 7114,
+      "name": "notSplitFillAuction"
+    },
+    {
+      "code": 7116,
+      "name": "splitFillAuction"
+    },
+    {
+      "code": 7118,
+      "name": "invalidTrancheIndex"
+    },
+    {
+      "code": 7120,
+      "name": "auctionTrancheMismatch"
+    },
+    {
+      "code": 7122,
+      "name": "auctionTrancheRequired"
+    },
+    {
+      "code": 7124,
+      "name": "auctionTranchesNotFilled"
+    },
+    {
+      "code": 7126,
+      "name": "auctionTranchesFilled"
+    },
+    {
+      "code": 7128,
+      "name": "auctionTrancheNotActive"
+    },
+    {
+      "code": 7130,
+      "name": "auctionTranchesNotExecuted"
+    },
+    {
+      "code": 7140,
+      "name": "localFastOrderMismatch"
+    },
+    {
+      "code": 7142,
+      "name": "solverVaultMintMismatch"
+    },
+    {
+      "code": 7280,
+      "name": "cannotCloseAuctionYet"
+    },
+    {
+      "code": 7282,
+      "name": "auctionHistoryNotFull"
+    },
+    {
+      "code": 7284,
+      "name": "auctionHistoryFull"
+    }
+  ],
+  "types": [
+    {
+      "name": "addCctpRouterEndpointArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chain",
+            "type": "u16"
+          },
+          {
+            "name": "cctpDomain",
+            "type": "u32"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mintRecipient",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "header",
+            "type": {
+              "defined": {
+                "name": "versionHeader"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vaaHash",
+            "docs": [
+              "VAA hash of the auction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vaaTimestamp",
+            "docs": [
+              "Timestamp of the fast market order VAA."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "targetProtocol",
+            "docs": [
+              "Transfer protocol used to move assets."
+            ],
+            "type": {
+              "defined": {
+                "name": "messageProtocol"
+              }
+            }
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Auction status."
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionStatus"
+              }
+            }
+          },
+          {
+            "name": "preparedBy",
+            "docs": [
+              "The fee payer when placing the initial offer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "info",
+            "docs": [
+              "Optional auction info. This field will be `None`` if there is no auction."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "auctionInfo"
+                }
+              }
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Circle-supported mint of the assets held in custody for this auction."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 7086,
-      "name": "baseFeeTokenRequired"
+      "name": "auctionClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": {
+              "defined": {
+                "name": "auction"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7280,
-      "name": "cannotCloseAuctionYet"
+      "name": "auctionConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "header",
+            "type": {
+              "defined": {
+                "name": "versionHeader"
+              }
+            }
+          },
+          {
+            "name": "id",
+            "docs": [
+              "Monotonically increasing identifier for auction configs."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "parameters",
+            "docs": [
+              "Auction parameters, which are validated by [crate::utils::auction::require_valid_parameters]."
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionParameters"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7282,
-      "name": "auctionHistoryNotFull"
+      "name": "auctionDestinationAssetInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "custodyTokenBump",
+            "docs": [
+              "Bump seed for the custody token account holding the destination asset delivered by the",
+              "executor, whose seeds are \\[\"destination-custody\", fast_fill.key()\\]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "amountOut",
+            "docs": [
+              "Amount of the destination asset delivered, which is the order's minimum amount out."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 7284,
-      "name": "auctionHistoryFull"
-    }
-  ],
-  "types": [
-    {
-      "name": "addCctpRouterEndpointArgs",
+      "name": "auctionInfo",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "chain",
-            "type": "u16"
+            "name": "configId",
+            "type": "u32"
+          },
+          {
+            "name": "custodyTokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "vaaSequence",
+            "docs": [
+              "Sequence of the fast market order VAA."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sourceChain",
+            "docs": [
+              "The chain where the transfer is initiated."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bestOfferToken",
+            "docs": [
+              "The highest bidder of the auction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "initialOfferToken",
+            "docs": [
+              "The initial bidder of the auction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "startSlot",
+            "docs": [
+              "The slot when the auction started."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amountIn",
+            "docs": [
+              "The amount reflecting the amount of assets transferred into the matching engine. This plus",
+              "and the security deposit are used to participate in the auction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "securityDeposit",
+            "docs": [
+              "The additional deposit made by the highest bidder.",
+              "",
+              "NOTE: This may not be the same denomination as the `amount_in`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "offerPrice",
+            "docs": [
+              "The offer price of the auction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redeemerMessageLen",
+            "docs": [
+              "Length of the redeemer message, which may impact the expense to execute the auction."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "destinationAssetInfo",
+            "docs": [
+              "If the order requested a destination asset, this will be some value specifying its custody",
+              "token bump and amount out once the order is executed locally."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "auctionDestinationAssetInfo"
+                }
+              }
+            }
+          },
+          {
+            "name": "bestOfferVault",
+            "docs": [
+              "If some, the best offer's total deposit is locked in this solver vault instead of being",
+              "held in the auction's custody token account. The best offer token is this vault's custody",
+              "token account."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "cctpDomain",
-            "type": "u32"
+            "name": "forfeitedUserReward",
+            "docs": [
+              "User rewards collected from security deposits forfeited by unrevealed commitments in a",
+              "sealed-bid auction. This amount is held in the auction's custody token account and is paid",
+              "to the user when the order is executed."
+            ],
+            "type": "u64"
           },
           {
-            "name": "address",
+            "name": "extendedEndSlot",
+            "docs": [
+              "If some, an improved offer placed near the end of the auction extended the auction to end at",
+              "this slot (see [AuctionParameters::extension_window])."
+            ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": "u64"
             }
           },
           {
-            "name": "mintRecipient",
+            "name": "solverAllowlist",
+            "docs": [
+              "If true, only registered solvers may place offers on this auction. This is determined by",
+              "the route's router endpoints when the auction starts."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "splitFill",
+            "docs": [
+              "If some, this auction is a split-fill auction. Offers are placed on tranches of the order",
+              "instead of the order itself, so the best offer token, security deposit and offer price of",
+              "this auction are unused (see [AuctionTranche](super::AuctionTranche))."
+            ],
             "type": {
               "option": {
-                "array": [
-                  "u8",
-                  32
-                ]
+                "defined": {
+                  "name": "auctionSplitFillInfo"
+                }
               }
             }
           }
@@ -3155,88 +5102,143 @@ export type MatchingEngine = {
       }
     },
     {
-      "name": "auction",
+      "name": "auctionParameters",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "userPenaltyRewardBps",
+            "type": "u32"
           },
           {
-            "name": "vaaHash",
+            "name": "initialPenaltyBps",
+            "type": "u32"
+          },
+          {
+            "name": "duration",
+            "type": "u16"
+          },
+          {
+            "name": "gracePeriod",
             "docs": [
-              "VAA hash of the auction."
+              "* The grace period of the auction in slots. This is the number of slots the highest bidder\n     * has to execute the fast order before incurring a penalty. About 15 seconds on Avalanche.\n     * This value INCLUDES the `_auctionDuration`."
             ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "type": "u16"
           },
           {
-            "name": "vaaTimestamp",
+            "name": "penaltyPeriod",
+            "type": "u16"
+          },
+          {
+            "name": "minOfferDeltaBps",
+            "type": "u32"
+          },
+          {
+            "name": "securityDepositBase",
             "docs": [
-              "Timestamp of the fast market order VAA."
+              "The base security deposit, which will the the additional amount an auction participant must",
+              "deposit to participate in an auction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "securityDepositBps",
+            "docs": [
+              "Additional security deposit based on the notional of the order amount."
             ],
             "type": "u32"
           },
           {
-            "name": "targetProtocol",
+            "name": "revealPeriod",
             "docs": [
-              "Transfer protocol used to move assets."
+              "If nonzero, auctions are sealed-bid auctions. Offers are committed during the auction",
+              "duration and revealed during this many slots after the auction duration. The reveal period",
+              "delays when the auction can be executed (and when the grace period begins)."
             ],
-            "type": {
-              "defined": {
-                "name": "messageProtocol"
-              }
-            }
+            "type": "u16"
           },
           {
-            "name": "status",
+            "name": "extensionWindow",
             "docs": [
-              "Auction status."
+              "An improved offer placed within this many slots of the end of the auction extends the",
+              "auction so that there are this many slots left to respond. Zero disables extensions."
             ],
-            "type": {
-              "defined": {
-                "name": "auctionStatus"
-              }
-            }
+            "type": "u16"
           },
           {
-            "name": "preparedBy",
+            "name": "maxExtension",
             "docs": [
-              "The fee payer when placing the initial offer."
+              "The maximum number of slots an auction can be extended beyond its duration."
             ],
-            "type": "pubkey"
+            "type": "u16"
           },
           {
-            "name": "info",
+            "name": "numTranches",
             "docs": [
-              "Optional auction info. This field will be `None`` if there is no auction."
+              "If greater than one, auctions are split-fill auctions. The fast order is split into this",
+              "many tranches, where each tranche has its own best offer, deposit and execution."
             ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "auctionInfo"
-                }
-              }
-            }
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "auctionClosed",
+      "name": "auctionSettled",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auction",
+            "name": "fastVaaHash",
+            "docs": [
+              "The pubkey of the auction that was settled."
+            ],
             "type": {
-              "defined": {
-                "name": "auction"
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bestOfferToken",
+            "docs": [
+              "If there was an active auction, this field will have the pubkey of the best offer token that",
+              "was paid back and its balance after repayment."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "settledTokenAccountInfo"
+                }
+              }
+            }
+          },
+          {
+            "name": "baseFeeToken",
+            "docs": [
+              "Depending on whether there was an active auction, this field will have the pubkey of the",
+              "base fee token account (if there was an auction) or fee recipient token (if there was no",
+              "auction)."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "settledTokenAccountInfo"
+                }
+              }
+            }
+          },
+          {
+            "name": "withExecute",
+            "docs": [
+              "This value will only be some if there was no active auction."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "messageProtocol"
+                }
               }
             }
           }
@@ -3244,208 +5246,227 @@ export type MatchingEngine = {
       }
     },
     {
-      "name": "auctionConfig",
+      "name": "auctionSplitFillInfo",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
+            "name": "numTranches",
             "docs": [
-              "Monotonically increasing identifier for auction configs."
+              "Number of tranches the fast order is split into."
             ],
-            "type": "u32"
+            "type": "u8"
           },
           {
-            "name": "parameters",
+            "name": "tranchesPlaced",
             "docs": [
-              "Auction parameters, which are validated by [crate::utils::auction::require_valid_parameters]."
+              "Number of tranches with an offer. Each placed tranche has an",
+              "[AuctionTranche](super::AuctionTranche) account."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tranchesExecuted",
+            "docs": [
+              "Number of tranches that have been executed."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "userAmount",
+            "docs": [
+              "Amount owed to the user, which accumulates as each tranche is executed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "executePenalty",
+            "docs": [
+              "Sum of penalties incurred by late tranche executions. This value is none if no tranche was",
+              "penalized."
             ],
             "type": {
-              "defined": {
-                "name": "auctionParameters"
-              }
+              "option": "u64"
             }
+          },
+          {
+            "name": "amountInSettled",
+            "docs": [
+              "Sum of the amounts in of tranches that have been settled."
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "auctionDestinationAssetInfo",
+      "name": "auctionStatus",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "custodyTokenBump",
-            "type": "u8"
+            "name": "notStarted"
           },
           {
-            "name": "amountOut",
-            "type": "u64"
+            "name": "active"
+          },
+          {
+            "name": "completed",
+            "fields": [
+              {
+                "name": "slot",
+                "type": "u64"
+              },
+              {
+                "name": "executePenalty",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "settled",
+            "fields": [
+              {
+                "name": "fee",
+                "type": "u64"
+              },
+              {
+                "name": "totalPenalty",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "auctionInfo",
+      "name": "auctionTranche",
+      "docs": [
+        "Tranche of a split-fill auction's fast order. Each tranche has its own best offer, whose total",
+        "deposit is held in the auction's custody token account."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "configId",
-            "type": "u32"
-          },
-          {
-            "name": "custodyTokenBump",
+            "name": "bump",
             "type": "u8"
           },
           {
-            "name": "vaaSequence",
+            "name": "auction",
             "docs": [
-              "Sequence of the fast market order VAA."
+              "Split-fill auction this tranche belongs to."
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
-            "name": "sourceChain",
+            "name": "index",
             "docs": [
-              "The chain where the transfer is initiated."
+              "Index of this tranche, which is less than the auction's number of tranches."
             ],
-            "type": "u16"
+            "type": "u8"
           },
           {
-            "name": "bestOfferToken",
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "auctionTrancheStatus"
+              }
+            }
+          },
+          {
+            "name": "preparedBy",
             "docs": [
-              "The highest bidder of the auction."
+              "The payer when placing the initial offer on this tranche, who will receive the rent for",
+              "this account."
             ],
             "type": "pubkey"
           },
           {
             "name": "initialOfferToken",
             "docs": [
-              "The initial bidder of the auction."
+              "The initial bidder of this tranche."
             ],
             "type": "pubkey"
           },
           {
-            "name": "startSlot",
+            "name": "bestOfferToken",
             "docs": [
-              "The slot when the auction started."
+              "The best bidder of this tranche."
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
             "name": "amountIn",
             "docs": [
-              "The amount reflecting the amount of assets transferred into the matching engine. This plus",
-              "and the security deposit are used to participate in the auction."
+              "This tranche's portion of the auction's amount in."
             ],
             "type": "u64"
           },
           {
             "name": "securityDeposit",
             "docs": [
-              "The additional deposit made by the highest bidder.",
-              "",
-              "NOTE: This may not be the same denomination as the `amount_in`."
+              "The additional deposit made by the best bidder of this tranche."
             ],
             "type": "u64"
           },
           {
             "name": "offerPrice",
             "docs": [
-              "The offer price of the auction."
+              "The offer price of this tranche."
             ],
             "type": "u64"
           },
           {
-            "name": "redeemerMessageLen",
-            "docs": [
-              "Length of the redeemer message, which may impact the expense to execute the auction."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "destinationAssetInfo",
+            "name": "initAuctionFee",
             "docs": [
-              "If the destination asset is not equal to the asset used for auctions, this will be some",
-              "value specifying its custody token bump and amount out.",
-              "",
-              "NOTE: Because this is an option, the `AuctionDestinationAssetInfo` having some definition while this",
-              "field is None will not impact future serialization because the option's serialized value is",
-              "zero. Only when there will be other assets will this struct's members have to be carefully",
-              "considered."
+              "This tranche's portion of the fast market order's init auction fee."
             ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "auctionDestinationAssetInfo"
-                }
-              }
-            }
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "auctionParameters",
+      "name": "auctionTrancheExecuted",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "userPenaltyRewardBps",
-            "type": "u32"
-          },
-          {
-            "name": "initialPenaltyBps",
-            "type": "u32"
-          },
-          {
-            "name": "duration",
-            "type": "u16"
-          },
-          {
-            "name": "gracePeriod",
-            "docs": [
-              "* The grace period of the auction in slots. This is the number of slots the highest bidder\n     * has to execute the fast order before incurring a penalty. About 15 seconds on Avalanche.\n     * This value INCLUDES the `_auctionDuration`."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "penaltyPeriod",
-            "type": "u16"
+            "name": "fastVaaHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "minOfferDeltaBps",
-            "type": "u32"
+            "name": "trancheIndex",
+            "type": "u8"
           },
           {
-            "name": "securityDepositBase",
-            "docs": [
-              "The base security deposit, which will the the additional amount an auction participant must",
-              "deposit to participate in an auction."
-            ],
-            "type": "u64"
+            "name": "bestOfferToken",
+            "type": "pubkey"
           },
           {
-            "name": "securityDepositBps",
-            "docs": [
-              "Additional security deposit based on the notional of the order amount."
-            ],
-            "type": "u32"
+            "name": "penalized",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "auctionSettled",
+      "name": "auctionTrancheRefunded",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "fastVaaHash",
-            "docs": [
-              "The pubkey of the auction that was settled."
-            ],
             "type": {
               "array": [
                 "u8",
@@ -3454,68 +5475,41 @@ export type MatchingEngine = {
             }
           },
           {
-            "name": "bestOfferToken",
-            "docs": [
-              "If there was an active auction, this field will have the pubkey of the best offer token that",
-              "was paid back and its balance after repayment."
-            ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "settledTokenAccountInfo"
-                }
-              }
-            }
+            "name": "trancheIndex",
+            "type": "u8"
           },
           {
-            "name": "baseFeeToken",
+            "name": "refundToken",
             "docs": [
-              "Depending on whether there was an active auction, this field will have the pubkey of the",
-              "base fee token account (if there was an auction) or fee recipient token (if there was no",
-              "auction)."
+              "Token account that received the refunded total deposit."
             ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "settledTokenAccountInfo"
-                }
-              }
-            }
+            "type": "pubkey"
           },
           {
-            "name": "withExecute",
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "auctionClosed",
             "docs": [
-              "This value will only be some if there was no active auction."
+              "Whether this was the last placed tranche, which closes the auction."
             ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "messageProtocol"
-                }
-              }
-            }
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "auctionStatus",
+      "name": "auctionTrancheStatus",
       "type": {
         "kind": "enum",
         "variants": [
-          {
-            "name": "notStarted"
-          },
           {
             "name": "active"
           },
           {
-            "name": "completed",
+            "name": "executed",
             "fields": [
-              {
-                "name": "slot",
-                "type": "u64"
-              },
               {
                 "name": "executePenalty",
                 "type": {
@@ -3523,21 +5517,70 @@ export type MatchingEngine = {
                 }
               }
             ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionTrancheUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "configId",
+            "type": "u32"
           },
           {
-            "name": "settled",
-            "fields": [
-              {
-                "name": "fee",
-                "type": "u64"
-              },
-              {
-                "name": "totalPenalty",
-                "type": {
-                  "option": "u64"
-                }
-              }
-            ]
+            "name": "fastVaaHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vaa",
+            "docs": [
+              "This value will only be some when the initial offer on a tranche is placed."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "trancheIndex",
+            "type": "u8"
+          },
+          {
+            "name": "numTranches",
+            "type": "u8"
+          },
+          {
+            "name": "endSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bestOfferToken",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenBalanceBefore",
+            "type": "u64"
+          },
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "totalDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "maxOfferPriceAllowed",
+            "type": {
+              "option": "u64"
+            }
           }
         ]
       }
@@ -3621,8 +5664,64 @@ export type MatchingEngine = {
             "type": "bytes"
           },
           {
-            "name": "cctpAttestation",
-            "type": "bytes"
+            "name": "cctpAttestation",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cctpMint",
+      "docs": [
+        "Registered CCTP asset other than USDC (e.g. EURC). USDC is always supported and does not need",
+        "to be registered."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Circle-supported mint."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mintRecipient",
+            "docs": [
+              "Custodian's associated token account for this mint, which must be encoded as the mint",
+              "recipient in CCTP messages sent to this program."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRecipientToken",
+            "docs": [
+              "Token account for this mint that collects protocol fees, which replaces the custodian's",
+              "fee recipient token account for orders of this mint."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether new auctions and order responses can use this mint."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "configId",
+            "docs": [
+              "Auction config ID used for auctions of this mint without a route override. If this value is",
+              "`None`, the global auction config is used."
+            ],
+            "type": {
+              "option": "u32"
+            }
           }
         ]
       }
@@ -3674,6 +5773,46 @@ export type MatchingEngine = {
           {
             "name": "nextProposalId",
             "type": "u64"
+          },
+          {
+            "name": "latestAuctionConfigId",
+            "docs": [
+              "Latest auction config ID, which may belong to a route override. New auction configs are",
+              "assigned the next ID."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "depositLocalFastOrderArgs",
+      "docs": [
+        "Arguments for [deposit_local_fast_order]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fastVaaSequence",
+            "docs": [
+              "Wormhole sequence of the fast market order message published by the local Token Router."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amountIn",
+            "docs": [
+              "Amount to deposit, which must equal the fast market order's amount in."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "baseFee",
+            "docs": [
+              "Fee paid to whomever prepares the order response."
+            ],
+            "type": "u64"
           }
         ]
       }
@@ -3750,6 +5889,14 @@ export type MatchingEngine = {
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "header",
+            "type": {
+              "defined": {
+                "name": "versionHeader"
+              }
+            }
+          },
           {
             "name": "seeds",
             "type": {
@@ -3813,6 +5960,14 @@ export type MatchingEngine = {
               "current [Clock] unix timestamp."
             ],
             "type": "i64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the filled amount, which is either the auction's mint or the destination asset",
+              "requested by the order."
+            ],
+            "type": "pubkey"
           }
         ]
       }
@@ -3905,36 +6060,282 @@ export type MatchingEngine = {
       }
     },
     {
-      "name": "fastFillSequencer",
+      "name": "fastFillSequencer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seeds",
+            "type": {
+              "defined": {
+                "name": "fastFillSequencerSeeds"
+              }
+            }
+          },
+          {
+            "name": "nextSequence",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fastFillSequencerSeeds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "type": "u16"
+          },
+          {
+            "name": "sender",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "initializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionParams",
+            "type": {
+              "defined": {
+                "name": "auctionParameters"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "localFastOrder",
+      "docs": [
+        "Deposit for a fast market order placed by the Token Router on this network. Because the order",
+        "never leaves Solana, this deposit stands in for the CCTP transfer and finalized VAA that would",
+        "otherwise be required to prepare the order response.",
+        "",
+        "NOTE: Only the local Token Router can create this account, which it does when it publishes the",
+        "fast market order."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "custodyTokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "preparedBy",
+            "docs": [
+              "Who paid the rent for this account and its custody token account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "fastVaaSequence",
+            "docs": [
+              "Wormhole sequence of the fast market order message published by the local Token Router."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amountIn",
+            "docs": [
+              "Amount deposited into the custody token account, which is the fast market order's amount in."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "baseFee",
+            "docs": [
+              "Fee paid to whomever prepares the order response."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "localFastOrderFilled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seeds",
+            "type": {
+              "defined": {
+                "name": "fastFillSeeds"
+              }
+            }
+          },
+          {
+            "name": "info",
+            "type": {
+              "defined": {
+                "name": "fastFillInfo"
+              }
+            }
+          },
+          {
+            "name": "auction",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "messageProtocol",
+      "docs": [
+        "Protocol used to transfer assets."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "local",
+            "fields": [
+              {
+                "name": "programId",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "cctp",
+            "fields": [
+              {
+                "name": "domain",
+                "docs": [
+                  "CCTP domain, which is how CCTP registers identifies foreign networks."
+                ],
+                "type": "u32"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "offerCommitment",
+      "docs": [
+        "Sealed offer committed to a sealed-bid auction. The committed offer's total deposit is held in",
+        "this commitment's custody token account until the offer is revealed (or forfeited if it is never",
+        "revealed)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "custodyTokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "auction",
+            "docs": [
+              "Auction this offer was committed to."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "offerToken",
+            "docs": [
+              "Token account that funded the deposit. If this offer is revealed as the best offer, this",
+              "token account becomes the auction's best offer token."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "preparedBy",
+            "docs": [
+              "The payer when committing the offer, who will receive the rent for this account and its",
+              "custody token account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "docs": [
+              "Hash of the offer token, offer price and salt (see [OfferCommitment::compute])."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "offerCommitmentForfeited",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "seeds",
+            "name": "fastVaaHash",
             "type": {
-              "defined": {
-                "name": "fastFillSequencerSeeds"
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "nextSequence",
+            "name": "offerToken",
+            "type": "pubkey"
+          },
+          {
+            "name": "penalty",
+            "docs": [
+              "Portion of the security deposit paid to whoever closed the unrevealed commitment."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "userReward",
+            "docs": [
+              "Portion of the security deposit paid to the user (or the fee recipient if the auction was",
+              "already executed)."
+            ],
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "fastFillSequencerSeeds",
+      "name": "offerCommitted",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "sourceChain",
-            "type": "u16"
-          },
-          {
-            "name": "sender",
+            "name": "fastVaaHash",
             "type": {
               "array": [
                 "u8",
@@ -3943,89 +6344,56 @@ export type MatchingEngine = {
             }
           },
           {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "initializeArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "offerToken",
+            "type": "pubkey"
+          },
           {
-            "name": "auctionParams",
+            "name": "commitment",
             "type": {
-              "defined": {
-                "name": "auctionParameters"
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
+          },
+          {
+            "name": "revealEndSlot",
+            "docs": [
+              "Last slot when the committed offer can be revealed."
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "localFastOrderFilled",
+      "name": "offerRevealed",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "seeds",
-            "type": {
-              "defined": {
-                "name": "fastFillSeeds"
-              }
-            }
-          },
-          {
-            "name": "info",
+            "name": "fastVaaHash",
             "type": {
-              "defined": {
-                "name": "fastFillInfo"
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "auction",
-            "type": {
-              "option": "pubkey"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "messageProtocol",
-      "docs": [
-        "Protocol used to transfer assets."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "none"
+            "name": "offerToken",
+            "type": "pubkey"
           },
           {
-            "name": "local",
-            "fields": [
-              {
-                "name": "programId",
-                "type": "pubkey"
-              }
-            ]
+            "name": "offerPrice",
+            "type": "u64"
           },
           {
-            "name": "cctp",
-            "fields": [
-              {
-                "name": "domain",
-                "docs": [
-                  "CCTP domain, which is how CCTP registers identifies foreign networks."
-                ],
-                "type": "u32"
-              }
-            ]
+            "name": "bestOffer",
+            "docs": [
+              "Whether the revealed offer is now the best offer."
+            ],
+            "type": "bool"
           }
         ]
       }
@@ -4153,6 +6521,48 @@ export type MatchingEngine = {
           {
             "name": "amountIn",
             "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "Minimum amount the user must receive. If the amount after fees falls below this value, the",
+              "funds are refunded to the refund address on the source chain instead."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refundAddress",
+            "docs": [
+              "Universal address on the source chain where funds are refunded."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Circle-supported mint of the finalized CCTP deposit."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "destinationAsset",
+            "docs": [
+              "Destination asset requested by the order (if any), which can only be delivered by an",
+              "auction's executor. Without an auction, these orders are refunded instead."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           }
         ]
       }
@@ -4183,6 +6593,14 @@ export type MatchingEngine = {
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "header",
+            "type": {
+              "defined": {
+                "name": "versionHeader"
+              }
+            }
+          },
           {
             "name": "id",
             "type": "u64"
@@ -4248,6 +6666,83 @@ export type MatchingEngine = {
                 }
               }
             ]
+          },
+          {
+            "name": "updateRouteAuctionParameters",
+            "fields": [
+              {
+                "name": "sourceChain",
+                "type": "u16"
+              },
+              {
+                "name": "targetChain",
+                "type": "u16"
+              },
+              {
+                "name": "id",
+                "type": "u32"
+              },
+              {
+                "name": "parameters",
+                "type": {
+                  "defined": {
+                    "name": "auctionParameters"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "removeRouteAuctionParameters",
+            "fields": [
+              {
+                "name": "sourceChain",
+                "type": "u16"
+              },
+              {
+                "name": "targetChain",
+                "type": "u16"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposeRouteAuctionParametersArgs",
+      "docs": [
+        "Arguments for [propose_route_auction_parameters]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "docs": [
+              "Chain where fast market orders are created."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "targetChain",
+            "docs": [
+              "Chain where fast market orders are destined."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "parameters",
+            "docs": [
+              "New auction parameters for this route. If `None`, the route's override will be removed so",
+              "auctions on this route use the global auction config."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "auctionParameters"
+                }
+              }
+            }
           }
         ]
       }
@@ -4338,6 +6833,43 @@ export type MatchingEngine = {
         ]
       }
     },
+    {
+      "name": "routeAuctionConfig",
+      "docs": [
+        "Account pointing to an [AuctionConfig](super::AuctionConfig) that overrides the global auction",
+        "config for auctions of fast market orders sent from the source chain to the target chain."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "sourceChain",
+            "docs": [
+              "Chain where the fast market order was created."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "targetChain",
+            "docs": [
+              "Chain where the fast market order is destined."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "configId",
+            "docs": [
+              "Auction config ID used for auctions on this route."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
     {
       "name": "routerEndpoint",
       "docs": [
@@ -4376,6 +6908,21 @@ export type MatchingEngine = {
           }
         ]
       }
+    },
+    {
+      "name": "versionHeader",
+      "docs": [
+        "Header found at the start of a versioned account's data (right after its discriminator)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
     }
   ]
 };
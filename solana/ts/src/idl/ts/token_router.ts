@@ -339,6 +339,141 @@ export type TokenRouter = {
       ],
       "args": []
     },
+    {
+      "name": "placeFastMarketOrder",
+      "docs": [
+        "This instruction is used to place a `FastMarketOrder`. A `FastMarketOrder` message is",
+        "published, which the matching engine uses to start an auction for the order. Because the",
+        "matching engine lives on Solana, the prepared order's tokens are deposited directly into the",
+        "matching engine (see `deposit_local_fast_order`) instead of being burned via CCTP. The order",
+        "must have been prepared using `prepare_fast_market_order`.",
+        "# Arguments",
+        "",
+        "* `ctx` - `PlaceFastMarketOrder` context."
+      ],
+      "discriminator": [
+        161,
+        120,
+        7,
+        55,
+        68,
+        89,
+        198,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "This account must be the same pubkey as the one who prepared the order."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "preparedBy",
+          "writable": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "preparedOrder",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Prepared custody token account. This account will be closed at the end of this",
+            "instruction after its tokens are deposited into the Matching Engine.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "targetRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
+          ]
+        },
+        {
+          "name": "matchingEngineCustodian"
+        },
+        {
+          "name": "localRouterEndpoint",
+          "docs": [
+            "This program's router endpoint registered in the Matching Engine.",
+            ""
+          ]
+        },
+        {
+          "name": "localFastOrder",
+          "docs": [
+            "Matching Engine account recording this deposit.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "localFastOrderCustodyToken",
+          "docs": [
+            "Matching Engine custody token account holding the deposited tokens.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "usdc",
+          "accounts": [
+            {
+              "name": "mint"
+            }
+          ]
+        },
+        {
+          "name": "coreBridgeConfig",
+          "writable": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "coreEmitterSequence",
+          "writable": true
+        },
+        {
+          "name": "coreFeeCollector",
+          "writable": true
+        },
+        {
+          "name": "coreBridgeProgram"
+        },
+        {
+          "name": "matchingEngineProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "clock"
+        },
+        {
+          "name": "rent"
+        }
+      ],
+      "args": []
+    },
     {
       "name": "placeMarketOrderCctp",
       "docs": [
@@ -490,6 +625,132 @@ export type TokenRouter = {
       ],
       "args": []
     },
+    {
+      "name": "prepareFastMarketOrder",
+      "docs": [
+        "This instruction is used to prepare a `FastMarketOrder`. Like `prepare_market_order`, the",
+        "order's tokens are transferred to a `prepared_custody_token` account. The order's max fee",
+        "is validated against the `Custodian` account's fast transfer parameters, whose init auction",
+        "fee is written to the `PreparedOrder` account.",
+        "# Arguments",
+        "",
+        "* `ctx`  - `PrepareFastMarketOrder` context.",
+        "* `args` - `PrepareFastMarketOrderArgs` struct, see `prepare_fast.rs` for more info."
+      ],
+      "discriminator": [
+        240,
+        198,
+        125,
+        51,
+        168,
+        132,
+        102,
+        57
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "docs": [
+            "Fast market orders are validated against the custodian's current fast transfer parameters."
+          ],
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "programTransferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA if the sender (signer) is not",
+            "provided. The delegated amount must equal the amount in or this instruction will revert.",
+            "",
+            "NOTE: If this account is provided, the sender token's owner will be encoded as the order",
+            "sender.",
+            ""
+          ],
+          "optional": true
+        },
+        {
+          "name": "sender",
+          "docs": [
+            "Sender, who has the authority to transfer assets from the sender token account. If this",
+            "account is not provided, the program transfer authority account must be some account.",
+            "",
+            "NOTE: If this account is provided, this pubkey will be encoded as the order sender."
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "preparedOrder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "senderToken",
+          "docs": [
+            "Token account where assets are burned from. The CCTP Token Messenger Minter program will",
+            "burn the configured [amount](TransferTokensWithPayloadArgs::amount) from this account.",
+            "",
+            "[burn_source_authority](Self::burn_source_authority). Its mint must be USDC.",
+            "",
+            "NOTE: This token account must have delegated transfer authority to the custodian prior to",
+            "invoking this instruction."
+          ],
+          "writable": true
+        },
+        {
+          "name": "refundToken"
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account. This account will be closed at the end of this instruction. It just",
+            "acts as a conduit to allow this program to be the transfer initiator in the CCTP message.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "usdc",
+          "accounts": [
+            {
+              "name": "mint"
+            }
+          ]
+        },
+        {
+          "name": "targetRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "prepareFastMarketOrderArgs"
+            }
+          }
+        }
+      ]
+    },
     {
       "name": "prepareMarketOrder",
       "docs": [
@@ -671,10 +932,17 @@ export type TokenRouter = {
           "writable": true
         },
         {
-          "name": "usdc",
+          "name": "mint",
           "accounts": [
             {
               "name": "mint"
+            },
+            {
+              "name": "cctpMint",
+              "docs": [
+                "CCTP mint registration, which may not exist if the mint is USDC.",
+                ""
+              ]
             }
           ]
         },
@@ -828,11 +1096,9 @@ export type TokenRouter = {
           "writable": true
         },
         {
-          "name": "usdc",
-          "accounts": [
-            {
-              "name": "mint"
-            }
+          "name": "mint",
+          "docs": [
+            "the destination asset requested by the order."
           ]
         },
         {
@@ -851,6 +1117,25 @@ export type TokenRouter = {
           ],
           "writable": true
         },
+        {
+          "name": "matchingEngineDestinationCustodyToken",
+          "docs": [
+            "This account must be provided if the fast fill delivers a destination asset.",
+            "",
+            "program)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "fastFillPreparedBy",
+          "docs": [
+            "This account must be provided if the fast fill delivers a destination asset.",
+            ""
+          ],
+          "writable": true,
+          "optional": true
+        },
         {
           "name": "matchingEngineEventAuthority"
         },
@@ -952,6 +1237,55 @@ export type TokenRouter = {
       ],
       "args": []
     },
+    {
+      "name": "updateFastTransferParameters",
+      "docs": [
+        "This instruction is used to update the fast transfer parameters in the `Custodian` account.",
+        "These parameters determine whether fast market orders can be placed and what fees they pay.",
+        "Only the `owner`, `owner_assistant` or a holder of the `FeeManager` role can update these",
+        "parameters.",
+        "# Arguments",
+        "",
+        "* `ctx`    - `UpdateFastTransferParameters` context.",
+        "* `params` - New `FastTransferParameters`. The `max_amount` must be greater than the sum of",
+        "the `base_fee` and `init_auction_fee`."
+      ],
+      "discriminator": [
+        54,
+        37,
+        50,
+        29,
+        228,
+        157,
+        15,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "fastTransferParameters"
+            }
+          }
+        }
+      ]
+    },
     {
       "name": "updateOwnerAssistant",
       "docs": [
@@ -1124,6 +1458,10 @@ export type TokenRouter = {
       "code": 6258,
       "name": "immutableProgram"
     },
+    {
+      "code": 6260,
+      "name": "invalidFastTransferParameters"
+    },
     {
       "code": 6514,
       "name": "invalidNewOwner"
@@ -1148,6 +1486,14 @@ export type TokenRouter = {
       "code": 6524,
       "name": "delegatedAmountMismatch"
     },
+    {
+      "code": 6580,
+      "name": "cctpMintNotSupported"
+    },
+    {
+      "code": 6582,
+      "name": "cctpMintMismatch"
+    },
     {
       "code": 7024,
       "name": "insufficientAmount"
@@ -1163,6 +1509,22 @@ export type TokenRouter = {
     {
       "code": 7030,
       "name": "preparedFillTooLarge"
+    },
+    {
+      "code": 7032,
+      "name": "fastTransferDisabled"
+    },
+    {
+      "code": 7034,
+      "name": "amountTooLarge"
+    },
+    {
+      "code": 7036,
+      "name": "invalidMaxFee"
+    },
+    {
+      "code": 7038,
+      "name": "invalidOrderType"
     }
   ],
   "types": [
@@ -1230,6 +1592,18 @@ export type TokenRouter = {
               "to the `owner`."
             ],
             "type": "pubkey"
+          },
+          {
+            "name": "fastTransferParameters",
+            "docs": [
+              "Parameters used to validate and place fast market orders. Fast market orders are disabled",
+              "until these parameters are set."
+            ],
+            "type": {
+              "defined": {
+                "name": "fastTransferParameters"
+              }
+            }
           }
         ]
       }
@@ -1290,6 +1664,14 @@ export type TokenRouter = {
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "header",
+            "type": {
+              "defined": {
+                "name": "versionHeader"
+              }
+            }
+          },
           {
             "name": "seeds",
             "type": {
@@ -1353,6 +1735,14 @@ export type TokenRouter = {
               "current [Clock] unix timestamp."
             ],
             "type": "i64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the filled amount, which is either the auction's mint or the destination asset",
+              "requested by the order."
+            ],
+            "type": "pubkey"
           }
         ]
       }
@@ -1399,6 +1789,42 @@ export type TokenRouter = {
         ]
       }
     },
+    {
+      "name": "fastTransferParameters",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "docs": [
+              "Boolean indicating whether fast market orders can be placed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "maxAmount",
+            "docs": [
+              "The maximum amount that can be transferred using a fast market order."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "baseFee",
+            "docs": [
+              "Fee paid to whomever prepares the order response in the Matching Engine."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initAuctionFee",
+            "docs": [
+              "Fee paid to the initial bidder of an auction."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "fillType",
       "type": {
@@ -1466,6 +1892,119 @@ export type TokenRouter = {
                 }
               }
             ]
+          },
+          {
+            "name": "fastMarket",
+            "fields": [
+              {
+                "name": "minAmountOut",
+                "type": {
+                  "option": "u64"
+                }
+              },
+              {
+                "name": "maxFee",
+                "docs": [
+                  "Maximum fee the order sender is willing to pay, which includes the init auction fee."
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "initAuctionFee",
+                "docs": [
+                  "Fee paid to the initial bidder of an auction, which was determined by the fast transfer",
+                  "parameters when the order was prepared."
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "deadline",
+                "docs": [
+                  "Unix timestamp by which an auction must start. If zero, there is no deadline."
+                ],
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "limit",
+            "fields": [
+              {
+                "name": "minAmountOut",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "prepareFastMarketOrderArgs",
+      "docs": [
+        "Arguments for [prepare_fast_market_order]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "docs": [
+              "Amount of tokens to transfer."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "If provided, minimum amount of tokens to receive in exchange for",
+              "[amount_in](Self::amount_in)."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "targetChain",
+            "docs": [
+              "The Wormhole chain ID of the network to transfer tokens to."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "redeemer",
+            "docs": [
+              "The address of the redeeming contract on the target chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "redeemerMessage",
+            "docs": [
+              "Arbitrary payload to be sent to the [redeemer](Self::redeemer), which can be used to encode",
+              "instructions or data for another network's smart contract."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "maxFee",
+            "docs": [
+              "Maximum fee the order sender is willing to pay, which includes the init auction fee."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Unix timestamp by which an auction must start. If zero, there is no deadline."
+            ],
+            "type": "u32"
           }
         ]
       }
@@ -1530,6 +2069,14 @@ export type TokenRouter = {
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "header",
+            "type": {
+              "defined": {
+                "name": "versionHeader"
+              }
+            }
+          },
           {
             "name": "seeds",
             "type": {
@@ -1620,6 +2167,21 @@ export type TokenRouter = {
               "an integrator so he can perform special handling based on when the fill happened."
             ],
             "type": "i64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the tokens held in the prepared custody token account. For fast fills, this mint",
+              "may be the destination asset requested by the order instead of a Circle-supported mint."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount delivered to the prepared custody token account."
+            ],
+            "type": "u64"
           }
         ]
       }
@@ -1731,6 +2293,21 @@ export type TokenRouter = {
           }
         ]
       }
+    },
+    {
+      "name": "versionHeader",
+      "docs": [
+        "Header found at the start of a versioned account's data (right after its discriminator)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
     }
   ]
 };
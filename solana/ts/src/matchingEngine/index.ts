@@ -43,17 +43,22 @@ import {
     AuctionHistoryHeader,
     AuctionInfo,
     AuctionParameters,
+    AuctionTranche,
+    CctpMint,
     Custodian,
     EndpointInfo,
     FastFill,
     FastFillInfo,
     FastFillSeeds,
     FastFillSequencer,
+    LocalFastOrder,
     MessageProtocol,
+    OfferCommitment,
     PreparedOrderResponse,
     Proposal,
     ProposalAction,
     ReservedFastFillSequence,
+    RouteAuctionConfig,
     RouterEndpoint,
 } from "./state";
 
@@ -130,6 +135,7 @@ export type BurnAndPublishAccounts = {
 
 export type RedeemFastFillAccounts = {
     custodian: PublicKey;
+    mint: PublicKey;
     fromRouterEndpoint: PublicKey;
     toRouterEndpoint: PublicKey;
     localCustodyToken: PublicKey;
@@ -395,8 +401,63 @@ export class MatchingEngineProgram {
         return this.fetchAuctionConfig(id).then((config) => config.parameters);
     }
 
-    cctpMintRecipientAddress(): PublicKey {
-        return splToken.getAssociatedTokenAddressSync(this.mint, this.custodianAddress(), true);
+    cctpMintAddress(mint: PublicKey): PublicKey {
+        return CctpMint.address(this.ID, mint);
+    }
+
+    async fetchCctpMint(input: PublicKey | { address: PublicKey }): Promise<CctpMint> {
+        const addr = "address" in input ? input.address : this.cctpMintAddress(input);
+        return this.program.account.cctpMint.fetch(addr);
+    }
+
+    /**
+     * Returns the auction config ID used for auctions of the given mint, which is the mint's
+     * override if it has one. Otherwise the global auction config ID is returned.
+     */
+    async fetchMintAuctionConfigId(mint?: PublicKey): Promise<number> {
+        const { auctionConfigId } = await this.fetchCustodian();
+        if (mint === undefined || mint.equals(this.mint)) {
+            return auctionConfigId;
+        }
+
+        const { configId } = await this.fetchCctpMint(mint);
+        return configId ?? auctionConfigId;
+    }
+
+    /**
+     * Returns the token account collecting protocol fees for the given mint. USDC fees are
+     * collected by the custodian's fee recipient token account.
+     */
+    async fetchFeeRecipientToken(mint?: PublicKey): Promise<PublicKey> {
+        if (mint === undefined || mint.equals(this.mint)) {
+            const { feeRecipientToken } = await this.fetchCustodian();
+            return feeRecipientToken;
+        }
+
+        const { feeRecipientToken } = await this.fetchCctpMint(mint);
+        return feeRecipientToken;
+    }
+
+    routeAuctionConfigAddress(sourceChain: ChainId, targetChain: ChainId): PublicKey {
+        return RouteAuctionConfig.address(this.ID, sourceChain, targetChain);
+    }
+
+    async fetchRouteAuctionConfig(
+        input: { sourceChain: ChainId; targetChain: ChainId } | { address: PublicKey },
+    ): Promise<RouteAuctionConfig> {
+        const addr =
+            "address" in input
+                ? input.address
+                : this.routeAuctionConfigAddress(input.sourceChain, input.targetChain);
+        return this.program.account.routeAuctionConfig.fetch(addr);
+    }
+
+    cctpMintRecipientAddress(mint?: PublicKey): PublicKey {
+        return splToken.getAssociatedTokenAddressSync(
+            mint ?? this.mint,
+            this.custodianAddress(),
+            true,
+        );
     }
 
     routerEndpointAddress(chain: ChainId): PublicKey {
@@ -545,6 +606,42 @@ export class MatchingEngineProgram {
         return this.program.account.reservedFastFillSequence.fetch(addr);
     }
 
+    localFastOrderAddress(fastVaaSequence: Uint64): PublicKey {
+        return LocalFastOrder.address(this.ID, fastVaaSequence);
+    }
+
+    async fetchLocalFastOrder(input: Uint64 | { address: PublicKey }): Promise<LocalFastOrder> {
+        const addr =
+            typeof input === "object" && "address" in input
+                ? input.address
+                : this.localFastOrderAddress(input);
+        return this.program.account.localFastOrder.fetch(addr);
+    }
+
+    localFastOrderCustodyTokenAddress(localFastOrder: PublicKey): PublicKey {
+        return LocalFastOrder.custodyTokenAddress(this.ID, localFastOrder);
+    }
+
+    inboundRateLimitAddress(sourceChain: ChainId): PublicKey {
+        const encodedSourceChain = Buffer.alloc(2);
+        encodedSourceChain.writeUInt16BE(sourceChain);
+
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("inbound-rate-limit"), encodedSourceChain],
+            this.ID,
+        )[0];
+    }
+
+    outboundRateLimitAddress(targetChain: ChainId): PublicKey {
+        const encodedTargetChain = Buffer.alloc(2);
+        encodedTargetChain.writeUInt16BE(targetChain);
+
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("outbound-rate-limit"), encodedTargetChain],
+            this.ID,
+        )[0];
+    }
+
     transferAuthorityAddress(auction: PublicKey, offerPrice: Uint64): PublicKey {
         const encodedOfferPrice = Buffer.alloc(8);
         writeUint64BE(encodedOfferPrice, offerPrice);
@@ -554,6 +651,51 @@ export class MatchingEngineProgram {
         )[0];
     }
 
+    commitmentTransferAuthorityAddress(
+        auction: PublicKey,
+        commitment: Buffer | Uint8Array,
+    ): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("transfer-authority"), auction.toBuffer(), Buffer.from(commitment)],
+            this.ID,
+        )[0];
+    }
+
+    offerCommitmentAddress(auction: PublicKey, offerToken: PublicKey): PublicKey {
+        return OfferCommitment.address(this.ID, auction, offerToken);
+    }
+
+    commitmentCustodyTokenAddress(offerCommitment: PublicKey): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("commitment-custody"), offerCommitment.toBuffer()],
+            this.ID,
+        )[0];
+    }
+
+    async fetchOfferCommitment(
+        input: { auction: PublicKey; offerToken: PublicKey } | { address: PublicKey },
+    ): Promise<OfferCommitment> {
+        const addr =
+            "address" in input
+                ? input.address
+                : this.offerCommitmentAddress(input.auction, input.offerToken);
+        return this.program.account.offerCommitment.fetch(addr);
+    }
+
+    auctionTrancheAddress(auction: PublicKey, index: number): PublicKey {
+        return AuctionTranche.address(this.ID, auction, index);
+    }
+
+    async fetchAuctionTranche(
+        input: { auction: PublicKey; index: number } | { address: PublicKey },
+    ): Promise<AuctionTranche> {
+        const addr =
+            "address" in input
+                ? input.address
+                : this.auctionTrancheAddress(input.auction, input.index);
+        return this.program.account.auctionTranche.fetch(addr);
+    }
+
     auctionHistoryAddress(id: Uint64): PublicKey {
         return AuctionHistory.address(this.ID, id);
     }
@@ -800,6 +942,14 @@ export class MatchingEngineProgram {
         };
     }
 
+    supportedCctpMintComposite(mint?: PublicKey): { mint: PublicKey; cctpMint: PublicKey } {
+        mint ??= this.mint;
+        return {
+            mint,
+            cctpMint: this.cctpMintAddress(mint),
+        };
+    }
+
     localTokenRouterComposite(tokenRouterProgram: PublicKey): {
         tokenRouterProgram: PublicKey;
         tokenRouterEmitter: PublicKey;
@@ -892,9 +1042,9 @@ export class MatchingEngineProgram {
         };
     }
 
-    cctpMintRecipientComposite(): { mintRecipient: PublicKey } {
+    cctpMintRecipientComposite(mint?: PublicKey): { mintRecipient: PublicKey } {
         return {
-            mintRecipient: this.cctpMintRecipientAddress(),
+            mintRecipient: this.cctpMintRecipientAddress(mint),
         };
     }
 
@@ -1155,9 +1305,9 @@ export class MatchingEngineProgram {
         proposal ??= await this.proposalAddress(opts.proposalId);
 
         if (auctionConfig === undefined) {
-            const { auctionConfigId } = await this.fetchCustodian();
-            // Add 1 to the current auction config ID to get the next one.
-            auctionConfig = this.auctionConfigAddress(auctionConfigId + 1);
+            const { latestAuctionConfigId } = await this.fetchCustodian();
+            // Add 1 to the latest auction config ID to get the next one.
+            auctionConfig = this.auctionConfigAddress(latestAuctionConfigId + 1);
         }
 
         return this.program.methods
@@ -1174,6 +1324,140 @@ export class MatchingEngineProgram {
             .instruction();
     }
 
+    async proposeRouteAuctionParametersIx(
+        accounts: {
+            ownerOrAssistant: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+            proposal?: PublicKey;
+        },
+        args: {
+            sourceChain: ChainId;
+            targetChain: ChainId;
+            parameters: AuctionParameters | null;
+        },
+        opts: {
+            proposalId?: Uint64;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        const { ownerOrAssistant, custodian } = accounts;
+
+        let { payer, proposal } = accounts;
+        payer ??= ownerOrAssistant;
+        proposal ??= await this.proposalAddress(opts.proposalId);
+
+        return this.program.methods
+            .proposeRouteAuctionParameters(args)
+            .accounts({
+                payer,
+                admin: {
+                    authority: ownerOrAssistant,
+                    custodian: this.checkedCustodianComposite(custodian),
+                },
+                proposal,
+                epochSchedule: SYSVAR_EPOCH_SCHEDULE_PUBKEY,
+                systemProgram: SystemProgram.programId,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async updateRouteAuctionParametersIx(
+        accounts: {
+            owner: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+            proposal?: PublicKey;
+            routeAuctionConfig?: PublicKey;
+            auctionConfig?: PublicKey;
+        },
+        opts: {
+            proposalId?: Uint64;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+
+        let { payer, proposal, routeAuctionConfig, auctionConfig } = accounts;
+        payer ??= owner;
+        proposal ??= await this.proposalAddress(opts.proposalId);
+
+        if (routeAuctionConfig === undefined) {
+            const { action } = await this.fetchProposal({ address: proposal });
+            if (action.updateRouteAuctionParameters === undefined) {
+                throw new Error("Proposal does not update route auction parameters");
+            }
+            const { sourceChain, targetChain } = action.updateRouteAuctionParameters;
+            routeAuctionConfig = this.routeAuctionConfigAddress(
+                toChainId(sourceChain),
+                toChainId(targetChain),
+            );
+        }
+
+        if (auctionConfig === undefined) {
+            const { latestAuctionConfigId } = await this.fetchCustodian();
+            // Add 1 to the latest auction config ID to get the next one.
+            auctionConfig = this.auctionConfigAddress(latestAuctionConfigId + 1);
+        }
+
+        return this.program.methods
+            .updateRouteAuctionParameters()
+            .accounts({
+                payer,
+                admin: this.ownerOnlyMutComposite(owner, custodian),
+                proposal,
+                routeAuctionConfig,
+                auctionConfig,
+                systemProgram: SystemProgram.programId,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async removeRouteAuctionParametersIx(
+        accounts: {
+            owner: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+            proposal?: PublicKey;
+            routeAuctionConfig?: PublicKey;
+        },
+        opts: {
+            proposalId?: Uint64;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+
+        let { payer, proposal, routeAuctionConfig } = accounts;
+        payer ??= owner;
+        proposal ??= await this.proposalAddress(opts.proposalId);
+
+        if (routeAuctionConfig === undefined) {
+            const { action } = await this.fetchProposal({ address: proposal });
+            if (action.removeRouteAuctionParameters === undefined) {
+                throw new Error("Proposal does not remove route auction parameters");
+            }
+            const { sourceChain, targetChain } = action.removeRouteAuctionParameters;
+            routeAuctionConfig = this.routeAuctionConfigAddress(
+                toChainId(sourceChain),
+                toChainId(targetChain),
+            );
+        }
+
+        return this.program.methods
+            .removeRouteAuctionParameters()
+            .accounts({
+                payer,
+                admin: this.ownerOnlyMutComposite(owner, custodian),
+                proposal,
+                routeAuctionConfig,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
     async addLocalRouterEndpointIx(accounts: {
         ownerOrAssistant: PublicKey;
         tokenRouterProgram: PublicKey;
@@ -1242,6 +1526,67 @@ export class MatchingEngineProgram {
             .instruction();
     }
 
+    async addCctpMintIx(accounts: {
+        owner: PublicKey;
+        mint: PublicKey;
+        feeRecipientToken: PublicKey;
+        payer?: PublicKey;
+        auctionConfig?: PublicKey | null;
+        custodian?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { owner, mint, feeRecipientToken, custodian: inputCustodian } = accounts;
+
+        let { payer, auctionConfig } = accounts;
+        payer ??= owner;
+        auctionConfig ??= null;
+
+        return this.program.methods
+            .addCctpMint()
+            .accounts({
+                payer,
+                admin: this.ownerOnlyComposite(owner, inputCustodian),
+                cctpMint: this.cctpMintAddress(mint),
+                mint,
+                mintRecipient: splToken.getAssociatedTokenAddressSync(
+                    mint,
+                    this.custodianAddress(),
+                    true,
+                ),
+                feeRecipientToken,
+                auctionConfig,
+                systemProgram: SystemProgram.programId,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+            })
+            .instruction();
+    }
+
+    async updateCctpMintIx(
+        accounts: {
+            ownerOrAssistant: PublicKey;
+            mint: PublicKey;
+            feeRecipientToken: PublicKey;
+            auctionConfig?: PublicKey | null;
+            custodian?: PublicKey;
+        },
+        enabled: boolean,
+    ): Promise<TransactionInstruction> {
+        const { ownerOrAssistant, mint, feeRecipientToken, custodian: inputCustodian } = accounts;
+
+        let { auctionConfig } = accounts;
+        auctionConfig ??= null;
+
+        return this.program.methods
+            .updateCctpMint(enabled)
+            .accounts({
+                admin: this.adminComposite(ownerOrAssistant, inputCustodian),
+                cctpMint: this.cctpMintAddress(mint),
+                feeRecipientToken,
+                auctionConfig,
+            })
+            .instruction();
+    }
+
     async updateFeeRecipientIx(accounts: {
         ownerOrAssistant: PublicKey;
         newFeeRecipient: PublicKey;
@@ -1320,6 +1665,7 @@ export class MatchingEngineProgram {
             auctionConfig?: PublicKey;
             fromRouterEndpoint?: PublicKey;
             toRouterEndpoint?: PublicKey;
+            mint?: PublicKey;
         },
         args: {
             offerPrice: Uint64;
@@ -1332,42 +1678,41 @@ export class MatchingEngineProgram {
 
         const { offerPrice } = args;
 
-        let { auction, auctionConfig, offerToken, fromRouterEndpoint, toRouterEndpoint } = accounts;
+        let { auction, auctionConfig, offerToken, fromRouterEndpoint, toRouterEndpoint, mint } =
+            accounts;
         let { totalDeposit } = args;
 
-        offerToken ??= await splToken.getAssociatedTokenAddress(this.mint, payer);
-        let fetchedConfigId: Uint64 | null = null;
-        if (
-            auction === undefined ||
-            fromRouterEndpoint === undefined ||
-            toRouterEndpoint === undefined ||
-            totalDeposit === undefined
-        ) {
-            const vaaAccount = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
-            auction ??= this.auctionAddress(vaaAccount.digest());
-            fromRouterEndpoint ??= this.routerEndpointAddress(vaaAccount.emitterInfo().chain);
-            const { fastMarketOrder } = LiquidityLayerMessage.decode(vaaAccount.payload());
-            if (fastMarketOrder === undefined) {
-                throw new Error("Message not FastMarketOrder");
-            }
-            toRouterEndpoint ??= this.routerEndpointAddress(toChainId(fastMarketOrder.targetChain));
+        mint ??= this.mint;
+        offerToken ??= await splToken.getAssociatedTokenAddress(mint, payer);
+
+        // The fast VAA determines the auction's route, which is needed to derive the route's
+        // auction config and rate limits.
+        const vaaAccount = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+        const { fastMarketOrder } = LiquidityLayerMessage.decode(vaaAccount.payload());
+        if (fastMarketOrder === undefined) {
+            throw new Error("Message not FastMarketOrder");
+        }
+        const sourceChain = vaaAccount.emitterInfo().chain;
+        const targetChain = toChainId(fastMarketOrder.targetChain);
+
+        auction ??= this.auctionAddress(vaaAccount.digest());
+        fromRouterEndpoint ??= this.routerEndpointAddress(sourceChain);
+        toRouterEndpoint ??= this.routerEndpointAddress(targetChain);
 
-            const custodianData = await this.fetchCustodian();
-            fetchedConfigId = custodianData.auctionConfigId;
+        let fetchedConfigId: number | null = null;
+        if (totalDeposit === undefined) {
+            fetchedConfigId = await this.fetchMintAuctionConfigId(mint);
 
             const notionalDeposit = await this.computeNotionalSecurityDeposit(
                 fastMarketOrder.amountIn,
                 fetchedConfigId,
             );
 
-            totalDeposit ??= fastMarketOrder.amountIn + fastMarketOrder.maxFee + notionalDeposit;
+            totalDeposit = fastMarketOrder.amountIn + fastMarketOrder.maxFee + notionalDeposit;
         }
 
         if (auctionConfig === undefined) {
-            if (fetchedConfigId === null) {
-                const custodianData = await this.fetchCustodian();
-                fetchedConfigId = custodianData.auctionConfigId;
-            }
+            fetchedConfigId ??= await this.fetchMintAuctionConfigId(mint);
             auctionConfig = this.auctionConfigAddress(fetchedConfigId);
         }
 
@@ -1386,6 +1731,9 @@ export class MatchingEngineProgram {
                 transferAuthority,
                 custodian: this.checkedCustodianComposite(),
                 auctionConfig,
+                routeAuctionConfig: this.routeAuctionConfigAddress(sourceChain, targetChain),
+                inboundRateLimit: this.inboundRateLimitAddress(sourceChain),
+                outboundRateLimit: this.outboundRateLimitAddress(targetChain),
                 auction,
                 fastOrderPath: this.fastOrderPathComposite({
                     fastVaa,
@@ -1393,8 +1741,10 @@ export class MatchingEngineProgram {
                     toEndpoint: toRouterEndpoint,
                 }),
                 offerToken,
+                solverVault: null,
+                registeredSolver: null,
                 auctionCustodyToken,
-                usdc: this.usdcComposite(),
+                mint: this.supportedCctpMintComposite(mint),
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
                 eventAuthority: this.eventAuthorityAddress(),
@@ -1494,28 +1844,381 @@ export class MatchingEngineProgram {
         return [approveIx, improveOfferIx];
     }
 
+    async commitOfferIx(
+        accounts: {
+            payer: PublicKey;
+            auction: PublicKey;
+            participant?: PublicKey;
+            offerToken?: PublicKey;
+            auctionConfig?: PublicKey;
+            bestOfferToken?: PublicKey;
+            registeredSolver?: PublicKey;
+        },
+        args: { offerPrice: Uint64; salt: Buffer | Uint8Array; totalDeposit?: Uint64 },
+    ): Promise<[approveIx: TransactionInstruction, commitOfferIx: TransactionInstruction]> {
+        const { payer, auction, auctionConfig, bestOfferToken, registeredSolver } = accounts;
+        const { offerPrice, salt } = args;
+
+        let { participant, offerToken } = accounts;
+        participant ??= payer;
+
+        const { info: auctionInfo, mint } = await this.fetchAuction({ address: auction });
+        if (auctionInfo === null) {
+            throw new Error("no auction info found");
+        }
+
+        offerToken ??= splToken.getAssociatedTokenAddressSync(mint, participant);
+
+        let { totalDeposit } = args;
+        totalDeposit ??= BigInt(auctionInfo.amountIn.add(auctionInfo.securityDeposit).toString());
+
+        // The commitment binds the offer token so that it cannot be copied by another participant.
+        const commitment = OfferCommitment.compute(offerToken, offerPrice, salt);
+        const transferAuthority = this.commitmentTransferAuthorityAddress(auction, commitment);
+        const offerCommitment = this.offerCommitmentAddress(auction, offerToken);
+
+        const approveIx = splToken.createApproveInstruction(
+            offerToken,
+            transferAuthority,
+            participant,
+            uint64ToBigInt(totalDeposit),
+        );
+
+        const commitOfferIx = await this.program.methods
+            .commitOffer(Array.from(commitment))
+            .accounts({
+                payer,
+                transferAuthority,
+                activeAuction: await this.activeAuctionComposite(
+                    { auction, config: auctionConfig, bestOfferToken },
+                    { auctionInfo },
+                ),
+                offerToken,
+                registeredSolver: registeredSolver ?? null,
+                offerCommitment,
+                commitmentCustodyToken: this.commitmentCustodyTokenAddress(offerCommitment),
+                mint,
+                systemProgram: SystemProgram.programId,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+
+        return [approveIx, commitOfferIx];
+    }
+
+    async revealOfferIx(
+        accounts: {
+            auction: PublicKey;
+            offerToken: PublicKey;
+            auctionConfig?: PublicKey;
+            bestOfferToken?: PublicKey;
+        },
+        args: { offerPrice: Uint64; salt: Buffer | Uint8Array },
+    ): Promise<TransactionInstruction> {
+        const { auction, offerToken, auctionConfig, bestOfferToken } = accounts;
+        const { offerPrice, salt } = args;
+
+        const { info: auctionInfo } = await this.fetchAuction({ address: auction });
+        if (auctionInfo === null) {
+            throw new Error("no auction info found");
+        }
+
+        const offerCommitment = this.offerCommitmentAddress(auction, offerToken);
+        const { preparedBy } = await this.fetchOfferCommitment({ address: offerCommitment });
+
+        return this.program.methods
+            .revealOffer(uint64ToBN(offerPrice), Array.from(salt))
+            .accounts({
+                activeAuction: await this.activeAuctionComposite(
+                    { auction, config: auctionConfig, bestOfferToken },
+                    { auctionInfo },
+                ),
+                offerCommitment,
+                commitmentCustodyToken: this.commitmentCustodyTokenAddress(offerCommitment),
+                offerToken,
+                bestOfferVault: auctionInfo.bestOfferVault ?? null,
+                preparedBy,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async closeUnrevealedOfferCommitmentIx(accounts: {
+        offerCommitment: PublicKey;
+        executorToken: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { offerCommitment, executorToken } = accounts;
+
+        const { auction, offerToken, preparedBy } = await this.fetchOfferCommitment({
+            address: offerCommitment,
+        });
+        const { info, mint } = await this.fetchAuction({ address: auction });
+        if (info === null) {
+            throw new Error("no auction info found");
+        }
+
+        return this.program.methods
+            .closeUnrevealedOfferCommitment()
+            .accounts({
+                custodian: this.checkedCustodianComposite(),
+                auction,
+                config: this.auctionConfigAddress(info.configId),
+                auctionCustodyToken: this.auctionCustodyTokenAddress(auction),
+                offerCommitment,
+                commitmentCustodyToken: this.commitmentCustodyTokenAddress(offerCommitment),
+                offerToken,
+                executorToken,
+                feeRecipientToken: await this.fetchFeeRecipientToken(mint),
+                cctpMint: this.cctpMintAddress(mint),
+                preparedBy,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async placeInitialTrancheOfferCctpIx(
+        accounts: {
+            payer: PublicKey;
+            fastVaa: PublicKey;
+            offerToken?: PublicKey;
+            auctionConfig?: PublicKey;
+            fromRouterEndpoint?: PublicKey;
+            toRouterEndpoint?: PublicKey;
+            mint?: PublicKey;
+            registeredSolver?: PublicKey;
+        },
+        args: {
+            trancheIndex: number;
+            offerPrice: Uint64;
+            totalDeposit: Uint64;
+        },
+    ): Promise<
+        [approveIx: TransactionInstruction, placeInitialTrancheOfferCctpIx: TransactionInstruction]
+    > {
+        const { payer, fastVaa, registeredSolver } = accounts;
+        const { trancheIndex, offerPrice, totalDeposit } = args;
+
+        let { auctionConfig, offerToken, fromRouterEndpoint, toRouterEndpoint, mint } = accounts;
+
+        mint ??= this.mint;
+        offerToken ??= splToken.getAssociatedTokenAddressSync(mint, payer);
+
+        // The fast VAA determines the auction's route, which is needed to derive the route's
+        // auction config and rate limits.
+        const vaaAccount = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+        const { fastMarketOrder } = LiquidityLayerMessage.decode(vaaAccount.payload());
+        if (fastMarketOrder === undefined) {
+            throw new Error("Message not FastMarketOrder");
+        }
+        const sourceChain = vaaAccount.emitterInfo().chain;
+        const targetChain = toChainId(fastMarketOrder.targetChain);
+
+        const auction = this.auctionAddress(vaaAccount.digest());
+        fromRouterEndpoint ??= this.routerEndpointAddress(sourceChain);
+        toRouterEndpoint ??= this.routerEndpointAddress(targetChain);
+
+        if (auctionConfig === undefined) {
+            // If the auction has already started, its config must be used.
+            const info = await this.fetchAuction({ address: auction }).then(
+                (auction) => auction.info,
+                (_) => null,
+            );
+            auctionConfig =
+                info === null
+                    ? this.auctionConfigAddress(await this.fetchMintAuctionConfigId(mint))
+                    : this.auctionConfigAddress(info.configId);
+        }
+
+        const auctionTranche = this.auctionTrancheAddress(auction, trancheIndex);
+        const transferAuthority = this.transferAuthorityAddress(auctionTranche, offerPrice);
+        const approveIx = splToken.createApproveInstruction(
+            offerToken,
+            transferAuthority,
+            payer,
+            uint64ToBigInt(totalDeposit),
+        );
+
+        const placeInitialTrancheOfferCctpIx = await this.program.methods
+            .placeInitialTrancheOfferCctp(trancheIndex, uint64ToBN(offerPrice))
+            .accounts({
+                payer,
+                transferAuthority,
+                custodian: this.checkedCustodianComposite(),
+                auctionConfig,
+                routeAuctionConfig: this.routeAuctionConfigAddress(sourceChain, targetChain),
+                inboundRateLimit: this.inboundRateLimitAddress(sourceChain),
+                outboundRateLimit: this.outboundRateLimitAddress(targetChain),
+                fastOrderPath: this.fastOrderPathComposite({
+                    fastVaa,
+                    fromEndpoint: fromRouterEndpoint,
+                    toEndpoint: toRouterEndpoint,
+                }),
+                auction,
+                auctionTranche,
+                offerToken,
+                registeredSolver: registeredSolver ?? null,
+                auctionCustodyToken: this.auctionCustodyTokenAddress(auction),
+                mint: this.supportedCctpMintComposite(mint),
+                systemProgram: SystemProgram.programId,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+
+        return [approveIx, placeInitialTrancheOfferCctpIx];
+    }
+
+    async improveTrancheOfferIx(
+        accounts: {
+            participant: PublicKey;
+            auctionTranche: PublicKey;
+            offerToken?: PublicKey;
+            registeredSolver?: PublicKey;
+        },
+        args: { offerPrice: Uint64; totalDeposit?: Uint64 },
+    ): Promise<[approveIx: TransactionInstruction, improveTrancheOfferIx: TransactionInstruction]> {
+        const { participant, auctionTranche, registeredSolver } = accounts;
+        const { offerPrice } = args;
+
+        const { auction, bestOfferToken, amountIn, securityDeposit } =
+            await this.fetchAuctionTranche({ address: auctionTranche });
+        const { info, mint } = await this.fetchAuction({ address: auction });
+        if (info === null) {
+            throw new Error("no auction info found");
+        }
+
+        let { offerToken } = accounts;
+        offerToken ??= splToken.getAssociatedTokenAddressSync(mint, participant);
+
+        let { totalDeposit } = args;
+        totalDeposit ??= BigInt(amountIn.add(securityDeposit).toString());
+
+        const transferAuthority = this.transferAuthorityAddress(auctionTranche, offerPrice);
+        const approveIx = splToken.createApproveInstruction(
+            offerToken,
+            transferAuthority,
+            participant,
+            uint64ToBigInt(totalDeposit),
+        );
+
+        const improveTrancheOfferIx = await this.program.methods
+            .improveTrancheOffer(uint64ToBN(offerPrice))
+            .accounts({
+                transferAuthority,
+                auction,
+                config: this.auctionConfigAddress(info.configId),
+                auctionTranche,
+                custodyToken: this.auctionCustodyTokenAddress(auction),
+                bestOfferToken,
+                offerToken,
+                registeredSolver: registeredSolver ?? null,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+
+        return [approveIx, improveTrancheOfferIx];
+    }
+
+    async executeAuctionTrancheIx(accounts: {
+        auctionTranche: PublicKey;
+        executorToken: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { auctionTranche, executorToken } = accounts;
+
+        const { auction, bestOfferToken, initialOfferToken } = await this.fetchAuctionTranche({
+            address: auctionTranche,
+        });
+        const { info } = await this.fetchAuction({ address: auction });
+        if (info === null) {
+            throw new Error("no auction info found");
+        }
+
+        return this.program.methods
+            .executeAuctionTranche()
+            .accounts({
+                auction,
+                config: this.auctionConfigAddress(info.configId),
+                auctionTranche,
+                custodyToken: this.auctionCustodyTokenAddress(auction),
+                bestOfferToken,
+                initialOfferToken,
+                executorToken,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async closeUnfilledAuctionTrancheIx(accounts: {
+        auctionTranche: PublicKey;
+        executorToken: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { auctionTranche, executorToken } = accounts;
+
+        const { auction, bestOfferToken, preparedBy } = await this.fetchAuctionTranche({
+            address: auctionTranche,
+        });
+        const { info, preparedBy: auctionPreparedBy } = await this.fetchAuction({
+            address: auction,
+        });
+        if (info === null) {
+            throw new Error("no auction info found");
+        }
+
+        return this.program.methods
+            .closeUnfilledAuctionTranche()
+            .accounts({
+                auction,
+                config: this.auctionConfigAddress(info.configId),
+                auctionTranche,
+                custodyToken: this.auctionCustodyTokenAddress(auction),
+                bestOfferToken,
+                executorToken,
+                preparedBy,
+                auctionPreparedBy,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
     async prepareOrderResponseCctpIx(
         accounts: {
             payer: PublicKey;
             fastVaa: PublicKey;
             finalizedVaa: PublicKey;
             baseFeeToken?: PublicKey;
+            mint?: PublicKey;
         },
         args: CctpMessageArgs,
     ): Promise<TransactionInstruction> {
         const { payer, fastVaa, finalizedVaa } = accounts;
 
-        let { baseFeeToken } = accounts;
-        baseFeeToken ??= await splToken.getAssociatedTokenAddress(this.mint, payer);
+        let { baseFeeToken, mint } = accounts;
+        mint ??= this.mint;
+        baseFeeToken ??= await splToken.getAssociatedTokenAddress(mint, payer);
 
         const fastVaaAcct = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
-        const fromEndpoint = this.routerEndpointAddress(fastVaaAcct.emitterInfo().chain);
+        const sourceChain = fastVaaAcct.emitterInfo().chain;
+        const fromEndpoint = this.routerEndpointAddress(sourceChain);
 
         const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAcct.payload());
         if (fastMarketOrder === undefined) {
             throw new Error("Message not FastMarketOrder");
         }
-        const toEndpoint = this.routerEndpointAddress(toChainId(fastMarketOrder.targetChain));
+        const targetChain = toChainId(fastMarketOrder.targetChain);
+        const toEndpoint = this.routerEndpointAddress(targetChain);
 
         const { encodedCctpMessage } = args;
         const {
@@ -1533,11 +2236,12 @@ export class MatchingEngineProgram {
             messageTransmitterProgram,
             tokenMessengerMinterEventAuthority,
         } = this.messageTransmitterProgram().receiveTokenMessengerMinterMessageAccounts(
-            this.mint,
+            mint,
             encodedCctpMessage,
         );
 
-        const preparedOrderResponse = this.preparedOrderResponseAddress(fastVaaAcct.digest());
+        const fastVaaHash = fastVaaAcct.digest();
+        const preparedOrderResponse = this.preparedOrderResponseAddress(fastVaaHash);
         return this.program.methods
             .prepareOrderResponseCctp(args)
             .accounts({
@@ -1548,9 +2252,12 @@ export class MatchingEngineProgram {
                 preparedOrderResponse,
                 preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrderResponse),
                 baseFeeToken,
-                usdc: this.usdcComposite(),
+                auction: this.auctionAddress(fastVaaHash),
+                inboundRateLimit: this.inboundRateLimitAddress(sourceChain),
+                outboundRateLimit: this.outboundRateLimitAddress(targetChain),
+                mint: this.supportedCctpMintComposite(mint),
                 cctp: {
-                    mintRecipient: this.cctpMintRecipientComposite(),
+                    mintRecipient: this.cctpMintRecipientComposite(mint),
                     messageTransmitterAuthority,
                     messageTransmitterConfig,
                     usedNonces,
@@ -1571,6 +2278,58 @@ export class MatchingEngineProgram {
             .instruction();
     }
 
+    async prepareOrderResponseLocalIx(accounts: {
+        payer: PublicKey;
+        fastVaa: PublicKey;
+        baseFeeToken?: PublicKey;
+        localFastOrderPreparedBy?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { payer, fastVaa } = accounts;
+
+        let { baseFeeToken, localFastOrderPreparedBy } = accounts;
+        baseFeeToken ??= await splToken.getAssociatedTokenAddress(this.mint, payer);
+
+        const fastVaaAcct = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+        const { chain: sourceChain, sequence } = fastVaaAcct.emitterInfo();
+        const fromEndpoint = this.routerEndpointAddress(sourceChain);
+
+        const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAcct.payload());
+        if (fastMarketOrder === undefined) {
+            throw new Error("Message not FastMarketOrder");
+        }
+        const targetChain = toChainId(fastMarketOrder.targetChain);
+        const toEndpoint = this.routerEndpointAddress(targetChain);
+
+        const localFastOrder = this.localFastOrderAddress(sequence);
+        if (localFastOrderPreparedBy === undefined) {
+            const { preparedBy } = await this.fetchLocalFastOrder({ address: localFastOrder });
+            localFastOrderPreparedBy = preparedBy;
+        }
+
+        const fastVaaHash = fastVaaAcct.digest();
+        const preparedOrderResponse = this.preparedOrderResponseAddress(fastVaaHash);
+        return this.program.methods
+            .prepareOrderResponseLocal()
+            .accounts({
+                payer,
+                custodian: this.checkedCustodianComposite(),
+                fastOrderPath: this.fastOrderPathComposite({ fastVaa, fromEndpoint, toEndpoint }),
+                localFastOrder,
+                localFastOrderPreparedBy,
+                localFastOrderCustodyToken: this.localFastOrderCustodyTokenAddress(localFastOrder),
+                preparedOrderResponse,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrderResponse),
+                baseFeeToken,
+                auction: this.auctionAddress(fastVaaHash),
+                inboundRateLimit: this.inboundRateLimitAddress(sourceChain),
+                outboundRateLimit: this.outboundRateLimitAddress(targetChain),
+                usdc: this.usdcComposite(),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
     async settleAuctionCompleteTx(
         accounts: {
             executor: PublicKey;
@@ -1747,6 +2506,7 @@ export class MatchingEngineProgram {
             sourceChain?: ChainId;
             orderSender?: Array<number>;
             sequence?: Uint64;
+            mint?: PublicKey;
         } = {},
     ) {
         const { payer, reservedSequence } = accounts;
@@ -1778,7 +2538,8 @@ export class MatchingEngineProgram {
             sequence ??= fastFillSeeds.sequence;
         }
 
-        const { feeRecipientToken } = await this.fetchCustodian();
+        const mint = opts.mint ?? this.mint;
+        const feeRecipientToken = await this.fetchFeeRecipientToken(mint);
 
         return this.program.methods
             .settleAuctionNoneLocal()
@@ -1786,6 +2547,7 @@ export class MatchingEngineProgram {
                 payer,
                 custodian: this.checkedCustodianComposite(),
                 feeRecipientToken,
+                cctpMint: this.cctpMintAddress(mint),
                 prepared: this.closePreparedOrderResponseComposite({
                     by: payer,
                     orderResponse: preparedOrderResponse,
@@ -1813,6 +2575,7 @@ export class MatchingEngineProgram {
         opts: {
             sourceChain?: ChainId;
             targetChain?: ChainId;
+            mint?: PublicKey;
         } = {},
     ) {
         const { payer, fastVaa } = accounts;
@@ -1856,9 +2619,10 @@ export class MatchingEngineProgram {
             tokenMessengerMinterEventAuthority,
             messageTransmitterProgram,
             tokenMessengerMinterProgram,
-        } = await this.burnAndPublishAccounts(auction, { targetChain });
+        } = await this.burnAndPublishAccounts(auction, { targetChain, mint: opts.mint });
 
-        const { feeRecipientToken } = await this.fetchCustodian();
+        const mint = opts.mint ?? this.mint;
+        const feeRecipientToken = await this.fetchFeeRecipientToken(mint);
 
         return this.program.methods
             .settleAuctionNoneCctp()
@@ -1868,6 +2632,7 @@ export class MatchingEngineProgram {
                 cctpMessage,
                 custodian: this.checkedCustodianComposite(custodian),
                 feeRecipientToken,
+                cctpMint: this.cctpMintAddress(mint),
                 prepared: this.closePreparedOrderResponseComposite({
                     by: payer,
                     orderResponse: preparedOrderResponse,
@@ -1880,7 +2645,7 @@ export class MatchingEngineProgram {
                     coreBridgeProgram,
                 },
                 cctp: {
-                    mint: this.mint,
+                    mint,
                     tokenMessengerMinterSenderAuthority,
                     messageTransmitterConfig,
                     tokenMessenger,
@@ -2422,6 +3187,7 @@ export class MatchingEngineProgram {
     async redeemFastFillAccounts(fastFill: PublicKey): Promise<RedeemFastFillAccounts> {
         const {
             seeds: { sourceChain },
+            info: { mint },
         } = await this.fetchFastFill({ address: fastFill });
 
         if (!isChainId(sourceChain)) {
@@ -2430,6 +3196,7 @@ export class MatchingEngineProgram {
 
         return {
             custodian: this.custodianAddress(),
+            mint,
             fromRouterEndpoint: this.routerEndpointAddress(sourceChain),
             toRouterEndpoint: this.routerEndpointAddress(toChainId("Solana")),
             localCustodyToken: this.localCustodyTokenAddress(sourceChain),
@@ -2468,9 +3235,10 @@ export class MatchingEngineProgram {
         args: {
             targetChain: ChainId;
             destinationCctpDomain?: number;
+            mint?: PublicKey;
         },
     ): Promise<BurnAndPublishAccounts> {
-        const { targetChain } = args;
+        const { targetChain, mint } = args;
 
         let { destinationCctpDomain } = args;
 
@@ -2493,7 +3261,7 @@ export class MatchingEngineProgram {
             messageTransmitterProgram,
             tokenMessengerMinterProgram,
         } = this.tokenMessengerMinterProgram().depositForBurnWithCallerAccounts(
-            this.mint,
+            mint ?? this.mint,
             destinationCctpDomain,
         );
 
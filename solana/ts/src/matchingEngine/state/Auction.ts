@@ -17,6 +17,15 @@ export type AuctionDestinationAssetInfo = {
     amountOut: BN;
 };
 
+export type AuctionSplitFillInfo = {
+    numTranches: number;
+    tranchesPlaced: number;
+    tranchesExecuted: number;
+    userAmount: BN;
+    executePenalty: BN | null;
+    amountInSettled: BN;
+};
+
 export type AuctionInfo = {
     configId: number;
     custodyTokenBump: number;
@@ -30,6 +39,9 @@ export type AuctionInfo = {
     offerPrice: BN;
     redeemerMessageLen: number;
     destinationAssetInfo: AuctionDestinationAssetInfo | null;
+    bestOfferVault: PublicKey | null;
+    forfeitedUserReward: BN;
+    splitFill: AuctionSplitFillInfo | null;
 };
 
 export class Auction {
@@ -40,6 +52,7 @@ export class Auction {
     status: AuctionStatus;
     preparedBy: PublicKey;
     info: AuctionInfo | null;
+    mint: PublicKey;
 
     constructor(
         bump: number,
@@ -49,6 +62,7 @@ export class Auction {
         status: AuctionStatus,
         preparedBy: PublicKey,
         info: AuctionInfo | null,
+        mint: PublicKey,
     ) {
         this.bump = bump;
         this.vaaHash = vaaHash;
@@ -57,6 +71,7 @@ export class Auction {
         this.status = status;
         this.preparedBy = preparedBy;
         this.info = info;
+        this.mint = mint;
     }
 
     static address(programId: PublicKey, vaaHash: Array<number> | Buffer | Uint8Array) {
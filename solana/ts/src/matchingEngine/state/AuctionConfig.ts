@@ -10,6 +10,10 @@ export type AuctionParameters = {
     minOfferDeltaBps: number;
     securityDepositBase: BN;
     securityDepositBps: number;
+    revealPeriod: number;
+    extensionWindow: number;
+    maxExtension: number;
+    numTranches: number;
 };
 
 export class AuctionConfig {
@@ -0,0 +1,54 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+export type AuctionTrancheStatus = {
+    active?: {};
+    executed?: { executePenalty: BN | null };
+};
+
+export class AuctionTranche {
+    bump: number;
+    auction: PublicKey;
+    index: number;
+    status: AuctionTrancheStatus;
+    preparedBy: PublicKey;
+    initialOfferToken: PublicKey;
+    bestOfferToken: PublicKey;
+    amountIn: BN;
+    securityDeposit: BN;
+    offerPrice: BN;
+    initAuctionFee: BN;
+
+    constructor(
+        bump: number,
+        auction: PublicKey,
+        index: number,
+        status: AuctionTrancheStatus,
+        preparedBy: PublicKey,
+        initialOfferToken: PublicKey,
+        bestOfferToken: PublicKey,
+        amountIn: BN,
+        securityDeposit: BN,
+        offerPrice: BN,
+        initAuctionFee: BN,
+    ) {
+        this.bump = bump;
+        this.auction = auction;
+        this.index = index;
+        this.status = status;
+        this.preparedBy = preparedBy;
+        this.initialOfferToken = initialOfferToken;
+        this.bestOfferToken = bestOfferToken;
+        this.amountIn = amountIn;
+        this.securityDeposit = securityDeposit;
+        this.offerPrice = offerPrice;
+        this.initAuctionFee = initAuctionFee;
+    }
+
+    static address(programId: PublicKey, auction: PublicKey, index: number) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("auction-tranche"), auction.toBuffer(), Buffer.from([index])],
+            programId,
+        )[0];
+    }
+}
@@ -0,0 +1,33 @@
+import { PublicKey } from "@solana/web3.js";
+
+export class CctpMint {
+    bump: number;
+    mint: PublicKey;
+    mintRecipient: PublicKey;
+    feeRecipientToken: PublicKey;
+    enabled: boolean;
+    configId: number | null;
+
+    constructor(
+        bump: number,
+        mint: PublicKey,
+        mintRecipient: PublicKey,
+        feeRecipientToken: PublicKey,
+        enabled: boolean,
+        configId: number | null,
+    ) {
+        this.bump = bump;
+        this.mint = mint;
+        this.mintRecipient = mintRecipient;
+        this.feeRecipientToken = feeRecipientToken;
+        this.enabled = enabled;
+        this.configId = configId;
+    }
+
+    static address(programId: PublicKey, mint: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("cctp-mint"), mint.toBuffer()],
+            programId,
+        )[0];
+    }
+}
@@ -11,6 +11,7 @@ export class Custodian {
     feeRecipientToken: PublicKey;
     auctionConfigId: number;
     nextProposalId: BN;
+    latestAuctionConfigId: number;
 
     constructor(
         owner: PublicKey,
@@ -21,6 +22,7 @@ export class Custodian {
         feeRecipientToken: PublicKey,
         auctionConfigId: number,
         nextProposalId: BN,
+        latestAuctionConfigId: number,
     ) {
         this.owner = owner;
         this.pendingOwner = pendingOwner;
@@ -30,6 +32,7 @@ export class Custodian {
         this.feeRecipientToken = feeRecipientToken;
         this.auctionConfigId = auctionConfigId;
         this.nextProposalId = nextProposalId;
+        this.latestAuctionConfigId = latestAuctionConfigId;
     }
 
     static address(programId: PublicKey) {
@@ -8,6 +8,7 @@ export type FastFillInfo = {
     amount: BN;
     redeemer: PublicKey;
     timestamp: BN;
+    mint: PublicKey;
 };
 
 export type FastFillSeeds = {
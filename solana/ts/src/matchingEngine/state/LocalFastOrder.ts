@@ -0,0 +1,45 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { Uint64, writeUint64BE } from "../../common";
+
+export class LocalFastOrder {
+    bump: number;
+    custodyTokenBump: number;
+    preparedBy: PublicKey;
+    fastVaaSequence: BN;
+    amountIn: BN;
+    baseFee: BN;
+
+    constructor(
+        bump: number,
+        custodyTokenBump: number,
+        preparedBy: PublicKey,
+        fastVaaSequence: BN,
+        amountIn: BN,
+        baseFee: BN,
+    ) {
+        this.bump = bump;
+        this.custodyTokenBump = custodyTokenBump;
+        this.preparedBy = preparedBy;
+        this.fastVaaSequence = fastVaaSequence;
+        this.amountIn = amountIn;
+        this.baseFee = baseFee;
+    }
+
+    static address(programId: PublicKey, fastVaaSequence: Uint64) {
+        const encodedSequence = Buffer.alloc(8);
+        writeUint64BE(encodedSequence, fastVaaSequence);
+
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("local-fast-order"), encodedSequence],
+            programId,
+        )[0];
+    }
+
+    static custodyTokenAddress(programId: PublicKey, localFastOrder: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("deposit-custody"), localFastOrder.toBuffer()],
+            programId,
+        )[0];
+    }
+}
@@ -0,0 +1,47 @@
+import { PublicKey } from "@solana/web3.js";
+import { keccak256 } from "@wormhole-foundation/sdk-definitions";
+import { Uint64, writeUint64BE } from "../../common";
+
+export class OfferCommitment {
+    bump: number;
+    custodyTokenBump: number;
+    auction: PublicKey;
+    offerToken: PublicKey;
+    preparedBy: PublicKey;
+    commitment: number[];
+
+    constructor(
+        bump: number,
+        custodyTokenBump: number,
+        auction: PublicKey,
+        offerToken: PublicKey,
+        preparedBy: PublicKey,
+        commitment: number[],
+    ) {
+        this.bump = bump;
+        this.custodyTokenBump = custodyTokenBump;
+        this.auction = auction;
+        this.offerToken = offerToken;
+        this.preparedBy = preparedBy;
+        this.commitment = commitment;
+    }
+
+    static address(programId: PublicKey, auction: PublicKey, offerToken: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("offer-commitment"), auction.toBuffer(), offerToken.toBuffer()],
+            programId,
+        )[0];
+    }
+
+    /**
+     * Computes keccak256(offer_token, offer_price, salt), which is committed to a sealed-bid
+     * auction before the offer is revealed.
+     */
+    static compute(offerToken: PublicKey, offerPrice: Uint64, salt: Buffer | Uint8Array) {
+        const encodedOfferPrice = Buffer.alloc(8);
+        writeUint64BE(encodedOfferPrice, offerPrice);
+        return Buffer.from(
+            keccak256(Buffer.concat([offerToken.toBuffer(), encodedOfferPrice, Buffer.from(salt)])),
+        );
+    }
+}
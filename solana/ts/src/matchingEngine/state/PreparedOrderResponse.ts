@@ -18,6 +18,10 @@ export type PreparedOrderResponseInfo = {
     sender: Array<number>;
     redeemer: Array<number>;
     amountIn: BN;
+    minAmountOut: BN;
+    refundAddress: Array<number>;
+    mint: PublicKey;
+    destinationAsset: Array<number> | null;
 };
 
 export class PreparedOrderResponse {
@@ -9,6 +9,16 @@ export type ProposalAction = {
         id: number;
         parameters: AuctionParameters;
     };
+    updateRouteAuctionParameters?: {
+        sourceChain: number;
+        targetChain: number;
+        id: number;
+        parameters: AuctionParameters;
+    };
+    removeRouteAuctionParameters?: {
+        sourceChain: number;
+        targetChain: number;
+    };
 };
 
 export class Proposal {
@@ -0,0 +1,29 @@
+import { PublicKey } from "@solana/web3.js";
+import { ChainId } from "@wormhole-foundation/sdk-base";
+
+export class RouteAuctionConfig {
+    bump: number;
+    sourceChain: number;
+    targetChain: number;
+    configId: number;
+
+    constructor(bump: number, sourceChain: number, targetChain: number, configId: number) {
+        this.bump = bump;
+        this.sourceChain = sourceChain;
+        this.targetChain = targetChain;
+        this.configId = configId;
+    }
+
+    static address(programId: PublicKey, sourceChain: ChainId, targetChain: ChainId) {
+        const encodedSourceChain = Buffer.alloc(2);
+        encodedSourceChain.writeUInt16BE(sourceChain);
+
+        const encodedTargetChain = Buffer.alloc(2);
+        encodedTargetChain.writeUInt16BE(targetChain);
+
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("route-auction-config"), encodedSourceChain, encodedTargetChain],
+            programId,
+        )[0];
+    }
+}
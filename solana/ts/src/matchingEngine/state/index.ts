@@ -1,10 +1,15 @@
 export * from "./Auction";
 export * from "./AuctionConfig";
 export * from "./AuctionHistory";
+export * from "./AuctionTranche";
+export * from "./CctpMint";
 export * from "./Custodian";
 export * from "./FastFill";
 export * from "./FastFillSequencer";
+export * from "./LocalFastOrder";
+export * from "./OfferCommitment";
 export * from "./PreparedOrderResponse";
 export * from "./Proposal";
 export * from "./ReservedFastFillSequence";
+export * from "./RouteAuctionConfig";
 export * from "./RouterEndpoint";
@@ -27,8 +27,8 @@ import * as matchingEngineSdk from "../matchingEngine";
 import { UpgradeManagerProgram } from "../upgradeManager";
 import { BPF_LOADER_UPGRADEABLE_PROGRAM_ID, programDataAddress } from "../utils";
 import { VaaAccount } from "../wormhole";
-import { Custodian, PreparedFill, PreparedOrder } from "./state";
-import { ChainId, isChainId } from "@wormhole-foundation/sdk-base";
+import { Custodian, FastTransferParameters, PreparedFill, PreparedOrder } from "./state";
+import { ChainId, isChainId, toChainId } from "@wormhole-foundation/sdk-base";
 
 export const PROGRAM_IDS = [
     "TokenRouter11111111111111111111111111111111",
@@ -46,6 +46,11 @@ export type PrepareMarketOrderArgs = {
     redeemerMessage: Buffer;
 };
 
+export type PrepareFastMarketOrderArgs = PrepareMarketOrderArgs & {
+    maxFee: bigint;
+    deadline: number;
+};
+
 export type PublishMessageAccounts = {
     coreBridgeConfig: PublicKey;
     coreEmitterSequence: PublicKey;
@@ -78,6 +83,8 @@ export type TokenRouterCommonAccounts = PublishMessageAccounts & {
 
 export type RedeemFillCctpAccounts = {
     custodian: PublicKey;
+    mint: PublicKey;
+    cctpMint: PublicKey;
     preparedFill: PublicKey;
     cctpMintRecipient: PublicKey;
     sourceRouterEndpoint: PublicKey;
@@ -98,6 +105,7 @@ export type RedeemFillCctpAccounts = {
 
 export type RedeemFastFillAccounts = {
     custodian: PublicKey;
+    mint: PublicKey;
     preparedFill: PublicKey;
     cctpMintRecipient: PublicKey;
     matchingEngineCustodian: PublicKey;
@@ -150,8 +158,12 @@ export class TokenRouterProgram {
         return this.program.account.custodian.fetch(addr);
     }
 
-    cctpMintRecipientAddress(): PublicKey {
-        return splToken.getAssociatedTokenAddressSync(this.mint, this.custodianAddress(), true);
+    cctpMintRecipientAddress(mint?: PublicKey): PublicKey {
+        return splToken.getAssociatedTokenAddressSync(
+            mint ?? this.mint,
+            this.custodianAddress(),
+            true,
+        );
     }
 
     preparedCustodyTokenAddress(preparedAccount: PublicKey): PublicKey {
@@ -194,7 +206,7 @@ export class TokenRouterProgram {
 
     transferAuthorityAddress(
         preparedOrder: PublicKey,
-        args: PrepareMarketOrderArgs,
+        args: PrepareMarketOrderArgs | PrepareFastMarketOrderArgs,
         refundToken: PublicKey,
     ): PublicKey {
         const { amountIn, minAmountOut, targetChain, redeemer, redeemerMessage } = args;
@@ -212,6 +224,17 @@ export class TokenRouterProgram {
         );
         hasher.update(Buffer.from(redeemer));
         hasher.update(redeemerMessage);
+        if ("maxFee" in args) {
+            const { maxFee, deadline } = args;
+            hasher.update(uint64ToBN(maxFee).toBuffer("be", 8));
+            hasher.update(
+                (() => {
+                    const buf = Buffer.alloc(4);
+                    buf.writeUInt32BE(deadline);
+                    return buf;
+                })(),
+            );
+        }
 
         return PublicKey.findProgramAddressSync(
             [
@@ -350,7 +373,7 @@ export class TokenRouterProgram {
             refundToken: PublicKey;
             senderTokenAuthority?: PublicKey;
         },
-        args: PrepareMarketOrderArgs,
+        args: PrepareMarketOrderArgs | PrepareFastMarketOrderArgs,
     ): Promise<{ transferAuthority: PublicKey; ix: TransactionInstruction }> {
         const { preparedOrder, senderToken, refundToken } = accounts;
         const { amountIn } = args;
@@ -454,6 +477,87 @@ export class TokenRouterProgram {
         return [approveIx, prepareIx];
     }
 
+    async prepareFastMarketOrderIx(
+        accounts: {
+            payer: PublicKey;
+            preparedOrder: PublicKey;
+            senderToken: PublicKey;
+            senderTokenAuthority?: PublicKey;
+            refundToken?: PublicKey;
+            programTransferAuthority?: PublicKey | null;
+            sender?: PublicKey | null;
+            targetRouterEndpoint?: PublicKey;
+        },
+        args: { useTransferAuthority?: boolean } & PrepareFastMarketOrderArgs,
+    ): Promise<[approveIx: TransactionInstruction | null, prepareIx: TransactionInstruction]> {
+        const {
+            payer,
+            preparedOrder,
+            senderToken,
+            senderTokenAuthority,
+            targetRouterEndpoint: endpoint,
+        } = accounts;
+
+        let { refundToken, programTransferAuthority, sender } = accounts;
+        refundToken ??= senderToken;
+
+        let { useTransferAuthority } = args;
+        useTransferAuthority ??= true;
+
+        let approveIx: TransactionInstruction | null = null;
+
+        if (sender === undefined) {
+            sender = null;
+        }
+
+        if (programTransferAuthority === undefined) {
+            if (useTransferAuthority) {
+                const approveResult = await this.approveTransferAuthorityIx(
+                    { preparedOrder, senderToken, refundToken, senderTokenAuthority },
+                    args,
+                );
+                programTransferAuthority = approveResult.transferAuthority;
+                approveIx = approveResult.ix;
+            } else {
+                programTransferAuthority = null;
+            }
+        }
+
+        const targetRouterEndpoint = this.registeredEndpointComposite({
+            chain: args.targetChain,
+            endpoint,
+        });
+
+        const prepareIx = await this.program.methods
+            .prepareFastMarketOrder({
+                amountIn: uint64ToBN(args.amountIn),
+                minAmountOut: args.minAmountOut === null ? null : uint64ToBN(args.minAmountOut),
+                targetChain: args.targetChain,
+                redeemer: args.redeemer,
+                redeemerMessage: args.redeemerMessage,
+                maxFee: uint64ToBN(args.maxFee),
+                deadline: args.deadline,
+            })
+            .accounts({
+                payer,
+                custodian: this.checkedCustodianComposite(),
+                programTransferAuthority,
+                // @ts-ignore Sender can be null.
+                sender,
+                preparedOrder,
+                senderToken,
+                refundToken,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
+                usdc: this.usdcComposite(),
+                targetRouterEndpoint,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+
+        return [approveIx, prepareIx];
+    }
+
     async closePreparedOrderIx(accounts: {
         preparedOrder: PublicKey;
         preparedBy?: PublicKey;
@@ -606,12 +710,83 @@ export class TokenRouterProgram {
             .instruction();
     }
 
+    async placeFastMarketOrderIx(accounts: {
+        payer: PublicKey;
+        preparedOrder: PublicKey;
+        preparedBy?: PublicKey;
+        targetRouterEndpoint?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { payer, preparedOrder, targetRouterEndpoint: endpoint } = accounts;
+        let { preparedBy } = accounts;
+
+        const { info } = await this.fetchPreparedOrder(preparedOrder).catch((_) => {
+            throw new Error("Cannot find prepared order");
+        });
+        preparedBy ??= info.preparedBy;
+
+        if (!isChainId(info.targetChain)) {
+            throw new Error("Invalid chain found in prepared order");
+        }
+        const targetRouterEndpoint = this.registeredEndpointComposite({
+            chain: info.targetChain,
+            endpoint,
+        });
+
+        const custodian = this.custodianAddress();
+        const { coreBridgeConfig, coreEmitterSequence, coreFeeCollector, coreBridgeProgram } =
+            this.publishMessageAccounts(custodian);
+
+        // The Matching Engine keys the deposit by the sequence of the fast market order message,
+        // which is the emitter's next sequence (zero if this emitter has never published).
+        const fastVaaSequence = await this.program.provider.connection
+            .getAccountInfo(coreEmitterSequence)
+            .then((acctInfo) =>
+                acctInfo === null || acctInfo.data.length < 8
+                    ? 0n
+                    : acctInfo.data.readBigUInt64LE(0),
+            );
+
+        const matchingEngine = this.matchingEngineProgram();
+        const localFastOrder = matchingEngine.localFastOrderAddress(fastVaaSequence);
+
+        return this.program.methods
+            .placeFastMarketOrder()
+            .accounts({
+                payer,
+                preparedBy,
+                custodian: this.checkedCustodianComposite(),
+                preparedOrder,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
+                targetRouterEndpoint,
+                matchingEngineCustodian: matchingEngine.custodianAddress(),
+                localRouterEndpoint: matchingEngine.routerEndpointAddress(toChainId("Solana")),
+                localFastOrder,
+                localFastOrderCustodyToken:
+                    matchingEngine.localFastOrderCustodyTokenAddress(localFastOrder),
+                usdc: this.usdcComposite(),
+                coreBridgeConfig,
+                coreMessage: this.coreMessageAddress(preparedOrder),
+                coreEmitterSequence,
+                coreFeeCollector,
+                coreBridgeProgram,
+                matchingEngineProgram: matchingEngine.ID,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                clock: SYSVAR_CLOCK_PUBKEY,
+                rent: SYSVAR_RENT_PUBKEY,
+            })
+            .instruction();
+    }
+
     async redeemCctpFillAccounts(
         fillVaa: PublicKey,
         cctpMessage: CctpTokenBurnMessage | Buffer,
+        mint?: PublicKey,
     ): Promise<RedeemFillCctpAccounts> {
+        mint ??= this.mint;
+
         const msg = CctpTokenBurnMessage.from(cctpMessage);
-        const cctpMintRecipient = this.cctpMintRecipientAddress();
+        const cctpMintRecipient = this.cctpMintRecipientAddress(mint);
 
         const vaaAccount = await VaaAccount.fetch(this.program.provider.connection, fillVaa);
         const { chain } = vaaAccount.emitterInfo();
@@ -631,13 +806,12 @@ export class TokenRouterProgram {
             custodyToken: tokenMessengerMinterCustodyToken,
             tokenMessengerMinterEventAuthority,
             messageTransmitterProgram,
-        } = this.messageTransmitterProgram().receiveTokenMessengerMinterMessageAccounts(
-            this.mint,
-            msg,
-        );
+        } = this.messageTransmitterProgram().receiveTokenMessengerMinterMessageAccounts(mint, msg);
 
         return {
             custodian: this.custodianAddress(),
+            mint,
+            cctpMint: this.matchingEngineProgram().cctpMintAddress(mint),
             preparedFill,
             cctpMintRecipient,
             sourceRouterEndpoint: this.matchingEngineProgram().routerEndpointAddress(chain),
@@ -662,6 +836,7 @@ export class TokenRouterProgram {
             payer: PublicKey;
             vaa: PublicKey;
             sourceRouterEndpoint?: PublicKey;
+            mint?: PublicKey;
         },
         args: {
             encodedCctpMessage: Buffer;
@@ -672,6 +847,8 @@ export class TokenRouterProgram {
         const { encodedCctpMessage } = args;
 
         const {
+            mint,
+            cctpMint,
             preparedFill,
             cctpMintRecipient,
             sourceRouterEndpoint: derivedRouterEndpoint,
@@ -688,7 +865,7 @@ export class TokenRouterProgram {
             tokenMessengerMinterProgram,
             messageTransmitterProgram,
             tokenMessengerMinterEventAuthority,
-        } = await this.redeemCctpFillAccounts(vaa, encodedCctpMessage);
+        } = await this.redeemCctpFillAccounts(vaa, encodedCctpMessage, accounts.mint);
         const sourceRouterEndpoint = this.registeredEndpointComposite({
             endpoint: endpoint ?? derivedRouterEndpoint,
         });
@@ -701,7 +878,7 @@ export class TokenRouterProgram {
                 fillVaa: this.liquidityLayerVaaComposite(vaa),
                 preparedFill,
                 preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
-                usdc: this.usdcComposite(),
+                mint: { mint, cctpMint },
                 sourceRouterEndpoint,
                 cctp: {
                     mintRecipient: { mintRecipient: cctpMintRecipient },
@@ -728,6 +905,7 @@ export class TokenRouterProgram {
     async redeemFastFillAccounts(fastFill: PublicKey): Promise<RedeemFastFillAccounts> {
         const {
             custodian: matchingEngineCustodian,
+            mint,
             fromRouterEndpoint: matchingEngineFromEndpoint,
             toRouterEndpoint: matchingEngineToEndpoint,
             localCustodyToken: matchingEngineLocalCustodyToken,
@@ -737,8 +915,9 @@ export class TokenRouterProgram {
 
         return {
             custodian: this.custodianAddress(),
+            mint,
             preparedFill: this.preparedFillAddress(fastFill),
-            cctpMintRecipient: this.cctpMintRecipientAddress(),
+            cctpMintRecipient: this.cctpMintRecipientAddress(mint),
             matchingEngineCustodian,
             matchingEngineFromEndpoint,
             matchingEngineToEndpoint,
@@ -753,6 +932,7 @@ export class TokenRouterProgram {
     }): Promise<TransactionInstruction> {
         const { payer, fastFill } = accounts;
         const {
+            mint,
             preparedFill,
             matchingEngineCustodian,
             matchingEngineFromEndpoint,
@@ -770,11 +950,13 @@ export class TokenRouterProgram {
                 fastFill,
                 preparedFill,
                 preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
-                usdc: this.usdcComposite(),
+                mint,
                 matchingEngineCustodian,
                 matchingEngineFromEndpoint,
                 matchingEngineToEndpoint,
                 matchingEngineLocalCustodyToken,
+                matchingEngineDestinationCustodyToken: null,
+                fastFillPreparedBy: null,
                 matchingEngineEventAuthority,
                 matchingEngineProgram,
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
@@ -883,6 +1065,25 @@ export class TokenRouterProgram {
             .instruction();
     }
 
+    async updateFastTransferParametersIx(
+        accounts: {
+            authority: PublicKey;
+            custodian?: PublicKey;
+        },
+        params: FastTransferParameters,
+    ): Promise<TransactionInstruction> {
+        const { authority, custodian: inputCustodian } = accounts;
+        return this.program.methods
+            .updateFastTransferParameters(params)
+            .accounts({
+                admin: {
+                    authority,
+                    custodian: inputCustodian ?? this.custodianAddress(),
+                },
+            })
+            .instruction();
+    }
+
     publishMessageAccounts(emitter: PublicKey): PublishMessageAccounts {
         const coreBridgeProgram = this.coreBridgeProgramId();
 
@@ -1,6 +1,14 @@
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { emitterAddress } from "../../common";
 
+export type FastTransferParameters = {
+    enabled: boolean;
+    maxAmount: BN;
+    baseFee: BN;
+    initAuctionFee: BN;
+};
+
 export class Custodian {
     paused: boolean;
     owner: PublicKey;
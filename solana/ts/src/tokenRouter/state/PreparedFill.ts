@@ -20,6 +20,8 @@ export type PreparedFillInfo = {
     orderSender: Array<number>;
     redeemer: PublicKey;
     timestamp: BN;
+    mint: PublicKey;
+    amount: BN;
 };
 
 export class PreparedFill {
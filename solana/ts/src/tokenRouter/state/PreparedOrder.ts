@@ -7,6 +7,12 @@ export type OrderType = {
     market?: {
         minAmountOut: BN | null;
     };
+    fastMarket?: {
+        minAmountOut: BN | null;
+        maxFee: BN;
+        initAuctionFee: BN;
+        deadline: number;
+    };
 };
 
 export type PreparedOrderInfo = {
@@ -111,6 +111,10 @@ describe("Matching Engine", function () {
         minOfferDeltaBps: 20_000, // 2%
         securityDepositBase: uint64ToBN(4_200_000n),
         securityDepositBps: 5_000, // 0.5%
+        revealPeriod: 0,
+        extensionWindow: 0,
+        maxExtension: 0,
+        numTranches: 0,
     };
 
     let testCctpNonce = 2n ** 64n - 1n;
@@ -407,6 +411,7 @@ describe("Matching Engine", function () {
                         feeRecipientToken,
                         expectedAuctionConfigId,
                         uint64ToBN(0),
+                        expectedAuctionConfigId,
                     ),
                 );
 
@@ -982,6 +987,10 @@ describe("Matching Engine", function () {
                 minOfferDeltaBps: 10_000, // 1%
                 securityDepositBase: uint64ToBN(69),
                 securityDepositBps: 100_000, // 10%
+                revealPeriod: 0,
+                extensionWindow: 0,
+                maxExtension: 0,
+                numTranches: 0,
             };
 
             const localVariables = new Map<string, any>();
@@ -1269,6 +1278,10 @@ describe("Matching Engine", function () {
                 minOfferDeltaBps: 50_000, // 5%
                 securityDepositBase: uint64ToBN(690_000), // 0.69 USDC
                 securityDepositBps: 20_000, // 2%
+                revealPeriod: 0,
+                extensionWindow: 0,
+                maxExtension: 0,
+                numTranches: 0,
             };
 
             before("Propose New Auction Parameters as Owner Assistant", async function () {
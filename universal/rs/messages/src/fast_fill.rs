@@ -0,0 +1,126 @@
+//! Fast Fill
+
+use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
+
+use crate::Fill;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastFill {
+    pub fill_amount: u64,
+    pub fill: Fill,
+}
+
+impl Readable for FastFill {
+    fn read<R>(reader: &mut R) -> std::io::Result<Self>
+    where
+        Self: Sized,
+        R: std::io::Read,
+    {
+        Ok(Self {
+            fill_amount: Readable::read(reader)?,
+            fill: Readable::read(reader)?,
+        })
+    }
+}
+
+impl Writeable for FastFill {
+    fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        Self: Sized,
+        W: std::io::Write,
+    {
+        self.fill_amount.write(writer)?;
+        self.fill.write(writer)?;
+        Ok(())
+    }
+}
+
+impl TypePrefixedPayload<1> for FastFill {
+    const TYPE: Option<[u8; 1]> = Some([12]);
+
+    fn written_size(&self) -> usize {
+        const FIXED: usize = 8 // fill_amount
+            ;
+        // This will panic if the size is too large to fit in a usize. But better to panic than to
+        // saturate to usize::MAX.
+        self.fill.written_size().checked_add(FIXED).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::raw;
+    use hex_literal::hex;
+
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let fast_fill = FastFill {
+            fill_amount: 1234567890,
+            fill: Fill {
+                source_chain: 69,
+                order_sender: hex!(
+                    "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"
+                ),
+                redeemer: hex!("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+                redeemer_message: b"All your base are belong to us."
+                    .to_vec()
+                    .try_into()
+                    .unwrap(),
+            },
+        };
+
+        let encoded = fast_fill.to_vec();
+
+        let msg = raw::LiquidityLayerMessage::parse(&encoded).unwrap();
+        let parsed = msg.to_fast_fill_unchecked();
+        let parsed_fill = parsed.fill();
+
+        let expected = FastFill {
+            fill_amount: parsed.fill_amount(),
+            fill: Fill {
+                source_chain: parsed_fill.source_chain(),
+                order_sender: parsed_fill.order_sender(),
+                redeemer: parsed_fill.redeemer(),
+                redeemer_message: parsed_fill
+                    .redeemer_message()
+                    .as_ref()
+                    .to_vec()
+                    .try_into()
+                    .unwrap(),
+            },
+        };
+
+        assert_eq!(fast_fill, expected);
+    }
+
+    #[test]
+    fn evm_encoding() {
+        // Encoded with `Messages.encode(FastFill)` from the EVM shared library.
+        let encoded = hex!(
+            "0c00000000499602d20045deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa0004deadbeef"
+        );
+
+        let fast_fill = FastFill {
+            fill_amount: 1234567890,
+            fill: Fill {
+                source_chain: 69,
+                order_sender: hex!(
+                    "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"
+                ),
+                redeemer: hex!("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+                redeemer_message: hex!("deadbeef").to_vec().try_into().unwrap(),
+            },
+        };
+
+        assert_eq!(fast_fill.to_vec(), encoded.to_vec());
+
+        let parsed = raw::LiquidityLayerMessage::parse(&encoded)
+            .unwrap()
+            .to_fast_fill_unchecked();
+        let parsed_fill = parsed.fill();
+        assert_eq!(parsed.fill_amount(), fast_fill.fill_amount);
+        assert_eq!(parsed_fill.redeemer_message().as_ref(), hex!("deadbeef"));
+    }
+}
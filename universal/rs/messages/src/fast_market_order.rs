@@ -14,6 +14,14 @@ pub struct FastMarketOrder {
     pub init_auction_fee: u64,
     pub deadline: u32,
     pub redeemer_message: WriteableBytes<u16>,
+
+    /// If some, the order requests this asset (by its universal address on the target chain)
+    /// instead of the asset used for auctions. In this case, `min_amount_out` is denominated in
+    /// this destination asset.
+    ///
+    /// NOTE: This field is optional and is encoded after the redeemer message so orders without a
+    /// destination asset keep their existing encoding.
+    pub destination_asset: Option<[u8; 32]>,
 }
 
 impl Readable for FastMarketOrder {
@@ -33,6 +41,20 @@ impl Readable for FastMarketOrder {
             init_auction_fee: Readable::read(reader)?,
             deadline: Readable::read(reader)?,
             redeemer_message: Readable::read(reader)?,
+            destination_asset: {
+                let mut remaining = Vec::with_capacity(32);
+                reader.read_to_end(&mut remaining)?;
+                match remaining.len() {
+                    0 => None,
+                    32 => remaining.try_into().ok(),
+                    _ => {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            "Invalid destination asset",
+                        ))
+                    }
+                }
+            },
         })
     }
 }
@@ -53,6 +75,9 @@ impl Writeable for FastMarketOrder {
         self.init_auction_fee.write(writer)?;
         self.deadline.write(writer)?;
         self.redeemer_message.write(writer)?;
+        if let Some(destination_asset) = &self.destination_asset {
+            destination_asset.write(writer)?;
+        }
         Ok(())
     }
 }
@@ -72,9 +97,17 @@ impl TypePrefixedPayload<1> for FastMarketOrder {
             + 4 // deadline
             + 2 // redeemer_message length
             ;
+        let destination_asset_size = match self.destination_asset {
+            Some(_) => 32,
+            None => 0,
+        };
         // This will panic if the size is too large to fit in a usize. But better to panic than to
         // saturate to usize::MAX.
-        self.redeemer_message.len().checked_add(FIXED).unwrap()
+        self.redeemer_message
+            .len()
+            .checked_add(FIXED)
+            .and_then(|size| size.checked_add(destination_asset_size))
+            .unwrap()
     }
 }
 
@@ -103,6 +136,7 @@ mod test {
                 .to_vec()
                 .try_into()
                 .unwrap(),
+            destination_asset: None,
         };
 
         let encoded = fast_market_order.to_vec();
@@ -126,8 +160,50 @@ mod test {
                 .to_vec()
                 .try_into()
                 .unwrap(),
+            destination_asset: parsed.destination_asset(),
         };
 
         assert_eq!(fast_market_order, expected);
     }
+
+    #[test]
+    fn serde_destination_asset() {
+        let fast_market_order = FastMarketOrder {
+            amount_in: 1234567890,
+            min_amount_out: 69420,
+            target_chain: 1,
+            redeemer: hex!("deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"),
+            sender: hex!("beefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdead"),
+            refund_address: hex!(
+                "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+            ),
+            max_fee: 1234567890,
+            init_auction_fee: 69420,
+            deadline: 420,
+            redeemer_message: b"All your base are belong to us."
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            destination_asset: Some(hex!(
+                "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+            )),
+        };
+
+        let encoded = fast_market_order.to_vec();
+        assert_eq!(encoded[1..].len(), fast_market_order.written_size());
+
+        let msg = raw::LiquidityLayerMessage::parse(&encoded).unwrap();
+        let parsed = msg.to_fast_market_order_unchecked();
+        assert_eq!(
+            parsed.redeemer_message().as_ref(),
+            b"All your base are belong to us."
+        );
+        assert_eq!(
+            parsed.destination_asset(),
+            fast_market_order.destination_asset
+        );
+
+        let decoded = FastMarketOrder::read(&mut &encoded[1..]).unwrap();
+        assert_eq!(fast_market_order, decoded);
+    }
 }
@@ -1,6 +1,9 @@
 mod deposit;
 pub use deposit::*;
 
+mod fast_fill;
+pub use fast_fill::*;
+
 mod fast_market_order;
 pub use fast_market_order::*;
 
@@ -8,6 +8,7 @@ use wormhole_raw_vaas::{cctp::Deposit, Payload};
 pub enum LiquidityLayerMessage<'a> {
     Deposit(Deposit<'a>),
     FastMarketOrder(FastMarketOrder<'a>),
+    FastFill(FastFill<'a>),
 }
 
 impl<'a> TryFrom<Payload<'a>> for LiquidityLayerMessage<'a> {
@@ -23,6 +24,7 @@ impl<'a> AsRef<[u8]> for LiquidityLayerMessage<'a> {
         match self {
             Self::Deposit(inner) => inner.as_ref(),
             Self::FastMarketOrder(inner) => inner.as_ref(),
+            Self::FastFill(inner) => inner.as_ref(),
         }
     }
 }
@@ -64,6 +66,22 @@ impl<'a> LiquidityLayerMessage<'a> {
         }
     }
 
+    pub fn fast_fill(&self) -> Option<&FastFill> {
+        match self {
+            Self::FastFill(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn to_fast_fill_unchecked(self) -> FastFill<'a> {
+        match self {
+            Self::FastFill(inner) => inner,
+            // The purpose of using this method is knowing that the enum variant is FastFill.
+            #[allow(clippy::panic)]
+            _ => panic!("LiquidityLayerMessage is not FastFill"),
+        }
+    }
+
     pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
         if span.is_empty() {
             return Err("LiquidityLayerMessage span too short. Need at least 1 byte");
@@ -72,6 +90,7 @@ impl<'a> LiquidityLayerMessage<'a> {
         match span[0] {
             1 => Ok(Self::Deposit(Deposit::parse(&span[1..])?)),
             11 => Ok(Self::FastMarketOrder(FastMarketOrder::parse(&span[1..])?)),
+            12 => Ok(Self::FastFill(FastFill::parse(&span[1..])?)),
             _ => Err("Unknown LiquidityLayerMessage type"),
         }
     }
@@ -128,7 +147,15 @@ impl<'a> FastMarketOrder<'a> {
     }
 
     pub fn redeemer_message(&'a self) -> Payload<'a> {
-        Payload::parse(&self.0[136..])
+        let end = usize::from(self.redeemer_message_len()).saturating_add(136);
+        Payload::parse(&self.0[136..end])
+    }
+
+    /// Universal address of the asset the order requests on the target chain. If none, the order
+    /// is filled with the asset used for auctions.
+    pub fn destination_asset(&self) -> Option<[u8; 32]> {
+        let start = usize::from(self.redeemer_message_len()).saturating_add(136);
+        self.0.get(start..).and_then(|span| span.try_into().ok())
     }
 
     pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
@@ -138,10 +165,14 @@ impl<'a> FastMarketOrder<'a> {
 
         let fast_market_order = Self(span);
 
-        // Check payload length vs actual payload.
-        if fast_market_order.redeemer_message().len()
-            != usize::from(fast_market_order.redeemer_message_len())
-        {
+        // Check payload length vs actual payload. The redeemer message may be followed by the
+        // 32-byte destination asset.
+        let trailing_len = span
+            .len()
+            .saturating_sub(136)
+            .checked_sub(usize::from(fast_market_order.redeemer_message_len()))
+            .ok_or("FastMarketOrder payload length mismatch")?;
+        if trailing_len != 0 && trailing_len != 32 {
             return Err("FastMarketOrder payload length mismatch");
         }
 
@@ -149,6 +180,37 @@ impl<'a> FastMarketOrder<'a> {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FastFill<'a>(&'a [u8]);
+
+impl<'a> AsRef<[u8]> for FastFill<'a> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl<'a> FastFill<'a> {
+    pub fn fill_amount(&self) -> u64 {
+        u64::from_be_bytes(self.0[..8].try_into().unwrap())
+    }
+
+    pub fn fill(&self) -> Fill<'a> {
+        // The fill span was validated when this fast fill was parsed.
+        Fill::parse(&self.0[8..]).unwrap()
+    }
+
+    pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
+        if span.len() < 76 {
+            return Err("FastFill span too short. Need at least 76 bytes");
+        }
+
+        // Validate the encoded fill.
+        Fill::parse(&span[8..])?;
+
+        Ok(Self(span))
+    }
+}
+
 pub trait MessageToVec {
     fn message_to_vec(&self) -> Vec<u8>;
 }
@@ -166,3 +228,9 @@ impl<'a> MessageToVec for FastMarketOrder<'a> {
         msg.to_vec()
     }
 }
+
+impl<'a> MessageToVec for FastFill<'a> {
+    fn message_to_vec(&self) -> Vec<u8> {
+        self.fill().message_to_vec()
+    }
+}